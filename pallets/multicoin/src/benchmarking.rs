@@ -1,222 +1,361 @@
-//! Benchmarking setup for pallet-multi-coin
-
-use super::*;
-
-#[allow(unused)]
-use crate::Pallet as MultiCoin;
-use frame_benchmarking::v2::*;
-use frame_system::RawOrigin;
-use frame_support::traits::Currency;
-
-#[benchmarks]
-mod benchmarks {
-	use super::*;
-
-	#[benchmark]
-	fn create_coin() {
-		let caller: T::AccountId = whitelisted_caller();
-		
-		// Fund the caller with enough balance for deposit
-		let deposit = T::CoinDeposit::get();
-		T::Currency::make_free_balance_be(&caller, deposit + deposit);
-		
-		let symbol = b"BENCH".to_vec();
-		let name = b"Benchmark Token".to_vec();
-		let decimals = 18u8;
-		let initial_supply = 1_000_000u128;
-
-		#[extrinsic_call]
-		create_coin(
-			RawOrigin::Signed(caller.clone()),
-			symbol.clone(),
-			name.clone(),
-			decimals,
-			initial_supply
-		);
-
-		// Verify the coin was created
-		assert_eq!(NextCoinId::<T>::get(), 1);
-		assert!(CoinMetadata::<T>::contains_key(0));
-		assert_eq!(TotalSupply::<T>::get(0), initial_supply);
-		assert_eq!(Balances::<T>::get(0, &caller), initial_supply);
-	}
-
-	#[benchmark]
-	fn transfer() {
-		let caller: T::AccountId = whitelisted_caller();
-		let recipient: T::AccountId = account("recipient", 0, 0);
-		
-		// Setup: create a coin with a transfer fee
-		let deposit = T::CoinDeposit::get();
-		T::Currency::make_free_balance_be(&caller, deposit + deposit);
-		
-		assert_ok!(MultiCoin::<T>::create_coin(
-			RawOrigin::Signed(caller.clone()).into(),
-			b"TEST".to_vec(),
-			b"Test Token".to_vec(),
-			18,
-			1_000_000,
-			None,
-			None,
-		));
-	
-		let coin_id = 0u32;
-		assert_ok!(MultiCoin::<T>::set_fee_config(
-			RawOrigin::Signed(caller.clone()).into(),
-			coin_id,
-			100, // Transfer fee
-			50,  // Minimum balance
-		));
-	
-		let amount = 500_000u128;
-	
-		#[extrinsic_call]
-		transfer(RawOrigin::Signed(caller.clone()), coin_id, recipient.clone(), amount);
-	
-		// Verify the transfer
-		assert_eq!(Balances::<T>::get(coin_id, &caller), 500_000 - 100); // Amount - fee
-		assert_eq!(Balances::<T>::get(coin_id, &recipient), 500_000);
-		assert_eq!(TotalSupply::<T>::get(coin_id), 1_000_000 - 100); // Fee burned
-	}
-
-	#[benchmark]
-	fn mint() {
-		let caller: T::AccountId = whitelisted_caller();
-		let recipient: T::AccountId = account("recipient", 0, 0);
-		
-		// Setup: create a coin first
-		let deposit = T::CoinDeposit::get();
-		T::Currency::make_free_balance_be(&caller, deposit + deposit);
-		
-		assert_ok!(MultiCoin::<T>::create_coin(
-			RawOrigin::Signed(caller.clone()).into(),
-			b"MINT".to_vec(),
-			b"Mint Token".to_vec(),
-			18,
-			1_000_000,
-		));
-
-		let coin_id = 0u32;
-		let amount = 500_000u128;
-
-		#[extrinsic_call]
-		mint(RawOrigin::Signed(caller), coin_id, recipient.clone(), amount);
-
-		// Verify the mint
-		assert_eq!(Balances::<T>::get(coin_id, &recipient), amount);
-		assert_eq!(TotalSupply::<T>::get(coin_id), 1_500_000);
-	}
-
-	#[benchmark]
-	fn burn() {
-		let caller: T::AccountId = whitelisted_caller();
-		
-		// Setup: create a coin first
-		let deposit = T::CoinDeposit::get();
-		T::Currency::make_free_balance_be(&caller, deposit + deposit);
-		
-		assert_ok!(MultiCoin::<T>::create_coin(
-			RawOrigin::Signed(caller.clone()).into(),
-			b"BURN".to_vec(),
-			b"Burn Token".to_vec(),
-			18,
-			1_000_000,
-		));
-
-		let coin_id = 0u32;
-		let amount = 300_000u128;
-
-		#[extrinsic_call]
-		burn(RawOrigin::Signed(caller.clone()), coin_id, amount);
-
-		// Verify the burn
-		assert_eq!(Balances::<T>::get(coin_id, &caller), 700_000);
-		assert_eq!(TotalSupply::<T>::get(coin_id), 700_000);
-	}
-
-	#[benchmark]
-	fn transfer_ownership() {
-		let caller: T::AccountId = whitelisted_caller();
-		let new_owner: T::AccountId = account("new_owner", 0, 0);
-		
-		// Setup: create a coin first
-		let deposit = T::CoinDeposit::get();
-		T::Currency::make_free_balance_be(&caller, deposit + deposit);
-		
-		assert_ok!(MultiCoin::<T>::create_coin(
-			RawOrigin::Signed(caller.clone()).into(),
-			b"OWN".to_vec(),
-			b"Ownership Token".to_vec(),
-			18,
-			1_000_000,
-		));
-
-		let coin_id = 0u32;
-
-		#[extrinsic_call]
-		transfer_ownership(RawOrigin::Signed(caller.clone()), coin_id, new_owner.clone());
-
-		// Verify ownership transfer
-		let coin_info = CoinMetadata::<T>::get(coin_id).unwrap();
-		assert_eq!(coin_info.owner, new_owner);
-		assert_eq!(MintPermissions::<T>::get(coin_id, &caller), false);
-		assert_eq!(MintPermissions::<T>::get(coin_id, &new_owner), true);
-	}
-
-	#[benchmark]
-	fn set_mint_permission() {
-		let caller: T::AccountId = whitelisted_caller();
-		let grantee: T::AccountId = account("grantee", 0, 0);
-		
-		// Setup: create a coin first
-		let deposit = T::CoinDeposit::get();
-		T::Currency::make_free_balance_be(&caller, deposit + deposit);
-		
-		assert_ok!(MultiCoin::<T>::create_coin(
-			RawOrigin::Signed(caller.clone()).into(),
-			b"PERM".to_vec(),
-			b"Permission Token".to_vec(),
-			18,
-			1_000_000,
-		));
-
-		let coin_id = 0u32;
-
-		#[extrinsic_call]
-		set_mint_permission(RawOrigin::Signed(caller), coin_id, grantee.clone(), true);
-
-		// Verify permission granted
-		assert_eq!(MintPermissions::<T>::get(coin_id, &grantee), true);
-	}
-
-	#[benchmark]
-	fn set_fee_config() {
-		let caller: T::AccountId = whitelisted_caller();
-		
-		// Setup: create a coin
-		let deposit = T::CoinDeposit::get();
-		T::Currency::make_free_balance_be(&caller, deposit + deposit);
-		
-		assert_ok!(MultiCoin::<T>::create_coin(
-			RawOrigin::Signed(caller.clone()).into(),
-			b"FEE".to_vec(),
-			b"Fee Token".to_vec(),
-			18,
-			1_000_000,
-			None,
-			None,
-		));
-
-		let coin_id = 0u32;
-
-		#[extrinsic_call]
-		set_fee_config(RawOrigin::Signed(caller), coin_id, 100, 50);
-
-		// Verify the fee config
-		let coin_info = CoinMetadata::<T>::get(coin_id).unwrap();
-		assert_eq!(coin_info.fee_config.transfer_fee, 100);
-		assert_eq!(coin_info.fee_config.minimum_balance, 50);
-	}
-
-	impl_benchmark_test_suite!(MultiCoin, crate::mock::new_test_ext(), crate::mock::Test);
-}
+//! Benchmarking setup for pallet-multi-coin
+//!
+//! Benchmarks run against a worst-case pre-state: `MaxCoins` coins already exist,
+//! the measured coin has many holders and permission grants, and every optional
+//! per-coin check (holder cap, decay, dormancy tracking) is enabled.
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as MultiCoin;
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+use frame_support::{assert_ok, traits::Currency};
+use sp_runtime::Perbill;
+use sp_std::vec::Vec;
+
+const SEED: u32 = 0;
+
+/// Holders and permission grants given to the measured coin
+const WORST_CASE_HOLDERS: u32 = 1_000;
+
+/// Largest `Balances` map the growth benchmarks measure
+const MAX_BALANCE_ENTRIES: u32 = 1_000_000;
+
+/// Give `who` enough native balance to pay the coin creation deposit
+fn fund<T: Config>(who: &T::AccountId) {
+	let deposit = T::CoinDeposit::get();
+	T::Currency::make_free_balance_be(who, deposit + deposit);
+}
+
+/// Create a coin owned by `owner` through the extrinsic, returning its ID
+fn create_coin_for<T: Config>(owner: &T::AccountId, symbol: &[u8]) -> CoinId {
+	fund::<T>(owner);
+	assert_ok!(MultiCoin::<T>::create_coin(
+		RawOrigin::Signed(owner.clone()).into(),
+		symbol.to_vec(),
+		b"Benchmark Token".to_vec(),
+		18,
+		1_000_000,
+		None,
+		None,
+		false,
+		None,
+		None,
+	));
+	NextCoinId::<T>::get() - 1
+}
+
+/// Fill coin storage until only `free` coin IDs are left below `MaxCoins`.
+///
+/// Coins are written directly to storage, as creating thousands of them through
+/// the extrinsic would make every benchmark run take minutes.
+fn populate_coins<T: Config>(free: u32) {
+	let owner: T::AccountId = account("filler", 0, SEED);
+	let target = T::MaxCoins::get().saturating_sub(free);
+	for coin_id in NextCoinId::<T>::get()..target {
+		let mut symbol = b"F".to_vec();
+		symbol.extend_from_slice(&coin_id.to_be_bytes());
+		let symbol: BoundedVec<u8, T::MaxSymbolLength> = symbol.try_into().expect("filler symbol fits");
+		let name: BoundedVec<u8, T::MaxNameLength> =
+			b"Filler".to_vec().try_into().expect("filler name fits");
+		CoinMetadata::<T>::insert(coin_id, CoinInfo {
+			symbol: symbol.clone(),
+			name,
+			decimals: 18,
+			owner: owner.clone(),
+			issuer: owner.clone(),
+			admin: owner.clone(),
+			freezer: owner.clone(),
+			deposit: Zero::zero(),
+			fee_config: FeeConfig::default(),
+			status: CoinStatus::Live,
+		});
+		SymbolToId::<T>::insert(symbol, coin_id);
+		TotalSupply::<T>::insert(coin_id, 1);
+		MultiCoin::<T>::set_balance(coin_id, &owner, 1);
+	}
+	NextCoinId::<T>::put(target.max(NextCoinId::<T>::get()));
+}
+
+/// Give `coin_id` many holders and minters, and enable every optional balance check
+fn populate_holders<T: Config>(coin_id: CoinId) {
+	for i in 0..WORST_CASE_HOLDERS {
+		let holder: T::AccountId = account("holder", i, SEED);
+		MultiCoin::<T>::set_balance(coin_id, &holder, 1);
+		MintPermissions::<T>::insert(coin_id, &holder, true);
+		BurnPermissions::<T>::insert(coin_id, &holder, true);
+	}
+	TotalSupply::<T>::mutate(coin_id, |supply| *supply += WORST_CASE_HOLDERS as u128);
+	MaxHolders::<T>::insert(coin_id, u32::MAX);
+	DecayRates::<T>::insert(coin_id, DecayConfig {
+		rate: Perbill::from_percent(1),
+		period: BlockNumberFor::<T>::from(1_000_000u32),
+	});
+	DormancyConfigs::<T>::insert(coin_id, DormancyConfig {
+		period: BlockNumberFor::<T>::from(1_000_000u32),
+		grace: BlockNumberFor::<T>::from(1_000u32),
+		beneficiary: account("community", 0, SEED),
+	});
+}
+
+/// Fill `Balances` with `n` holders of `coin_id`, written directly so that
+/// millions of entries can be set up in reasonable time
+fn populate_balances<T: Config>(coin_id: CoinId, n: u32) {
+	for i in 0..n {
+		let holder: T::AccountId = account("grower", i, SEED);
+		Balances::<T>::insert(coin_id, holder, 1);
+	}
+	HolderCount::<T>::mutate(coin_id, |count| *count += n);
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn create_coin(
+		m: Linear<0, { T::MaxInitialMinters::get() }>,
+		b: Linear<0, { T::MaxInitialBurners::get() }>,
+	) {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+
+		// Worst case: the last free coin ID, with distinct minters and burners to grant
+		populate_coins::<T>(1);
+		let coin_id = NextCoinId::<T>::get();
+		let minters: Vec<T::AccountId> = (0..m).map(|i| account("minter", i, SEED)).collect();
+		let minters = BoundedVec::try_from(minters).expect("within MaxInitialMinters");
+		let burners: Vec<T::AccountId> = (0..b).map(|i| account("burner", i, SEED)).collect();
+		let burners = BoundedVec::try_from(burners).expect("within MaxInitialBurners");
+
+		let symbol = b"BENCH".to_vec();
+		let name = b"Benchmark Token".to_vec();
+		let decimals = 18u8;
+		let initial_supply = 1_000_000u128;
+
+		#[extrinsic_call]
+		create_coin(
+			RawOrigin::Signed(caller.clone()),
+			symbol.clone(),
+			name.clone(),
+			decimals,
+			initial_supply,
+			Some(minters),
+			Some(burners),
+			true,
+			None,
+			None,
+		);
+
+		// Verify the coin was created
+		assert_eq!(NextCoinId::<T>::get(), T::MaxCoins::get());
+		assert!(CoinMetadata::<T>::contains_key(coin_id));
+		assert_eq!(TotalSupply::<T>::get(coin_id), initial_supply);
+		assert_eq!(Balances::<T>::get(coin_id, &caller), initial_supply);
+	}
+
+	#[benchmark]
+	fn transfer() {
+		let caller: T::AccountId = whitelisted_caller();
+		let recipient: T::AccountId = account("recipient", 0, 0);
+
+		// Setup: a coin with a transfer fee among `MaxCoins` coins and many holders
+		let coin_id = create_coin_for::<T>(&caller, b"TEST");
+		populate_coins::<T>(0);
+		populate_holders::<T>(coin_id);
+		assert_ok!(MultiCoin::<T>::set_fee_config(
+			RawOrigin::Signed(caller.clone()).into(),
+			coin_id,
+			100, // Transfer fee
+			50,  // Minimum balance
+			false,
+			FeeCallScope::Any,
+			None,
+		));
+
+		let amount = 500_000u128;
+
+		// Worst case: the recipient is newly endowed
+		#[extrinsic_call]
+		transfer(RawOrigin::Signed(caller.clone()), coin_id, recipient.clone(), amount, None);
+
+		// Verify the transfer
+		assert_eq!(Balances::<T>::get(coin_id, &caller), 500_000 - 100); // Amount - fee
+		assert_eq!(Balances::<T>::get(coin_id, &recipient), 500_000);
+		assert_eq!(
+			TotalSupply::<T>::get(coin_id),
+			1_000_000 + WORST_CASE_HOLDERS as u128 - 100 // Fee burned
+		);
+	}
+
+	#[benchmark]
+	fn mint() {
+		let caller: T::AccountId = whitelisted_caller();
+		let recipient: T::AccountId = account("recipient", 0, 0);
+
+		// Setup: a coin among `MaxCoins` coins and many holders
+		let coin_id = create_coin_for::<T>(&caller, b"MINT");
+		populate_coins::<T>(0);
+		populate_holders::<T>(coin_id);
+
+		let amount = 500_000u128;
+
+		// Worst case: the recipient is newly endowed
+		#[extrinsic_call]
+		mint(RawOrigin::Signed(caller), coin_id, recipient.clone(), amount, None);
+
+		// Verify the mint
+		assert_eq!(Balances::<T>::get(coin_id, &recipient), amount);
+		assert_eq!(TotalSupply::<T>::get(coin_id), 1_500_000 + WORST_CASE_HOLDERS as u128);
+	}
+
+	#[benchmark]
+	fn burn() {
+		let caller: T::AccountId = whitelisted_caller();
+
+		// Setup: create a coin first
+		let coin_id = create_coin_for::<T>(&caller, b"BURN");
+		let amount = 300_000u128;
+
+		#[extrinsic_call]
+		burn(RawOrigin::Signed(caller.clone()), coin_id, amount, None);
+
+		// Verify the burn
+		assert_eq!(Balances::<T>::get(coin_id, &caller), 700_000);
+		assert_eq!(TotalSupply::<T>::get(coin_id), 700_000);
+	}
+
+	#[benchmark]
+	fn transfer_ownership() {
+		let caller: T::AccountId = whitelisted_caller();
+		let new_owner: T::AccountId = account("new_owner", 0, 0);
+		fund::<T>(&new_owner);
+
+		// Setup: create a coin first
+		let coin_id = create_coin_for::<T>(&caller, b"OWN");
+
+		// Worst case: revoking the old owner's grants writes to both permission maps,
+		// and the deposit moves to the new owner
+		#[extrinsic_call]
+		transfer_ownership(RawOrigin::Signed(caller.clone()), coin_id, new_owner.clone(), true, true, None);
+
+		// Verify ownership transfer
+		let coin_info = CoinMetadata::<T>::get(coin_id).unwrap();
+		assert_eq!(coin_info.owner, new_owner);
+		assert_eq!(CoinDepositors::<T>::get(coin_id), Some(new_owner.clone()));
+		assert_eq!(MintPermissions::<T>::get(coin_id, &caller), false);
+		assert_eq!(BurnPermissions::<T>::get(coin_id, &caller), false);
+		assert_eq!(MintPermissions::<T>::get(coin_id, &new_owner), true);
+		assert_eq!(BurnPermissions::<T>::get(coin_id, &new_owner), true);
+	}
+
+	#[benchmark]
+	fn set_mint_permission() {
+		let caller: T::AccountId = whitelisted_caller();
+		let grantee: T::AccountId = account("grantee", 0, 0);
+
+		// Setup: create a coin first
+		let coin_id = create_coin_for::<T>(&caller, b"PERM");
+
+		#[extrinsic_call]
+		set_mint_permission(RawOrigin::Signed(caller), coin_id, grantee.clone(), true, None);
+
+		// Verify permission granted
+		assert_eq!(MintPermissions::<T>::get(coin_id, &grantee), true);
+	}
+
+	#[benchmark]
+	fn set_fee_config() {
+		let caller: T::AccountId = whitelisted_caller();
+
+		// Setup: create a coin
+		let coin_id = create_coin_for::<T>(&caller, b"FEE");
+
+		#[extrinsic_call]
+		set_fee_config(RawOrigin::Signed(caller), coin_id, 100, 50, true, FeeCallScope::OwnPalletOnly, None);
+
+		// Verify the fee config
+		let coin_info = CoinMetadata::<T>::get(coin_id).unwrap();
+		assert_eq!(coin_info.fee_config.transfer_fee, 100);
+		assert_eq!(coin_info.fee_config.minimum_balance, 50);
+	}
+
+	#[benchmark]
+	fn balances_many(n: Linear<1, 100>) {
+		// Worst case: every queried coin decays and rebases, so each balance
+		// reads its decay rate, last touch and accrual index
+		populate_coins::<T>(0);
+		let holder: T::AccountId = account("filler", 0, SEED);
+		let coin_ids: Vec<CoinId> = (0..n).collect();
+		for coin_id in &coin_ids {
+			DecayRates::<T>::insert(coin_id, DecayConfig {
+				rate: Perbill::from_percent(1),
+				period: BlockNumberFor::<T>::from(1_000_000u32),
+			});
+			LastTouched::<T>::insert(coin_id, &holder, BlockNumberFor::<T>::from(1u32));
+			AccrualIndex::<T>::insert(coin_id, FixedU128::one());
+		}
+
+		let balances;
+		#[block]
+		{
+			balances = MultiCoin::<T>::balances_many(&holder, &coin_ids);
+		}
+
+		assert_eq!(balances.len(), n as usize);
+	}
+
+	// Growth of the `Balances` map. These only profile storage access as the map
+	// grows, so they are `extra` and run with `--extra --pov-mode=measured`; their
+	// results back the hashers of `Balances` rather than a `WeightInfo` function.
+
+	#[benchmark(extra, pov_mode = Measured)]
+	fn balance_read(n: Linear<1, MAX_BALANCE_ENTRIES>) {
+		let owner: T::AccountId = whitelisted_caller();
+		let coin_id = create_coin_for::<T>(&owner, b"GROW");
+		populate_balances::<T>(coin_id, n);
+		let holder: T::AccountId = account("grower", n / 2, SEED);
+
+		let balance;
+		#[block]
+		{
+			balance = Balances::<T>::get(coin_id, &holder);
+		}
+
+		assert_eq!(balance, 1);
+	}
+
+	#[benchmark(extra, pov_mode = Measured)]
+	fn balance_insert(n: Linear<1, MAX_BALANCE_ENTRIES>) {
+		let owner: T::AccountId = whitelisted_caller();
+		let coin_id = create_coin_for::<T>(&owner, b"GROW");
+		populate_balances::<T>(coin_id, n);
+		let holder: T::AccountId = account("newcomer", 0, SEED);
+
+		#[block]
+		{
+			MultiCoin::<T>::set_balance(coin_id, &holder, 1);
+		}
+
+		assert_eq!(Balances::<T>::get(coin_id, &holder), 1);
+	}
+
+	#[benchmark(extra, pov_mode = Measured)]
+	fn balance_remove(n: Linear<1, MAX_BALANCE_ENTRIES>) {
+		let owner: T::AccountId = whitelisted_caller();
+		let coin_id = create_coin_for::<T>(&owner, b"GROW");
+		populate_balances::<T>(coin_id, n);
+		let holder: T::AccountId = account("grower", n / 2, SEED);
+
+		#[block]
+		{
+			MultiCoin::<T>::set_balance(coin_id, &holder, 0);
+		}
+
+		assert!(!Balances::<T>::contains_key(coin_id, &holder));
+	}
+
+	// The `extra` growth benchmarks fill up to a million entries, too slow for tests
+	impl_benchmark_test_suite!(MultiCoin, crate::mock::new_test_ext(), crate::mock::Test, extra = false);
+}
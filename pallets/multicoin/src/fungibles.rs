@@ -0,0 +1,224 @@
+//! `frame_support`'s `fungibles` traits over the coin balances.
+//!
+//! Lets other pallets (asset-conversion, treasury spends, XCM asset transactors)
+//! hold and move coins generically instead of dispatching extrinsics. Amounts are
+//! coin amounts, not stored shares. Minting, burning and transfers go through
+//! `do_mint`, `do_burn` and `do_transfer`, so supply caps, locks, holder limits,
+//! transfer fees and royalties apply exactly as they do to the extrinsics.
+//!
+//! Coins have no existential deposit: a coin's `minimum_balance` is a floor that
+//! holders can't spend below, so it is reported through `reducible_balance` and
+//! `minimum_balance` is always zero.
+
+use crate::{
+	BlockedSinks, CoinMetadata, CoinStatus, Config, Error, FrozenAccounts, HolderCount, MaxHolders, Pallet,
+	TotalSupply,
+};
+use frame_support::{
+	defensive,
+	traits::{
+		tokens::{
+			fungibles::{self, Dust},
+			DepositConsequence, Fortitude, Precision, Preservation, Provenance, WithdrawConsequence,
+		},
+		Get,
+	},
+};
+use sp_runtime::{ArithmeticError, DispatchError};
+use sp_std::vec::Vec;
+
+impl<T: Config> fungibles::Inspect<T::AccountId> for Pallet<T> {
+	type AssetId = crate::CoinId;
+	type Balance = u128;
+
+	fn total_issuance(asset: Self::AssetId) -> Self::Balance {
+		Pallet::<T>::total_supply_of(asset)
+	}
+
+	fn minimum_balance(_asset: Self::AssetId) -> Self::Balance {
+		0
+	}
+
+	fn total_balance(asset: Self::AssetId, who: &T::AccountId) -> Self::Balance {
+		Pallet::<T>::balance_of(who, asset)
+	}
+
+	fn balance(asset: Self::AssetId, who: &T::AccountId) -> Self::Balance {
+		let shares = Pallet::<T>::spendable_shares(asset, who);
+		Pallet::<T>::to_amount(asset, shares.saturating_sub(Pallet::<T>::held_shares(asset, who)))
+	}
+
+	fn reducible_balance(
+		asset: Self::AssetId,
+		who: &T::AccountId,
+		preservation: Preservation,
+		force: Fortitude,
+	) -> Self::Balance {
+		let Some(coin_info) = CoinMetadata::<T>::get(asset) else { return 0 };
+		if coin_info.status != CoinStatus::Live || FrozenAccounts::<T>::contains_key(asset, who) {
+			return 0;
+		}
+		let unlocked = Pallet::<T>::spendable_shares(asset, who)
+			.saturating_sub(Pallet::<T>::locked_shares(asset, who));
+		let unlocked = Pallet::<T>::to_amount(asset, unlocked);
+		// Transfers always keep the floor, burns only when asked to
+		match (preservation, force) {
+			(Preservation::Expendable, Fortitude::Force) => unlocked,
+			_ => unlocked.saturating_sub(coin_info.fee_config.minimum_balance),
+		}
+	}
+
+	fn can_deposit(
+		asset: Self::AssetId,
+		who: &T::AccountId,
+		amount: Self::Balance,
+		provenance: Provenance,
+	) -> DepositConsequence {
+		let Some(coin_info) = CoinMetadata::<T>::get(asset) else {
+			return DepositConsequence::UnknownAsset;
+		};
+		let blocked = BlockedSinks::<T>::contains_key(asset, who) || FrozenAccounts::<T>::contains_key(asset, who);
+		if coin_info.status != CoinStatus::Live || blocked {
+			return DepositConsequence::Blocked;
+		}
+		if amount == 0 {
+			return DepositConsequence::Success;
+		}
+		if let Provenance::Minted = provenance {
+			match Pallet::<T>::total_supply_of(asset).checked_add(amount) {
+				None => return DepositConsequence::Overflow,
+				Some(supply) if supply > T::MaxSupply::get() => return DepositConsequence::Overflow,
+				Some(_) => {},
+			}
+		}
+		if Pallet::<T>::balance_of(who, asset).checked_add(amount).is_none() {
+			return DepositConsequence::Overflow;
+		}
+		let is_new_holder = crate::Balances::<T>::get(asset, who) == 0;
+		let holders_full = MaxHolders::<T>::get(asset).is_some_and(|max| HolderCount::<T>::get(asset) >= max);
+		if is_new_holder && holders_full {
+			return DepositConsequence::CannotCreate;
+		}
+		DepositConsequence::Success
+	}
+
+	fn can_withdraw(
+		asset: Self::AssetId,
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> WithdrawConsequence<Self::Balance> {
+		let Some(coin_info) = CoinMetadata::<T>::get(asset) else {
+			return WithdrawConsequence::UnknownAsset;
+		};
+		if coin_info.status != CoinStatus::Live || FrozenAccounts::<T>::contains_key(asset, who) {
+			return WithdrawConsequence::Frozen;
+		}
+		if amount == 0 {
+			return WithdrawConsequence::Success;
+		}
+		if Pallet::<T>::balance_of(who, asset) < amount {
+			return WithdrawConsequence::BalanceLow;
+		}
+		if Self::reducible_balance(asset, who, Preservation::Expendable, Fortitude::Force) < amount {
+			return WithdrawConsequence::Frozen;
+		}
+		WithdrawConsequence::Success
+	}
+
+	fn asset_exists(asset: Self::AssetId) -> bool {
+		CoinMetadata::<T>::contains_key(asset)
+	}
+}
+
+impl<T: Config> fungibles::Unbalanced<T::AccountId> for Pallet<T> {
+	fn handle_dust(_dust: Dust<T::AccountId, Self>) {
+		defensive!("coins have no existential deposit, so balances never leave dust");
+	}
+
+	fn write_balance(
+		asset: Self::AssetId,
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> Result<Option<Self::Balance>, DispatchError> {
+		CoinMetadata::<T>::get(asset).ok_or(Error::<T>::CoinNotFound)?;
+		Pallet::<T>::apply_decay(asset, who);
+
+		// `amount` is the free balance, holds stay where they are
+		let shares = Pallet::<T>::to_shares(asset, amount)
+			.checked_add(Pallet::<T>::held_shares(asset, who))
+			.ok_or(ArithmeticError::Overflow)?;
+		if crate::Balances::<T>::get(asset, who) == 0 && shares > 0 {
+			Pallet::<T>::ensure_holder_slot(asset)?;
+		}
+		Pallet::<T>::set_balance(asset, who, shares);
+		Ok(None)
+	}
+
+	fn set_total_issuance(asset: Self::AssetId, amount: Self::Balance) {
+		TotalSupply::<T>::insert(asset, Pallet::<T>::to_shares(asset, amount));
+	}
+}
+
+impl<T: Config> fungibles::Mutate<T::AccountId> for Pallet<T> {
+	fn mint_into(
+		asset: Self::AssetId,
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError> {
+		if amount > 0 {
+			Pallet::<T>::do_mint(asset, who, amount)?;
+		}
+		Ok(amount)
+	}
+
+	fn burn_from(
+		asset: Self::AssetId,
+		who: &T::AccountId,
+		amount: Self::Balance,
+		preservation: Preservation,
+		precision: Precision,
+		force: Fortitude,
+	) -> Result<Self::Balance, DispatchError> {
+		let reducible =
+			<Self as fungibles::Inspect<T::AccountId>>::reducible_balance(asset, who, preservation, force);
+		let amount = match precision {
+			Precision::BestEffort => amount.min(reducible),
+			Precision::Exact => {
+				frame_support::ensure!(amount <= reducible, Error::<T>::InsufficientBalance);
+				amount
+			},
+		};
+		if amount > 0 {
+			Pallet::<T>::do_burn(asset, who, amount)?;
+		}
+		Ok(amount)
+	}
+
+	fn transfer(
+		asset: Self::AssetId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: Self::Balance,
+		_preservation: Preservation,
+	) -> Result<Self::Balance, DispatchError> {
+		// The coin's minimum balance is kept whatever the preservation
+		if amount > 0 {
+			Pallet::<T>::do_transfer(asset, source, dest, amount)?;
+		}
+		Ok(amount)
+	}
+}
+
+impl<T: Config> fungibles::metadata::Inspect<T::AccountId> for Pallet<T> {
+	fn name(asset: Self::AssetId) -> Vec<u8> {
+		CoinMetadata::<T>::get(asset).map_or_else(Vec::new, |info| info.name.into_inner())
+	}
+
+	fn symbol(asset: Self::AssetId) -> Vec<u8> {
+		CoinMetadata::<T>::get(asset).map_or_else(Vec::new, |info| info.symbol.into_inner())
+	}
+
+	fn decimals(asset: Self::AssetId) -> u8 {
+		CoinMetadata::<T>::get(asset).map_or(0, |info| info.decimals)
+	}
+}
@@ -0,0 +1,236 @@
+//! Coin imbalances: amounts of a coin moved in or out of balances whose effect on
+//! `TotalSupply` is settled when they are dropped.
+//!
+//! Amounts are in stored units, i.e. shares for rebasing coins. Code moving coins
+//! between accounts and pallet ledgers (fee routing, slashing, minting) passes
+//! imbalances around instead of editing `TotalSupply` by hand; whatever is not
+//! placed somewhere by the time an imbalance is dropped is burned or minted.
+
+use crate::{Balances, CoinId, Config, Event, Holds, Pallet, SubBalances, TotalSupply};
+use sp_runtime::DispatchError;
+use sp_std::marker::PhantomData;
+
+/// Coins credited to an account that are not yet part of the supply.
+///
+/// Dropping it adds its amount to `TotalSupply`.
+#[must_use]
+pub struct PositiveImbalance<T: Config> {
+	coin_id: CoinId,
+	amount: u128,
+	_phantom: PhantomData<T>,
+}
+
+/// Coins debited from an account that are still part of the supply.
+///
+/// Dropping it removes its amount from `TotalSupply`, burning the coins.
+#[must_use]
+pub struct NegativeImbalance<T: Config> {
+	coin_id: CoinId,
+	amount: u128,
+	_phantom: PhantomData<T>,
+}
+
+macro_rules! impl_imbalance {
+	($imbalance:ident) => {
+		impl<T: Config> $imbalance<T> {
+			pub(crate) fn new(coin_id: CoinId, amount: u128) -> Self {
+				Self { coin_id, amount, _phantom: PhantomData }
+			}
+
+			/// An empty imbalance of `coin_id`
+			pub fn zero(coin_id: CoinId) -> Self {
+				Self::new(coin_id, 0)
+			}
+
+			/// The coin this imbalance is in
+			pub fn coin_id(&self) -> CoinId {
+				self.coin_id
+			}
+
+			/// The amount of this imbalance, in stored units
+			pub fn peek(&self) -> u128 {
+				self.amount
+			}
+
+			/// Split off `amount` (at most all of it) into a first imbalance, leaving the rest in a second
+			pub fn split(mut self, amount: u128) -> (Self, Self) {
+				let first = amount.min(self.amount);
+				let second = self.take() - first;
+				(Self::new(self.coin_id, first), Self::new(self.coin_id, second))
+			}
+
+			/// Merge `other` into this imbalance; both must be of the same coin
+			pub fn merge(mut self, mut other: Self) -> Result<Self, (Self, Self)> {
+				if self.coin_id != other.coin_id {
+					return Err((self, other));
+				}
+				self.amount = self.amount.saturating_add(other.take());
+				Ok(self)
+			}
+
+			/// Empty the imbalance without settling it, returning its amount; the
+			/// caller accounts for it elsewhere
+			pub(crate) fn take(&mut self) -> u128 {
+				sp_std::mem::take(&mut self.amount)
+			}
+		}
+
+		impl<T: Config> sp_std::fmt::Debug for $imbalance<T> {
+			fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+				f.debug_struct(stringify!($imbalance))
+					.field("coin_id", &self.coin_id)
+					.field("amount", &self.amount)
+					.finish()
+			}
+		}
+	};
+}
+
+impl_imbalance!(PositiveImbalance);
+impl_imbalance!(NegativeImbalance);
+
+impl<T: Config> Drop for PositiveImbalance<T> {
+	fn drop(&mut self) {
+		if self.amount > 0 {
+			TotalSupply::<T>::mutate(self.coin_id, |supply| *supply = supply.saturating_add(self.amount));
+		}
+	}
+}
+
+impl<T: Config> Drop for NegativeImbalance<T> {
+	fn drop(&mut self) {
+		if self.amount > 0 {
+			TotalSupply::<T>::mutate(self.coin_id, |supply| *supply = supply.saturating_sub(self.amount));
+		}
+	}
+}
+
+/// Handler for coins taken out of balances, e.g. transaction fees
+pub trait OnCoinUnbalanced<T: Config> {
+	/// Place `amount` somewhere; dropping it burns it
+	fn on_unbalanced(amount: NegativeImbalance<T>);
+}
+
+impl<T: Config> OnCoinUnbalanced<T> for () {
+	fn on_unbalanced(amount: NegativeImbalance<T>) {
+		Pallet::<T>::note_fee_burned(amount.coin_id(), Pallet::<T>::to_amount(amount.coin_id(), amount.peek()));
+		drop(amount);
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Credit `shares` to `who`, returning the imbalance that mints them when dropped
+	pub fn deposit_creating(
+		coin_id: CoinId,
+		who: &T::AccountId,
+		shares: u128,
+	) -> Result<PositiveImbalance<T>, DispatchError> {
+		Self::credit(coin_id, who, shares)?;
+		Ok(PositiveImbalance::new(coin_id, shares))
+	}
+
+	/// Debit `shares` from `who`, returning the imbalance that burns them when dropped
+	pub fn withdraw(
+		coin_id: CoinId,
+		who: &T::AccountId,
+		shares: u128,
+	) -> Result<NegativeImbalance<T>, DispatchError> {
+		Self::debit(coin_id, who, shares)?;
+		Ok(NegativeImbalance::new(coin_id, shares))
+	}
+
+	/// Pay the coins of `imbalance` to `who`, leaving the supply unchanged.
+	///
+	/// Hands the imbalance back if `who` can't receive it.
+	pub fn resolve(who: &T::AccountId, mut imbalance: NegativeImbalance<T>) -> Result<(), NegativeImbalance<T>> {
+		if imbalance.peek() == 0 {
+			return Ok(());
+		}
+		if Self::credit(imbalance.coin_id, who, imbalance.peek()).is_err() {
+			return Err(imbalance);
+		}
+		imbalance.take();
+		Ok(())
+	}
+
+	/// Slash up to `amount` of `who`'s balance of `coin_id`, e.g. as a penalty.
+	///
+	/// Locks don't protect against slashing. The spendable balance is slashed
+	/// first, then labeled sub-balances and then holds, each in order. Returns the
+	/// slashed coins and the part of `amount` that could not be slashed.
+	pub fn slash(coin_id: CoinId, who: &T::AccountId, amount: u128) -> (NegativeImbalance<T>, u128) {
+		Self::apply_decay(coin_id, who);
+		let shares = Self::to_shares(coin_id, amount);
+		let balance = Balances::<T>::get(coin_id, who);
+		let free = balance
+			.saturating_sub(Self::labeled_shares(coin_id, who))
+			.saturating_sub(Self::held_shares(coin_id, who));
+		let mut slashed = shares.min(free);
+
+		let mut labels = SubBalances::<T>::get(coin_id, who);
+		for (_, held) in labels.iter_mut() {
+			let take = (shares - slashed).min(*held).min(balance - slashed);
+			*held -= take;
+			slashed += take;
+		}
+		labels.retain(|(_, held)| *held > 0);
+		SubBalances::<T>::set(coin_id, who, labels);
+
+		let mut holds = Holds::<T>::get(coin_id, who);
+		for (_, held) in holds.iter_mut() {
+			let take = (shares - slashed).min(*held).min(balance - slashed);
+			*held -= take;
+			slashed += take;
+		}
+		holds.retain(|(_, held)| *held > 0);
+		Holds::<T>::set(coin_id, who, holds);
+
+		Self::settle_slash(coin_id, who, balance, shares, slashed, amount)
+	}
+
+	/// Slash up to `amount` of the sub-balance `who` holds under `label`.
+	///
+	/// Returns the slashed coins and the part of `amount` that could not be slashed.
+	pub fn slash_reserved(
+		coin_id: CoinId,
+		who: &T::AccountId,
+		label: &[u8],
+		amount: u128,
+	) -> (NegativeImbalance<T>, u128) {
+		Self::apply_decay(coin_id, who);
+		let shares = Self::to_shares(coin_id, amount);
+		let balance = Balances::<T>::get(coin_id, who);
+
+		let mut labels = SubBalances::<T>::get(coin_id, who);
+		let mut slashed = 0;
+		if let Some(index) = labels.iter().position(|(l, _)| l.as_slice() == label) {
+			let held = &mut labels[index].1;
+			slashed = shares.min(*held).min(balance);
+			*held -= slashed;
+			if *held == 0 {
+				labels.remove(index);
+			}
+		}
+		SubBalances::<T>::set(coin_id, who, labels);
+
+		Self::settle_slash(coin_id, who, balance, shares, slashed, amount)
+	}
+
+	/// Debit `slashed` of the `shares` to slash from `who`'s `balance` and report it
+	fn settle_slash(
+		coin_id: CoinId,
+		who: &T::AccountId,
+		balance: u128,
+		shares: u128,
+		slashed: u128,
+		amount: u128,
+	) -> (NegativeImbalance<T>, u128) {
+		if slashed == 0 {
+			return (NegativeImbalance::zero(coin_id), amount);
+		}
+		Self::set_balance(coin_id, who, balance - slashed);
+		let unslashed = if slashed == shares { 0 } else { amount.saturating_sub(Self::to_amount(coin_id, slashed)) };
+		Self::deposit_event(Event::Slashed { coin_id, who: who.clone(), amount: amount - unslashed });
+		(NegativeImbalance::new(coin_id, slashed), unslashed)
+	}
+}
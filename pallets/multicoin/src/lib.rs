@@ -1,912 +1,7588 @@
-//! # Multi-Coin Pallet
-//!
-//! A FRAME pallet that enables a blockchain to natively support and manage multiple coins
-//! on a single runtime. Each coin is treated as a native asset with individual state,
-//! supply, and economic logic.
-//!
-//! ## Overview
-//!
-//! This pallet provides functionality for:
-//! - Creating and registering multiple native coins
-//! - Managing coin ownership and roles (admin, minter)
-//! - Transferring coins between accounts
-//! - Minting and burning coins with proper permissions
-//! - On-chain metadata storage and retrieval
-//! - Multi-currency fee payment support
-//!
-//! ## Interface
-//!
-//! ### Dispatchable Functions
-//!
-//! * `create_coin` - Create a new coin with metadata and initial supply
-//! * `transfer` - Transfer coins between accounts
-//! * `mint` - Mint new coins (requires permissions)
-//! * `burn` - Burn coins (requires permissions)
-//! * `set_metadata` - Update coin metadata (admin only)
-//! * `transfer_ownership` - Transfer coin ownership
-//! * `set_mint_permission` - Grant/revoke minting permissions
-//!
-//! ### Public Functions
-//!
-//! * `balance_of` - Query account balance for a specific coin
-//! * `total_supply` - Get total supply of a coin
-//! * `get_metadata` - Retrieve coin metadata
-//! * `get_coin_id_by_symbol` - Find coin ID by symbol
-
-#![cfg_attr(not(feature = "std"), no_std)]
-
-pub use pallet::*;
-use frame_support::BoundedVec;
-
-#[cfg(test)]
-mod mock;
-
-#[cfg(test)]
-mod tests;
-
-#[cfg(feature = "runtime-benchmarks")]
-mod benchmarking;
-pub mod weights;
-pub use weights::*;
-
-// Type definitions
-pub mod types;
-pub use types::*;
-
-pub mod transaction_payment;
-pub use transaction_payment::*;
-
-#[frame_support::pallet]
-pub mod pallet {
-	use super::*;
-	use frame_support::{
-		dispatch::DispatchResult,
-		pallet_prelude::*,
-		traits::{Get, Currency, ReservableCurrency},
-		BoundedVec,
-	};
-	use frame_system::pallet_prelude::*;
-	use sp_std::vec::Vec;
-	use sp_runtime::traits::Dispatchable;
-	use scale_info::prelude::boxed::Box;
-
-	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
-
-	#[pallet::pallet]
-	#[pallet::storage_version(STORAGE_VERSION)]
-	pub struct Pallet<T>(_);
-
-	/// Configure the pallet by specifying the parameters and types on which it depends.
-	#[pallet::config]
-	pub trait Config: frame_system::Config {
-		/// Because this pallet emits events, it depends on the runtime's definition of an event.
-		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
-
-		/// Type representing the weight of this pallet
-		type WeightInfo: WeightInfo;
-
-		/// The currency used for reserving funds for coin creation
-		type Currency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
-
-		/// Maximum length of coin symbol
-		#[pallet::constant]
-		type MaxSymbolLength: Get<u32>;
-
-		/// Maximum length of coin name
-		#[pallet::constant]
-		type MaxNameLength: Get<u32>;
-
-		/// Maximum number of coins that can be created
-		#[pallet::constant]
-		type MaxCoins: Get<u32>;
-
-		/// Deposit required for creating a coin
-		#[pallet::constant]
-		type CoinDeposit: Get<<Self::Currency as Currency<Self::AccountId>>::Balance>;
-
-		/// Maximum supply for any coin
-		#[pallet::constant]
-		type MaxSupply: Get<u128>;
-	}
-
-	/// Storage for coin metadata
-	#[pallet::storage]
-	#[pallet::getter(fn coin_metadata)]
-	pub type CoinMetadata<T: Config> = StorageMap<
-		_,
-		Blake2_128Concat,
-		CoinId,
-		CoinInfo<
-			BoundedVec<u8, T::MaxSymbolLength>,
-			BoundedVec<u8, T::MaxNameLength>,
-			T::AccountId,
-			<T::Currency as Currency<T::AccountId>>::Balance,
-            FeeConfig,
-		>,
-		OptionQuery,
-	>;
-
-	/// Storage for coin balances: CoinId -> AccountId -> Balance
-	#[pallet::storage]
-	#[pallet::getter(fn balances)]
-	pub type Balances<T: Config> = StorageDoubleMap<
-		_,
-		Blake2_128Concat,
-		CoinId,
-		Blake2_128Concat,
-		T::AccountId,
-		u128,
-		ValueQuery,
-	>;
-
-	/// Storage for total supply of each coin
-	#[pallet::storage]
-	#[pallet::getter(fn total_supply)]
-	pub type TotalSupply<T: Config> = StorageMap<_, Blake2_128Concat, CoinId, u128, ValueQuery>;
-
-	/// Storage for coin symbol to ID mapping
-	#[pallet::storage]
-	#[pallet::getter(fn symbol_to_id)]
-	pub type SymbolToId<T: Config> = StorageMap<
-		_,
-		Blake2_128Concat,
-		BoundedVec<u8, T::MaxSymbolLength>,
-		CoinId,
-		OptionQuery,
-	>;
-
-	/// Next available coin ID
-	#[pallet::storage]
-	#[pallet::getter(fn next_coin_id)]
-	pub type NextCoinId<T: Config> = StorageValue<_, CoinId, ValueQuery>;
-
-	/// Minting permissions: CoinId -> AccountId -> bool
-	#[pallet::storage]
-	#[pallet::getter(fn mint_permissions)]
-	pub type MintPermissions<T: Config> = StorageDoubleMap<
-		_,
-		Blake2_128Concat,
-		CoinId,
-		Blake2_128Concat,
-		T::AccountId,
-		bool,
-		ValueQuery,
-	>;
-
-    #[pallet::storage]
-    #[pallet::getter(fn burn_permissions)]
-    pub type BurnPermissions<T: Config> = StorageDoubleMap<
-        _,
-        Blake2_128Concat,
-        CoinId,
-        Blake2_128Concat,
-        T::AccountId,
-        bool,
-        ValueQuery,
-    >;
-
-	/// Storage for user's preferred fee coin
-	#[pallet::storage]
-	#[pallet::getter(fn preferred_fee_coin)]
-	pub type PreferredFeeCoin<T: Config> = StorageMap<
-		_,
-		Blake2_128Concat,
-		T::AccountId,
-		CoinId,
-		OptionQuery,
-	>;
-
-	/// Events emitted by this pallet
-	#[pallet::event]
-	#[pallet::generate_deposit(pub(super) fn deposit_event)]
-	pub enum Event<T: Config> {
-		/// A new coin was created
-		CoinCreated {
-			coin_id: CoinId,
-			symbol: Vec<u8>,
-			name: Vec<u8>,
-			creator: T::AccountId,
-			initial_supply: u128,
-		},
-		/// Coins were transferred
-		Transfer {
-			coin_id: CoinId,
-			from: T::AccountId,
-			to: T::AccountId,
-			amount: u128,
-		},
-		/// Coins were minted
-		Minted {
-			coin_id: CoinId,
-			to: T::AccountId,
-			amount: u128,
-		},
-		/// Coins were burned
-		Burned {
-			coin_id: CoinId,
-			from: T::AccountId,
-			amount: u128,
-		},
-		/// Coin ownership was transferred
-		OwnershipTransferred {
-			coin_id: CoinId,
-			old_owner: T::AccountId,
-			new_owner: T::AccountId,
-		},
-		/// Mint permission was granted or revoked
-		MintPermissionSet {
-			coin_id: CoinId,
-			account: T::AccountId,
-			can_mint: bool,
-		},
-		/// Coin metadata was updated
-		MetadataUpdated {
-			coin_id: CoinId,
-		},
-        /// Burn permission was granted or revoked
-        BurnPermissionSet {
-            coin_id: CoinId,
-            account: T::AccountId,
-            can_burn: bool,
-        },
-        /// Fee configuration was updated
-        FeeConfigUpdated {
-            coin_id: CoinId,
-            transfer_fee: u128,
-            minimum_balance: u128,
-			can_pay_tx_fees: bool, // Add this field
-        },
-		/// Preferred fee coin set for an account
-		PreferredFeeCoinSet {
-			account: T::AccountId,
-			coin_id: Option<CoinId>,
-		},
-	}
-
-	/// Errors that can occur when using this pallet
-	#[pallet::error]
-	pub enum Error<T> {
-		/// The coin does not exist
-		CoinNotFound,
-		/// Insufficient balance for the operation
-		InsufficientBalance,
-		/// Arithmetic overflow occurred
-		Overflow,
-		/// The symbol is already in use
-		SymbolAlreadyExists,
-		/// Symbol is too long
-		SymbolTooLong,
-		/// Name is too long
-		NameTooLong,
-		/// Maximum number of coins reached
-		TooManyCoins,
-		/// Not authorized for this operation
-		NotAuthorized,
-		/// Cannot transfer to the same account
-		TransferToSelf,
-		/// Initial supply exceeds maximum allowed
-		ExceedsMaxSupply,
-		/// Amount is zero
-		ZeroAmount,
-		/// No minting permission
-		NoMintPermission,
-        /// No burning permission
-        NoBurnPermission,
-        /// Balance would fall below minimum required
-        BelowMinimumBalance,
-		/// Coin cannot be used to pay transaction fees
-		CannotPayFees,
-	}
-
-	#[pallet::call]
-	impl<T: Config> Pallet<T> {
-		/// Create a new coin with the specified metadata and initial supply
-		///
-		/// The origin must be signed and will become the owner of the coin.
-		/// A deposit is required to create a coin.
-		///
-		/// # Arguments
-		/// * `symbol` - The coin symbol (e.g., "BTC", "ETH")
-		/// * `name` - The coin name (e.g., "Bitcoin", "Ethereum")
-		/// * `decimals` - Number of decimal places
-		/// * `initial_supply` - Initial supply of coins to mint to creator
-		#[pallet::call_index(0)]
-		#[pallet::weight(T::WeightInfo::create_coin())]
-		pub fn create_coin(
-			origin: OriginFor<T>,
-			symbol: Vec<u8>,
-			name: Vec<u8>,
-			decimals: u8,
-			initial_supply: u128,
-            initial_minters: Option<Vec<T::AccountId>>,  // New: Optional additional minters
-            initial_burners: Option<Vec<T::AccountId>>,  // New: Optional additional burners
-			can_pay_tx_fees: bool, // New: Optional fee payment eligibility
-			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
-		) -> DispatchResult {
-			let who = ensure_signed(origin)?;
-
-			// Validate inputs
-			// If coin_id is provided, ensure it exists and can pay fees
-			if let Some(tx_coin_id) = tx_fee_coin {
-				let coin_info = CoinMetadata::<T>::get(&tx_coin_id)
-					.ok_or(Error::<T>::CoinNotFound)?;
-				ensure!(coin_info.fee_config.can_pay_tx_fees, Error::<T>::CannotPayFees);
-			}
-
-			ensure!(initial_supply <= T::MaxSupply::get(), Error::<T>::ExceedsMaxSupply);
-			ensure!(initial_supply > 0, Error::<T>::ZeroAmount);
-
-			let bounded_symbol: BoundedVec<u8, T::MaxSymbolLength> = 
-				symbol.clone().try_into().map_err(|_| Error::<T>::SymbolTooLong)?;
-			let bounded_name: BoundedVec<u8, T::MaxNameLength> = 
-				name.clone().try_into().map_err(|_| Error::<T>::NameTooLong)?;
-
-			// Check if symbol already exists
-			ensure!(
-				!SymbolToId::<T>::contains_key(&bounded_symbol),
-				Error::<T>::SymbolAlreadyExists
-			);
-
-			// Check maximum coins limit
-			let coin_id = NextCoinId::<T>::get();
-			ensure!(coin_id < T::MaxCoins::get(), Error::<T>::TooManyCoins);
-
-			// Reserve deposit for coin creation
-			let deposit_amount = T::CoinDeposit::get();
-			T::Currency::reserve(&who, deposit_amount)
-				.map_err(|_| Error::<T>::InsufficientBalance)?;
-
-			// Create coin metadata with default fee config
-            let coin_info = CoinInfo {
-                symbol: bounded_symbol.clone(),
-                name: bounded_name,
-                decimals,
-                owner: who.clone(),
-                deposit: deposit_amount,
-                fee_config: FeeConfig {
-                    transfer_fee: 0, // Default: no fee
-                    minimum_balance: 0, // Default: no minimum
-                    can_pay_tx_fees, // Default: cannot pay tx fees (for Task 6)
-                },
-            };
-
-			// Store coin information
-			CoinMetadata::<T>::insert(&coin_id, &coin_info);
-			SymbolToId::<T>::insert(&bounded_symbol, &coin_id);
-			
-			// Set initial supply and balance
-			TotalSupply::<T>::insert(&coin_id, initial_supply);
-			Balances::<T>::insert(&coin_id, &who, initial_supply);
-
-			// Grant permissions to creator
-            MintPermissions::<T>::insert(&coin_id, &who, true);
-            BurnPermissions::<T>::insert(&coin_id, &who, true);  // New: Grant burn to creator
-
-            // Grant additional initial minters
-            if let Some(minters) = initial_minters {
-                for minter in minters {
-                    MintPermissions::<T>::insert(&coin_id, &minter, true);
-                    Self::deposit_event(Event::MintPermissionSet {  // Reuse event
-                        coin_id,
-                        account: minter.clone(),
-                        can_mint: true,
-                    });
-                }
-            }
-
-            // Grant additional initial burners
-            if let Some(burners) = initial_burners {
-                for burner in burners {
-                    BurnPermissions::<T>::insert(&coin_id, &burner, true);
-                    // Optionally add a new Event::BurnPermissionSet if you want separation
-                    Self::deposit_event(Event::MintPermissionSet {  // Reuse for now, or add new event
-                        coin_id,
-                        account: burner.clone(),
-                        can_mint: true,  // Adjust if adding separate event
-                    });
-                }
-            }
-
-			// Update next coin ID
-			NextCoinId::<T>::put(coin_id + 1);
-
-			// Emit event
-			Self::deposit_event(Event::CoinCreated {
-				coin_id,
-				symbol,
-				name,
-				creator: who,
-				initial_supply,
-			});
-
-			Ok(())
-		}
-
-		/// Transfer coins from one account to another
-		///
-		/// # Arguments
-		/// * `coin_id` - The ID of the coin to transfer
-		/// * `to` - The recipient account
-		/// * `amount` - The amount to transfer
-		#[pallet::call_index(1)]
-		#[pallet::weight(T::WeightInfo::transfer())]
-		pub fn transfer(
-			origin: OriginFor<T>,
-			coin_id: CoinId,
-			to: T::AccountId,
-			amount: u128,
-			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
-		) -> DispatchResult {
-			let from = ensure_signed(origin)?;
-
-			// Validate inputs
-			// If coin_id is provided, ensure it exists and can pay fees
-			if let Some(tx_coin_id) = tx_fee_coin {
-				let coin_info = CoinMetadata::<T>::get(&tx_coin_id)
-					.ok_or(Error::<T>::CoinNotFound)?;
-				ensure!(coin_info.fee_config.can_pay_tx_fees, Error::<T>::CannotPayFees);
-			}
-
-			ensure!(amount > 0, Error::<T>::ZeroAmount);
-            ensure!(from != to, Error::<T>::TransferToSelf);
-            let coin_info = CoinMetadata::<T>::get(&coin_id)
-                .ok_or(Error::<T>::CoinNotFound)?;
-
-            // Calculate total amount to deduct (amount + fee)
-            let transfer_fee = coin_info.fee_config.transfer_fee;
-            let total_deduct = amount.checked_add(transfer_fee)
-                .ok_or(Error::<T>::Overflow)?;
-
-            // Check sender's balance
-            let from_balance = Balances::<T>::get(&coin_id, &from);
-            ensure!(from_balance >= total_deduct, Error::<T>::InsufficientBalance);
-
-            // Check minimum balance requirement for sender after transfer
-            let new_from_balance = from_balance.saturating_sub(total_deduct);
-            ensure!(
-                new_from_balance >= coin_info.fee_config.minimum_balance,
-                Error::<T>::BelowMinimumBalance // New error
-            );
-
-            // Update recipient's balance
-            let to_balance = Balances::<T>::get(&coin_id, &to);
-            let new_to_balance = to_balance.checked_add(amount)
-                .ok_or(Error::<T>::Overflow)?;
-
-            // Apply transfer and fee (burn the fee for simplicity)
-            Balances::<T>::insert(&coin_id, &from, new_from_balance);
-            Balances::<T>::insert(&coin_id, &to, new_to_balance);
-            if transfer_fee > 0 {
-                let current_supply = TotalSupply::<T>::get(&coin_id);
-                let new_supply = current_supply.saturating_sub(transfer_fee);
-                TotalSupply::<T>::insert(&coin_id, new_supply);
-                // Emit burn event for fee
-                Self::deposit_event(Event::Burned {
-                    coin_id,
-                    from: from.clone(),
-                    amount: transfer_fee,
-                });
-            }
-
-			// Emit event
-			Self::deposit_event(Event::Transfer {
-				coin_id,
-				from,
-				to,
-				amount,
-			});
-
-			Ok(())
-		}
-
-		/// Mint new coins to a specified account
-		///
-		/// Only accounts with minting permission can call this function.
-		///
-		/// # Arguments
-		/// * `coin_id` - The ID of the coin to mint
-		/// * `to` - The account to receive the minted coins
-		/// * `amount` - The amount to mint
-		#[pallet::call_index(2)]
-		#[pallet::weight(T::WeightInfo::mint())]
-		pub fn mint(
-			origin: OriginFor<T>,
-			coin_id: CoinId,
-			to: T::AccountId,
-			amount: u128,
-			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
-		) -> DispatchResult {
-			let who = ensure_signed(origin)?;
-
-			// Validate inputs
-			// If coin_id is provided, ensure it exists and can pay fees
-			if let Some(tx_coin_id) = tx_fee_coin {
-				let coin_info = CoinMetadata::<T>::get(&tx_coin_id)
-					.ok_or(Error::<T>::CoinNotFound)?;
-				ensure!(coin_info.fee_config.can_pay_tx_fees, Error::<T>::CannotPayFees);
-			}
-
-			ensure!(amount > 0, Error::<T>::ZeroAmount);
-			ensure!(CoinMetadata::<T>::contains_key(&coin_id), Error::<T>::CoinNotFound);
-
-			// Check minting permission
-			ensure!(
-				MintPermissions::<T>::get(&coin_id, &who),
-				Error::<T>::NoMintPermission
-			);
-
-			// Check if minting would exceed max supply
-			let current_supply = TotalSupply::<T>::get(&coin_id);
-			let new_supply = current_supply.checked_add(amount)
-				.ok_or(Error::<T>::Overflow)?;
-			ensure!(new_supply <= T::MaxSupply::get(), Error::<T>::ExceedsMaxSupply);
-
-			// Update balance and total supply
-			let current_balance = Balances::<T>::get(&coin_id, &to);
-			let new_balance = current_balance.checked_add(amount)
-				.ok_or(Error::<T>::Overflow)?;
-
-			Balances::<T>::insert(&coin_id, &to, new_balance);
-			TotalSupply::<T>::insert(&coin_id, new_supply);
-
-			// Emit event
-			Self::deposit_event(Event::Minted {
-				coin_id,
-				to,
-				amount,
-			});
-
-			Ok(())
-		}
-
-		/// Burn coins from the caller's account
-		///
-		/// # Arguments
-		/// * `coin_id` - The ID of the coin to burn
-		/// * `amount` - The amount to burn
-		#[pallet::call_index(3)]
-		#[pallet::weight(T::WeightInfo::burn())]
-		pub fn burn(
-			origin: OriginFor<T>,
-			coin_id: CoinId,
-			amount: u128,
-			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
-		) -> DispatchResult {
-			let who = ensure_signed(origin)?;
-
-			// Validate inputs
-			// If coin_id is provided, ensure it exists and can pay fees
-			if let Some(tx_coin_id) = tx_fee_coin {
-				let coin_info = CoinMetadata::<T>::get(&tx_coin_id)
-					.ok_or(Error::<T>::CoinNotFound)?;
-				ensure!(coin_info.fee_config.can_pay_tx_fees, Error::<T>::CannotPayFees);
-			}
-
-			ensure!(amount > 0, Error::<T>::ZeroAmount);
-			ensure!(CoinMetadata::<T>::contains_key(&coin_id), Error::<T>::CoinNotFound);
-
-            // Check burning permission
-            ensure!(
-                BurnPermissions::<T>::get(&coin_id, &who),
-                Error::<T>::NoBurnPermission  // Add this to Error enum
-            );
-
-			// Check balance
-			let current_balance = Balances::<T>::get(&coin_id, &who);
-			ensure!(current_balance >= amount, Error::<T>::InsufficientBalance);
-
-			// Update balance and total supply
-			let new_balance = current_balance.saturating_sub(amount);
-			let current_supply = TotalSupply::<T>::get(&coin_id);
-			let new_supply = current_supply.saturating_sub(amount);
-
-			Balances::<T>::insert(&coin_id, &who, new_balance);
-			TotalSupply::<T>::insert(&coin_id, new_supply);
-
-			// Emit event
-			Self::deposit_event(Event::Burned {
-				coin_id,
-				from: who,
-				amount,
-			});
-
-			Ok(())
-		}
-
-		/// Transfer ownership of a coin to another account
-		///
-		/// Only the current owner can call this function.
-		///
-		/// # Arguments
-		/// * `coin_id` - The ID of the coin
-		/// * `new_owner` - The new owner account
-		#[pallet::call_index(4)]
-		#[pallet::weight(T::WeightInfo::transfer_ownership())]
-		pub fn transfer_ownership(
-			origin: OriginFor<T>,
-			coin_id: CoinId,
-			new_owner: T::AccountId,
-			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
-		) -> DispatchResult {
-			let who = ensure_signed(origin)?;
-
-			// If coin_id is provided, ensure it exists and can pay fees
-			if let Some(tx_coin_id) = tx_fee_coin {
-				let coin_info = CoinMetadata::<T>::get(&tx_coin_id)
-					.ok_or(Error::<T>::CoinNotFound)?;
-				ensure!(coin_info.fee_config.can_pay_tx_fees, Error::<T>::CannotPayFees);
-			}
-
-			// Get coin metadata
-			let mut coin_info = CoinMetadata::<T>::get(&coin_id)
-				.ok_or(Error::<T>::CoinNotFound)?;
-
-			// Check authorization
-			ensure!(coin_info.owner == who, Error::<T>::NotAuthorized);
-
-			let old_owner = coin_info.owner.clone();
-			
-			// Update owner
-			coin_info.owner = new_owner.clone();
-			CoinMetadata::<T>::insert(&coin_id, &coin_info);
-
-			// Transfer minting permission from old to new owner
-			MintPermissions::<T>::remove(&coin_id, &old_owner);
-			MintPermissions::<T>::insert(&coin_id, &new_owner, true);
-
-			// Emit event
-			Self::deposit_event(Event::OwnershipTransferred {
-				coin_id,
-				old_owner,
-				new_owner,
-			});
-
-			Ok(())
-		}
-
-		/// Set minting permission for an account
-		///
-		/// Only the coin owner can grant or revoke minting permissions.
-		///
-		/// # Arguments
-		/// * `coin_id` - The ID of the coin
-		/// * `account` - The account to set permission for
-		/// * `can_mint` - Whether the account can mint
-		#[pallet::call_index(5)]
-		#[pallet::weight(T::WeightInfo::set_mint_permission())]
-		pub fn set_mint_permission(
-			origin: OriginFor<T>,
-			coin_id: CoinId,
-			account: T::AccountId,
-			can_mint: bool,
-			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
-		) -> DispatchResult {
-			let who = ensure_signed(origin)?;
-
-			// If coin_id is provided, ensure it exists and can pay fees
-			if let Some(tx_coin_id) = tx_fee_coin {
-				let coin_info = CoinMetadata::<T>::get(&tx_coin_id)
-					.ok_or(Error::<T>::CoinNotFound)?;
-				ensure!(coin_info.fee_config.can_pay_tx_fees, Error::<T>::CannotPayFees);
-			}
-
-			// Get coin metadata
-			let coin_info = CoinMetadata::<T>::get(&coin_id)
-				.ok_or(Error::<T>::CoinNotFound)?;
-
-			// Check authorization
-			ensure!(coin_info.owner == who, Error::<T>::NotAuthorized);
-
-			// Set permission
-			if can_mint {
-				MintPermissions::<T>::insert(&coin_id, &account, true);
-			} else {
-				MintPermissions::<T>::remove(&coin_id, &account);
-			}
-
-			// Emit event
-			Self::deposit_event(Event::MintPermissionSet {
-				coin_id,
-				account,
-				can_mint,
-			});
-
-			Ok(())
-		}
-
-        #[pallet::call_index(6)]  // Adjust index as needed
-        #[pallet::weight(T::WeightInfo::set_mint_permission())]  // Reuse weight or add new
-        pub fn set_burn_permission(
-            origin: OriginFor<T>,
-            coin_id: CoinId,
-            account: T::AccountId,
-            can_burn: bool,
-			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
-        ) -> DispatchResult {
-            let who = ensure_signed(origin)?;
-
-			// If coin_id is provided, ensure it exists and can pay fees
-			if let Some(tx_coin_id) = tx_fee_coin {
-				let coin_info = CoinMetadata::<T>::get(&tx_coin_id)
-					.ok_or(Error::<T>::CoinNotFound)?;
-				ensure!(coin_info.fee_config.can_pay_tx_fees, Error::<T>::CannotPayFees);
-			}
-
-            // Get coin metadata
-            let coin_info = CoinMetadata::<T>::get(&coin_id)
-                .ok_or(Error::<T>::CoinNotFound)?;
-
-            // Check authorization
-            ensure!(coin_info.owner == who, Error::<T>::NotAuthorized);
-
-            // Set permission
-            if can_burn {
-                BurnPermissions::<T>::insert(&coin_id, &account, true);
-            } else {
-                BurnPermissions::<T>::remove(&coin_id, &account);
-            }
-
-            // Emit event (add new Event::BurnPermissionSet { coin_id, account, can_burn })
-            Self::deposit_event(Event::BurnPermissionSet {  // Add this to Event enum
-                coin_id,
-                account,
-                can_burn,
-            });
-
-            Ok(())
-        }
-
-        #[pallet::call_index(7)] // Adjust index (e.g., after set_burn_permission)
-        #[pallet::weight(T::WeightInfo::set_fee_config())] // Add new weight in weights.rs
-        pub fn set_fee_config(
-            origin: OriginFor<T>,
-            coin_id: CoinId,
-            transfer_fee: u128,
-            minimum_balance: u128,
-			can_pay_tx_fees: bool, // New: Add this parameter
-			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
-        ) -> DispatchResult {
-            let who = ensure_signed(origin)?;
-
-			// If coin_id is provided, ensure it exists and can pay fees
-			if let Some(tx_coin_id) = tx_fee_coin {
-				let coin_info = CoinMetadata::<T>::get(&tx_coin_id)
-					.ok_or(Error::<T>::CoinNotFound)?;
-				ensure!(coin_info.fee_config.can_pay_tx_fees, Error::<T>::CannotPayFees);
-			}
-
-            // Get coin metadata
-            let mut coin_info = CoinMetadata::<T>::get(&coin_id)
-                .ok_or(Error::<T>::CoinNotFound)?;
-
-            // Check authorization
-            ensure!(coin_info.owner == who, Error::<T>::NotAuthorized);
-
-            // Update fee config
-            coin_info.fee_config.transfer_fee = transfer_fee;
-            coin_info.fee_config.minimum_balance = minimum_balance;
-			coin_info.fee_config.can_pay_tx_fees = can_pay_tx_fees;
-            CoinMetadata::<T>::insert(&coin_id, &coin_info);
-
-            // Emit event
-            Self::deposit_event(Event::FeeConfigUpdated {
-                coin_id,
-                transfer_fee,
-                minimum_balance,
-				can_pay_tx_fees, // Update event
-            });
-
-            Ok(())
-        }
-
-		#[pallet::call_index(8)] // Adjust index as needed
-		#[pallet::weight(T::WeightInfo::set_preferred_fee_coin())] // Add new weight
-		pub fn set_preferred_fee_coin(
-			origin: OriginFor<T>,
-			coin_id: Option<CoinId>, // None to use Balances pallet
-			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
-		) -> DispatchResult {
-			let who = ensure_signed(origin)?;
-
-			// If coin_id is provided, ensure it exists and can pay fees
-			if let Some(tx_coin_id) = tx_fee_coin {
-				let coin_info = CoinMetadata::<T>::get(&tx_coin_id)
-					.ok_or(Error::<T>::CoinNotFound)?;
-				ensure!(coin_info.fee_config.can_pay_tx_fees, Error::<T>::CannotPayFees);
-			}
-
-			// If coin_id is provided, ensure it exists and can pay fees
-			if let Some(coin_id) = coin_id {
-				let coin_info = CoinMetadata::<T>::get(&coin_id)
-					.ok_or(Error::<T>::CoinNotFound)?;
-				ensure!(coin_info.fee_config.can_pay_tx_fees, Error::<T>::CannotPayFees);
-			}
-
-			// Set or clear preferred coin
-			if let Some(coin_id) = coin_id {
-				PreferredFeeCoin::<T>::insert(&who, coin_id);
-			} else {
-				PreferredFeeCoin::<T>::remove(&who);
-			}
-
-			// Emit event
-			Self::deposit_event(Event::PreferredFeeCoinSet {
-				account: who,
-				coin_id,
-			});
-
-			Ok(())
-		}
-
-		/// Execute any pallet call with a specific fee coin
-		/// This is a wrapper that temporarily sets the fee coin for one transaction
-		#[pallet::call_index(9)] // Adjust index as needed
-		#[pallet::weight(T::WeightInfo::set_preferred_fee_coin())] // Add proper weight calculation
-		pub fn call_with_fee_coin(
-			origin: OriginFor<T>,
-			tx_fee_coin: Option<CoinId>,
-			call: Box<T::RuntimeCall>,
-		) -> DispatchResult {
-			let _who = ensure_signed(origin.clone())?;
-
-			// If coin_id is provided, ensure it exists and can pay fees
-			if let Some(tx_coin_id) = tx_fee_coin {
-				let coin_info = CoinMetadata::<T>::get(&tx_coin_id)
-					.ok_or(Error::<T>::CoinNotFound)?;
-				ensure!(coin_info.fee_config.can_pay_tx_fees, Error::<T>::CannotPayFees);
-			}
-
-			// Execute the call
-			let result = call.dispatch(origin);
-
-			result.map(|_| ()).map_err(|e| e.error)
-		}
-		
-	}
-}
-
-// Helper functions implementation
-impl<T: Config> Pallet<T> {
-	/// Get the balance of an account for a specific coin
-	pub fn balance_of(account: &T::AccountId, coin_id: CoinId) -> u128 {
-		Balances::<T>::get(coin_id, account)
-	}
-
-	/// Get the total supply of a coin
-	pub fn total_supply_of(coin_id: CoinId) -> u128 {
-		TotalSupply::<T>::get(coin_id)
-	}
-
-	/// Get coin metadata
-	pub fn get_coin_metadata(
-		coin_id: CoinId,
-	) -> Option<CoinInfo<
-		BoundedVec<u8, T::MaxSymbolLength>, 
-		BoundedVec<u8, T::MaxNameLength>, 
-		T::AccountId,
-		<T::Currency as frame_support::traits::Currency<T::AccountId>>::Balance,
-        FeeConfig,
-	>> {
-		CoinMetadata::<T>::get(coin_id)
-	}
-
-	/// Get coin ID by symbol
-	pub fn get_coin_id_by_symbol(symbol: &[u8]) -> Option<CoinId> {
-		let bounded_symbol: BoundedVec<u8, T::MaxSymbolLength> = symbol.to_vec()
-			.try_into()
-			.ok()?;
-		SymbolToId::<T>::get(bounded_symbol)
-	}
-
-	/// Check if an account has minting permission for a coin
-	pub fn has_mint_permission(coin_id: CoinId, account: &T::AccountId) -> bool {
-		MintPermissions::<T>::get(coin_id, account)
-	}
-
-    /// Check if an account has burning permission for a coin
-    pub fn has_burn_permission(coin_id: CoinId, account: &T::AccountId) -> bool {
-        BurnPermissions::<T>::get(coin_id, account)
-    }
-
-}
+//! # Multi-Coin Pallet
+//!
+//! A FRAME pallet that enables a blockchain to natively support and manage multiple coins
+//! on a single runtime. Each coin is treated as a native asset with individual state,
+//! supply, and economic logic.
+//!
+//! ## Overview
+//!
+//! This pallet provides functionality for:
+//! - Creating and registering multiple native coins
+//! - Managing coin ownership and roles (admin, minter)
+//! - Transferring coins between accounts
+//! - Minting and burning coins with proper permissions
+//! - On-chain metadata storage and retrieval
+//! - Multi-currency fee payment support
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `create_coin` - Create a new coin with metadata and initial supply
+//! * `transfer` - Transfer coins between accounts
+//! * `mint` - Mint new coins (requires permissions)
+//! * `burn` - Burn coins (requires permissions)
+//! * `set_metadata` - Update coin metadata (admin only)
+//! * `transfer_ownership` - Transfer coin ownership, optionally revoking the old owner's permissions
+//! * `set_mint_permission` - Grant/revoke minting permissions
+//!
+//! ### Public Functions
+//!
+//! * `balance_of` - Query account balance for a specific coin
+//! * `total_supply` - Get total supply of a coin
+//! * `get_metadata` - Retrieve coin metadata
+//! * `get_coin_id_by_symbol` - Find coin ID by symbol
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	storage::{with_transaction, PrefixIterator, TransactionOutcome},
+	traits::{
+		BalanceStatus, Contains, Currency, EnsureOrigin, ExistenceRequirement, Get, OriginTrait, PalletInfoAccess,
+		ReservableCurrency,
+	},
+	weights::Weight,
+	BoundedVec,
+};
+use frame_system::{ensure_signed, pallet_prelude::{BlockNumberFor, OriginFor}};
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{AccountIdConversion, One, SaturatedConversion, Saturating, Zero},
+	DispatchError, FixedPointNumber, FixedU128, PerThing, Perbill, Permill, Rounding,
+};
+use sp_std::vec::Vec;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(test)]
+mod scale_vectors;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::*;
+
+// Type definitions
+pub mod types;
+pub use types::*;
+
+pub mod transaction_payment;
+pub use transaction_payment::*;
+
+pub mod imbalances;
+pub use imbalances::*;
+
+pub mod treasury;
+pub use treasury::*;
+
+pub mod fungibles;
+
+pub mod vesting;
+pub use vesting::*;
+
+pub mod runtime_api;
+
+pub mod migrations;
+
+/// Balance type of the currency used for coin creation deposits
+pub type DepositBalanceOf<T> = <<T as Config>::Currency as frame_support::traits::Currency<
+	<T as frame_system::Config>::AccountId,
+>>::Balance;
+
+/// Fee change guard as stored in `FeeChangeGuards`
+pub type FeeChangeGuardOf<T> = FeeChangeGuard<
+	<T as frame_system::Config>::AccountId,
+	frame_system::pallet_prelude::BlockNumberFor<T>,
+>;
+
+/// Decay configuration as stored in `DecayRates`
+pub type DecayConfigOf<T> = DecayConfig<BlockNumberFor<T>>;
+
+/// Staking reward pool as stored in `RewardPools`
+pub type RewardPoolOf<T> = RewardPool<BlockNumberFor<T>>;
+
+/// Vote lock as stored in `VoteLocks`
+pub type VoteLockOf<T> = VoteLock<BlockNumberFor<T>>;
+
+/// Launch sale as stored in `Sales`
+pub type SaleOf<T> = Sale<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+/// Subscription as stored in `Subscriptions`
+pub type SubscriptionOf<T> = Subscription<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+/// Recovery designation as stored in `RecoveryConfigs`
+pub type RecoveryConfigOf<T> = RecoveryConfig<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+/// Dormancy settings as stored in `DormancyConfigs`
+pub type DormancyConfigOf<T> = DormancyConfig<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+/// Mint lock-up as stored in `MintLocks`
+pub type MintLockOf<T> = MintLock<BlockNumberFor<T>>;
+
+/// Faucet settings as stored in `Faucets`
+pub type FaucetConfigOf<T> = FaucetConfig<BlockNumberFor<T>>;
+
+/// Type alias for supply checkpoints
+pub type SupplyCheckpointOf<T> = SupplyCheckpoint<BlockNumberFor<T>>;
+
+/// Type alias for transfer tickets
+pub type TransferTicketOf<T> = TransferTicket<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+/// Type alias for liquidation auctions
+pub type LiquidationAuctionOf<T> = LiquidationAuction<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+/// Oracle settings as stored in `OracleConfigs`
+pub type OracleConfigOf<T> = OracleConfig<BlockNumberFor<T>>;
+
+/// Price feed as stored in `PriceFeeds`
+pub type PriceFeedOf<T> = PriceFeed<BlockNumberFor<T>>;
+
+/// Royalty settings as stored in `Royalties`
+pub type RoyaltyConfigOf<T> = RoyaltyConfig<<T as frame_system::Config>::AccountId>;
+
+/// Coin metadata as stored in `CoinMetadata`
+pub type CoinInfoOf<T> = CoinInfo<
+	BoundedVec<u8, <T as Config>::MaxSymbolLength>,
+	BoundedVec<u8, <T as Config>::MaxNameLength>,
+	<T as frame_system::Config>::AccountId,
+	DepositBalanceOf<T>,
+	FeeConfig,
+>;
+
+/// Localized names and descriptions as stored in `CoinLocalizations`
+pub type LocalizationsOf<T> = frame_support::BoundedBTreeMap<
+	Locale,
+	LocalizedText<BoundedVec<u8, <T as Config>::MaxNameLength>, BoundedVec<u8, <T as Config>::MaxDescriptionLength>>,
+	<T as Config>::MaxLocales,
+>;
+
+/// Extended metadata as stored in `ExtendedMetadata`
+pub type ExtendedCoinMetadataOf<T> = ExtendedCoinMetadata<
+	BoundedVec<u8, <T as Config>::MaxDescriptionLength>,
+	BoundedVec<u8, <T as Config>::MaxUriLength>,
+>;
+
+/// Metadata deposit as stored in `MetadataDeposits`
+pub type MetadataDepositOf<T> = MetadataDeposit<<T as frame_system::Config>::AccountId, DepositBalanceOf<T>>;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		dispatch::DispatchResult,
+		pallet_prelude::*,
+		traits::{Get, Currency, ReservableCurrency},
+		BoundedVec, PalletId,
+	};
+	use sp_std::vec::Vec;
+	use frame_support::storage::with_storage_layer;
+	use sp_runtime::traits::{Dispatchable, Saturating, Zero};
+	use scale_info::prelude::boxed::Box;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(6);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// Configure the pallet by specifying the parameters and types on which it depends.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Because this pallet emits events, it depends on the runtime's definition of an event.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Type representing the weight of this pallet
+		type WeightInfo: WeightInfo;
+
+		/// The currency used for reserving funds for coin creation
+		type Currency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
+
+		/// Maximum length of coin symbol
+		#[pallet::constant]
+		type MaxSymbolLength: Get<u32>;
+
+		/// Maximum length of coin name
+		#[pallet::constant]
+		type MaxNameLength: Get<u32>;
+
+		/// Maximum number of coins that can be created
+		#[pallet::constant]
+		type MaxCoins: Get<u32>;
+
+		/// Deposit required for creating a coin
+		#[pallet::constant]
+		type CoinDeposit: Get<<Self::Currency as Currency<Self::AccountId>>::Balance>;
+
+		/// Maximum supply for any coin
+		#[pallet::constant]
+		type MaxSupply: Get<u128>;
+
+		/// Origin allowed to perform privileged operations on any coin (e.g. governance)
+		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Origin admitting coins proposed by their owners as fee coins (e.g. governance)
+		type FeeCoinAdmissionOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Lock period of a 1x conviction vote lock; higher convictions lock for multiples of it
+		#[pallet::constant]
+		type VoteLockingPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Window over which per-bridge mint caps apply, e.g. a day of blocks
+		#[pallet::constant]
+		type BridgeMintWindow: Get<BlockNumberFor<Self>>;
+
+		/// The pallet's id, used to derive the account holding native sale proceeds
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Maximum number of recipients of a single `transfer_split`
+		#[pallet::constant]
+		type MaxSplitRecipients: Get<u32>;
+
+		/// Maximum number of recipients of a single `transfer_batch`
+		#[pallet::constant]
+		type MaxBatchRecipients: Get<u32>;
+
+		/// Maximum number of additional minters granted by `create_coin`
+		#[pallet::constant]
+		type MaxInitialMinters: Get<u32>;
+
+		/// Maximum number of additional burners granted by `create_coin`
+		#[pallet::constant]
+		type MaxInitialBurners: Get<u32>;
+
+		/// Maximum number of price feeders of a coin
+		#[pallet::constant]
+		type MaxPriceFeeders: Get<u32>;
+
+		/// Share of all fees a single coin may pay before its fee multiplier rises
+		#[pallet::constant]
+		type FeeShareTarget: Get<Perbill>;
+
+		/// Per-block relative change of a coin's fee multiplier
+		#[pallet::constant]
+		type FeeMultiplierAdjustment: Get<Perbill>;
+
+		/// Blocks over which a liquidation auction's discount grows to its maximum
+		#[pallet::constant]
+		type LiquidationAuctionDuration: Get<BlockNumberFor<Self>>;
+
+		/// Share of a liquidated debt's value paid in collateral to the keeper that
+		/// started the auction
+		#[pallet::constant]
+		type KeeperReward: Get<Perbill>;
+
+		/// Hook run on every transfer
+		type TransferHook: TransferHook<Self::AccountId>;
+
+		/// Most weight `TransferHook` may declare for a single transfer
+		#[pallet::constant]
+		type MaxTransferHookWeight: Get<Weight>;
+
+		/// Fee coin selection for calls of other pallets carrying their own `tx_fee_coin`
+		type ExternalFeeCoin: ExternalFeeCoin<<Self as frame_system::Config>::RuntimeCall>;
+
+		/// Third party paying native fees for some accounts, e.g. newly bridged users
+		type FeeSponsor: FeeSponsor<Self::AccountId>;
+
+		/// Calls of other pallets that dispatch a wrapped call, which the wrappers of
+		/// this pallet refuse to nest
+		type ExternalWrappers: Contains<<Self as frame_system::Config>::RuntimeCall>;
+
+		/// Whether native fee payments may reap the paying account
+		///
+		/// `KeepAlive` matches `pallet_transaction_payment`'s adapters; `AllowDeath`
+		/// lets accounts spend their last tokens on fees.
+		type NativeFeeExistence: Get<ExistenceRequirement>;
+
+		/// Destination of transaction fees paid in coins; `()` burns them
+		type OnCoinFee: OnCoinUnbalanced<Self>;
+
+		/// Reasons consumer pallets (escrows, sales, bridges, ...) put coins on hold for
+		type RuntimeHoldReason: Parameter + Member + MaxEncodedLen + Copy;
+
+		/// Maximum distinct hold reasons per account and coin
+		#[pallet::constant]
+		type MaxHolds: Get<u32>;
+
+		/// Supply checkpoints kept per coin; the oldest is dropped when full
+		#[pallet::constant]
+		type MaxSupplyCheckpoints: Get<u32>;
+
+		/// Blocks that must pass between two supply checkpoints of a coin
+		#[pallet::constant]
+		type SupplyCheckpointInterval: Get<BlockNumberFor<Self>>;
+
+		/// Maximum per-account entries the `on_idle` cleanup visits per block
+		#[pallet::constant]
+		type MaxCleanupScan: Get<u32>;
+
+		/// Maximum accounts that may keep transferring a coin whose transfers are paused
+		#[pallet::constant]
+		type MaxPauseExceptions: Get<u32>;
+
+		/// Blocks per era of the transfer velocity metrics
+		#[pallet::constant]
+		type VelocityEraLength: Get<BlockNumberFor<Self>>;
+
+		/// Eras of transfer velocity metrics kept per coin; the oldest is dropped when full
+		#[pallet::constant]
+		type MaxVelocityEras: Get<u32>;
+
+		/// Maximum per-account entries a single `destroy_accounts` call removes
+		#[pallet::constant]
+		type RemoveItemsLimit: Get<u32>;
+
+		/// Source of randomness for `sample_holders`
+		type Randomness: frame_support::traits::Randomness<Self::Hash, BlockNumberFor<Self>>;
+
+		/// Holders per segment of a holder snapshot, which bounds the balances read per draw
+		#[pallet::constant]
+		type HolderCheckpointInterval: Get<u32>;
+
+		/// Maximum segments `checkpoint_holders` adds to a holder snapshot per call
+		#[pallet::constant]
+		type MaxHolderCheckpoints: Get<u32>;
+
+		/// Maximum locales a coin can have localized names and descriptions for
+		#[pallet::constant]
+		type MaxLocales: Get<u32>;
+
+		/// Maximum length of a coin description
+		#[pallet::constant]
+		type MaxDescriptionLength: Get<u32>;
+
+		/// Maximum length of a URI or external reference in extended coin metadata
+		#[pallet::constant]
+		type MaxUriLength: Get<u32>;
+
+		/// Deposit reserved per byte of optional coin metadata, such as localizations
+		#[pallet::constant]
+		type MetadataDepositPerByte: Get<DepositBalanceOf<Self>>;
+
+		/// Maximum accounts `set_preferred_fee_coin_for` configures at once
+		#[pallet::constant]
+		type MaxFeePreferenceBatch: Get<u32>;
+	}
+
+	/// Storage for coin metadata
+	#[pallet::storage]
+	#[pallet::getter(fn coin_metadata)]
+	pub type CoinMetadata<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		CoinId,
+		CoinInfo<
+			BoundedVec<u8, T::MaxSymbolLength>,
+			BoundedVec<u8, T::MaxNameLength>,
+			T::AccountId,
+			<T::Currency as Currency<T::AccountId>>::Balance,
+            FeeConfig,
+		>,
+		OptionQuery,
+	>;
+
+	/// Storage for coin balances: CoinId -> AccountId -> Balance
+	///
+	/// Account IDs are chosen by users, so they keep a cryptographic hasher: crafted
+	/// keys can't deepen one branch of the trie, and the proof of an access grows
+	/// with the logarithm of the holder count. The `balance_*` benchmarks profile
+	/// this as the map grows.
+	#[pallet::storage]
+	#[pallet::getter(fn balances)]
+	pub type Balances<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CoinId,
+		Blake2_128Concat,
+		T::AccountId,
+		u128,
+		ValueQuery,
+	>;
+
+	/// Storage for total supply of each coin
+	#[pallet::storage]
+	#[pallet::getter(fn total_supply)]
+	pub type TotalSupply<T: Config> = StorageMap<_, Blake2_128Concat, CoinId, u128, ValueQuery>;
+
+	/// Storage for coin symbol to ID mapping
+	#[pallet::storage]
+	#[pallet::getter(fn symbol_to_id)]
+	pub type SymbolToId<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxSymbolLength>,
+		CoinId,
+		OptionQuery,
+	>;
+
+	/// Next available coin ID
+	#[pallet::storage]
+	#[pallet::getter(fn next_coin_id)]
+	pub type NextCoinId<T: Config> = StorageValue<_, CoinId, ValueQuery>;
+
+	/// Account whose reserved native balance backs a coin's creation deposit
+	#[pallet::storage]
+	#[pallet::getter(fn coin_depositor)]
+	pub type CoinDepositors<T: Config> = StorageMap<_, Blake2_128Concat, CoinId, T::AccountId, OptionQuery>;
+
+	/// Sum of the creation deposits reserved for all coins
+	#[pallet::storage]
+	#[pallet::getter(fn total_coin_deposits)]
+	pub type TotalCoinDeposits<T: Config> = StorageValue<_, DepositBalanceOf<T>, ValueQuery>;
+
+	/// Minting permissions: CoinId -> AccountId -> bool
+	#[pallet::storage]
+	#[pallet::getter(fn mint_permissions)]
+	pub type MintPermissions<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CoinId,
+		Blake2_128Concat,
+		T::AccountId,
+		bool,
+		ValueQuery,
+	>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn burn_permissions)]
+    pub type BurnPermissions<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        CoinId,
+        Blake2_128Concat,
+        T::AccountId,
+        bool,
+        ValueQuery,
+    >;
+
+	/// Storage for user's preferred fee coin
+	#[pallet::storage]
+	#[pallet::getter(fn preferred_fee_coin)]
+	pub type PreferredFeeCoin<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		CoinId,
+		OptionQuery,
+	>;
+
+	/// Dual-control guard for sensitive fee configuration changes
+	#[pallet::storage]
+	#[pallet::getter(fn fee_change_guard)]
+	pub type FeeChangeGuards<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		CoinId,
+		FeeChangeGuardOf<T>,
+		OptionQuery,
+	>;
+
+	/// Sensitive fee configuration changes awaiting confirmation
+	#[pallet::storage]
+	#[pallet::getter(fn pending_fee_change)]
+	pub type PendingFeeChanges<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		CoinId,
+		PendingFeeChange<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// Progress of the `on_idle` sweep removing zero balance entries
+	#[pallet::storage]
+	#[pallet::getter(fn zero_balance_sweep)]
+	pub type ZeroBalanceSweep<T: Config> = StorageValue<_, SweepState, ValueQuery>;
+
+	/// Secondary account allowed to perform day-to-day admin calls for a coin
+	#[pallet::storage]
+	#[pallet::getter(fn coin_manager)]
+	pub type CoinManagers<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		CoinId,
+		T::AccountId,
+		OptionQuery,
+	>;
+
+	/// Accrual index of rebasing coins: the amount one stored unit is worth.
+	/// Coins without an entry are not rebasing.
+	#[pallet::storage]
+	#[pallet::getter(fn accrual_index)]
+	pub type AccrualIndex<T: Config> = StorageMap<_, Blake2_128Concat, CoinId, FixedU128, OptionQuery>;
+
+	/// Decay rate of coins whose balances expire over time
+	#[pallet::storage]
+	#[pallet::getter(fn decay_rate)]
+	pub type DecayRates<T: Config> = StorageMap<_, Blake2_128Concat, CoinId, DecayConfigOf<T>, OptionQuery>;
+
+	/// Block from which pending decay of an account's balance is counted
+	#[pallet::storage]
+	#[pallet::getter(fn last_touched)]
+	pub type LastTouched<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CoinId,
+		Blake2_128Concat,
+		T::AccountId,
+		BlockNumberFor<T>,
+		OptionQuery,
+	>;
+
+	/// Staking reward pool of each coin
+	#[pallet::storage]
+	#[pallet::getter(fn reward_pool)]
+	pub type RewardPools<T: Config> = StorageMap<_, Blake2_128Concat, CoinId, RewardPoolOf<T>, ValueQuery>;
+
+	/// Stakes: CoinId -> AccountId -> StakeInfo
+	#[pallet::storage]
+	#[pallet::getter(fn stake_of)]
+	pub type Stakes<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CoinId,
+		Blake2_128Concat,
+		T::AccountId,
+		StakeInfo,
+		ValueQuery,
+	>;
+
+	/// Coins locked for voting: CoinId -> AccountId -> VoteLock
+	#[pallet::storage]
+	#[pallet::getter(fn vote_lock)]
+	pub type VoteLocks<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CoinId,
+		Blake2_128Concat,
+		T::AccountId,
+		VoteLockOf<T>,
+		OptionQuery,
+	>;
+
+	/// Launch sales by id
+	#[pallet::storage]
+	#[pallet::getter(fn sale)]
+	pub type Sales<T: Config> = StorageMap<_, Blake2_128Concat, SaleId, SaleOf<T>, OptionQuery>;
+
+	/// Next available sale ID
+	#[pallet::storage]
+	#[pallet::getter(fn next_sale_id)]
+	pub type NextSaleId<T: Config> = StorageValue<_, SaleId, ValueQuery>;
+
+	/// Amount of the sold coin each account bought in a sale: SaleId -> AccountId -> amount.
+	/// Kept after the sale closes as its public record.
+	#[pallet::storage]
+	#[pallet::getter(fn sale_contribution)]
+	pub type SaleContributions<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		SaleId,
+		Blake2_128Concat,
+		T::AccountId,
+		u128,
+		ValueQuery,
+	>;
+
+	/// Open OTC orders by id
+	#[pallet::storage]
+	#[pallet::getter(fn order)]
+	pub type Orders<T: Config> = StorageMap<_, Blake2_128Concat, OrderId, Order<T::AccountId>, OptionQuery>;
+
+	/// Next available order ID
+	#[pallet::storage]
+	#[pallet::getter(fn next_order_id)]
+	pub type NextOrderId<T: Config> = StorageValue<_, OrderId, ValueQuery>;
+
+	/// Recurring payments by id
+	#[pallet::storage]
+	#[pallet::getter(fn subscription)]
+	pub type Subscriptions<T: Config> = StorageMap<_, Blake2_128Concat, SubscriptionId, SubscriptionOf<T>, OptionQuery>;
+
+	/// Next available subscription ID
+	#[pallet::storage]
+	#[pallet::getter(fn next_subscription_id)]
+	pub type NextSubscriptionId<T: Config> = StorageValue<_, SubscriptionId, ValueQuery>;
+
+	/// Accounts a coin may not be transferred into: CoinId -> AccountId
+	#[pallet::storage]
+	#[pallet::getter(fn blocked_sink)]
+	pub type BlockedSinks<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CoinId,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
+	/// Recovery account and delay designated by each holder
+	#[pallet::storage]
+	#[pallet::getter(fn recovery_config)]
+	pub type RecoveryConfigs<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, RecoveryConfigOf<T>, OptionQuery>;
+
+	/// Announced recoveries: lost account -> block from which its balances may be recovered
+	#[pallet::storage]
+	#[pallet::getter(fn active_recovery)]
+	pub type ActiveRecoveries<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+	/// Dormant balance reclamation settings of coins that opted in at creation
+	#[pallet::storage]
+	#[pallet::getter(fn dormancy_config)]
+	pub type DormancyConfigs<T: Config> = StorageMap<_, Blake2_128Concat, CoinId, DormancyConfigOf<T>, OptionQuery>;
+
+	/// Last block an account's balance changed, tracked only for coins with a dormancy config
+	#[pallet::storage]
+	#[pallet::getter(fn last_activity)]
+	pub type LastActivity<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CoinId,
+		Blake2_128Concat,
+		T::AccountId,
+		BlockNumberFor<T>,
+		OptionQuery,
+	>;
+
+	/// Block at which a dormant account was warned of an upcoming sweep
+	#[pallet::storage]
+	#[pallet::getter(fn dormancy_warning)]
+	pub type DormancyWarnings<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CoinId,
+		Blake2_128Concat,
+		T::AccountId,
+		BlockNumberFor<T>,
+		OptionQuery,
+	>;
+
+	/// Number of accounts holding a non-zero balance of each coin
+	#[pallet::storage]
+	#[pallet::getter(fn holder_count)]
+	pub type HolderCount<T: Config> = StorageMap<_, Blake2_128Concat, CoinId, u32, ValueQuery>;
+
+	/// Optional cap on the number of holders of a coin
+	#[pallet::storage]
+	#[pallet::getter(fn max_holders)]
+	pub type MaxHolders<T: Config> = StorageMap<_, Blake2_128Concat, CoinId, u32, OptionQuery>;
+
+	/// Royalty charged on transfers of a coin between non-exempt accounts
+	#[pallet::storage]
+	#[pallet::getter(fn royalty)]
+	pub type Royalties<T: Config> = StorageMap<_, Blake2_128Concat, CoinId, RoyaltyConfigOf<T>, OptionQuery>;
+
+	/// Accounts whose transfers of a coin are royalty free: CoinId -> AccountId
+	#[pallet::storage]
+	#[pallet::getter(fn royalty_exempt)]
+	pub type RoyaltyExempt<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CoinId,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
+	/// Blocks freshly minted coins stay untransferable for, per coin
+	#[pallet::storage]
+	#[pallet::getter(fn mint_lock_period)]
+	pub type MintLockPeriods<T: Config> = StorageMap<_, Blake2_128Concat, CoinId, BlockNumberFor<T>, OptionQuery>;
+
+	/// Running mint lock-ups: CoinId -> AccountId -> MintLock
+	#[pallet::storage]
+	#[pallet::getter(fn mint_lock)]
+	pub type MintLocks<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CoinId,
+		Blake2_128Concat,
+		T::AccountId,
+		MintLockOf<T>,
+		OptionQuery,
+	>;
+
+	/// Labeled sub-balances carved out of an account's balance: CoinId -> AccountId -> labels
+	#[pallet::storage]
+	#[pallet::getter(fn sub_balances)]
+	pub type SubBalances<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CoinId,
+		Blake2_128Concat,
+		T::AccountId,
+		LabeledBalances,
+		ValueQuery,
+	>;
+
+	/// Faucet settings of coins that can be dripped on test networks
+	#[pallet::storage]
+	#[pallet::getter(fn faucet)]
+	pub type Faucets<T: Config> = StorageMap<_, Blake2_128Concat, CoinId, FaucetConfigOf<T>, OptionQuery>;
+
+	/// Block of each account's last faucet drip: CoinId -> AccountId -> BlockNumber
+	#[pallet::storage]
+	#[pallet::getter(fn last_drip)]
+	pub type LastDrip<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CoinId,
+		Blake2_128Concat,
+		T::AccountId,
+		BlockNumberFor<T>,
+		OptionQuery,
+	>;
+
+	/// Mint/burn grants made to pallet accounts: CoinId -> pallet account -> PalletId bytes
+	#[pallet::storage]
+	#[pallet::getter(fn pallet_grants)]
+	pub type PalletGrants<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CoinId,
+		Blake2_128Concat,
+		T::AccountId,
+		[u8; 8],
+		OptionQuery,
+	>;
+
+	/// Per-window mint caps of bridge pallet accounts: CoinId -> bridge account -> cap
+	#[pallet::storage]
+	#[pallet::getter(fn bridge_mint_caps)]
+	pub type BridgeMintCaps<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CoinId,
+		Blake2_128Concat,
+		T::AccountId,
+		u128,
+		OptionQuery,
+	>;
+
+	/// Start of the current cap window and amount minted in it by each bridge
+	#[pallet::storage]
+	pub type BridgeMinted<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CoinId,
+		Blake2_128Concat,
+		T::AccountId,
+		(BlockNumberFor<T>, u128),
+		OptionQuery,
+	>;
+
+	/// Price oracle settings of coins
+	#[pallet::storage]
+	#[pallet::getter(fn oracle_configs)]
+	pub type OracleConfigs<T: Config> = StorageMap<_, Blake2_128Concat, CoinId, OracleConfigOf<T>, OptionQuery>;
+
+	/// Accounts allowed to feed prices of a coin
+	#[pallet::storage]
+	#[pallet::getter(fn price_feeders)]
+	pub type PriceFeeders<T: Config> =
+		StorageMap<_, Blake2_128Concat, CoinId, BoundedVec<T::AccountId, T::MaxPriceFeeders>, ValueQuery>;
+
+	/// Latest price reported by each feeder of a coin
+	#[pallet::storage]
+	pub type PriceFeeds<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CoinId,
+		Blake2_128Concat,
+		T::AccountId,
+		PriceFeedOf<T>,
+		OptionQuery,
+	>;
+
+	/// Coins proposed by their owner as fee coins: CoinId -> proposer
+	#[pallet::storage]
+	#[pallet::getter(fn fee_coin_proposals)]
+	pub type FeeCoinProposals<T: Config> = StorageMap<_, Blake2_128Concat, CoinId, T::AccountId, OptionQuery>;
+
+	/// Coins admitted by `FeeCoinAdmissionOrigin` to pay transaction fees
+	#[pallet::storage]
+	pub type AdmittedFeeCoins<T: Config> = StorageMap<_, Blake2_128Concat, CoinId, (), OptionQuery>;
+
+	/// Emergency switch forcing every transaction fee to be paid in the native currency
+	#[pallet::storage]
+	#[pallet::getter(fn fee_payment_suspended)]
+	pub type FeePaymentSuspended<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// Native value of the fees paid in each coin in the current block
+	#[pallet::storage]
+	pub type FeeUsage<T: Config> = StorageMap<_, Blake2_128Concat, CoinId, u128, ValueQuery>;
+
+	/// Native value of all fees, native or coin, paid in the current block
+	#[pallet::storage]
+	pub type TotalFeeUsage<T: Config> = StorageValue<_, u128, ValueQuery>;
+
+	/// Fee multipliers of coins paying a disproportionate share of fees; absent means one
+	#[pallet::storage]
+	#[pallet::getter(fn fee_multipliers)]
+	pub type FeeMultipliers<T: Config> = StorageMap<_, Blake2_128Concat, CoinId, FixedU128, OptionQuery>;
+
+	/// Collateralized minting settings of stablecoins
+	#[pallet::storage]
+	#[pallet::getter(fn stable_configs)]
+	pub type StableConfigs<T: Config> = StorageMap<_, Blake2_128Concat, CoinId, StableConfig, OptionQuery>;
+
+	/// Collateral positions: stable CoinId -> AccountId -> CollateralPosition
+	#[pallet::storage]
+	#[pallet::getter(fn collateral_positions)]
+	pub type CollateralPositions<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CoinId,
+		Blake2_128Concat,
+		T::AccountId,
+		CollateralPosition,
+		OptionQuery,
+	>;
+
+	/// Next liquidation auction ID
+	#[pallet::storage]
+	#[pallet::getter(fn next_auction_id)]
+	pub type NextAuctionId<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Open liquidation auctions
+	#[pallet::storage]
+	#[pallet::getter(fn liquidation_auctions)]
+	pub type LiquidationAuctions<T: Config> = StorageMap<_, Blake2_128Concat, u32, LiquidationAuctionOf<T>, OptionQuery>;
+
+	/// Fee of flash mints of a coin; coins without one can't be flash minted
+	#[pallet::storage]
+	#[pallet::getter(fn flash_mint_fees)]
+	pub type FlashMintFees<T: Config> = StorageMap<_, Blake2_128Concat, CoinId, Permill, OptionQuery>;
+
+	/// Coins on hold per reason: CoinId -> AccountId -> [(reason, stored units)]
+	#[pallet::storage]
+	#[pallet::getter(fn holds)]
+	pub type Holds<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CoinId,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<(T::RuntimeHoldReason, u128), T::MaxHolds>,
+		ValueQuery,
+	>;
+
+	/// Next transfer ticket ID
+	#[pallet::storage]
+	#[pallet::getter(fn next_ticket_id)]
+	pub type NextTicketId<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Unclaimed transfer tickets
+	#[pallet::storage]
+	#[pallet::getter(fn transfer_tickets)]
+	pub type TransferTickets<T: Config> = StorageMap<_, Blake2_128Concat, u32, TransferTicketOf<T>, OptionQuery>;
+
+	/// Recent supply checkpoints of each coin, oldest first
+	#[pallet::storage]
+	#[pallet::getter(fn supply_checkpoints)]
+	pub type SupplyCheckpoints<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		CoinId,
+		BoundedVec<SupplyCheckpointOf<T>, T::MaxSupplyCheckpoints>,
+		ValueQuery,
+	>;
+
+	/// Transfer and transaction fees of each coin burned since its last supply checkpoint
+	#[pallet::storage]
+	pub type FeesBurnedSinceCheckpoint<T: Config> = StorageMap<_, Blake2_128Concat, CoinId, u128, ValueQuery>;
+
+	/// Transfer and transaction fees of each coin burned since genesis
+	#[pallet::storage]
+	#[pallet::getter(fn total_fees_burned)]
+	pub type TotalFeesBurned<T: Config> = StorageMap<_, Blake2_128Concat, CoinId, u128, ValueQuery>;
+
+	/// Progress of the `on_idle` cleanup of per-account entries of emptied accounts
+	#[pallet::storage]
+	#[pallet::getter(fn dust_cleanup)]
+	pub type DustCleanup<T: Config> = StorageValue<_, DustCleanupState, ValueQuery>;
+
+	/// Coins whose transfers are paused, with the accounts still allowed to send
+	/// and receive them (e.g. a bridge custodian or the treasury)
+	#[pallet::storage]
+	#[pallet::getter(fn paused_except)]
+	pub type PausedExcept<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		CoinId,
+		BoundedVec<T::AccountId, T::MaxPauseExceptions>,
+		OptionQuery,
+	>;
+
+	/// Content hash of each coin's legal documents (prospectus, terms), set by its issuer
+	#[pallet::storage]
+	#[pallet::getter(fn legal_document)]
+	pub type LegalDocuments<T: Config> = StorageMap<_, Blake2_128Concat, CoinId, DocumentHash, OptionQuery>;
+
+	/// Transfer volume and distinct senders of each coin over its latest
+	/// `MaxVelocityEras` eras, oldest first
+	#[pallet::storage]
+	#[pallet::getter(fn coin_velocity)]
+	pub type CoinVelocity<T: Config> =
+		StorageMap<_, Blake2_128Concat, CoinId, BoundedVec<VelocityStats, T::MaxVelocityEras>, ValueQuery>;
+
+	/// Last era each account sent each coin in, to count distinct senders
+	#[pallet::storage]
+	pub type VelocitySenders<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CoinId,
+		Blake2_128Concat,
+		T::AccountId,
+		u32,
+		OptionQuery,
+	>;
+
+	/// Allowances of delegates to transfer the coins of owners, by coin and (owner, delegate)
+	#[pallet::storage]
+	#[pallet::getter(fn approval)]
+	pub type Approvals<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CoinId,
+		Blake2_128Concat,
+		(T::AccountId, T::AccountId),
+		Approval<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// Approval nonce of each owner per coin; allowances granted under an older nonce are void
+	#[pallet::storage]
+	#[pallet::getter(fn approval_nonce)]
+	pub type ApprovalNonces<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, CoinId, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Accounts that may not send, receive, mint or burn a coin: CoinId -> AccountId
+	#[pallet::storage]
+	#[pallet::getter(fn frozen_account)]
+	pub type FrozenAccounts<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CoinId,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
+	/// Latest complete holder snapshot of a coin, drawn from by `sample_holders`
+	#[pallet::storage]
+	#[pallet::getter(fn holder_snapshot)]
+	pub type HolderSnapshots<T: Config> = StorageMap<_, Blake2_128Concat, CoinId, HolderSnapshot, OptionQuery>;
+
+	/// Holder snapshot of a coin being built by `checkpoint_holders`
+	#[pallet::storage]
+	pub type PendingHolderSnapshots<T: Config> = StorageMap<_, Blake2_128Concat, CoinId, HolderSnapshot, OptionQuery>;
+
+	/// Segments of holder snapshots: CoinId -> (slot, segment index) -> checkpoint
+	#[pallet::storage]
+	#[pallet::getter(fn holder_checkpoints)]
+	pub type HolderCheckpoints<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, CoinId, Twox64Concat, (u8, u32), HolderCheckpoint, OptionQuery>;
+
+	/// Localized names and descriptions of a coin by locale code
+	#[pallet::storage]
+	#[pallet::getter(fn coin_localizations)]
+	pub type CoinLocalizations<T: Config> = StorageMap<_, Blake2_128Concat, CoinId, LocalizationsOf<T>, ValueQuery>;
+
+	/// Deposit held for a coin's optional metadata, scaled by its byte length
+	#[pallet::storage]
+	#[pallet::getter(fn metadata_deposit)]
+	pub type MetadataDeposits<T: Config> = StorageMap<_, Blake2_128Concat, CoinId, MetadataDepositOf<T>, OptionQuery>;
+
+	/// Description, logo, homepage and external chain reference of a coin
+	#[pallet::storage]
+	#[pallet::getter(fn extended_metadata)]
+	pub type ExtendedMetadata<T: Config> =
+		StorageMap<_, Blake2_128Concat, CoinId, ExtendedCoinMetadataOf<T>, OptionQuery>;
+
+	/// Operators an account authorized to set its preferred fee coin: account -> operator
+	#[pallet::storage]
+	#[pallet::getter(fn fee_operators)]
+	pub type FeeOperators<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		bool,
+		ValueQuery,
+	>;
+
+	/// Open liquidation auctions, launch sales and transfer tickets holding a coin or
+	/// paid in it, which keep the coin from being destroyed
+	#[pallet::storage]
+	#[pallet::getter(fn coin_references)]
+	pub type CoinReferences<T: Config> = StorageMap<_, Blake2_128Concat, CoinId, u32, ValueQuery>;
+
+	/// Events emitted by this pallet
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A new coin was created
+		CoinCreated {
+			coin_id: CoinId,
+			symbol: Vec<u8>,
+			name: Vec<u8>,
+			creator: T::AccountId,
+			initial_supply: u128,
+		},
+		/// Coins were transferred
+		Transfer {
+			coin_id: CoinId,
+			from: T::AccountId,
+			to: T::AccountId,
+			amount: u128,
+		},
+		/// Coins were minted
+		Minted {
+			coin_id: CoinId,
+			to: T::AccountId,
+			amount: u128,
+		},
+		/// Coins were burned
+		Burned {
+			coin_id: CoinId,
+			from: T::AccountId,
+			amount: u128,
+		},
+		/// Coin ownership was transferred
+		OwnershipTransferred {
+			coin_id: CoinId,
+			old_owner: T::AccountId,
+			new_owner: T::AccountId,
+		},
+		/// Mint permission was granted or revoked
+		MintPermissionSet {
+			coin_id: CoinId,
+			account: T::AccountId,
+			can_mint: bool,
+		},
+		/// Coin metadata was updated
+		MetadataUpdated {
+			coin_id: CoinId,
+		},
+        /// Burn permission was granted or revoked
+        BurnPermissionSet {
+            coin_id: CoinId,
+            account: T::AccountId,
+            can_burn: bool,
+        },
+        /// Fee configuration was updated
+        FeeConfigUpdated {
+            coin_id: CoinId,
+            transfer_fee: u128,
+            minimum_balance: u128,
+			can_pay_tx_fees: bool, // Add this field
+			call_scope: FeeCallScope,
+        },
+		/// Preferred fee coin set for an account
+		PreferredFeeCoinSet {
+			account: T::AccountId,
+			coin_id: Option<CoinId>,
+		},
+		/// Fee change guard was installed, replaced or removed
+		FeeGuardSet {
+			coin_id: CoinId,
+			guard: Option<FeeChangeGuardOf<T>>,
+		},
+		/// A sensitive fee change was queued for confirmation
+		FeeChangeAnnounced {
+			coin_id: CoinId,
+			fee_config: FeeConfig,
+			enact_after: BlockNumberFor<T>,
+		},
+		/// A pending fee change was cancelled
+		FeeChangeCancelled {
+			coin_id: CoinId,
+		},
+		/// All transfers, mints and burns of a coin were halted
+		CoinFrozen {
+			coin_id: CoinId,
+		},
+		/// A frozen coin was made live again
+		CoinThawed {
+			coin_id: CoinId,
+		},
+		/// Coin manager was set or removed
+		CoinManagerSet {
+			coin_id: CoinId,
+			manager: Option<T::AccountId>,
+		},
+		/// Accrual index of a rebasing coin was updated
+		AccrualIndexUpdated {
+			coin_id: CoinId,
+			index: FixedU128,
+		},
+		/// Decay rate of a coin was set or removed
+		DecayRateSet {
+			coin_id: CoinId,
+			decay: Option<DecayConfigOf<T>>,
+		},
+		/// Part of an account's balance decayed and was burned
+		Decayed {
+			coin_id: CoinId,
+			who: T::AccountId,
+			amount: u128,
+		},
+		/// Staking reward rate of a coin was set
+		RewardRateSet {
+			coin_id: CoinId,
+			reward_per_block: u128,
+		},
+		/// Rewards were added to a coin's staking pool
+		RewardPoolFunded {
+			coin_id: CoinId,
+			funder: T::AccountId,
+			amount: u128,
+		},
+		/// Coins were staked
+		Staked {
+			coin_id: CoinId,
+			who: T::AccountId,
+			amount: u128,
+		},
+		/// Staked coins were returned to their owner
+		Unstaked {
+			coin_id: CoinId,
+			who: T::AccountId,
+			amount: u128,
+		},
+		/// Staking rewards were paid out
+		RewardsClaimed {
+			coin_id: CoinId,
+			who: T::AccountId,
+			amount: u128,
+		},
+		/// Coins were locked for voting
+		VoteLocked {
+			coin_id: CoinId,
+			who: T::AccountId,
+			amount: u128,
+			conviction: Conviction,
+			unlock_at: BlockNumberFor<T>,
+		},
+		/// A vote lock was removed
+		VoteUnlocked {
+			coin_id: CoinId,
+			who: T::AccountId,
+		},
+		/// A launch sale was opened
+		SaleOpened {
+			sale_id: SaleId,
+			coin_id: CoinId,
+			owner: T::AccountId,
+			amount: u128,
+			payment: PaymentAsset,
+			price: FixedU128,
+			end: BlockNumberFor<T>,
+		},
+		/// Coins were bought in a launch sale
+		SalePurchase {
+			sale_id: SaleId,
+			buyer: T::AccountId,
+			amount: u128,
+			cost: u128,
+		},
+		/// Sale proceeds were paid to the sale owner
+		SaleProceedsWithdrawn {
+			sale_id: SaleId,
+			amount: u128,
+		},
+		/// A launch sale was closed and its unsold coins burned
+		SaleClosed {
+			sale_id: SaleId,
+			burned: u128,
+		},
+		/// An OTC order was placed
+		OrderPlaced {
+			order_id: OrderId,
+			maker: T::AccountId,
+			sell_coin: CoinId,
+			sell_amount: u128,
+			buy_coin: CoinId,
+			price: FixedU128,
+		},
+		/// An OTC order was filled, fully or in part
+		OrderFilled {
+			order_id: OrderId,
+			taker: T::AccountId,
+			amount: u128,
+			cost: u128,
+		},
+		/// An OTC order was cancelled and its escrow returned
+		OrderCancelled {
+			order_id: OrderId,
+			refunded: u128,
+		},
+		/// A subscription was created
+		SubscriptionCreated {
+			subscription_id: SubscriptionId,
+			coin_id: CoinId,
+			payer: T::AccountId,
+			payee: T::AccountId,
+			amount: u128,
+			period: BlockNumberFor<T>,
+			count: u32,
+		},
+		/// Due subscription periods were charged
+		SubscriptionCharged {
+			subscription_id: SubscriptionId,
+			periods: u32,
+			amount: u128,
+		},
+		/// A subscription was cancelled by its payer
+		SubscriptionCancelled {
+			subscription_id: SubscriptionId,
+		},
+		/// An amount was split among several recipients
+		SplitTransferred {
+			coin_id: CoinId,
+			from: T::AccountId,
+			amount: u128,
+			recipients: u32,
+		},
+		/// An account was blocked or unblocked as a transfer destination for a coin
+		SinkBlockedSet {
+			coin_id: CoinId,
+			who: T::AccountId,
+			blocked: bool,
+		},
+		/// A holder set or removed its recovery account
+		RecoverySet {
+			who: T::AccountId,
+			config: Option<RecoveryConfigOf<T>>,
+		},
+		/// A recovery of a lost account was announced
+		RecoveryAnnounced {
+			lost: T::AccountId,
+			recoverer: T::AccountId,
+			executable_at: BlockNumberFor<T>,
+		},
+		/// An announced recovery was cancelled by the holder
+		RecoveryCancelled {
+			lost: T::AccountId,
+		},
+		/// A lost account's balance of a coin was moved to its recovery account
+		Recovered {
+			coin_id: CoinId,
+			lost: T::AccountId,
+			recoverer: T::AccountId,
+			amount: u128,
+		},
+		/// A coin was created with dormant balance reclamation
+		DormancyConfigured {
+			coin_id: CoinId,
+			config: DormancyConfigOf<T>,
+		},
+		/// A dormant account was warned that its balance will be swept
+		DormancyWarning {
+			coin_id: CoinId,
+			who: T::AccountId,
+			sweepable_at: BlockNumberFor<T>,
+		},
+		/// A dormant balance was swept to the coin's beneficiary
+		DormantSwept {
+			coin_id: CoinId,
+			who: T::AccountId,
+			beneficiary: T::AccountId,
+			amount: u128,
+		},
+		/// The holder cap of a coin was set or removed
+		MaxHoldersSet {
+			coin_id: CoinId,
+			max_holders: Option<u32>,
+		},
+		/// The royalty of a coin was set or removed
+		RoyaltySet {
+			coin_id: CoinId,
+			royalty: Option<RoyaltyConfigOf<T>>,
+		},
+		/// An account was made exempt from, or subject to, a coin's royalty
+		RoyaltyExemptSet {
+			coin_id: CoinId,
+			who: T::AccountId,
+			exempt: bool,
+		},
+		/// A royalty was paid on a transfer
+		RoyaltyPaid {
+			coin_id: CoinId,
+			from: T::AccountId,
+			beneficiary: T::AccountId,
+			amount: u128,
+		},
+		/// The mint lock-up period of a coin was set or removed
+		MintLockPeriodSet {
+			coin_id: CoinId,
+			period: Option<BlockNumberFor<T>>,
+		},
+		/// Minted coins were locked until the given block
+		MintLocked {
+			coin_id: CoinId,
+			who: T::AccountId,
+			amount: u128,
+			until: BlockNumberFor<T>,
+		},
+		/// Coins were moved between an account's sub-balances; `None` is the unlabeled balance
+		MovedBetweenLabels {
+			coin_id: CoinId,
+			who: T::AccountId,
+			from: Option<Label>,
+			to: Option<Label>,
+			amount: u128,
+		},
+		/// The faucet of a coin was configured or removed
+		FaucetSet {
+			coin_id: CoinId,
+			config: Option<FaucetConfigOf<T>>,
+		},
+		/// Coins were dripped from a coin's faucet
+		Dripped {
+			coin_id: CoinId,
+			who: T::AccountId,
+			amount: u128,
+		},
+		/// Mint/burn permissions of a pallet account were set
+		PalletPermissionGranted {
+			coin_id: CoinId,
+			pallet_id: [u8; 8],
+			account: T::AccountId,
+			mint: bool,
+			burn: bool,
+		},
+		/// The mint cap of a bridge account was set or removed
+		BridgeMintCapSet {
+			coin_id: CoinId,
+			bridge: T::AccountId,
+			cap: Option<u128>,
+		},
+		/// A bridge pallet minted coins within its cap
+		BridgeMinted {
+			coin_id: CoinId,
+			bridge: T::AccountId,
+			to: T::AccountId,
+			amount: u128,
+		},
+		/// The price oracle settings of a coin were set or removed
+		OracleConfigSet {
+			coin_id: CoinId,
+			config: Option<OracleConfigOf<T>>,
+		},
+		/// An account was registered or unregistered as price feeder of a coin
+		PriceFeederSet {
+			coin_id: CoinId,
+			feeder: T::AccountId,
+			enabled: bool,
+		},
+		/// A feeder reported the price of a coin
+		PriceFed {
+			coin_id: CoinId,
+			feeder: T::AccountId,
+			price: FixedU128,
+		},
+		/// A coin owner asked for the coin to be admitted as fee coin
+		FeeCoinProposed {
+			coin_id: CoinId,
+			proposer: T::AccountId,
+		},
+		/// A coin was admitted as fee coin
+		FeeCoinAdmitted {
+			coin_id: CoinId,
+		},
+		/// A coin's fee coin admission or pending proposal was revoked
+		FeeCoinRevoked {
+			coin_id: CoinId,
+		},
+		/// Paying transaction fees in coins was suspended or resumed
+		FeePaymentSuspendedSet {
+			suspended: bool,
+		},
+		/// A fee correction above the withdrawn fee could not be charged
+		FeeShortfall {
+			who: T::AccountId,
+			coin_id: Option<CoinId>,
+			amount: u128,
+		},
+		/// Coins were slashed from an account
+		Slashed {
+			coin_id: CoinId,
+			who: T::AccountId,
+			amount: u128,
+		},
+		/// A coin's collateralized minting settings were set or removed
+		StableConfigSet {
+			coin_id: CoinId,
+			config: Option<StableConfig>,
+		},
+		/// Collateral was locked in a position
+		CollateralDeposited {
+			coin_id: CoinId,
+			who: T::AccountId,
+			amount: u128,
+		},
+		/// Collateral was withdrawn from a position
+		CollateralWithdrawn {
+			coin_id: CoinId,
+			who: T::AccountId,
+			amount: u128,
+		},
+		/// Stablecoins were minted against a position's collateral
+		StableMinted {
+			coin_id: CoinId,
+			who: T::AccountId,
+			amount: u128,
+		},
+		/// Stablecoin debt of a position was repaid
+		StableRepaid {
+			coin_id: CoinId,
+			who: T::AccountId,
+			amount: u128,
+		},
+		/// An undercollateralized position was put up for auction
+		LiquidationStarted {
+			auction_id: u32,
+			coin_id: CoinId,
+			who: T::AccountId,
+			keeper: T::AccountId,
+			collateral: u128,
+			debt: u128,
+		},
+		/// A liquidation auction was settled by a bidder repaying the debt
+		LiquidationSettled {
+			auction_id: u32,
+			bidder: T::AccountId,
+			collateral: u128,
+			keeper_reward: u128,
+		},
+		/// A coin's flash mint fee was set, or flash minting disabled
+		FlashMintFeeSet {
+			coin_id: CoinId,
+			fee: Option<Permill>,
+		},
+		/// Coins were flash minted and burned again with the fee within one call
+		FlashMinted {
+			coin_id: CoinId,
+			who: T::AccountId,
+			amount: u128,
+			fee: u128,
+		},
+		/// Coins were put on hold
+		Held {
+			coin_id: CoinId,
+			who: T::AccountId,
+			reason: T::RuntimeHoldReason,
+			amount: u128,
+		},
+		/// Coins on hold were released
+		Released {
+			coin_id: CoinId,
+			who: T::AccountId,
+			reason: T::RuntimeHoldReason,
+			amount: u128,
+		},
+		/// Coins were set aside in a ticket for a recipient to claim
+		TransferTicketCreated {
+			ticket_id: u32,
+			coin_id: CoinId,
+			from: T::AccountId,
+			to: T::AccountId,
+			amount: u128,
+			expiry: BlockNumberFor<T>,
+		},
+		/// A transfer ticket was claimed by its recipient
+		TransferTicketClaimed {
+			ticket_id: u32,
+			to: T::AccountId,
+			amount: u128,
+		},
+		/// An expired transfer ticket was refunded to its creator
+		TransferTicketRefunded {
+			ticket_id: u32,
+			to: T::AccountId,
+			amount: u128,
+		},
+		/// A coin's supply was checkpointed
+		SupplyCheckpointed {
+			coin_id: CoinId,
+			total_supply: u128,
+			holders: u32,
+			fees_burned: u128,
+		},
+		/// A transfer or transaction fee paid in a coin was burned
+		FeeBurned {
+			coin_id: CoinId,
+			amount: u128,
+		},
+		/// The `on_idle` cleanup visited `scanned` per-account entries and removed
+		/// `removed` of them, left behind by emptied accounts
+		CleanupReport {
+			scanned: u32,
+			removed: u32,
+		},
+		/// Transfers of a coin were paused except for the given accounts, or resumed
+		TransferPauseSet {
+			coin_id: CoinId,
+			except: Option<Vec<T::AccountId>>,
+		},
+		/// The legal document hash of a coin was set or removed
+		LegalDocumentSet {
+			coin_id: CoinId,
+			hash: Option<DocumentHash>,
+		},
+		/// An owner allowed a delegate to transfer up to `amount` of its coins
+		Approved {
+			coin_id: CoinId,
+			owner: T::AccountId,
+			delegate: T::AccountId,
+			amount: u128,
+			expires_at: Option<BlockNumberFor<T>>,
+		},
+		/// A delegate transferred coins of an owner under its allowance
+		TransferredApproved {
+			coin_id: CoinId,
+			owner: T::AccountId,
+			delegate: T::AccountId,
+			destination: T::AccountId,
+			amount: u128,
+		},
+		/// An owner withdrew the allowance of a delegate
+		ApprovalCancelled { coin_id: CoinId, owner: T::AccountId, delegate: T::AccountId },
+		/// An owner voided all its outstanding allowances of a coin
+		ApprovalsInvalidated { coin_id: CoinId, owner: T::AccountId, nonce: u32 },
+		/// An account's balance of a coin was frozen
+		AccountFrozen { coin_id: CoinId, who: T::AccountId },
+		/// A frozen account's balance of a coin may move again
+		AccountThawed { coin_id: CoinId, who: T::AccountId },
+		/// The owner started destroying a coin
+		CoinDestructionStarted { coin_id: CoinId },
+		/// Per-account entries of a coin being destroyed were removed
+		CoinAccountsDestroyed { coin_id: CoinId, removed: u32, complete: bool },
+		/// A coin was destroyed and its creation deposit returned
+		CoinDestroyed { coin_id: CoinId, owner: T::AccountId, deposit: DepositBalanceOf<T> },
+		/// The issuer, admin and freezer of a coin were set
+		TeamChanged { coin_id: CoinId, issuer: T::AccountId, admin: T::AccountId, freezer: T::AccountId },
+		/// Amounts of a coin were sent to several recipients in one batch
+		BatchTransferred { coin_id: CoinId, from: T::AccountId, recipients: u32, total: u128 },
+		/// The owner of a coin renounced it; no signed account can administer it anymore
+		OwnershipRenounced { coin_id: CoinId, old_owner: T::AccountId },
+		/// The creation deposit of a coin moved to another account
+		CoinDepositMoved { coin_id: CoinId, from: T::AccountId, to: T::AccountId, amount: DepositBalanceOf<T> },
+		/// `ForceOrigin` moved coins between two accounts, see `Transfer`
+		ForcedTransfer { coin_id: CoinId, from: T::AccountId, to: T::AccountId, amount: u128 },
+		/// `ForceOrigin` minted coins, see `Minted`
+		ForcedMint { coin_id: CoinId, to: T::AccountId, amount: u128 },
+		/// `ForceOrigin` burned coins, see `Burned`
+		ForcedBurn { coin_id: CoinId, from: T::AccountId, amount: u128 },
+		/// `checkpoint_holders` extended a coin's holder snapshot, which replaced the
+		/// previous one if `complete`
+		HolderSnapshotUpdated { coin_id: CoinId, checkpoints: u32, total: u128, complete: bool },
+		/// A coin's symbol, name and decimals were updated
+		MetadataSet { coin_id: CoinId, symbol: Vec<u8>, name: Vec<u8>, decimals: u8 },
+		/// The localized name and description of a coin for a locale were set, or
+		/// removed if both are `None`
+		LocalizationSet {
+			coin_id: CoinId,
+			locale: Vec<u8>,
+			name: Option<Vec<u8>>,
+			description: Option<Vec<u8>>,
+		},
+		/// The extended metadata of a coin was set, or removed if every field is `None`
+		ExtendedMetadataSet {
+			coin_id: CoinId,
+			description: Option<Vec<u8>>,
+			logo_uri: Option<Vec<u8>>,
+			homepage: Option<Vec<u8>>,
+			external_reference: Option<Vec<u8>>,
+		},
+		/// An account authorized or deauthorized an operator to set its preferred fee coin
+		FeeOperatorSet {
+			account: T::AccountId,
+			operator: T::AccountId,
+			authorized: bool,
+		},
+	}
+
+	/// Errors that can occur when using this pallet
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The coin does not exist
+		CoinNotFound,
+		/// Insufficient balance for the operation
+		InsufficientBalance,
+		/// Arithmetic overflow occurred
+		Overflow,
+		/// The symbol is already in use
+		SymbolAlreadyExists,
+		/// Symbol is too long
+		SymbolTooLong,
+		/// Name is too long
+		NameTooLong,
+		/// Maximum number of coins reached
+		TooManyCoins,
+		/// Not authorized for this operation
+		NotAuthorized,
+		/// Cannot transfer to the same account
+		TransferToSelf,
+		/// Initial supply exceeds maximum allowed
+		ExceedsMaxSupply,
+		/// Amount is zero
+		ZeroAmount,
+		/// No minting permission
+		NoMintPermission,
+        /// No burning permission
+        NoBurnPermission,
+        /// Balance would fall below minimum required
+        BelowMinimumBalance,
+		/// Coin cannot be used to pay transaction fees
+		CannotPayFees,
+		/// The coin is frozen
+		CoinFrozen,
+		/// The coin is being destroyed
+		CoinDestroying,
+		/// There is no pending fee change for the coin
+		NoPendingFeeChange,
+		/// The announcement delay of the pending fee change has not passed yet
+		FeeChangeNotReady,
+		/// The pending fee change must be approved by the guard's approver
+		FeeChangeNeedsApproval,
+		/// Only the approver can relax or remove the fee change guard
+		FeeGuardRelaxationNotAllowed,
+		/// The coin is not frozen
+		CoinNotFrozen,
+		/// The accrual index must be greater than zero
+		InvalidAccrualIndex,
+		/// The decay period must be greater than zero
+		InvalidDecayPeriod,
+		/// A coin can be either rebasing or decaying, not both
+		IncompatibleSupplyMode,
+		/// The account has not staked enough
+		InsufficientStake,
+		/// There are no rewards to claim
+		NothingToClaim,
+		/// The balance is locked for voting or was minted too recently
+		BalanceLocked,
+		/// A vote lock can only be extended, not reduced
+		VoteLockReduced,
+		/// There is no vote lock
+		NoVoteLock,
+		/// The vote lock has not expired yet
+		VoteLockNotExpired,
+		/// The sale does not exist
+		SaleNotFound,
+		/// The sale no longer accepts purchases
+		SaleEnded,
+		/// The sale is still running
+		SaleNotEnded,
+		/// Not enough coins left for sale, or the per-account cap would be exceeded
+		SaleCapExceeded,
+		/// The payment coin can't be the coin being sold
+		InvalidPaymentAsset,
+		/// The order does not exist
+		OrderNotFound,
+		/// The fill is larger than what is left on the order
+		OrderAmountExceeded,
+		/// The subscription does not exist
+		SubscriptionNotFound,
+		/// Subscription period and count must be non-zero
+		InvalidSubscription,
+		/// No subscription charge is due yet
+		NothingDue,
+		/// Split recipients must be non-empty and their shares must add up to 100%
+		InvalidSplit,
+		/// The recipient is blocked from receiving this coin
+		RecipientBlocked,
+		/// The account has no recovery account designated
+		NoRecoveryConfig,
+		/// No recovery has been announced for the account
+		RecoveryNotAnnounced,
+		/// The recovery delay has not passed yet
+		RecoveryDelayNotPassed,
+		/// The dormancy period must be non-zero
+		InvalidDormancyConfig,
+		/// The coin was not created with dormant balance reclamation
+		DormancyNotEnabled,
+		/// The account has been active within the dormancy period or since its warning
+		AccountNotDormant,
+		/// The account has not been warned
+		NoDormancyWarning,
+		/// The grace period after the warning has not passed yet
+		DormancyGraceNotPassed,
+		/// The coin has reached its maximum number of holders
+		TooManyHolders,
+		/// Royalty rate exceeds 10_000 basis points
+		InvalidRoyalty,
+		/// Source and destination sub-balance are the same
+		SameLabel,
+		/// The account already has the maximum number of labeled sub-balances
+		TooManyLabels,
+		/// The faucet is not compiled in or not configured for this coin
+		FaucetDisabled,
+		/// The account's faucet cooldown has not passed yet
+		FaucetCooldown,
+		/// The bridge has no mint cap or would exceed it in the current window
+		BridgeMintCapExceeded,
+		/// Oracle settings need between one and `MaxPriceFeeders` feeds
+		InvalidOracleConfig,
+		/// The coin has no price oracle
+		OracleNotConfigured,
+		/// The coin already has `MaxPriceFeeders` feeders
+		TooManyPriceFeeders,
+		/// The caller is not a price feeder of the coin
+		NotPriceFeeder,
+		/// Prices must be non-zero
+		InvalidPrice,
+		/// The price deviates too far from the current median price
+		PriceDeviationTooLarge,
+		/// The coin has no pending fee coin proposal
+		FeeCoinNotProposed,
+		/// The coin is already admitted as fee coin
+		FeeCoinAlreadyAdmitted,
+		/// Stable settings need `min_ratio >= liquidation_ratio >= 1` and other collateral than the coin itself
+		InvalidStableConfig,
+		/// The coin can't be minted against collateral
+		NotStableCoin,
+		/// An asset has no fresh oracle price
+		PriceUnavailable,
+		/// The position's collateral ratio would fall below the minimum
+		CollateralRatioTooLow,
+		/// The account has no collateral position in the coin
+		PositionNotFound,
+		/// The position is collateralized well enough not to be liquidated
+		PositionSafe,
+		/// More would be repaid than the position owes
+		RepayExceedsDebt,
+		/// Positions are open, so minting can't be disabled or switched to other collateral
+		StablePositionsOpen,
+		/// No liquidation auction with this ID is open
+		AuctionNotFound,
+		/// The coin can't be flash minted
+		FlashMintDisabled,
+		/// The flash minted amount plus fee could not be burned after the inner call
+		FlashMintNotRepaid,
+		/// The account already has `MaxHolds` hold reasons in the coin
+		TooManyHolds,
+		/// No transfer ticket with this ID exists
+		TicketNotFound,
+		/// A ticket's expiry must lie in the future
+		InvalidTicketExpiry,
+		/// The ticket expired and can only be refunded
+		TicketExpired,
+		/// The ticket can't be refunded before it expires
+		TicketNotExpired,
+		/// The coin's last supply checkpoint is more recent than `SupplyCheckpointInterval`
+		CheckpointTooSoon,
+		/// Transfers of the coin are paused and neither side is allowlisted
+		TransfersPaused,
+		/// More pause exceptions than `MaxPauseExceptions`
+		TooManyPauseExceptions,
+		/// No allowance covers the transfer
+		Unapproved,
+		/// The allowance expired
+		ApprovalExpired,
+		/// Wrapper calls can't dispatch another wrapper call
+		NestedWrapperCall,
+		/// The account is frozen for this coin
+		AccountFrozen,
+		/// The account is not frozen for this coin
+		AccountNotFrozen,
+		/// The coin is not being destroyed
+		NotDestroying,
+		/// Per-account entries of the coin remain; call `destroy_accounts` first
+		AccountsRemaining,
+		/// The stablecoin still has open collateral positions
+		PositionsOpen,
+		/// Liquidation auctions, launch sales or transfer tickets still hold or take the coin
+		CoinReferenced,
+		/// The transfer would leave the sender without a balance of the coin
+		WouldEmptyAccount,
+		/// The locale code is empty or too long
+		InvalidLocale,
+		/// Description is too long
+		DescriptionTooLong,
+		/// The coin already has localizations for `MaxLocales` locales
+		TooManyLocales,
+		/// A URI or external reference is too long
+		UriTooLong,
+		/// The operator isn't authorized to set the account's preferred fee coin
+		NotFeeOperator,
+	}
+
+	/// Coins created at genesis, so chains don't have to bootstrap them via extrinsics
+	///
+	/// Genesis coins carry no creation deposit. Their owner takes every role and the
+	/// mint and burn permissions, as with `create_coin`.
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// Coins as (id, owner, symbol, name, decimals); `NextCoinId` continues after the highest id
+		pub coins: Vec<(CoinId, T::AccountId, Vec<u8>, Vec<u8>, u8)>,
+		/// Initial balances as (coin id, account, amount), which make up the total supply
+		pub accounts: Vec<(CoinId, T::AccountId, u128)>,
+		/// Fee settings as (coin id, transfer fee, minimum balance, can pay transaction fees);
+		/// coins that can pay transaction fees are admitted as fee coins right away
+		pub fee_configs: Vec<(CoinId, u128, u128, bool)>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for (coin_id, owner, symbol, name, decimals) in &self.coins {
+				assert!(!CoinMetadata::<T>::contains_key(coin_id), "Coin id already in use");
+				assert!(*coin_id < T::MaxCoins::get(), "Coin id exceeds MaxCoins");
+				let symbol: BoundedVec<u8, T::MaxSymbolLength> =
+					symbol.clone().try_into().expect("Coin symbol too long");
+				let name: BoundedVec<u8, T::MaxNameLength> = name.clone().try_into().expect("Coin name too long");
+				assert!(!SymbolToId::<T>::contains_key(&symbol), "Coin symbol already in use");
+
+				SymbolToId::<T>::insert(&symbol, coin_id);
+				CoinMetadata::<T>::insert(
+					coin_id,
+					CoinInfo {
+						symbol,
+						name,
+						decimals: *decimals,
+						owner: owner.clone(),
+						issuer: owner.clone(),
+						admin: owner.clone(),
+						freezer: owner.clone(),
+						deposit: Zero::zero(),
+						fee_config: FeeConfig {
+							transfer_fee: 0,
+							minimum_balance: 0,
+							can_pay_tx_fees: false,
+							call_scope: FeeCallScope::Any,
+						},
+						status: CoinStatus::Live,
+					},
+				);
+				MintPermissions::<T>::insert(coin_id, owner, true);
+				BurnPermissions::<T>::insert(coin_id, owner, true);
+				NextCoinId::<T>::mutate(|next| *next = (*next).max(coin_id.saturating_add(1)));
+			}
+
+			for (coin_id, transfer_fee, minimum_balance, can_pay_tx_fees) in &self.fee_configs {
+				CoinMetadata::<T>::mutate(coin_id, |coin_info| {
+					let coin_info = coin_info.as_mut().expect("Fee config for unknown coin");
+					coin_info.fee_config.transfer_fee = *transfer_fee;
+					coin_info.fee_config.minimum_balance = *minimum_balance;
+					coin_info.fee_config.can_pay_tx_fees = *can_pay_tx_fees;
+				});
+				if *can_pay_tx_fees {
+					AdmittedFeeCoins::<T>::insert(coin_id, ());
+				}
+			}
+
+			for (coin_id, who, amount) in &self.accounts {
+				assert!(CoinMetadata::<T>::contains_key(coin_id), "Balance for unknown coin");
+				let supply = TotalSupply::<T>::get(coin_id).checked_add(*amount).expect("Coin supply overflow");
+				assert!(supply <= T::MaxSupply::get(), "Coin supply exceeds MaxSupply");
+				TotalSupply::<T>::insert(coin_id, supply);
+				Pallet::<T>::set_balance(*coin_id, who, Balances::<T>::get(coin_id, who).saturating_add(*amount));
+			}
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			Self::adjust_fee_multipliers()
+		}
+
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let used = Self::sweep_zero_balances(remaining_weight);
+			used.saturating_add(Self::cleanup_dust(remaining_weight.saturating_sub(used)))
+		}
+
+		fn integrity_test() {
+			assert!(
+				T::TransferHook::weight().all_lte(T::MaxTransferHookWeight::get()),
+				"TransferHook weight exceeds MaxTransferHookWeight"
+			);
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a new coin with the specified metadata and initial supply
+		///
+		/// The origin must be signed and will become the owner of the coin.
+		/// A deposit is required to create a coin.
+		///
+		/// # Arguments
+		/// * `symbol` - The coin symbol (e.g., "BTC", "ETH")
+		/// * `name` - The coin name (e.g., "Bitcoin", "Ethereum")
+		/// * `decimals` - Number of decimal places
+		/// * `initial_supply` - Initial supply of coins to mint to creator
+		/// * `initial_minters` - Additional minters, at most `MaxInitialMinters` (checked when the
+		///   call is decoded); duplicates and the creator are ignored and grants are emitted in
+		///   ascending account order
+		/// * `initial_burners` - Additional burners, at most `MaxInitialBurners`, handled likewise
+		/// * `dormancy` - Optional dormant balance reclamation; can only be chosen at creation
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::create_coin(
+			initial_minters.as_ref().map_or(0, |m| m.len() as u32),
+			initial_burners.as_ref().map_or(0, |b| b.len() as u32),
+		))]
+		pub fn create_coin(
+			origin: OriginFor<T>,
+			symbol: Vec<u8>,
+			name: Vec<u8>,
+			decimals: u8,
+			initial_supply: u128,
+            initial_minters: Option<BoundedVec<T::AccountId, T::MaxInitialMinters>>,  // New: Optional additional minters
+            initial_burners: Option<BoundedVec<T::AccountId, T::MaxInitialBurners>>,  // New: Optional additional burners
+			can_pay_tx_fees: bool, // New: Optional fee payment eligibility
+			dormancy: Option<DormancyConfigOf<T>>,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// Validate inputs
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			// Run creation in its own storage layer so that no failure can leave
+			// partial state behind (e.g. a symbol claimed without a coin)
+			with_storage_layer(|| -> DispatchResult {
+				ensure!(initial_supply <= T::MaxSupply::get(), Error::<T>::ExceedsMaxSupply);
+				ensure!(initial_supply > 0, Error::<T>::ZeroAmount);
+				if let Some(config) = &dormancy {
+					ensure!(!config.period.is_zero(), Error::<T>::InvalidDormancyConfig);
+				}
+				let initial_minters = Self::initial_grants(initial_minters.into_iter().flatten(), &who);
+				let initial_burners = Self::initial_grants(initial_burners.into_iter().flatten(), &who);
+
+				let bounded_symbol: BoundedVec<u8, T::MaxSymbolLength> = 
+					symbol.clone().try_into().map_err(|_| Error::<T>::SymbolTooLong)?;
+				let bounded_name: BoundedVec<u8, T::MaxNameLength> = 
+					name.clone().try_into().map_err(|_| Error::<T>::NameTooLong)?;
+
+				// Check if symbol already exists
+				ensure!(
+					!SymbolToId::<T>::contains_key(&bounded_symbol),
+					Error::<T>::SymbolAlreadyExists
+				);
+
+				// Check maximum coins limit
+				let coin_id = NextCoinId::<T>::get();
+				ensure!(coin_id < T::MaxCoins::get(), Error::<T>::TooManyCoins);
+
+				let deposit_amount = T::CoinDeposit::get();
+
+				// Create coin metadata with default fee config
+				let coin_info = CoinInfo {
+					symbol: bounded_symbol.clone(),
+					name: bounded_name,
+					decimals,
+					owner: who.clone(),
+					issuer: who.clone(),
+					admin: who.clone(),
+					freezer: who.clone(),
+					deposit: deposit_amount,
+					fee_config: FeeConfig {
+						transfer_fee: 0, // Default: no fee
+						minimum_balance: 0, // Default: no minimum
+						can_pay_tx_fees, // Default: cannot pay tx fees (for Task 6)
+						call_scope: FeeCallScope::Any,
+					},
+					status: CoinStatus::Live,
+				};
+
+				// Store coin information
+				CoinMetadata::<T>::insert(&coin_id, &coin_info);
+				SymbolToId::<T>::insert(&bounded_symbol, &coin_id);
+				if let Some(config) = &dormancy {
+					DormancyConfigs::<T>::insert(&coin_id, config);
+				}
+			
+				// Set initial supply and balance
+				TotalSupply::<T>::insert(&coin_id, initial_supply);
+				Self::set_balance(coin_id, &who, initial_supply);
+
+				// Grant permissions to creator
+				MintPermissions::<T>::insert(&coin_id, &who, true);
+				BurnPermissions::<T>::insert(&coin_id, &who, true);  // New: Grant burn to creator
+
+				// Update next coin ID
+				NextCoinId::<T>::put(coin_id + 1);
+
+				// Reserve deposit for coin creation; a failure here also undoes the writes above
+				T::Currency::reserve(&who, deposit_amount)
+					.map_err(|_| Error::<T>::InsufficientBalance)?;
+				CoinDepositors::<T>::insert(coin_id, &who);
+				TotalCoinDeposits::<T>::mutate(|total| *total = total.saturating_add(deposit_amount));
+
+				// Emit creation event before any permission events so indexers
+				// always see the coin before grants referencing it
+				Self::deposit_event(Event::CoinCreated {
+					coin_id,
+					symbol,
+					name,
+					creator: who,
+					initial_supply,
+				});
+				if let Some(config) = dormancy {
+					Self::deposit_event(Event::DormancyConfigured { coin_id, config });
+				}
+
+				// Grant additional initial minters
+				for minter in initial_minters {
+					MintPermissions::<T>::insert(&coin_id, &minter, true);
+					Self::deposit_event(Event::MintPermissionSet {
+						coin_id,
+						account: minter,
+						can_mint: true,
+					});
+				}
+
+				// Grant additional initial burners
+				for burner in initial_burners {
+					BurnPermissions::<T>::insert(&coin_id, &burner, true);
+					Self::deposit_event(Event::BurnPermissionSet {
+						coin_id,
+						account: burner,
+						can_burn: true,
+					});
+				}
+
+				Ok(())
+			})
+		}
+
+		/// Transfer coins from one account to another
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin to transfer
+		/// * `to` - The recipient account
+		/// * `amount` - The amount to transfer
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::transfer().saturating_add(Pallet::<T>::transfer_hook_weight()))]
+		pub fn transfer(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			to: T::AccountId,
+			amount: u128,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+
+			// Validate inputs
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			Self::do_transfer(coin_id, &from, &to, amount)
+		}
+
+		/// Mint new coins to a specified account
+		///
+		/// Only the coin's issuer and accounts with minting permission can call this function.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin to mint
+		/// * `to` - The account to receive the minted coins
+		/// * `amount` - The amount to mint
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::mint())]
+		pub fn mint(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			to: T::AccountId,
+			amount: u128,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// Validate inputs
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			// Check minting permission
+			ensure!(
+				Self::permissions_of(coin_id, &who).can_mint,
+				Error::<T>::NoMintPermission
+			);
+
+			Self::do_mint(coin_id, &to, amount)
+		}
+
+		/// Burn coins from the caller's account
+		///
+		/// Only the coin's admin and accounts with burning permission can call this function.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin to burn
+		/// * `amount` - The amount to burn
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::burn())]
+		pub fn burn(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			amount: u128,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// Validate inputs
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+            // Check burning permission
+            ensure!(
+                Self::permissions_of(coin_id, &who).can_burn,
+                Error::<T>::NoBurnPermission  // Add this to Error enum
+            );
+
+			Self::do_burn(coin_id, &who, amount)
+		}
+
+		/// Transfer ownership of a coin to another account
+		///
+		/// Only the current owner can call this function. The new owner is always
+		/// granted mint and burn permission and takes over every role the old owner
+		/// held; the old owner's grants are only removed when `revoke_old_permissions`
+		/// is set, so a previous owner may stay on as a minter/burner. The creation
+		/// deposit stays reserved on its depositor unless `move_deposit` is set, in
+		/// which case it is repatriated to the new owner and reserved there.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `new_owner` - The new owner account
+		/// * `revoke_old_permissions` - Whether to revoke the old owner's mint and burn permissions
+		/// * `move_deposit` - Whether the new owner takes over the creation deposit
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::transfer_ownership())]
+		pub fn transfer_ownership(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			new_owner: T::AccountId,
+			revoke_old_permissions: bool,
+			move_deposit: bool,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			// Get coin metadata
+			let mut coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+
+			// Check authorization
+			ensure!(coin_info.owner == who, Error::<T>::NotAuthorized);
+			Self::ensure_not_destroying(coin_info.status)?;
+
+			let old_owner = coin_info.owner.clone();
+			
+			// Update owner, handing over the roles the old owner held
+			coin_info.owner = new_owner.clone();
+			for role in [&mut coin_info.issuer, &mut coin_info.admin, &mut coin_info.freezer] {
+				if *role == old_owner {
+					*role = new_owner.clone();
+				}
+			}
+			CoinMetadata::<T>::insert(&coin_id, &coin_info);
+
+			if move_deposit {
+				Self::move_deposit(coin_id, coin_info.deposit, &new_owner)?;
+			}
+
+			// Revoke the old owner's permissions only when explicitly requested
+			if revoke_old_permissions && old_owner != new_owner {
+				MintPermissions::<T>::remove(&coin_id, &old_owner);
+				BurnPermissions::<T>::remove(&coin_id, &old_owner);
+				Self::deposit_event(Event::MintPermissionSet {
+					coin_id,
+					account: old_owner.clone(),
+					can_mint: false,
+				});
+				Self::deposit_event(Event::BurnPermissionSet {
+					coin_id,
+					account: old_owner.clone(),
+					can_burn: false,
+				});
+			}
+
+			// The new owner always holds both permissions
+			MintPermissions::<T>::insert(&coin_id, &new_owner, true);
+			BurnPermissions::<T>::insert(&coin_id, &new_owner, true);
+			Self::deposit_event(Event::MintPermissionSet {
+				coin_id,
+				account: new_owner.clone(),
+				can_mint: true,
+			});
+			Self::deposit_event(Event::BurnPermissionSet {
+				coin_id,
+				account: new_owner.clone(),
+				can_burn: true,
+			});
+
+			// Emit event
+			Self::deposit_event(Event::OwnershipTransferred {
+				coin_id,
+				old_owner,
+				new_owner,
+			});
+
+			Ok(())
+		}
+
+		/// Set minting permission for an account
+		///
+		/// Only the coin's admin or its manager can grant or revoke minting permissions.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `account` - The account to set permission for
+		/// * `can_mint` - Whether the account can mint
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::set_mint_permission())]
+		pub fn set_mint_permission(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			account: T::AccountId,
+			can_mint: bool,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			// Get coin metadata
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+
+			// Check authorization
+			ensure!(Self::is_owner_or_manager(coin_id, &coin_info.admin, &who), Error::<T>::NotAuthorized);
+			Self::ensure_not_destroying(coin_info.status)?;
+
+			// Set permission
+			if can_mint {
+				MintPermissions::<T>::insert(&coin_id, &account, true);
+			} else {
+				MintPermissions::<T>::remove(&coin_id, &account);
+			}
+
+			// Emit event
+			Self::deposit_event(Event::MintPermissionSet {
+				coin_id,
+				account,
+				can_mint,
+			});
+
+			Ok(())
+		}
+
+        #[pallet::call_index(6)]  // Adjust index as needed
+        #[pallet::weight(T::WeightInfo::set_mint_permission())]  // Reuse weight or add new
+        pub fn set_burn_permission(
+            origin: OriginFor<T>,
+            coin_id: CoinId,
+            account: T::AccountId,
+            can_burn: bool,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+            // Get coin metadata
+            let coin_info = CoinMetadata::<T>::get(&coin_id)
+                .ok_or(Error::<T>::CoinNotFound)?;
+
+            // Check authorization
+            ensure!(Self::is_owner_or_manager(coin_id, &coin_info.admin, &who), Error::<T>::NotAuthorized);
+            Self::ensure_not_destroying(coin_info.status)?;
+
+            // Set permission
+            if can_burn {
+                BurnPermissions::<T>::insert(&coin_id, &account, true);
+            } else {
+                BurnPermissions::<T>::remove(&coin_id, &account);
+            }
+
+            // Emit event (add new Event::BurnPermissionSet { coin_id, account, can_burn })
+            Self::deposit_event(Event::BurnPermissionSet {  // Add this to Event enum
+                coin_id,
+                account,
+                can_burn,
+            });
+
+            Ok(())
+        }
+
+        #[pallet::call_index(7)] // Adjust index (e.g., after set_burn_permission)
+        #[pallet::weight(T::WeightInfo::set_fee_config())] // Add new weight in weights.rs
+        pub fn set_fee_config(
+            origin: OriginFor<T>,
+            coin_id: CoinId,
+            transfer_fee: u128,
+            minimum_balance: u128,
+			can_pay_tx_fees: bool, // New: Add this parameter
+			call_scope: FeeCallScope, // Calls this coin may pay fees for
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+        ) -> DispatchResult {
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+            // Get coin metadata
+            let coin_info = CoinMetadata::<T>::get(&coin_id)
+                .ok_or(Error::<T>::CoinNotFound)?;
+
+            // Check authorization; `ForceOrigin` may act on any coin
+            let is_force = Self::ensure_manager_or_force(origin, coin_id, &coin_info.owner)?.is_none();
+            Self::ensure_not_destroying(coin_info.status)?;
+
+            let mut new_config = coin_info.fee_config.clone();
+            new_config.transfer_fee = transfer_fee;
+            new_config.minimum_balance = minimum_balance;
+			new_config.can_pay_tx_fees = can_pay_tx_fees;
+			new_config.call_scope = call_scope;
+
+			// Sensitive changes on guarded coins are queued for confirmation,
+			// unless they come from `ForceOrigin`
+			if let Some(guard) = FeeChangeGuards::<T>::get(&coin_id).filter(|_| !is_force) {
+				if guard.is_sensitive(&coin_info.fee_config, &new_config) {
+					let now = frame_system::Pallet::<T>::block_number();
+					let enact_after = match guard.control {
+						FeeChangeControl::Delay(delay) => now.saturating_add(delay),
+						FeeChangeControl::Approver(_) => now,
+					};
+					PendingFeeChanges::<T>::insert(
+						&coin_id,
+						PendingFeeChange { fee_config: new_config.clone(), enact_after },
+					);
+					Self::deposit_event(Event::FeeChangeAnnounced {
+						coin_id,
+						fee_config: new_config,
+						enact_after,
+					});
+					return Ok(());
+				}
+			}
+
+            // Update fee config
+            Self::apply_fee_config(coin_id, coin_info, new_config);
+
+            Ok(())
+        }
+
+		#[pallet::call_index(8)] // Adjust index as needed
+		#[pallet::weight(T::WeightInfo::set_preferred_fee_coin())] // Add new weight
+		pub fn set_preferred_fee_coin(
+			origin: OriginFor<T>,
+			coin_id: Option<CoinId>, // None to use Balances pallet
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(coin_id)?;
+
+			// Set or clear preferred coin
+			if let Some(coin_id) = coin_id {
+				PreferredFeeCoin::<T>::insert(&who, coin_id);
+			} else {
+				PreferredFeeCoin::<T>::remove(&who);
+			}
+
+			// Emit event
+			Self::deposit_event(Event::PreferredFeeCoinSet {
+				account: who,
+				coin_id,
+			});
+
+			Ok(())
+		}
+
+		/// Execute any pallet call with a specific fee coin
+		/// This is a wrapper that temporarily sets the fee coin for one transaction
+		/// `call` can't be another wrapper and must pass the origin's call filter
+		#[pallet::call_index(9)] // Adjust index as needed
+		#[pallet::weight(T::WeightInfo::set_preferred_fee_coin())] // Add proper weight calculation
+		pub fn call_with_fee_coin(
+			origin: OriginFor<T>,
+			tx_fee_coin: Option<CoinId>,
+			call: Box<T::RuntimeCall>,
+		) -> DispatchResult {
+			let _who = ensure_signed(origin.clone())?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+			Self::ensure_wrappable(&origin, &call)?;
+
+			// Execute the call
+			let result = call.dispatch(origin);
+
+			result.map(|_| ()).map_err(|e| e.error)
+		}
+
+		/// Install, tighten, relax or remove the dual-control guard on fee changes
+		///
+		/// The owner may install a guard on an unguarded coin or replace the current
+		/// guard with a stricter one. Relaxing or removing an existing guard is only
+		/// possible for the approver of an approver-controlled guard.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `guard` - The new guard, or `None` to remove it
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::set_fee_guard())]
+		pub fn set_fee_guard(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			guard: Option<FeeChangeGuardOf<T>>,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			Self::ensure_not_destroying(coin_info.status)?;
+
+			let is_owner = coin_info.owner == who;
+			match FeeChangeGuards::<T>::get(&coin_id) {
+				None => ensure!(is_owner, Error::<T>::NotAuthorized),
+				Some(current) => {
+					let is_approver = matches!(
+						&current.control,
+						FeeChangeControl::Approver(approver) if *approver == who
+					);
+					ensure!(is_owner || is_approver, Error::<T>::NotAuthorized);
+					let tightening = guard
+						.as_ref()
+						.map_or(false, |new| new.is_at_least_as_strict_as(&current));
+					ensure!(is_approver || tightening, Error::<T>::FeeGuardRelaxationNotAllowed);
+				},
+			}
+
+			match &guard {
+				Some(guard) => FeeChangeGuards::<T>::insert(&coin_id, guard),
+				None => FeeChangeGuards::<T>::remove(&coin_id),
+			}
+
+			Self::deposit_event(Event::FeeGuardSet { coin_id, guard });
+
+			Ok(())
+		}
+
+		/// Approve a pending sensitive fee change as the guard's approver
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::approve_fee_change())]
+		pub fn approve_fee_change(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			Self::ensure_not_destroying(coin_info.status)?;
+
+			let guard = FeeChangeGuards::<T>::get(&coin_id).ok_or(Error::<T>::NotAuthorized)?;
+			ensure!(
+				matches!(&guard.control, FeeChangeControl::Approver(approver) if *approver == who),
+				Error::<T>::NotAuthorized
+			);
+
+			let pending = PendingFeeChanges::<T>::take(&coin_id)
+				.ok_or(Error::<T>::NoPendingFeeChange)?;
+			Self::apply_fee_config(coin_id, coin_info, pending.fee_config);
+
+			Ok(())
+		}
+
+		/// Enact a delayed sensitive fee change once its delay has passed
+		///
+		/// Callable by any signed account.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::enact_fee_change())]
+		pub fn enact_fee_change(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			Self::ensure_not_destroying(coin_info.status)?;
+
+			let pending = PendingFeeChanges::<T>::get(&coin_id)
+				.ok_or(Error::<T>::NoPendingFeeChange)?;
+			if let Some(guard) = FeeChangeGuards::<T>::get(&coin_id) {
+				ensure!(
+					!matches!(guard.control, FeeChangeControl::Approver(_)),
+					Error::<T>::FeeChangeNeedsApproval
+				);
+			}
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= pending.enact_after,
+				Error::<T>::FeeChangeNotReady
+			);
+
+			PendingFeeChanges::<T>::remove(&coin_id);
+			Self::apply_fee_config(coin_id, coin_info, pending.fee_config);
+
+			Ok(())
+		}
+
+		/// Cancel a pending sensitive fee change
+		///
+		/// Callable by the coin owner or the guard's approver.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::cancel_fee_change())]
+		pub fn cancel_fee_change(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			let is_approver = FeeChangeGuards::<T>::get(&coin_id).map_or(false, |guard| {
+				matches!(guard.control, FeeChangeControl::Approver(approver) if approver == who)
+			});
+			ensure!(coin_info.owner == who || is_approver, Error::<T>::NotAuthorized);
+
+			ensure!(PendingFeeChanges::<T>::contains_key(&coin_id), Error::<T>::NoPendingFeeChange);
+			PendingFeeChanges::<T>::remove(&coin_id);
+
+			Self::deposit_event(Event::FeeChangeCancelled { coin_id });
+
+			Ok(())
+		}
+
+		/// Halt all transfers, mints and burns of a coin
+		///
+		/// Callable by the coin's freezer or `ForceOrigin`.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin to freeze
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::freeze_coin())]
+		pub fn freeze_coin(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let mut coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			Self::ensure_owner_or_force(origin, &coin_info.freezer)?;
+			Self::ensure_live(coin_info.status)?;
+
+			coin_info.status = CoinStatus::Frozen;
+			CoinMetadata::<T>::insert(&coin_id, &coin_info);
+
+			Self::deposit_event(Event::CoinFrozen { coin_id });
+
+			Ok(())
+		}
+
+		/// Resume transfers, mints and burns of a frozen coin
+		///
+		/// Callable by the coin's freezer or `ForceOrigin`.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin to thaw
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::thaw_coin())]
+		pub fn thaw_coin(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let mut coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			Self::ensure_owner_or_force(origin, &coin_info.freezer)?;
+			ensure!(coin_info.status == CoinStatus::Frozen, Error::<T>::CoinNotFrozen);
+
+			coin_info.status = CoinStatus::Live;
+			CoinMetadata::<T>::insert(&coin_id, &coin_info);
+
+			Self::deposit_event(Event::CoinThawed { coin_id });
+
+			Ok(())
+		}
+
+		/// Move coins between two arbitrary accounts
+		///
+		/// Only callable by `ForceOrigin`, e.g. to correct balances after an exploit.
+		/// The usual transfer fee and minimum balance rules apply, but blocked sinks
+		/// may be paid into. `ForcedTransfer` follows the `Transfer` event.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin to transfer
+		/// * `from` - The account to debit
+		/// * `to` - The recipient account
+		/// * `amount` - The amount to transfer
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::force_transfer().saturating_add(Pallet::<T>::transfer_hook_weight()))]
+		pub fn force_transfer(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			from: T::AccountId,
+			to: T::AccountId,
+			amount: u128,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			Self::transfer_inner(coin_id, &from, &to, amount)?;
+			Self::deposit_event(Event::ForcedTransfer { coin_id, from, to, amount });
+
+			Ok(())
+		}
+
+		/// Set or remove the manager of a coin
+		///
+		/// The manager may grant and revoke mint/burn permissions and change the
+		/// fee configuration, while ownership (and `transfer_ownership`) stays with
+		/// the owner, which can then be kept on a cold key. The manager is kept
+		/// across ownership transfers until the new owner replaces it.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `manager` - The new manager, or `None` to remove it
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::set_coin_manager())]
+		pub fn set_coin_manager(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			manager: Option<T::AccountId>,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			ensure!(coin_info.owner == who, Error::<T>::NotAuthorized);
+			Self::ensure_not_destroying(coin_info.status)?;
+
+			match &manager {
+				Some(manager) => CoinManagers::<T>::insert(&coin_id, manager),
+				None => CoinManagers::<T>::remove(&coin_id),
+			}
+
+			Self::deposit_event(Event::CoinManagerSet { coin_id, manager });
+
+			Ok(())
+		}
+
+		/// Set the accrual index of a coin, making it rebasing
+		///
+		/// Balances of a rebasing coin are stored as shares and are worth
+		/// `shares * index`, so raising the index grows every balance (and the total
+		/// supply) proportionally. The index starts at one, so any coin can opt in
+		/// without changing existing balances; there is no way back.
+		/// Callable by the coin owner, its manager (e.g. an oracle account) or `ForceOrigin`.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `index` - The new accrual index
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::set_accrual_index())]
+		pub fn set_accrual_index(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			index: FixedU128,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			Self::ensure_manager_or_force(origin, coin_id, &coin_info.owner)?;
+			Self::ensure_not_destroying(coin_info.status)?;
+			ensure!(!index.is_zero(), Error::<T>::InvalidAccrualIndex);
+			ensure!(!DecayRates::<T>::contains_key(&coin_id), Error::<T>::IncompatibleSupplyMode);
+
+			AccrualIndex::<T>::insert(&coin_id, index);
+
+			Self::deposit_event(Event::AccrualIndexUpdated { coin_id, index });
+
+			Ok(())
+		}
+
+		/// Set or remove the decay rate of a coin
+		///
+		/// Balances of a decaying coin lose `rate` of their value every `period`
+		/// blocks, e.g. for loyalty points that expire. Decay is applied lazily
+		/// whenever an account's balance is touched and the decayed part is burned,
+		/// so `TotalSupply` only reflects decay that has been settled. An account's
+		/// clock starts at its first touch after decay is enabled.
+		/// Callable by the coin owner, its manager or `ForceOrigin`.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `decay` - The new decay rate, or `None` to stop decay
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::set_decay_rate())]
+		pub fn set_decay_rate(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			decay: Option<DecayConfigOf<T>>,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			Self::ensure_manager_or_force(origin, coin_id, &coin_info.owner)?;
+			Self::ensure_not_destroying(coin_info.status)?;
+
+			match &decay {
+				Some(config) => {
+					ensure!(!config.period.is_zero(), Error::<T>::InvalidDecayPeriod);
+					ensure!(!AccrualIndex::<T>::contains_key(&coin_id), Error::<T>::IncompatibleSupplyMode);
+					DecayRates::<T>::insert(&coin_id, config);
+				},
+				None => DecayRates::<T>::remove(&coin_id),
+			}
+
+			Self::deposit_event(Event::DecayRateSet { coin_id, decay });
+
+			Ok(())
+		}
+
+		/// Set the per-block staking reward rate of a coin
+		///
+		/// Rewards accrued so far are settled at the old rate first. Callable by the
+		/// coin owner, its manager or `ForceOrigin`.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `reward_per_block` - Rewards shared between all stakers each block
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::set_reward_rate())]
+		pub fn set_reward_rate(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			reward_per_block: u128,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			Self::ensure_manager_or_force(origin, coin_id, &coin_info.owner)?;
+			Self::ensure_not_destroying(coin_info.status)?;
+
+			RewardPools::<T>::mutate(&coin_id, |pool| {
+				Self::accrue_rewards(pool);
+				pool.reward_per_block = reward_per_block;
+			});
+
+			Self::deposit_event(Event::RewardRateSet { coin_id, reward_per_block });
+
+			Ok(())
+		}
+
+		/// Add coins from the caller's balance to the coin's staking reward pool
+		///
+		/// Only the coin owner or its manager can fund the pool.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `amount` - The amount to add to the pool
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::fund_rewards())]
+		pub fn fund_rewards(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			amount: u128,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			ensure!(amount > 0, Error::<T>::ZeroAmount);
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			ensure!(Self::is_owner_or_manager(coin_id, &coin_info.owner, &who), Error::<T>::NotAuthorized);
+			Self::ensure_live(coin_info.status)?;
+
+			let shares = Self::to_shares(coin_id, amount);
+			Self::debit(coin_id, &who, shares)?;
+			RewardPools::<T>::try_mutate(&coin_id, |pool| -> DispatchResult {
+				Self::accrue_rewards(pool);
+				pool.remaining_rewards = pool.remaining_rewards.checked_add(shares)
+					.ok_or(Error::<T>::Overflow)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::RewardPoolFunded { coin_id, funder: who, amount });
+
+			Ok(())
+		}
+
+		/// Stake coins from the caller's balance to earn rewards
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `amount` - The amount to stake
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::stake())]
+		pub fn stake(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			amount: u128,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			ensure!(amount > 0, Error::<T>::ZeroAmount);
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			Self::ensure_live(coin_info.status)?;
+
+			let shares = Self::to_shares(coin_id, amount);
+			Self::debit(coin_id, &who, shares)?;
+			Self::update_stake(coin_id, &who, |pool, stake| {
+				stake.amount = stake.amount.checked_add(shares).ok_or(Error::<T>::Overflow)?;
+				pool.total_staked = pool.total_staked.checked_add(shares).ok_or(Error::<T>::Overflow)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::Staked { coin_id, who, amount });
+
+			Ok(())
+		}
+
+		/// Return staked coins to the caller's balance
+		///
+		/// Rewards earned so far stay claimable.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `amount` - The amount to unstake
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::unstake())]
+		pub fn unstake(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			amount: u128,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			ensure!(amount > 0, Error::<T>::ZeroAmount);
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			Self::ensure_live(coin_info.status)?;
+
+			let shares = Self::to_shares(coin_id, amount);
+			Self::update_stake(coin_id, &who, |pool, stake| {
+				stake.amount = stake.amount.checked_sub(shares).ok_or(Error::<T>::InsufficientStake)?;
+				pool.total_staked = pool.total_staked.saturating_sub(shares);
+				Ok(())
+			})?;
+			Self::credit(coin_id, &who, shares)?;
+
+			Self::deposit_event(Event::Unstaked { coin_id, who, amount });
+
+			Ok(())
+		}
+
+		/// Pay out the caller's accrued staking rewards
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::claim_rewards())]
+		pub fn claim_rewards(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			Self::ensure_live(coin_info.status)?;
+
+			let mut rewards = 0;
+			Self::update_stake(coin_id, &who, |_, stake| {
+				ensure!(stake.unclaimed > 0, Error::<T>::NothingToClaim);
+				rewards = core::mem::take(&mut stake.unclaimed);
+				Ok(())
+			})?;
+			Self::credit(coin_id, &who, rewards)?;
+
+			Self::deposit_event(Event::RewardsClaimed {
+				coin_id,
+				who,
+				amount: Self::to_amount(coin_id, rewards),
+			});
+
+			Ok(())
+		}
+
+		/// Lock part of the caller's balance to vote with it
+		///
+		/// Locked coins can't be transferred or burned until the lock expires and is
+		/// removed with `unlock_vote`. Longer locks give more voting power, see
+		/// [`Pallet::voting_power`]. An existing lock can only be extended: the
+		/// amount and conviction can't decrease and the unlock block never moves earlier.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `amount` - The amount to lock
+		/// * `conviction` - How long to lock for
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::lock_for_vote())]
+		pub fn lock_for_vote(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			amount: u128,
+			conviction: Conviction,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			ensure!(amount > 0, Error::<T>::ZeroAmount);
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			Self::ensure_live(coin_info.status)?;
+
+			let shares = Self::to_shares(coin_id, amount);
+			ensure!(Self::spendable_shares(coin_id, &who) >= shares, Error::<T>::InsufficientBalance);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut unlock_at = now.saturating_add(
+				T::VoteLockingPeriod::get().saturating_mul(conviction.lock_periods().into()),
+			);
+			if let Some(existing) = VoteLocks::<T>::get(&coin_id, &who) {
+				ensure!(
+					shares >= existing.amount && conviction >= existing.conviction,
+					Error::<T>::VoteLockReduced
+				);
+				unlock_at = unlock_at.max(existing.unlock_at);
+			}
+
+			VoteLocks::<T>::insert(&coin_id, &who, VoteLock { amount: shares, conviction, unlock_at });
+
+			Self::deposit_event(Event::VoteLocked { coin_id, who, amount, conviction, unlock_at });
+
+			Ok(())
+		}
+
+		/// Remove the caller's expired vote lock
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::unlock_vote())]
+		pub fn unlock_vote(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let lock = VoteLocks::<T>::get(&coin_id, &who).ok_or(Error::<T>::NoVoteLock)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= lock.unlock_at,
+				Error::<T>::VoteLockNotExpired
+			);
+			VoteLocks::<T>::remove(&coin_id, &who);
+
+			Self::deposit_event(Event::VoteUnlocked { coin_id, who });
+
+			Ok(())
+		}
+
+		/// Open a launch sale of a coin
+		///
+		/// `amount` is taken from the owner's balance and sold at a fixed `price` in
+		/// native currency or another coin until `duration` blocks have passed.
+		/// Whatever is unsold when the sale is closed is burned.
+		///
+		/// # Arguments
+		/// * `coin_id` - The coin to sell; the caller must own it
+		/// * `amount` - The amount offered
+		/// * `payment` - The asset buyers pay with
+		/// * `price` - Payment units per unit of the sold coin
+		/// * `per_account_cap` - Most a single account may buy; zero for no limit
+		/// * `duration` - Number of blocks the sale accepts purchases for
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::open_sale())]
+		pub fn open_sale(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			amount: u128,
+			payment: PaymentAsset,
+			price: FixedU128,
+			per_account_cap: u128,
+			duration: BlockNumberFor<T>,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			ensure!(amount > 0, Error::<T>::ZeroAmount);
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			ensure!(coin_info.owner == who, Error::<T>::NotAuthorized);
+			Self::ensure_live(coin_info.status)?;
+			if let PaymentAsset::Coin(payment_coin) = payment {
+				ensure!(payment_coin != coin_id, Error::<T>::InvalidPaymentAsset);
+				ensure!(CoinMetadata::<T>::contains_key(&payment_coin), Error::<T>::CoinNotFound);
+			}
+
+			let shares = Self::to_shares(coin_id, amount);
+			Self::debit(coin_id, &who, shares)?;
+
+			let sale_id = NextSaleId::<T>::get();
+			let end = frame_system::Pallet::<T>::block_number().saturating_add(duration);
+			Self::add_references(coin_id, payment);
+			Sales::<T>::insert(sale_id, Sale {
+				coin_id,
+				owner: who.clone(),
+				payment,
+				price,
+				remaining: shares,
+				per_account_cap,
+				end,
+				proceeds: 0,
+			});
+			NextSaleId::<T>::put(sale_id.checked_add(1).ok_or(Error::<T>::Overflow)?);
+
+			Self::deposit_event(Event::SaleOpened {
+				sale_id,
+				coin_id,
+				owner: who,
+				amount,
+				payment,
+				price,
+				end,
+			});
+
+			Ok(())
+		}
+
+		/// Buy coins in a running launch sale
+		///
+		/// # Arguments
+		/// * `sale_id` - The ID of the sale
+		/// * `amount` - The amount of the sold coin to buy
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::buy())]
+		pub fn buy(
+			origin: OriginFor<T>,
+			sale_id: SaleId,
+			amount: u128,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			ensure!(amount > 0, Error::<T>::ZeroAmount);
+			let mut sale = Sales::<T>::get(sale_id).ok_or(Error::<T>::SaleNotFound)?;
+			ensure!(frame_system::Pallet::<T>::block_number() <= sale.end, Error::<T>::SaleEnded);
+			let coin_info = CoinMetadata::<T>::get(&sale.coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			Self::ensure_live(coin_info.status)?;
+
+			// Check both caps
+			let shares = Self::to_shares(sale.coin_id, amount);
+			ensure!(shares <= sale.remaining, Error::<T>::SaleCapExceeded);
+			let bought = SaleContributions::<T>::get(sale_id, &who)
+				.checked_add(amount)
+				.ok_or(Error::<T>::Overflow)?;
+			ensure!(
+				sale.per_account_cap == 0 || bought <= sale.per_account_cap,
+				Error::<T>::SaleCapExceeded
+			);
+
+			// Collect payment, rounding in the sale's favour
+			let cost = multiply_by_rational_with_rounding(amount, sale.price.into_inner(), FixedU128::DIV, Rounding::Up)
+				.ok_or(Error::<T>::Overflow)?;
+			let collected = Self::collect_payment(sale.payment, &who, cost)?;
+			sale.proceeds = sale.proceeds.checked_add(collected).ok_or(Error::<T>::Overflow)?;
+
+			// Deliver the coins
+			sale.remaining -= shares;
+			Self::credit(sale.coin_id, &who, shares)?;
+			SaleContributions::<T>::insert(sale_id, &who, bought);
+			Sales::<T>::insert(sale_id, sale);
+
+			Self::deposit_event(Event::SalePurchase { sale_id, buyer: who, amount, cost });
+
+			Ok(())
+		}
+
+		/// Pay the proceeds collected so far to the sale owner
+		///
+		/// # Arguments
+		/// * `sale_id` - The ID of the sale
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::withdraw_proceeds())]
+		pub fn withdraw_proceeds(
+			origin: OriginFor<T>,
+			sale_id: SaleId,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let mut sale = Sales::<T>::get(sale_id).ok_or(Error::<T>::SaleNotFound)?;
+			ensure!(sale.owner == who, Error::<T>::NotAuthorized);
+
+			let amount = Self::pay_out_proceeds(&mut sale)?;
+			Sales::<T>::insert(sale_id, sale);
+
+			Self::deposit_event(Event::SaleProceedsWithdrawn { sale_id, amount });
+
+			Ok(())
+		}
+
+		/// Close an ended sale: burn the unsold coins and pay out remaining proceeds
+		///
+		/// Callable by anyone once the sale has ended.
+		///
+		/// # Arguments
+		/// * `sale_id` - The ID of the sale
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::close_sale())]
+		pub fn close_sale(
+			origin: OriginFor<T>,
+			sale_id: SaleId,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let mut sale = Sales::<T>::get(sale_id).ok_or(Error::<T>::SaleNotFound)?;
+			ensure!(frame_system::Pallet::<T>::block_number() > sale.end, Error::<T>::SaleNotEnded);
+			// Closing burns the unsold coins
+			Self::ensure_coin_live(sale.coin_id)?;
+
+			let withdrawn = Self::pay_out_proceeds(&mut sale)?;
+			if withdrawn > 0 {
+				Self::deposit_event(Event::SaleProceedsWithdrawn { sale_id, amount: withdrawn });
+			}
+
+			// Unsold coins were already taken out of the owner's balance; burn them
+			TotalSupply::<T>::mutate(sale.coin_id, |supply| *supply = supply.saturating_sub(sale.remaining));
+			Sales::<T>::remove(sale_id);
+			Self::remove_references(sale.coin_id, sale.payment);
+
+			Self::deposit_event(Event::SaleClosed {
+				sale_id,
+				burned: Self::to_amount(sale.coin_id, sale.remaining),
+			});
+
+			Ok(())
+		}
+
+		/// Place a fixed-price order selling one coin for another
+		///
+		/// `sell_amount` is moved out of the caller's balance into escrow until the
+		/// order is filled or cancelled.
+		///
+		/// # Arguments
+		/// * `sell_coin` - The coin offered
+		/// * `sell_amount` - The amount offered
+		/// * `buy_coin` - The coin asked in return
+		/// * `price` - Units of `buy_coin` per unit of `sell_coin`
+		#[pallet::call_index(31)]
+		#[pallet::weight(T::WeightInfo::place_order())]
+		pub fn place_order(
+			origin: OriginFor<T>,
+			sell_coin: CoinId,
+			sell_amount: u128,
+			buy_coin: CoinId,
+			price: FixedU128,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			ensure!(sell_amount > 0, Error::<T>::ZeroAmount);
+			ensure!(sell_coin != buy_coin, Error::<T>::InvalidPaymentAsset);
+			for coin_id in [sell_coin, buy_coin] {
+				let coin_info = CoinMetadata::<T>::get(&coin_id)
+					.ok_or(Error::<T>::CoinNotFound)?;
+				Self::ensure_live(coin_info.status)?;
+			}
+
+			let shares = Self::to_shares(sell_coin, sell_amount);
+			Self::debit(sell_coin, &who, shares)?;
+
+			let order_id = NextOrderId::<T>::get();
+			Orders::<T>::insert(order_id, Order {
+				maker: who.clone(),
+				sell_coin,
+				remaining: shares,
+				buy_coin,
+				price,
+			});
+			NextOrderId::<T>::put(order_id.checked_add(1).ok_or(Error::<T>::Overflow)?);
+
+			Self::deposit_event(Event::OrderPlaced {
+				order_id,
+				maker: who,
+				sell_coin,
+				sell_amount,
+				buy_coin,
+				price,
+			});
+
+			Ok(())
+		}
+
+		/// Fill an order, fully or in part
+		///
+		/// The caller pays `amount` times the order price in the order's buy coin
+		/// to the maker and receives `amount` of the sell coin from escrow.
+		///
+		/// # Arguments
+		/// * `order_id` - The ID of the order
+		/// * `amount` - The amount of the sell coin to take
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::fill_order())]
+		pub fn fill_order(
+			origin: OriginFor<T>,
+			order_id: OrderId,
+			amount: u128,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			ensure!(amount > 0, Error::<T>::ZeroAmount);
+			let mut order = Orders::<T>::get(order_id).ok_or(Error::<T>::OrderNotFound)?;
+			ensure!(order.maker != who, Error::<T>::TransferToSelf);
+			for coin_id in [order.sell_coin, order.buy_coin] {
+				let coin_info = CoinMetadata::<T>::get(&coin_id)
+					.ok_or(Error::<T>::CoinNotFound)?;
+				Self::ensure_live(coin_info.status)?;
+			}
+
+			let shares = Self::to_shares(order.sell_coin, amount);
+			ensure!(shares <= order.remaining, Error::<T>::OrderAmountExceeded);
+
+			// Pay the maker, rounding in the maker's favour
+			let cost = multiply_by_rational_with_rounding(amount, order.price.into_inner(), FixedU128::DIV, Rounding::Up)
+				.ok_or(Error::<T>::Overflow)?;
+			let cost_shares = Self::to_shares(order.buy_coin, cost);
+			Self::debit(order.buy_coin, &who, cost_shares)?;
+			Self::credit(order.buy_coin, &order.maker, cost_shares)?;
+
+			// Release the escrow to the taker
+			order.remaining -= shares;
+			Self::credit(order.sell_coin, &who, shares)?;
+			if order.remaining == 0 {
+				Orders::<T>::remove(order_id);
+			} else {
+				Orders::<T>::insert(order_id, order);
+			}
+
+			Self::deposit_event(Event::OrderFilled { order_id, taker: who, amount, cost });
+
+			Ok(())
+		}
+
+		/// Cancel an order and return the escrowed coins to the maker
+		///
+		/// # Arguments
+		/// * `order_id` - The ID of the order
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::cancel_order())]
+		pub fn cancel_order(
+			origin: OriginFor<T>,
+			order_id: OrderId,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let order = Orders::<T>::get(order_id).ok_or(Error::<T>::OrderNotFound)?;
+			ensure!(order.maker == who, Error::<T>::NotAuthorized);
+			Self::ensure_coin_live(order.sell_coin)?;
+
+			Self::credit(order.sell_coin, &who, order.remaining)?;
+			Orders::<T>::remove(order_id);
+
+			Self::deposit_event(Event::OrderCancelled {
+				order_id,
+				refunded: Self::to_amount(order.sell_coin, order.remaining),
+			});
+
+			Ok(())
+		}
+
+		/// Subscribe to pay `amount` of a coin to `to` every `period` blocks
+		///
+		/// Charges are pulled with `claim_due`; the first one is due immediately.
+		///
+		/// # Arguments
+		/// * `coin_id` - The coin to pay in
+		/// * `to` - The payee
+		/// * `amount` - Amount charged per period
+		/// * `period` - Blocks between charges
+		/// * `count` - Number of charges
+		#[pallet::call_index(34)]
+		#[pallet::weight(T::WeightInfo::create_subscription())]
+		pub fn create_subscription(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			to: T::AccountId,
+			amount: u128,
+			period: BlockNumberFor<T>,
+			count: u32,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			ensure!(amount > 0, Error::<T>::ZeroAmount);
+			ensure!(who != to, Error::<T>::TransferToSelf);
+			ensure!(!period.is_zero() && count > 0, Error::<T>::InvalidSubscription);
+			ensure!(CoinMetadata::<T>::contains_key(&coin_id), Error::<T>::CoinNotFound);
+
+			let subscription_id = NextSubscriptionId::<T>::get();
+			Subscriptions::<T>::insert(subscription_id, Subscription {
+				payer: who.clone(),
+				payee: to.clone(),
+				coin_id,
+				amount,
+				period,
+				remaining_count: count,
+				next_due: frame_system::Pallet::<T>::block_number(),
+			});
+			NextSubscriptionId::<T>::put(subscription_id.checked_add(1).ok_or(Error::<T>::Overflow)?);
+
+			Self::deposit_event(Event::SubscriptionCreated {
+				subscription_id,
+				coin_id,
+				payer: who,
+				payee: to,
+				amount,
+				period,
+				count,
+			});
+
+			Ok(())
+		}
+
+		/// Charge every period of a subscription that has come due
+		///
+		/// Callable by anyone. Missed periods are charged together in a single
+		/// transfer; the subscription is removed after its last charge.
+		///
+		/// # Arguments
+		/// * `subscription_id` - The ID of the subscription
+		#[pallet::call_index(35)]
+		#[pallet::weight(T::WeightInfo::claim_due().saturating_add(Pallet::<T>::transfer_hook_weight()))]
+		pub fn claim_due(
+			origin: OriginFor<T>,
+			subscription_id: SubscriptionId,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let mut subscription = Subscriptions::<T>::get(subscription_id)
+				.ok_or(Error::<T>::SubscriptionNotFound)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now >= subscription.next_due, Error::<T>::NothingDue);
+
+			// Count due periods, including the one starting at `next_due`
+			let elapsed = (now - subscription.next_due) / subscription.period;
+			let periods = elapsed
+				.saturated_into::<u32>()
+				.saturating_add(1)
+				.min(subscription.remaining_count);
+			let amount = subscription.amount
+				.checked_mul(periods as u128)
+				.ok_or(Error::<T>::Overflow)?;
+
+			Self::do_transfer(subscription.coin_id, &subscription.payer, &subscription.payee, amount)?;
+
+			subscription.remaining_count -= periods;
+			if subscription.remaining_count == 0 {
+				Subscriptions::<T>::remove(subscription_id);
+			} else {
+				subscription.next_due = subscription.next_due
+					.saturating_add(subscription.period.saturating_mul(periods.into()));
+				Subscriptions::<T>::insert(subscription_id, subscription);
+			}
+
+			Self::deposit_event(Event::SubscriptionCharged { subscription_id, periods, amount });
+
+			Ok(())
+		}
+
+		/// Cancel a subscription before its remaining charges are made
+		///
+		/// # Arguments
+		/// * `subscription_id` - The ID of the subscription; the caller must be its payer
+		#[pallet::call_index(36)]
+		#[pallet::weight(T::WeightInfo::cancel_subscription())]
+		pub fn cancel_subscription(
+			origin: OriginFor<T>,
+			subscription_id: SubscriptionId,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let subscription = Subscriptions::<T>::get(subscription_id)
+				.ok_or(Error::<T>::SubscriptionNotFound)?;
+			ensure!(subscription.payer == who, Error::<T>::NotAuthorized);
+
+			Subscriptions::<T>::remove(subscription_id);
+
+			Self::deposit_event(Event::SubscriptionCancelled { subscription_id });
+
+			Ok(())
+		}
+
+		/// Divide an amount among several recipients by share
+		///
+		/// Each part is sent as a regular transfer. Rounding dust goes to the
+		/// last recipient so the full amount is always paid out.
+		///
+		/// # Arguments
+		/// * `coin_id` - The coin to send
+		/// * `recipients` - Recipients and their shares, adding up to 100%
+		/// * `amount` - The total amount to divide
+		#[pallet::call_index(37)]
+		#[pallet::weight(
+			T::WeightInfo::transfer_split(recipients.len() as u32)
+				.saturating_add(Pallet::<T>::transfer_hook_weight().saturating_mul(recipients.len() as u64))
+		)]
+		pub fn transfer_split(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			recipients: BoundedVec<(T::AccountId, Permill), T::MaxSplitRecipients>,
+			amount: u128,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			ensure!(amount > 0, Error::<T>::ZeroAmount);
+			let total_share = recipients
+				.iter()
+				.try_fold(0u32, |acc, (_, share)| acc.checked_add(share.deconstruct()))
+				.ok_or(Error::<T>::InvalidSplit)?;
+			ensure!(total_share == Permill::ACCURACY, Error::<T>::InvalidSplit);
+
+			let last = recipients.len() - 1;
+			let mut sent = 0u128;
+			for (index, (to, share)) in recipients.iter().enumerate() {
+				let part = if index == last { amount - sent } else { share.mul_floor(amount) };
+				if part > 0 {
+					Self::do_transfer(coin_id, &who, to, part)?;
+					sent = sent.saturating_add(part);
+				}
+			}
+
+			Self::deposit_event(Event::SplitTransferred {
+				coin_id,
+				from: who,
+				amount,
+				recipients: recipients.len() as u32,
+			});
+
+			Ok(())
+		}
+
+		/// Block or unblock an account as a destination for transfers of a coin
+		///
+		/// Meant for known burn sinks or bridge addresses under migration. Only
+		/// `force_transfer` can still pay into a blocked account.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `who` - The account to block or unblock
+		/// * `blocked` - Whether transfers into `who` are refused
+		#[pallet::call_index(38)]
+		#[pallet::weight(T::WeightInfo::set_sink_blocked())]
+		pub fn set_sink_blocked(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			who: T::AccountId,
+			blocked: bool,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			Self::ensure_owner_or_force(origin, &coin_info.owner)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			if blocked {
+				BlockedSinks::<T>::insert(coin_id, &who, ());
+			} else {
+				BlockedSinks::<T>::remove(coin_id, &who);
+			}
+
+			Self::deposit_event(Event::SinkBlockedSet { coin_id, who, blocked });
+
+			Ok(())
+		}
+
+		/// Designate or remove the account that may recover the caller's balances
+		///
+		/// Removing the designation also cancels any announced recovery.
+		///
+		/// # Arguments
+		/// * `config` - The recovery account and delay, or `None` to remove them
+		#[pallet::call_index(39)]
+		#[pallet::weight(T::WeightInfo::set_recovery())]
+		pub fn set_recovery(
+			origin: OriginFor<T>,
+			config: Option<RecoveryConfigOf<T>>,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			match &config {
+				Some(config) => {
+					ensure!(config.recoverer != who, Error::<T>::TransferToSelf);
+					RecoveryConfigs::<T>::insert(&who, config);
+				},
+				None => {
+					RecoveryConfigs::<T>::remove(&who);
+					ActiveRecoveries::<T>::remove(&who);
+				},
+			}
+
+			Self::deposit_event(Event::RecoverySet { who, config });
+
+			Ok(())
+		}
+
+		/// Announce the recovery of a lost account
+		///
+		/// Only the designated recovery account may call this. The lost account's
+		/// balances can be recovered once its delay has passed, unless it cancels first.
+		///
+		/// # Arguments
+		/// * `lost` - The account to recover
+		#[pallet::call_index(40)]
+		#[pallet::weight(T::WeightInfo::announce_recovery())]
+		pub fn announce_recovery(
+			origin: OriginFor<T>,
+			lost: T::AccountId,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let config = RecoveryConfigs::<T>::get(&lost).ok_or(Error::<T>::NoRecoveryConfig)?;
+			ensure!(config.recoverer == who, Error::<T>::NotAuthorized);
+
+			let executable_at = frame_system::Pallet::<T>::block_number().saturating_add(config.delay);
+			ActiveRecoveries::<T>::insert(&lost, executable_at);
+
+			Self::deposit_event(Event::RecoveryAnnounced { lost, recoverer: who, executable_at });
+
+			Ok(())
+		}
+
+		/// Cancel an announced recovery of the caller's account
+		#[pallet::call_index(41)]
+		#[pallet::weight(T::WeightInfo::cancel_recovery())]
+		pub fn cancel_recovery(
+			origin: OriginFor<T>,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			ensure!(ActiveRecoveries::<T>::contains_key(&who), Error::<T>::RecoveryNotAnnounced);
+			ActiveRecoveries::<T>::remove(&who);
+
+			Self::deposit_event(Event::RecoveryCancelled { lost: who });
+
+			Ok(())
+		}
+
+		/// Move a lost account's whole balance of a coin to its recovery account
+		///
+		/// Callable by the recovery account once the announced delay has passed.
+		/// Fees and minimum balance rules do not apply; vote-locked balances can't be moved.
+		///
+		/// # Arguments
+		/// * `lost` - The account being recovered
+		/// * `coin_id` - The coin to sweep
+		#[pallet::call_index(42)]
+		#[pallet::weight(T::WeightInfo::recover_balance())]
+		pub fn recover_balance(
+			origin: OriginFor<T>,
+			lost: T::AccountId,
+			coin_id: CoinId,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let config = RecoveryConfigs::<T>::get(&lost).ok_or(Error::<T>::NoRecoveryConfig)?;
+			ensure!(config.recoverer == who, Error::<T>::NotAuthorized);
+			let executable_at = ActiveRecoveries::<T>::get(&lost)
+				.ok_or(Error::<T>::RecoveryNotAnnounced)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= executable_at,
+				Error::<T>::RecoveryDelayNotPassed
+			);
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			Self::ensure_live(coin_info.status)?;
+
+			Self::apply_decay(coin_id, &lost);
+			let shares = Balances::<T>::get(coin_id, &lost);
+			ensure!(shares > 0, Error::<T>::InsufficientBalance);
+			// The whole balance moves, labeled or not
+			SubBalances::<T>::remove(coin_id, &lost);
+			Self::debit(coin_id, &lost, shares)?;
+			Self::credit(coin_id, &who, shares)?;
+
+			Self::deposit_event(Event::Recovered {
+				coin_id,
+				lost,
+				recoverer: who,
+				amount: Self::to_amount(coin_id, shares),
+			});
+
+			Ok(())
+		}
+
+		/// Warn an account whose balance has been inactive for the coin's dormancy period
+		///
+		/// Callable by anyone. The balance may be swept once the grace period has
+		/// passed, unless it changes in the meantime.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `who` - The dormant account
+		#[pallet::call_index(43)]
+		#[pallet::weight(T::WeightInfo::warn_dormant())]
+		pub fn warn_dormant(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			who: T::AccountId,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let config = DormancyConfigs::<T>::get(coin_id).ok_or(Error::<T>::DormancyNotEnabled)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			let last = LastActivity::<T>::get(coin_id, &who).ok_or(Error::<T>::AccountNotDormant)?;
+			ensure!(last.saturating_add(config.period) <= now, Error::<T>::AccountNotDormant);
+
+			DormancyWarnings::<T>::insert(coin_id, &who, now);
+
+			Self::deposit_event(Event::DormancyWarning {
+				coin_id,
+				who,
+				sweepable_at: now.saturating_add(config.grace),
+			});
+
+			Ok(())
+		}
+
+		/// Sweep a warned dormant balance to the coin's beneficiary
+		///
+		/// Callable by anyone once the grace period after the warning has passed
+		/// without any activity on the balance.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `who` - The dormant account
+		#[pallet::call_index(44)]
+		#[pallet::weight(T::WeightInfo::sweep_dormant())]
+		pub fn sweep_dormant(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			who: T::AccountId,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let config = DormancyConfigs::<T>::get(coin_id).ok_or(Error::<T>::DormancyNotEnabled)?;
+			let warned_at = DormancyWarnings::<T>::get(coin_id, &who)
+				.ok_or(Error::<T>::NoDormancyWarning)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= warned_at.saturating_add(config.grace),
+				Error::<T>::DormancyGraceNotPassed
+			);
+			let last = LastActivity::<T>::get(coin_id, &who).ok_or(Error::<T>::AccountNotDormant)?;
+			ensure!(last <= warned_at, Error::<T>::AccountNotDormant);
+			Self::ensure_coin_live(coin_id)?;
+
+			Self::apply_decay(coin_id, &who);
+			let shares = Balances::<T>::get(coin_id, &who);
+			// The whole balance moves, labeled or not
+			SubBalances::<T>::remove(coin_id, &who);
+			Self::debit(coin_id, &who, shares)?;
+			Self::credit(coin_id, &config.beneficiary, shares)?;
+			DormancyWarnings::<T>::remove(coin_id, &who);
+
+			Self::deposit_event(Event::DormantSwept {
+				coin_id,
+				who,
+				beneficiary: config.beneficiary,
+				amount: Self::to_amount(coin_id, shares),
+			});
+
+			Ok(())
+		}
+
+		/// Set or remove the maximum number of holders of a coin
+		///
+		/// Accounts can't be newly endowed once the cap is reached; existing
+		/// holders are unaffected, even if the cap is set below their count.
+		/// Callable by the coin owner, its manager or `ForceOrigin`.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `max_holders` - The new cap, or `None` for no limit
+		#[pallet::call_index(45)]
+		#[pallet::weight(T::WeightInfo::set_max_holders())]
+		pub fn set_max_holders(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			max_holders: Option<u32>,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			Self::ensure_manager_or_force(origin, coin_id, &coin_info.owner)?;
+
+			MaxHolders::<T>::set(coin_id, max_holders);
+
+			Self::deposit_event(Event::MaxHoldersSet { coin_id, max_holders });
+
+			Ok(())
+		}
+
+		/// Set or remove the royalty charged on secondary transfers of a coin
+		///
+		/// The royalty is paid by the sender on top of the transferred amount,
+		/// independently of the burned transfer fee. Transfers from or to the
+		/// beneficiary or an exempt account are royalty free. Callable by the
+		/// coin owner or `ForceOrigin`.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `royalty` - Rate in basis points and beneficiary, or `None` to remove it
+		#[pallet::call_index(46)]
+		#[pallet::weight(T::WeightInfo::set_royalty())]
+		pub fn set_royalty(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			royalty: Option<RoyaltyConfigOf<T>>,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			Self::ensure_owner_or_force(origin, &coin_info.owner)?;
+			Self::ensure_not_destroying(coin_info.status)?;
+			if let Some(royalty) = &royalty {
+				ensure!(royalty.bps <= 10_000, Error::<T>::InvalidRoyalty);
+			}
+
+			Royalties::<T>::set(coin_id, royalty.clone());
+
+			Self::deposit_event(Event::RoyaltySet { coin_id, royalty });
+
+			Ok(())
+		}
+
+		/// Exempt an account from a coin's royalty, or make it subject to it again
+		///
+		/// Meant for marketplaces, custody and bridge accounts whose transfers are
+		/// not secondary sales. Callable by the coin owner or `ForceOrigin`.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `who` - The account to exempt or un-exempt
+		/// * `exempt` - Whether transfers from or to `who` are royalty free
+		#[pallet::call_index(47)]
+		#[pallet::weight(T::WeightInfo::set_royalty_exempt())]
+		pub fn set_royalty_exempt(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			who: T::AccountId,
+			exempt: bool,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			Self::ensure_owner_or_force(origin, &coin_info.owner)?;
+
+			if exempt {
+				RoyaltyExempt::<T>::insert(coin_id, &who, ());
+			} else {
+				RoyaltyExempt::<T>::remove(coin_id, &who);
+			}
+
+			Self::deposit_event(Event::RoyaltyExemptSet { coin_id, who, exempt });
+
+			Ok(())
+		}
+
+		/// Set or remove the lock-up period applied to freshly minted coins
+		///
+		/// Coins minted while a period is set can't be transferred, burned or
+		/// staked by the recipient for that many blocks. A new mint during a
+		/// running lock-up extends it to cover the whole locked amount. Balances
+		/// minted before the change are unaffected. Callable by the coin owner
+		/// or `ForceOrigin`.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `period` - Lock-up length in blocks, or `None` to disable it
+		#[pallet::call_index(48)]
+		#[pallet::weight(T::WeightInfo::set_mint_lock_period())]
+		pub fn set_mint_lock_period(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			period: Option<BlockNumberFor<T>>,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			Self::ensure_owner_or_force(origin, &coin_info.owner)?;
+			Self::ensure_not_destroying(coin_info.status)?;
+
+			MintLockPeriods::<T>::set(coin_id, period);
+
+			Self::deposit_event(Event::MintLockPeriodSet { coin_id, period });
+
+			Ok(())
+		}
+
+		/// Move coins between the caller's labeled sub-balances
+		///
+		/// Labeled sub-balances segregate part of an account's balance on-chain,
+		/// e.g. an exchange's "trading" and "savings" funds, without extra keys.
+		/// Labeled coins stay in the account's balance but can't be transferred,
+		/// burned or used for fees until moved back to the unlabeled balance.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `from` - Sub-balance to take from, or `None` for the unlabeled balance
+		/// * `to` - Sub-balance to move into, or `None` for the unlabeled balance
+		/// * `amount` - The amount to move
+		#[pallet::call_index(49)]
+		#[pallet::weight(T::WeightInfo::move_between_labels())]
+		pub fn move_between_labels(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			from: Option<Label>,
+			to: Option<Label>,
+			amount: u128,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			ensure!(amount > 0, Error::<T>::ZeroAmount);
+			ensure!(from != to, Error::<T>::SameLabel);
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			Self::ensure_live(coin_info.status)?;
+
+			let shares = Self::to_shares(coin_id, amount);
+			let mut labels = SubBalances::<T>::get(coin_id, &who);
+			match &from {
+				Some(label) => {
+					let index = labels.iter().position(|(l, _)| l == label)
+						.ok_or(Error::<T>::InsufficientBalance)?;
+					let held = &mut labels[index].1;
+					ensure!(*held >= shares, Error::<T>::InsufficientBalance);
+					*held -= shares;
+					if *held == 0 {
+						labels.remove(index);
+					}
+				},
+				None => {
+					// The unlabeled balance must cover the move on top of every lock
+					Self::apply_decay(coin_id, &who);
+					let balance = Balances::<T>::get(coin_id, &who);
+					ensure!(balance >= shares, Error::<T>::InsufficientBalance);
+					Self::ensure_unlocked(coin_id, &who, balance - shares)?;
+				},
+			}
+			if let Some(label) = &to {
+				match labels.iter_mut().find(|(l, _)| l == label) {
+					Some((_, held)) => *held = held.checked_add(shares).ok_or(Error::<T>::Overflow)?,
+					None => labels.try_push((label.clone(), shares))
+						.map_err(|_| Error::<T>::TooManyLabels)?,
+				}
+			}
+			SubBalances::<T>::set(coin_id, &who, labels);
+
+			Self::deposit_event(Event::MovedBetweenLabels { coin_id, who, from, to, amount });
+
+			Ok(())
+		}
+
+		/// Configure or remove the test network faucet of a coin
+		///
+		/// Drips only work when the pallet is built with the `faucet` feature.
+		/// Callable by the coin owner or `ForceOrigin`.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `config` - Drip amount and per-account cooldown, or `None` to remove the faucet
+		#[pallet::call_index(50)]
+		#[pallet::weight(T::WeightInfo::set_faucet())]
+		pub fn set_faucet(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			config: Option<FaucetConfigOf<T>>,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			Self::ensure_owner_or_force(origin, &coin_info.owner)?;
+			Self::ensure_not_destroying(coin_info.status)?;
+			if let Some(config) = &config {
+				ensure!(config.amount > 0, Error::<T>::ZeroAmount);
+			}
+
+			Faucets::<T>::set(coin_id, config.clone());
+
+			Self::deposit_event(Event::FaucetSet { coin_id, config });
+
+			Ok(())
+		}
+
+		/// Mint the faucet amount of a coin to the caller
+		///
+		/// Meant for test networks so they don't need a separate faucet service.
+		/// Fails with `FaucetDisabled` unless the pallet is built with the
+		/// `faucet` feature and the coin owner configured a faucet. Each account
+		/// must wait the coin's cooldown between drips.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		#[pallet::call_index(51)]
+		#[pallet::weight(T::WeightInfo::drip())]
+		pub fn drip(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			ensure!(cfg!(feature = "faucet"), Error::<T>::FaucetDisabled);
+			let config = Faucets::<T>::get(coin_id).ok_or(Error::<T>::FaucetDisabled)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			if let Some(last) = LastDrip::<T>::get(coin_id, &who) {
+				ensure!(now >= last.saturating_add(config.cooldown), Error::<T>::FaucetCooldown);
+			}
+
+			Self::do_mint(coin_id, &who, config.amount)?;
+			LastDrip::<T>::insert(coin_id, &who, now);
+
+			Self::deposit_event(Event::Dripped { coin_id, who, amount: config.amount });
+
+			Ok(())
+		}
+
+		/// Grant or revoke mint/burn permission of a pallet's account
+		///
+		/// Derives the account of `pallet_id` so bridges such as proof-of-reserve
+		/// can mint and burn wrapped assets. The grant is recorded in
+		/// `PalletGrants` until both permissions are revoked, so pallet grants
+		/// can be told apart from grants to ordinary accounts.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `pallet_id` - The `PalletId` bytes of the pallet whose account gets the permissions
+		/// * `mint` - Whether the pallet account can mint
+		/// * `burn` - Whether the pallet account can burn
+		#[pallet::call_index(52)]
+		#[pallet::weight(T::WeightInfo::grant_pallet_permission())]
+		pub fn grant_pallet_permission(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			pallet_id: [u8; 8],
+			mint: bool,
+			burn: bool,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			ensure!(Self::is_owner_or_manager(coin_id, &coin_info.owner, &who), Error::<T>::NotAuthorized);
+			Self::ensure_not_destroying(coin_info.status)?;
+
+			let account: T::AccountId = PalletId(pallet_id).into_account_truncating();
+			if mint {
+				MintPermissions::<T>::insert(&coin_id, &account, true);
+			} else {
+				MintPermissions::<T>::remove(&coin_id, &account);
+			}
+			if burn {
+				BurnPermissions::<T>::insert(&coin_id, &account, true);
+			} else {
+				BurnPermissions::<T>::remove(&coin_id, &account);
+			}
+			if mint || burn {
+				PalletGrants::<T>::insert(&coin_id, &account, pallet_id);
+			} else {
+				PalletGrants::<T>::remove(&coin_id, &account);
+			}
+
+			Self::deposit_event(Event::PalletPermissionGranted { coin_id, pallet_id, account, mint, burn });
+
+			Ok(())
+		}
+
+		/// Set or remove the per-window mint cap of a bridge account
+		///
+		/// Bridges minting through `bridge_mint` need both a mint permission and a
+		/// cap; the cap bounds what they mint per `BridgeMintWindow`. Callable by
+		/// the coin owner or `ForceOrigin`.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `bridge` - The bridge pallet account
+		/// * `cap` - Maximum amount minted per window, or `None` to remove the cap
+		#[pallet::call_index(53)]
+		#[pallet::weight(T::WeightInfo::set_bridge_mint_cap())]
+		pub fn set_bridge_mint_cap(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			bridge: T::AccountId,
+			cap: Option<u128>,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			Self::ensure_owner_or_force(origin, &coin_info.owner)?;
+			Self::ensure_not_destroying(coin_info.status)?;
+
+			BridgeMintCaps::<T>::set(coin_id, &bridge, cap);
+
+			Self::deposit_event(Event::BridgeMintCapSet { coin_id, bridge, cap });
+
+			Ok(())
+		}
+
+		/// Set or remove the price oracle settings of a coin
+		///
+		/// With settings in place, registered feeders report prices and the median
+		/// of fresh feeds is used to convert transaction fees paid in the coin and
+		/// to check bridge exchange ratios. Callable by the coin owner or `ForceOrigin`.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `config` - Quorum, deviation bound and feed validity, or `None` to remove the oracle
+		#[pallet::call_index(54)]
+		#[pallet::weight(T::WeightInfo::set_oracle_config())]
+		pub fn set_oracle_config(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			config: Option<OracleConfigOf<T>>,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			Self::ensure_owner_or_force(origin, &coin_info.owner)?;
+			Self::ensure_not_destroying(coin_info.status)?;
+			if let Some(config) = &config {
+				ensure!(
+					config.min_feeds > 0 && config.min_feeds <= T::MaxPriceFeeders::get(),
+					Error::<T>::InvalidOracleConfig
+				);
+			}
+
+			OracleConfigs::<T>::set(coin_id, config.clone());
+
+			Self::deposit_event(Event::OracleConfigSet { coin_id, config });
+
+			Ok(())
+		}
+
+		/// Register or unregister a price feeder of a coin
+		///
+		/// Unregistering drops the feeder's latest price. Callable by the coin owner
+		/// or `ForceOrigin`.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `feeder` - The feeder account
+		/// * `enabled` - Whether the account may feed prices
+		#[pallet::call_index(55)]
+		#[pallet::weight(T::WeightInfo::set_price_feeder())]
+		pub fn set_price_feeder(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			feeder: T::AccountId,
+			enabled: bool,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			Self::ensure_owner_or_force(origin, &coin_info.owner)?;
+
+			PriceFeeders::<T>::try_mutate(coin_id, |feeders| -> DispatchResult {
+				let registered = feeders.contains(&feeder);
+				if enabled && !registered {
+					feeders.try_push(feeder.clone()).map_err(|_| Error::<T>::TooManyPriceFeeders)?;
+				} else if !enabled && registered {
+					feeders.retain(|account| account != &feeder);
+					PriceFeeds::<T>::remove(coin_id, &feeder);
+				}
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::PriceFeederSet { coin_id, feeder, enabled });
+
+			Ok(())
+		}
+
+		/// Report the price of one unit of a coin in native units
+		///
+		/// Once a median price is published, reports deviating from it by more than
+		/// the coin's `max_deviation` are rejected, so a single feeder can't move
+		/// the price.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `price` - Native units per coin unit
+		#[pallet::call_index(56)]
+		#[pallet::weight(T::WeightInfo::feed_price())]
+		pub fn feed_price(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			price: FixedU128,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let feeder = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let config = OracleConfigs::<T>::get(coin_id).ok_or(Error::<T>::OracleNotConfigured)?;
+			ensure!(PriceFeeders::<T>::get(coin_id).contains(&feeder), Error::<T>::NotPriceFeeder);
+			ensure!(!price.is_zero(), Error::<T>::InvalidPrice);
+			if let Some(median) = Self::median_price(coin_id) {
+				ensure!(
+					!Self::price_deviates(median, price, config.max_deviation),
+					Error::<T>::PriceDeviationTooLarge
+				);
+			}
+
+			let now = frame_system::Pallet::<T>::block_number();
+			PriceFeeds::<T>::insert(coin_id, &feeder, PriceFeed { price, at: now });
+
+			Self::deposit_event(Event::PriceFed { coin_id, feeder, price });
+
+			Ok(())
+		}
+
+		/// Propose a coin for admission as fee coin
+		///
+		/// Owners opting into fee payment through `can_pay_tx_fees` still need
+		/// `FeeCoinAdmissionOrigin` to approve the coin before it can pay fees.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		#[pallet::call_index(57)]
+		#[pallet::weight(T::WeightInfo::propose_fee_coin())]
+		pub fn propose_fee_coin(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			ensure!(coin_info.owner == who, Error::<T>::NotAuthorized);
+			Self::ensure_live(coin_info.status)?;
+			ensure!(!AdmittedFeeCoins::<T>::contains_key(coin_id), Error::<T>::FeeCoinAlreadyAdmitted);
+
+			FeeCoinProposals::<T>::insert(coin_id, &who);
+
+			Self::deposit_event(Event::FeeCoinProposed { coin_id, proposer: who });
+
+			Ok(())
+		}
+
+		/// Admit a proposed coin as fee coin
+		///
+		/// Only callable by `FeeCoinAdmissionOrigin`.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		#[pallet::call_index(58)]
+		#[pallet::weight(T::WeightInfo::approve_fee_coin())]
+		pub fn approve_fee_coin(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			T::FeeCoinAdmissionOrigin::ensure_origin(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			FeeCoinProposals::<T>::take(coin_id).ok_or(Error::<T>::FeeCoinNotProposed)?;
+			AdmittedFeeCoins::<T>::insert(coin_id, ());
+
+			Self::deposit_event(Event::FeeCoinAdmitted { coin_id });
+
+			Ok(())
+		}
+
+		/// Revoke a coin's fee coin admission, or reject its pending proposal
+		///
+		/// Only callable by `FeeCoinAdmissionOrigin`, e.g. when a fee coin turns out
+		/// to be worthless or mispriced.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		#[pallet::call_index(59)]
+		#[pallet::weight(T::WeightInfo::revoke_fee_coin())]
+		pub fn revoke_fee_coin(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			T::FeeCoinAdmissionOrigin::ensure_origin(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let admitted = AdmittedFeeCoins::<T>::take(coin_id).is_some();
+			let proposed = FeeCoinProposals::<T>::take(coin_id).is_some();
+			ensure!(admitted || proposed, Error::<T>::FeeCoinNotProposed);
+
+			Self::deposit_event(Event::FeeCoinRevoked { coin_id });
+
+			Ok(())
+		}
+
+		/// Suspend or resume paying transaction fees in coins
+		///
+		/// While suspended, every fee is charged in the native currency whatever fee
+		/// coin a transaction selects, so a mispriced or exploited fee coin can't be
+		/// used to fill blocks cheaply during an incident. Only callable by `ForceOrigin`.
+		///
+		/// # Arguments
+		/// * `suspended` - Whether coin fee payment is suspended
+		#[pallet::call_index(60)]
+		#[pallet::weight(T::WeightInfo::set_fee_payment_suspended())]
+		pub fn set_fee_payment_suspended(
+			origin: OriginFor<T>,
+			suspended: bool,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			FeePaymentSuspended::<T>::put(suspended);
+
+			Self::deposit_event(Event::FeePaymentSuspendedSet { suspended });
+
+			Ok(())
+		}
+
+		/// Set or remove the collateralized minting settings of a coin
+		///
+		/// Anyone can then lock the collateral asset and mint the coin against it at
+		/// the oracle prices of both. Callable by the coin owner or `ForceOrigin`.
+		/// While positions are open the collateral asset can't change.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the stablecoin
+		/// * `config` - Collateral asset, ratios and liquidation penalty, or `None`
+		#[pallet::call_index(61)]
+		#[pallet::weight(T::WeightInfo::set_stable_config())]
+		pub fn set_stable_config(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			config: Option<StableConfig>,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			Self::ensure_owner_or_force(origin, &coin_info.owner)?;
+			Self::ensure_not_destroying(coin_info.status)?;
+			if let Some(config) = &config {
+				ensure!(
+					config.collateral != PaymentAsset::Coin(coin_id) &&
+						config.liquidation_ratio >= FixedU128::one() &&
+						config.min_ratio >= config.liquidation_ratio,
+					Error::<T>::InvalidStableConfig
+				);
+			}
+			if CollateralPositions::<T>::iter_prefix(coin_id).next().is_some() {
+				let current = StableConfigs::<T>::get(coin_id).map(|config| config.collateral);
+				ensure!(current == config.map(|config| config.collateral), Error::<T>::StablePositionsOpen);
+			}
+
+			StableConfigs::<T>::set(coin_id, config);
+
+			Self::deposit_event(Event::StableConfigSet { coin_id, config });
+
+			Ok(())
+		}
+
+		/// Lock collateral in the caller's position of a stablecoin
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the stablecoin
+		/// * `amount` - Amount of the collateral asset to lock
+		#[pallet::call_index(62)]
+		#[pallet::weight(T::WeightInfo::deposit_collateral())]
+		pub fn deposit_collateral(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			amount: u128,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			ensure!(amount > 0, Error::<T>::ZeroAmount);
+			let config = StableConfigs::<T>::get(coin_id).ok_or(Error::<T>::NotStableCoin)?;
+			let units = Self::collect_payment(config.collateral, &who, amount)?;
+
+			let mut position = CollateralPositions::<T>::get(coin_id, &who).unwrap_or_default();
+			position.collateral = position.collateral.checked_add(units).ok_or(Error::<T>::Overflow)?;
+			Self::store_position(coin_id, &who, position);
+
+			Self::deposit_event(Event::CollateralDeposited { coin_id, who, amount });
+
+			Ok(())
+		}
+
+		/// Withdraw collateral from the caller's position of a stablecoin
+		///
+		/// The position must stay at or above the minimum collateral ratio.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the stablecoin
+		/// * `amount` - Amount of the collateral asset to withdraw
+		#[pallet::call_index(63)]
+		#[pallet::weight(T::WeightInfo::withdraw_collateral())]
+		pub fn withdraw_collateral(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			amount: u128,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			ensure!(amount > 0, Error::<T>::ZeroAmount);
+			let config = StableConfigs::<T>::get(coin_id).ok_or(Error::<T>::NotStableCoin)?;
+			let mut position = CollateralPositions::<T>::get(coin_id, &who).ok_or(Error::<T>::PositionNotFound)?;
+			let units = Self::payment_units(config.collateral, amount);
+			position.collateral = position.collateral.checked_sub(units).ok_or(Error::<T>::InsufficientBalance)?;
+			Self::ensure_collateralized(coin_id, &config, &position)?;
+
+			Self::release_payment(config.collateral, &who, units)?;
+			Self::store_position(coin_id, &who, position);
+
+			Self::deposit_event(Event::CollateralWithdrawn { coin_id, who, amount });
+
+			Ok(())
+		}
+
+		/// Mint a stablecoin against the caller's collateral
+		///
+		/// The position must stay at or above the minimum collateral ratio.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the stablecoin
+		/// * `amount` - Amount to mint
+		#[pallet::call_index(64)]
+		#[pallet::weight(T::WeightInfo::mint_stable())]
+		pub fn mint_stable(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			amount: u128,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let config = StableConfigs::<T>::get(coin_id).ok_or(Error::<T>::NotStableCoin)?;
+			let mut position = CollateralPositions::<T>::get(coin_id, &who).ok_or(Error::<T>::PositionNotFound)?;
+			position.debt = position.debt.checked_add(amount).ok_or(Error::<T>::Overflow)?;
+			Self::ensure_collateralized(coin_id, &config, &position)?;
+
+			Self::do_mint(coin_id, &who, amount)?;
+			Self::store_position(coin_id, &who, position);
+
+			Self::deposit_event(Event::StableMinted { coin_id, who, amount });
+
+			Ok(())
+		}
+
+		/// Burn stablecoins of the caller to repay its position's debt
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the stablecoin
+		/// * `amount` - Amount to repay
+		#[pallet::call_index(65)]
+		#[pallet::weight(T::WeightInfo::repay_stable())]
+		pub fn repay_stable(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			amount: u128,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let mut position = CollateralPositions::<T>::get(coin_id, &who).ok_or(Error::<T>::PositionNotFound)?;
+			position.debt = position.debt.checked_sub(amount).ok_or(Error::<T>::RepayExceedsDebt)?;
+
+			Self::do_burn(coin_id, &who, amount)?;
+			Self::store_position(coin_id, &who, position);
+
+			Self::deposit_event(Event::StableRepaid { coin_id, who, amount });
+
+			Ok(())
+		}
+
+		/// Put a position that fell below the liquidation ratio up for auction
+		///
+		/// The position's collateral and debt move into a Dutch auction, see
+		/// `bid_liquidation`. The caller is the auction's keeper and earns
+		/// `KeeperReward` of the debt's value in collateral once it settles.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the stablecoin
+		/// * `who` - Owner of the position
+		#[pallet::call_index(66)]
+		#[pallet::weight(T::WeightInfo::liquidate())]
+		pub fn liquidate(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			who: T::AccountId,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let keeper = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let config = StableConfigs::<T>::get(coin_id).ok_or(Error::<T>::NotStableCoin)?;
+			let position = CollateralPositions::<T>::get(coin_id, &who).ok_or(Error::<T>::PositionNotFound)?;
+			let ratio = Self::collateral_ratio(coin_id, &config, &position)?;
+			ensure!(ratio.is_some_and(|ratio| ratio < config.liquidation_ratio), Error::<T>::PositionSafe);
+
+			let auction_id = NextAuctionId::<T>::get();
+			NextAuctionId::<T>::put(auction_id.checked_add(1).ok_or(Error::<T>::Overflow)?);
+			CollateralPositions::<T>::remove(coin_id, &who);
+			Self::add_references(coin_id, config.collateral);
+			LiquidationAuctions::<T>::insert(auction_id, LiquidationAuction {
+				coin_id,
+				owner: who.clone(),
+				keeper: keeper.clone(),
+				collateral_asset: config.collateral,
+				collateral: position.collateral,
+				debt: position.debt,
+				max_discount: config.liquidation_penalty,
+				started_at: frame_system::Pallet::<T>::block_number(),
+			});
+
+			Self::deposit_event(Event::LiquidationStarted {
+				auction_id,
+				coin_id,
+				who,
+				keeper,
+				collateral: Self::payment_amount(config.collateral, position.collateral),
+				debt: position.debt,
+			});
+
+			Ok(())
+		}
+
+		/// Repay the debt of a liquidation auction and buy its collateral
+		///
+		/// The bidder burns the whole debt and receives collateral worth the debt
+		/// plus the auction's current discount, which grows from zero to the
+		/// liquidation penalty over `LiquidationAuctionDuration`. The keeper gets
+		/// its reward and the position's owner whatever collateral is left.
+		///
+		/// # Arguments
+		/// * `auction_id` - The ID of the auction
+		#[pallet::call_index(67)]
+		#[pallet::weight(T::WeightInfo::bid_liquidation())]
+		pub fn bid_liquidation(
+			origin: OriginFor<T>,
+			auction_id: u32,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let bidder = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let auction = LiquidationAuctions::<T>::get(auction_id).ok_or(Error::<T>::AuctionNotFound)?;
+			let asset = auction.collateral_asset;
+			Self::do_burn(auction.coin_id, &bidder, auction.debt)?;
+
+			let debt_value = Self::stable_value(auction.coin_id, auction.debt)?;
+			let discount = Self::auction_discount(&auction).mul_floor(debt_value);
+			let bought = Self::value_to_units(asset, debt_value.saturating_add(discount))?.min(auction.collateral);
+			let reward = Self::value_to_units(asset, T::KeeperReward::get().mul_floor(debt_value))?
+				.min(auction.collateral - bought);
+			let rest = auction.collateral - bought - reward;
+			Self::release_payment(asset, &bidder, bought)?;
+			if reward > 0 {
+				Self::release_payment(asset, &auction.keeper, reward)?;
+			}
+			if rest > 0 {
+				Self::release_payment(asset, &auction.owner, rest)?;
+			}
+			LiquidationAuctions::<T>::remove(auction_id);
+			Self::remove_references(auction.coin_id, asset);
+
+			Self::deposit_event(Event::LiquidationSettled {
+				auction_id,
+				bidder,
+				collateral: Self::payment_amount(asset, bought),
+				keeper_reward: Self::payment_amount(asset, reward),
+			});
+
+			Ok(())
+		}
+
+		/// Enable flash minting of a coin at a fee, or disable it
+		///
+		/// Callable by the coin owner or `ForceOrigin`.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `fee` - Share of the flash minted amount burned on top, or `None` to disable
+		#[pallet::call_index(68)]
+		#[pallet::weight(T::WeightInfo::set_flash_mint_fee())]
+		pub fn set_flash_mint_fee(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			fee: Option<Permill>,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			Self::ensure_owner_or_force(origin, &coin_info.owner)?;
+			Self::ensure_not_destroying(coin_info.status)?;
+
+			FlashMintFees::<T>::set(coin_id, fee);
+
+			Self::deposit_event(Event::FlashMintFeeSet { coin_id, fee });
+
+			Ok(())
+		}
+
+		/// Mint coins to the caller, dispatch `call` and burn them again plus a fee
+		///
+		/// Lets callers arbitrage or refinance without standing liquidity. If the
+		/// caller can't burn the amount plus the coin's flash mint fee once `call`
+		/// returns, the whole extrinsic fails and nothing was ever minted. `call` can't
+		/// be another wrapper and must pass the origin's call filter.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `amount` - Amount to mint for the duration of `call`
+		/// * `call` - Call dispatched with the caller's origin
+		#[pallet::call_index(69)]
+		#[pallet::weight(T::WeightInfo::flash_mint().saturating_add(call.get_dispatch_info().call_weight))]
+		pub fn flash_mint(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			amount: u128,
+			call: Box<T::RuntimeCall>,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+			Self::ensure_wrappable(&origin, &call)?;
+
+			let fee = FlashMintFees::<T>::get(coin_id).ok_or(Error::<T>::FlashMintDisabled)?.mul_ceil(amount);
+			let repayment = amount.checked_add(fee).ok_or(Error::<T>::Overflow)?;
+
+			// The minted coins never outlive the call, so the mint lock-up doesn't apply
+			Self::ensure_not_frozen(coin_id, &who)?;
+			Self::mint_unlocked(coin_id, &who, amount)?;
+			call.dispatch(origin).map_err(|e| e.error)?;
+			Self::do_burn(coin_id, &who, repayment).map_err(|_| Error::<T>::FlashMintNotRepaid)?;
+
+			Self::deposit_event(Event::FlashMinted { coin_id, who, amount, fee });
+
+			Ok(())
+		}
+
+		/// Set coins aside in a ticket that `to` can claim until `expiry`
+		///
+		/// Useful for sending to someone who has no account yet: the coins leave the
+		/// caller's balance now and reach `to` only when it claims them. Unclaimed
+		/// tickets can be refunded to the caller once expired.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `to` - The account that can claim the ticket
+		/// * `amount` - Amount to set aside
+		/// * `expiry` - First block in which the ticket can no longer be claimed
+		#[pallet::call_index(70)]
+		#[pallet::weight(T::WeightInfo::create_transfer_ticket())]
+		pub fn create_transfer_ticket(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			to: T::AccountId,
+			amount: u128,
+			expiry: BlockNumberFor<T>,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			ensure!(amount > 0, Error::<T>::ZeroAmount);
+			ensure!(expiry > frame_system::Pallet::<T>::block_number(), Error::<T>::InvalidTicketExpiry);
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			Self::ensure_live(coin_info.status)?;
+			ensure!(!BlockedSinks::<T>::contains_key(coin_id, &to), Error::<T>::RecipientBlocked);
+
+			let shares = Self::to_shares(coin_id, amount);
+			Self::debit(coin_id, &from, shares)?;
+			let ticket_id = NextTicketId::<T>::get();
+			NextTicketId::<T>::put(ticket_id.checked_add(1).ok_or(Error::<T>::Overflow)?);
+			Self::add_references(coin_id, PaymentAsset::Native);
+			TransferTickets::<T>::insert(ticket_id, TransferTicket {
+				coin_id,
+				from: from.clone(),
+				to: to.clone(),
+				shares,
+				expiry,
+			});
+
+			Self::deposit_event(Event::TransferTicketCreated { ticket_id, coin_id, from, to, amount, expiry });
+
+			Ok(())
+		}
+
+		/// Claim a transfer ticket addressed to the caller before it expires
+		///
+		/// # Arguments
+		/// * `ticket_id` - The ID of the ticket
+		#[pallet::call_index(71)]
+		#[pallet::weight(T::WeightInfo::claim_ticket())]
+		pub fn claim_ticket(
+			origin: OriginFor<T>,
+			ticket_id: u32,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let ticket = TransferTickets::<T>::get(ticket_id).ok_or(Error::<T>::TicketNotFound)?;
+			ensure!(ticket.to == who, Error::<T>::NotAuthorized);
+			ensure!(frame_system::Pallet::<T>::block_number() < ticket.expiry, Error::<T>::TicketExpired);
+			Self::ensure_coin_live(ticket.coin_id)?;
+
+			Self::credit(ticket.coin_id, &who, ticket.shares)?;
+			TransferTickets::<T>::remove(ticket_id);
+			Self::remove_references(ticket.coin_id, PaymentAsset::Native);
+
+			Self::deposit_event(Event::TransferTicketClaimed {
+				ticket_id,
+				to: who,
+				amount: Self::to_amount(ticket.coin_id, ticket.shares),
+			});
+
+			Ok(())
+		}
+
+		/// Refund an expired, unclaimed transfer ticket to its creator
+		///
+		/// Callable by anyone.
+		///
+		/// # Arguments
+		/// * `ticket_id` - The ID of the ticket
+		#[pallet::call_index(72)]
+		#[pallet::weight(T::WeightInfo::refund_ticket())]
+		pub fn refund_ticket(
+			origin: OriginFor<T>,
+			ticket_id: u32,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let ticket = TransferTickets::<T>::get(ticket_id).ok_or(Error::<T>::TicketNotFound)?;
+			ensure!(frame_system::Pallet::<T>::block_number() >= ticket.expiry, Error::<T>::TicketNotExpired);
+			Self::ensure_coin_live(ticket.coin_id)?;
+
+			Self::credit(ticket.coin_id, &ticket.from, ticket.shares)?;
+			TransferTickets::<T>::remove(ticket_id);
+			Self::remove_references(ticket.coin_id, PaymentAsset::Native);
+
+			Self::deposit_event(Event::TransferTicketRefunded {
+				ticket_id,
+				to: ticket.from,
+				amount: Self::to_amount(ticket.coin_id, ticket.shares),
+			});
+
+			Ok(())
+		}
+
+		/// Record the current supply, holder count and fees burned since the last
+		/// checkpoint of a coin
+		///
+		/// Gives auditors on-chain supply history without an indexer. Callable by
+		/// anyone once every `SupplyCheckpointInterval` blocks per coin; only the
+		/// latest `MaxSupplyCheckpoints` checkpoints are kept.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		#[pallet::call_index(73)]
+		#[pallet::weight(T::WeightInfo::checkpoint_supply())]
+		pub fn checkpoint_supply(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			ensure!(CoinMetadata::<T>::contains_key(coin_id), Error::<T>::CoinNotFound);
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut checkpoints = SupplyCheckpoints::<T>::get(coin_id);
+			if let Some(last) = checkpoints.last() {
+				ensure!(now >= last.block.saturating_add(T::SupplyCheckpointInterval::get()), Error::<T>::CheckpointTooSoon);
+			}
+
+			let checkpoint = SupplyCheckpoint {
+				block: now,
+				total_supply: Self::total_supply_of(coin_id),
+				holders: HolderCount::<T>::get(coin_id),
+				fees_burned: FeesBurnedSinceCheckpoint::<T>::take(coin_id),
+			};
+			if checkpoints.is_full() && !checkpoints.is_empty() {
+				checkpoints.remove(0);
+			}
+			let _ = checkpoints.try_push(checkpoint.clone());
+			SupplyCheckpoints::<T>::insert(coin_id, checkpoints);
+
+			Self::deposit_event(Event::SupplyCheckpointed {
+				coin_id,
+				total_supply: checkpoint.total_supply,
+				holders: checkpoint.holders,
+				fees_burned: checkpoint.fees_burned,
+			});
+
+			Ok(())
+		}
+
+		/// Pause general transfers of a coin, keeping an allowlist operational, or resume them
+		///
+		/// Unlike `freeze_coin`, mints and burns continue, and transfers from or to
+		/// an allowlisted account (e.g. the bridge custodian and the treasury)
+		/// still go through, which keeps a coin usable during migrations.
+		/// Callable by the coin owner or `ForceOrigin`.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `except` - Accounts that may keep transferring, or `None` to resume transfers
+		#[pallet::call_index(74)]
+		#[pallet::weight(T::WeightInfo::set_transfer_pause())]
+		pub fn set_transfer_pause(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			except: Option<Vec<T::AccountId>>,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			Self::ensure_owner_or_force(origin, &coin_info.owner)?;
+			Self::ensure_not_destroying(coin_info.status)?;
+
+			let allowlist = except
+				.clone()
+				.map(BoundedVec::<T::AccountId, T::MaxPauseExceptions>::try_from)
+				.transpose()
+				.map_err(|_| Error::<T>::TooManyPauseExceptions)?;
+			PausedExcept::<T>::set(coin_id, allowlist);
+
+			Self::deposit_event(Event::TransferPauseSet { coin_id, except });
+
+			Ok(())
+		}
+
+		/// Anchor a coin's off-chain legal documents by their content hash, or remove it
+		///
+		/// Lets security-token issuers point holders at the prospectus or terms
+		/// that apply to the coin. Callable by the coin owner or `ForceOrigin`.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `hash` - Content hash of the documents, e.g. an IPFS CID, or `None` to remove it
+		#[pallet::call_index(75)]
+		#[pallet::weight(T::WeightInfo::set_legal_document())]
+		pub fn set_legal_document(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			hash: Option<DocumentHash>,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			Self::ensure_owner_or_force(origin, &coin_info.owner)?;
+			Self::ensure_not_destroying(coin_info.status)?;
+
+			LegalDocuments::<T>::set(coin_id, hash.clone());
+
+			Self::deposit_event(Event::LegalDocumentSet { coin_id, hash });
+
+			Ok(())
+		}
+
+		/// Allow `delegate` to transfer up to `amount` of the caller's coins
+		///
+		/// Replaces any previous allowance of the delegate. With `expires_at` the
+		/// allowance can't be used after that block.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `delegate` - The account allowed to spend
+		/// * `amount` - The allowance
+		/// * `expires_at` - Last block the allowance can be used at, if any
+		#[pallet::call_index(76)]
+		#[pallet::weight(T::WeightInfo::approve())]
+		pub fn approve(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			delegate: T::AccountId,
+			amount: u128,
+			expires_at: Option<BlockNumberFor<T>>,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			Self::ensure_not_destroying(coin_info.status)?;
+			ensure!(amount > 0, Error::<T>::ZeroAmount);
+
+			let nonce = ApprovalNonces::<T>::get(coin_id, &owner);
+			Approvals::<T>::insert(
+				coin_id,
+				(&owner, &delegate),
+				Approval { amount, expires_at, nonce },
+			);
+
+			Self::deposit_event(Event::Approved { coin_id, owner, delegate, amount, expires_at });
+
+			Ok(())
+		}
+
+		/// Transfer coins of `owner` to `to` under the caller's allowance
+		///
+		/// The allowance shrinks by `amount`; transfer fees are paid by the owner as
+		/// with a direct transfer.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `owner` - The account whose coins are spent
+		/// * `to` - The recipient account
+		/// * `amount` - The amount to transfer
+		#[pallet::call_index(77)]
+		#[pallet::weight(T::WeightInfo::transfer_from().saturating_add(Pallet::<T>::transfer_hook_weight()))]
+		pub fn transfer_from(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			owner: T::AccountId,
+			to: T::AccountId,
+			amount: u128,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let delegate = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			Approvals::<T>::try_mutate_exists(coin_id, (&owner, &delegate), |approval| -> DispatchResult {
+				let current = approval.as_mut().ok_or(Error::<T>::Unapproved)?;
+				ensure!(
+					current.nonce == ApprovalNonces::<T>::get(coin_id, &owner),
+					Error::<T>::Unapproved
+				);
+				if let Some(expires_at) = current.expires_at {
+					ensure!(
+						frame_system::Pallet::<T>::block_number() <= expires_at,
+						Error::<T>::ApprovalExpired
+					);
+				}
+				current.amount = current.amount.checked_sub(amount).ok_or(Error::<T>::Unapproved)?;
+				if current.amount == 0 {
+					*approval = None;
+				}
+				Ok(())
+			})?;
+
+			Self::do_transfer(coin_id, &owner, &to, amount)?;
+
+			Self::deposit_event(Event::TransferredApproved {
+				coin_id,
+				owner,
+				delegate,
+				destination: to,
+				amount,
+			});
+
+			Ok(())
+		}
+
+		/// Withdraw the allowance of `delegate` over the caller's coins
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `delegate` - The account whose allowance is withdrawn
+		#[pallet::call_index(78)]
+		#[pallet::weight(T::WeightInfo::cancel_approval())]
+		pub fn cancel_approval(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			delegate: T::AccountId,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			Approvals::<T>::take(coin_id, (&owner, &delegate)).ok_or(Error::<T>::Unapproved)?;
+
+			Self::deposit_event(Event::ApprovalCancelled { coin_id, owner, delegate });
+
+			Ok(())
+		}
+
+		/// Void every outstanding allowance over the caller's coins at once
+		///
+		/// Meant for a compromised key: bumping the caller's approval nonce makes
+		/// all allowances granted before unusable without touching each of them.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		#[pallet::call_index(79)]
+		#[pallet::weight(T::WeightInfo::invalidate_approvals())]
+		pub fn invalidate_approvals(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			ensure!(CoinMetadata::<T>::contains_key(&coin_id), Error::<T>::CoinNotFound);
+
+			let nonce = ApprovalNonces::<T>::mutate(coin_id, &owner, |nonce| {
+				*nonce = nonce.wrapping_add(1);
+				*nonce
+			});
+
+			Self::deposit_event(Event::ApprovalsInvalidated { coin_id, owner, nonce });
+
+			Ok(())
+		}
+
+		/// Freeze an account's balance of a coin
+		///
+		/// The account can no longer send or receive the coin, nor have it minted to
+		/// or burned from it. Only `force_transfer` can still move its balance.
+		/// Callable by the coin's freezer, its manager or `ForceOrigin`.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `who` - The account to freeze
+		#[pallet::call_index(80)]
+		#[pallet::weight(T::WeightInfo::freeze_account())]
+		pub fn freeze_account(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			who: T::AccountId,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			Self::ensure_manager_or_force(origin, coin_id, &coin_info.freezer)?;
+			ensure!(!FrozenAccounts::<T>::contains_key(coin_id, &who), Error::<T>::AccountFrozen);
+
+			FrozenAccounts::<T>::insert(coin_id, &who, ());
+
+			Self::deposit_event(Event::AccountFrozen { coin_id, who });
+
+			Ok(())
+		}
+
+		/// Let a frozen account's balance of a coin move again
+		///
+		/// Callable by the coin's freezer, its manager or `ForceOrigin`.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `who` - The account to thaw
+		#[pallet::call_index(81)]
+		#[pallet::weight(T::WeightInfo::thaw_account())]
+		pub fn thaw_account(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			who: T::AccountId,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			Self::ensure_manager_or_force(origin, coin_id, &coin_info.freezer)?;
+			ensure!(FrozenAccounts::<T>::contains_key(coin_id, &who), Error::<T>::AccountNotFrozen);
+
+			FrozenAccounts::<T>::remove(coin_id, &who);
+
+			Self::deposit_event(Event::AccountThawed { coin_id, who });
+
+			Ok(())
+		}
+
+		/// Start destroying a coin
+		///
+		/// Balances stop moving at once. `destroy_accounts` then clears the per-account
+		/// entries in batches, and `finish_destroy` removes the coin and returns its
+		/// creation deposit. Stablecoins can only be destroyed once every collateral
+		/// position is closed. Callable by the coin owner or `ForceOrigin`.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin to destroy
+		#[pallet::call_index(82)]
+		#[pallet::weight(T::WeightInfo::start_destroy())]
+		pub fn start_destroy(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let mut coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			Self::ensure_owner_or_force(origin, &coin_info.owner)?;
+			Self::ensure_not_destroying(coin_info.status)?;
+			ensure!(
+				CollateralPositions::<T>::iter_key_prefix(coin_id).next().is_none(),
+				Error::<T>::PositionsOpen
+			);
+			ensure!(CoinReferences::<T>::get(coin_id) == 0, Error::<T>::CoinReferenced);
+
+			coin_info.status = CoinStatus::Destroying;
+			CoinMetadata::<T>::insert(&coin_id, &coin_info);
+
+			Self::deposit_event(Event::CoinDestructionStarted { coin_id });
+
+			Ok(())
+		}
+
+		/// Remove up to `RemoveItemsLimit` per-account entries of a coin being destroyed
+		///
+		/// Balances go first, then permissions, stakes, locks, holds, approvals and the
+		/// other per-account records. Callable by any signed account.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin being destroyed
+		#[pallet::call_index(83)]
+		#[pallet::weight(T::WeightInfo::destroy_accounts(T::RemoveItemsLimit::get()))]
+		pub fn destroy_accounts(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			ensure!(coin_info.status.is_destroying(), Error::<T>::NotDestroying);
+
+			let limit = T::RemoveItemsLimit::get();
+			let mut budget = limit;
+			let complete = Self::remove_coin_accounts(coin_id, &mut budget);
+
+			Self::deposit_event(Event::CoinAccountsDestroyed {
+				coin_id,
+				removed: limit.saturating_sub(budget),
+				complete,
+			});
+
+			Ok(())
+		}
+
+		/// Remove a coin whose per-account entries are gone and return its deposit
+		///
+		/// Clears the coin's metadata, symbol, supply and every per-coin setting, and
+		/// unreserves the creation deposit of its depositor. Callable by any signed account.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin being destroyed
+		#[pallet::call_index(84)]
+		#[pallet::weight(T::WeightInfo::finish_destroy())]
+		pub fn finish_destroy(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			ensure!(coin_info.status.is_destroying(), Error::<T>::NotDestroying);
+			// A zero budget only checks that nothing is left
+			ensure!(Self::remove_coin_accounts(coin_id, &mut 0), Error::<T>::AccountsRemaining);
+
+			CoinMetadata::<T>::remove(coin_id);
+			SymbolToId::<T>::remove(&coin_info.symbol);
+			TotalSupply::<T>::remove(coin_id);
+			HolderCount::<T>::remove(coin_id);
+			MaxHolders::<T>::remove(coin_id);
+			CoinManagers::<T>::remove(coin_id);
+			FeeChangeGuards::<T>::remove(coin_id);
+			PendingFeeChanges::<T>::remove(coin_id);
+			AccrualIndex::<T>::remove(coin_id);
+			DecayRates::<T>::remove(coin_id);
+			RewardPools::<T>::remove(coin_id);
+			DormancyConfigs::<T>::remove(coin_id);
+			Royalties::<T>::remove(coin_id);
+			MintLockPeriods::<T>::remove(coin_id);
+			Faucets::<T>::remove(coin_id);
+			OracleConfigs::<T>::remove(coin_id);
+			PriceFeeders::<T>::remove(coin_id);
+			FeeCoinProposals::<T>::remove(coin_id);
+			AdmittedFeeCoins::<T>::remove(coin_id);
+			FeeMultipliers::<T>::remove(coin_id);
+			StableConfigs::<T>::remove(coin_id);
+			FlashMintFees::<T>::remove(coin_id);
+			SupplyCheckpoints::<T>::remove(coin_id);
+			FeesBurnedSinceCheckpoint::<T>::remove(coin_id);
+			TotalFeesBurned::<T>::remove(coin_id);
+			PausedExcept::<T>::remove(coin_id);
+			LegalDocuments::<T>::remove(coin_id);
+			CoinVelocity::<T>::remove(coin_id);
+			HolderSnapshots::<T>::remove(coin_id);
+			PendingHolderSnapshots::<T>::remove(coin_id);
+			CoinLocalizations::<T>::remove(coin_id);
+			ExtendedMetadata::<T>::remove(coin_id);
+			if let Some(deposit) = MetadataDeposits::<T>::take(coin_id) {
+				T::Currency::unreserve(&deposit.depositor, deposit.amount);
+			}
+			let usage = FeeUsage::<T>::take(coin_id);
+			TotalFeeUsage::<T>::mutate(|total| *total = total.saturating_sub(usage));
+
+			let depositor = CoinDepositors::<T>::take(coin_id).unwrap_or_else(|| coin_info.owner.clone());
+			T::Currency::unreserve(&depositor, coin_info.deposit);
+			TotalCoinDeposits::<T>::mutate(|total| *total = total.saturating_sub(coin_info.deposit));
+
+			Self::deposit_event(Event::CoinDestroyed {
+				coin_id,
+				owner: coin_info.owner,
+				deposit: coin_info.deposit,
+			});
+
+			Ok(())
+		}
+
+		/// Hand the issuer, admin and freezer roles of a coin to other accounts
+		///
+		/// The issuer mints, the admin burns its own coins and grants mint and burn
+		/// permissions, and the freezer freezes the coin and its holders. Callable by
+		/// the coin owner or `ForceOrigin`.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `issuer` - The new issuer
+		/// * `admin` - The new admin
+		/// * `freezer` - The new freezer
+		#[pallet::call_index(85)]
+		#[pallet::weight(T::WeightInfo::set_team())]
+		pub fn set_team(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			issuer: T::AccountId,
+			admin: T::AccountId,
+			freezer: T::AccountId,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let mut coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			Self::ensure_owner_or_force(origin, &coin_info.owner)?;
+			Self::ensure_not_destroying(coin_info.status)?;
+
+			coin_info.issuer = issuer.clone();
+			coin_info.admin = admin.clone();
+			coin_info.freezer = freezer.clone();
+			CoinMetadata::<T>::insert(&coin_id, &coin_info);
+
+			Self::deposit_event(Event::TeamChanged { coin_id, issuer, admin, freezer });
+
+			Ok(())
+		}
+
+		/// Send amounts of a coin to many recipients at once
+		///
+		/// All parts succeed or none does. Each part pays the coin's transfer fee and
+		/// royalty like a regular transfer, but the sender's balance is only read and
+		/// written once, which makes airdrops and payroll cheaper than single transfers.
+		///
+		/// # Arguments
+		/// * `coin_id` - The coin to send
+		/// * `recipients` - Recipients and the amounts they get
+		#[pallet::call_index(86)]
+		#[pallet::weight(
+			T::WeightInfo::transfer_batch(recipients.len() as u32)
+				.saturating_add(Pallet::<T>::transfer_hook_weight().saturating_mul(recipients.len() as u64))
+		)]
+		pub fn transfer_batch(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			recipients: BoundedVec<(T::AccountId, u128), T::MaxBatchRecipients>,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let total = Self::do_transfer_batch(coin_id, &who, &recipients)?;
+
+			Self::deposit_event(Event::BatchTransferred {
+				coin_id,
+				from: who,
+				recipients: recipients.len() as u32,
+				total,
+			});
+
+			Ok(())
+		}
+
+		/// Permanently give up ownership of a coin
+		///
+		/// The owner and the issuer, admin and freezer roles move to `no_owner_account`,
+		/// which nobody holds a key for, and the manager is cleared, so fee changes,
+		/// permissioning and every other owner or role operation are disabled for good.
+		/// A pending fee change is cancelled so it can't be enacted afterwards. The
+		/// renouncing owner's own mint and burn grants are revoked; grants held by other
+		/// accounts are left as they are. Only a live coin can be renounced, as a frozen
+		/// one could never be thawed again. Callable by the coin owner.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		#[pallet::call_index(87)]
+		#[pallet::weight(T::WeightInfo::renounce_ownership())]
+		pub fn renounce_ownership(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let mut coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			ensure!(coin_info.owner == who, Error::<T>::NotAuthorized);
+			Self::ensure_live(coin_info.status)?;
+
+			let no_owner = Self::no_owner_account();
+			coin_info.owner = no_owner.clone();
+			coin_info.issuer = no_owner.clone();
+			coin_info.admin = no_owner.clone();
+			coin_info.freezer = no_owner;
+			CoinMetadata::<T>::insert(&coin_id, &coin_info);
+			if CoinManagers::<T>::take(coin_id).is_some() {
+				Self::deposit_event(Event::CoinManagerSet { coin_id, manager: None });
+			}
+			if PendingFeeChanges::<T>::take(coin_id).is_some() {
+				Self::deposit_event(Event::FeeChangeCancelled { coin_id });
+			}
+
+			MintPermissions::<T>::remove(&coin_id, &who);
+			BurnPermissions::<T>::remove(&coin_id, &who);
+			Self::deposit_event(Event::MintPermissionSet {
+				coin_id,
+				account: who.clone(),
+				can_mint: false,
+			});
+			Self::deposit_event(Event::BurnPermissionSet {
+				coin_id,
+				account: who.clone(),
+				can_burn: false,
+			});
+
+			Self::deposit_event(Event::OwnershipRenounced { coin_id, old_owner: who });
+
+			Ok(())
+		}
+
+		/// Transfer coins like `transfer`, but never empty the sender's balance
+		///
+		/// Balances are always kept at or above the coin's minimum balance; this also
+		/// refuses transfers that would take the sender's balance to zero, so it stays
+		/// a holder of the coin.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin to transfer
+		/// * `to` - The recipient account
+		/// * `amount` - The amount to transfer
+		#[pallet::call_index(88)]
+		#[pallet::weight(T::WeightInfo::transfer_keep_alive().saturating_add(Pallet::<T>::transfer_hook_weight()))]
+		pub fn transfer_keep_alive(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			to: T::AccountId,
+			amount: u128,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			Self::do_transfer(coin_id, &from, &to, amount)?;
+
+			// Failing here rolls the transfer back with the rest of the call
+			ensure!(Balances::<T>::get(coin_id, &from) > 0, Error::<T>::WouldEmptyAccount);
+			Ok(())
+		}
+
+		/// Transfer the sender's whole transferable balance of a coin
+		///
+		/// Sends `transferable_amount`: everything that isn't locked, less the transfer
+		/// fee, any royalty and the coin's minimum balance, so no residual has to be
+		/// worked out off-chain.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin to transfer
+		/// * `dest` - The recipient account
+		/// * `keep_alive` - Whether to keep a non-zero balance when the coin has no minimum balance
+		#[pallet::call_index(89)]
+		#[pallet::weight(T::WeightInfo::transfer_all().saturating_add(Pallet::<T>::transfer_hook_weight()))]
+		pub fn transfer_all(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			dest: T::AccountId,
+			keep_alive: bool,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let amount = Self::transferable_amount(coin_id, &from, &dest, keep_alive);
+			Self::do_transfer(coin_id, &from, &dest, amount)
+		}
+
+		/// Mint coins to any account without a mint permission
+		///
+		/// Only callable by `ForceOrigin`, e.g. to restore balances after an exploit or
+		/// a migration. `MaxSupply` and mint lock-ups apply, but frozen accounts may be
+		/// minted to. `ForcedMint` follows the `Minted` event.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin to mint
+		/// * `to` - The account to receive the minted coins
+		/// * `amount` - The amount to mint
+		#[pallet::call_index(90)]
+		#[pallet::weight(T::WeightInfo::force_mint())]
+		pub fn force_mint(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			to: T::AccountId,
+			amount: u128,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			Self::mint_inner(coin_id, &to, amount)?;
+			Self::deposit_event(Event::ForcedMint { coin_id, to, amount });
+
+			Ok(())
+		}
+
+		/// Burn coins from any account without a burn permission
+		///
+		/// Only callable by `ForceOrigin`, e.g. to remove exploited funds. Locked and
+		/// held coins can't be burned, but frozen accounts can be burned from.
+		/// `ForcedBurn` follows the `Burned` event.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin to burn
+		/// * `from` - The account to burn from
+		/// * `amount` - The amount to burn
+		#[pallet::call_index(91)]
+		#[pallet::weight(T::WeightInfo::force_burn())]
+		pub fn force_burn(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			from: T::AccountId,
+			amount: u128,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			Self::burn_inner(coin_id, &from, amount)?;
+			Self::deposit_event(Event::ForcedBurn { coin_id, from, amount });
+
+			Ok(())
+		}
+
+		/// Extend the holder snapshot of a coin by up to `segments` segments
+		///
+		/// A snapshot walks the coin's balances once, recording for every
+		/// `HolderCheckpointInterval` holders where they start and the balance held
+		/// before them, so `sample_holders` can draw balance-weighted holders reading
+		/// only one segment. The walk resumes where the previous call stopped; once it
+		/// reaches the end the snapshot replaces the coin's current one. Callable by
+		/// any signed account.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `segments` - Maximum segments to add, capped at `MaxHolderCheckpoints`
+		///
+		/// A snapshot has no segment limit, so any coin can be covered in enough calls.
+		#[pallet::call_index(92)]
+		#[pallet::weight(T::WeightInfo::checkpoint_holders(
+			(*segments).min(T::MaxHolderCheckpoints::get()).saturating_mul(T::HolderCheckpointInterval::get())
+		))]
+		pub fn checkpoint_holders(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			segments: u32,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			ensure!(CoinMetadata::<T>::contains_key(coin_id), Error::<T>::CoinNotFound);
+
+			// A new snapshot is built in the slot the current one doesn't use
+			let mut snapshot = PendingHolderSnapshots::<T>::take(coin_id).unwrap_or_else(|| HolderSnapshot {
+				slot: HolderSnapshots::<T>::get(coin_id).map_or(0, |current| current.slot ^ 1),
+				..Default::default()
+			});
+			let interval = T::HolderCheckpointInterval::get();
+			let mut iter = match snapshot.after.clone() {
+				Some(after) => Balances::<T>::iter_prefix_from(coin_id, after.into_inner()),
+				None => Balances::<T>::iter_prefix(coin_id),
+			};
+
+			let mut complete = false;
+			for _ in 0..segments.min(T::MaxHolderCheckpoints::get()) {
+				let (mut visited, mut weight) = (0u32, 0u128);
+				while visited < interval {
+					let Some((_, balance)) = iter.next() else { break };
+					visited += 1;
+					weight = weight.saturating_add(balance);
+				}
+				if visited > 0 {
+					let checkpoint = HolderCheckpoint { after: snapshot.after.take(), cumulative: snapshot.total };
+					HolderCheckpoints::<T>::insert(coin_id, (snapshot.slot, snapshot.segments), checkpoint);
+					snapshot.segments = snapshot.segments.saturating_add(1);
+					snapshot.total = snapshot.total.saturating_add(weight);
+					snapshot.after = Some(
+						SweepCursor::try_from(iter.last_raw_key().to_vec()).map_err(|_| Error::<T>::Overflow)?,
+					);
+				}
+				if visited < interval {
+					complete = true;
+					break;
+				}
+			}
+
+			Self::deposit_event(Event::HolderSnapshotUpdated {
+				coin_id,
+				checkpoints: snapshot.segments,
+				total: snapshot.total,
+				complete,
+			});
+			if complete {
+				snapshot.after = None;
+				HolderSnapshots::<T>::insert(coin_id, snapshot);
+			} else {
+				PendingHolderSnapshots::<T>::insert(coin_id, snapshot);
+			}
+
+			Ok(())
+		}
+
+		/// Update the symbol, name and decimals of a coin
+		///
+		/// A new symbol must not be used by another coin; the old one is released and
+		/// `get_coin_id_by_symbol` resolves the new one from then on. Callable by the
+		/// coin admin, the coin manager or `ForceOrigin`.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `symbol` - The new coin symbol
+		/// * `name` - The new coin name
+		/// * `decimals` - The new number of decimal places
+		#[pallet::call_index(93)]
+		#[pallet::weight(T::WeightInfo::set_metadata())]
+		pub fn set_metadata(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			symbol: Vec<u8>,
+			name: Vec<u8>,
+			decimals: u8,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let mut coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			Self::ensure_manager_or_force(origin, coin_id, &coin_info.admin)?;
+			Self::ensure_not_destroying(coin_info.status)?;
+
+			let bounded_symbol: BoundedVec<u8, T::MaxSymbolLength> =
+				symbol.clone().try_into().map_err(|_| Error::<T>::SymbolTooLong)?;
+			let bounded_name: BoundedVec<u8, T::MaxNameLength> =
+				name.clone().try_into().map_err(|_| Error::<T>::NameTooLong)?;
+
+			// Move the symbol mapping over if the symbol changes
+			if bounded_symbol != coin_info.symbol {
+				ensure!(
+					!SymbolToId::<T>::contains_key(&bounded_symbol),
+					Error::<T>::SymbolAlreadyExists
+				);
+				SymbolToId::<T>::remove(&coin_info.symbol);
+				SymbolToId::<T>::insert(&bounded_symbol, coin_id);
+			}
+
+			coin_info.symbol = bounded_symbol;
+			coin_info.name = bounded_name;
+			coin_info.decimals = decimals;
+			CoinMetadata::<T>::insert(&coin_id, coin_info);
+
+			Self::deposit_event(Event::MetadataSet { coin_id, symbol, name, decimals });
+
+			Ok(())
+		}
+
+		/// Set or remove the localized name and description of a coin for a locale
+		///
+		/// Lets multi-language wallets render user-facing names. Passing `None` for both
+		/// removes the locale. The signer pays `MetadataDepositPerByte` for every byte
+		/// of the coin's optional metadata, taking over any deposit held for it, which
+		/// is returned to its payer; `ForceOrigin` leaves the deposit as it is. Callable
+		/// by the coin admin, the coin manager or `ForceOrigin`.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `locale` - Locale code, e.g. "fr" or "pt-BR"
+		/// * `name` - Localized coin name
+		/// * `description` - Localized coin description
+		#[pallet::call_index(94)]
+		#[pallet::weight(T::WeightInfo::set_localization())]
+		pub fn set_localization(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			locale: Vec<u8>,
+			name: Option<Vec<u8>>,
+			description: Option<Vec<u8>>,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			let payer = Self::ensure_manager_or_force(origin, coin_id, &coin_info.admin)?;
+			Self::ensure_not_destroying(coin_info.status)?;
+
+			let bounded_locale = Locale::try_from(locale.clone()).map_err(|_| Error::<T>::InvalidLocale)?;
+			ensure!(!bounded_locale.is_empty(), Error::<T>::InvalidLocale);
+
+			let mut localizations = CoinLocalizations::<T>::get(coin_id);
+			if name.is_none() && description.is_none() {
+				localizations.remove(&bounded_locale);
+			} else {
+				let text: LocalizedText<BoundedVec<u8, T::MaxNameLength>, BoundedVec<u8, T::MaxDescriptionLength>> = LocalizedText {
+					name: name.clone().map(TryInto::try_into).transpose().map_err(|_| Error::<T>::NameTooLong)?,
+					description: description
+						.clone()
+						.map(TryInto::try_into)
+						.transpose()
+						.map_err(|_| Error::<T>::DescriptionTooLong)?,
+				};
+				localizations.try_insert(bounded_locale, text).map_err(|_| Error::<T>::TooManyLocales)?;
+			}
+			if localizations.is_empty() {
+				CoinLocalizations::<T>::remove(coin_id);
+			} else {
+				CoinLocalizations::<T>::insert(coin_id, localizations);
+			}
+			if let Some(payer) = payer {
+				Self::update_metadata_deposit(coin_id, &payer)?;
+			}
+
+			Self::deposit_event(Event::LocalizationSet { coin_id, locale, name, description });
+
+			Ok(())
+		}
+
+		/// Set or remove the extended metadata of a coin
+		///
+		/// Gives wallets and explorers a description, logo, homepage and a reference to
+		/// the coin on another chain, e.g. a CAIP-19 asset id. Passing `None` for every
+		/// field removes it. The deposit is charged per byte as for `set_localization`.
+		/// Callable by the coin admin, the coin manager or `ForceOrigin`.
+		///
+		/// # Arguments
+		/// * `coin_id` - The ID of the coin
+		/// * `description` - Coin description
+		/// * `logo_uri` - URI of the coin logo
+		/// * `homepage` - URL of the project homepage
+		/// * `external_reference` - Reference to the coin on another chain
+		#[pallet::call_index(95)]
+		#[pallet::weight(T::WeightInfo::set_extended_metadata())]
+		pub fn set_extended_metadata(
+			origin: OriginFor<T>,
+			coin_id: CoinId,
+			description: Option<Vec<u8>>,
+			logo_uri: Option<Vec<u8>>,
+			homepage: Option<Vec<u8>>,
+			external_reference: Option<Vec<u8>>,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			let payer = Self::ensure_manager_or_force(origin, coin_id, &coin_info.admin)?;
+			Self::ensure_not_destroying(coin_info.status)?;
+
+			let uri = |value: &Option<Vec<u8>>| -> Result<Option<BoundedVec<u8, T::MaxUriLength>>, Error<T>> {
+				value.clone().map(TryInto::try_into).transpose().map_err(|_| Error::<T>::UriTooLong)
+			};
+			let metadata = ExtendedCoinMetadata {
+				description: description
+					.clone()
+					.map(BoundedVec::<u8, T::MaxDescriptionLength>::try_from)
+					.transpose()
+					.map_err(|_| Error::<T>::DescriptionTooLong)?,
+				logo_uri: uri(&logo_uri)?,
+				homepage: uri(&homepage)?,
+				external_reference: uri(&external_reference)?,
+			};
+			if metadata == ExtendedCoinMetadata::default() {
+				ExtendedMetadata::<T>::remove(coin_id);
+			} else {
+				ExtendedMetadata::<T>::insert(coin_id, metadata);
+			}
+			if let Some(payer) = payer {
+				Self::update_metadata_deposit(coin_id, &payer)?;
+			}
+
+			Self::deposit_event(Event::ExtendedMetadataSet {
+				coin_id,
+				description,
+				logo_uri,
+				homepage,
+				external_reference,
+			});
+
+			Ok(())
+		}
+
+		/// Authorize or deauthorize `operator` to set the caller's preferred fee coin
+		///
+		/// Lets custodial wallets manage fee preferences of their accounts through
+		/// `set_preferred_fee_coin_for`.
+		///
+		/// # Arguments
+		/// * `operator` - The account to authorize or deauthorize
+		/// * `authorized` - Whether the operator may set the preferred fee coin
+		#[pallet::call_index(96)]
+		#[pallet::weight(T::WeightInfo::set_fee_operator())]
+		pub fn set_fee_operator(
+			origin: OriginFor<T>,
+			operator: T::AccountId,
+			authorized: bool,
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			if authorized {
+				FeeOperators::<T>::insert(&who, &operator, true);
+			} else {
+				FeeOperators::<T>::remove(&who, &operator);
+			}
+
+			Self::deposit_event(Event::FeeOperatorSet { account: who, operator, authorized });
+
+			Ok(())
+		}
+
+		/// Set the preferred fee coin of accounts that authorized the caller as their
+		/// fee operator
+		///
+		/// Fails without changing any account if one of them hasn't authorized the caller.
+		///
+		/// # Arguments
+		/// * `accounts` - Accounts to configure, at most `MaxFeePreferenceBatch`
+		/// * `coin_id` - The preferred fee coin, or `None` to pay fees in the native currency
+		#[pallet::call_index(97)]
+		#[pallet::weight(T::WeightInfo::set_preferred_fee_coin_for(accounts.len() as u32))]
+		pub fn set_preferred_fee_coin_for(
+			origin: OriginFor<T>,
+			accounts: BoundedVec<T::AccountId, T::MaxFeePreferenceBatch>,
+			coin_id: Option<CoinId>, // None to use Balances pallet
+			tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+		) -> DispatchResult {
+			let operator = ensure_signed(origin)?;
+
+			// If coin_id is provided, ensure it exists and can pay fees
+			Self::ensure_fee_coin(tx_fee_coin)?;
+
+			Self::ensure_fee_coin(coin_id)?;
+
+			for account in accounts {
+				ensure!(FeeOperators::<T>::get(&account, &operator), Error::<T>::NotFeeOperator);
+				if let Some(coin_id) = coin_id {
+					PreferredFeeCoin::<T>::insert(&account, coin_id);
+				} else {
+					PreferredFeeCoin::<T>::remove(&account);
+				}
+				Self::deposit_event(Event::PreferredFeeCoinSet { account, coin_id });
+			}
+
+			Ok(())
+		}
+	}
+}
+
+// Helper functions implementation
+impl<T: Config> Pallet<T> {
+	/// Get the balance of an account for a specific coin
+	pub fn balance_of(account: &T::AccountId, coin_id: CoinId) -> u128 {
+		Self::to_amount(coin_id, Self::spendable_shares(coin_id, account))
+	}
+
+	/// Balances of an account in several coins at once, in the order of `coin_ids`.
+	///
+	/// Unknown coins report a zero balance. Callers dispatching this on chain
+	/// charge `WeightInfo::balances_many`.
+	pub fn balances_many(account: &T::AccountId, coin_ids: &[CoinId]) -> Vec<u128> {
+		coin_ids.iter().map(|coin_id| Self::balance_of(account, *coin_id)).collect()
+	}
+
+	/// Get the total supply of a coin
+	pub fn total_supply_of(coin_id: CoinId) -> u128 {
+		Self::to_amount(coin_id, TotalSupply::<T>::get(coin_id))
+	}
+
+	/// Convert an amount of a coin into the units stored in `Balances` and `TotalSupply`.
+	///
+	/// For rebasing coins these are shares of the growing supply (rounded down);
+	/// for every other coin they are the amount itself.
+	pub fn to_shares(coin_id: CoinId, amount: u128) -> u128 {
+		match AccrualIndex::<T>::get(coin_id) {
+			Some(index) =>
+				multiply_by_rational_with_rounding(amount, FixedU128::DIV, index.into_inner(), Rounding::Down)
+					.unwrap_or(u128::MAX),
+			None => amount,
+		}
+	}
+
+	/// Convert stored units of a coin back into an amount, rounding down
+	pub fn to_amount(coin_id: CoinId, shares: u128) -> u128 {
+		match AccrualIndex::<T>::get(coin_id) {
+			Some(index) => index.saturating_mul_int(shares),
+			None => shares,
+		}
+	}
+
+	/// Get coin metadata
+	pub fn get_coin_metadata(
+		coin_id: CoinId,
+	) -> Option<CoinInfo<
+		BoundedVec<u8, T::MaxSymbolLength>, 
+		BoundedVec<u8, T::MaxNameLength>, 
+		T::AccountId,
+		<T::Currency as frame_support::traits::Currency<T::AccountId>>::Balance,
+        FeeConfig,
+	>> {
+		CoinMetadata::<T>::get(coin_id)
+	}
+
+	/// Get coin ID by symbol
+	pub fn get_coin_id_by_symbol(symbol: &[u8]) -> Option<CoinId> {
+		let bounded_symbol: BoundedVec<u8, T::MaxSymbolLength> = symbol.to_vec()
+			.try_into()
+			.ok()?;
+		SymbolToId::<T>::get(bounded_symbol)
+	}
+
+	/// IDs of all existing coins, in ascending order.
+	///
+	/// Coin IDs are allocated sequentially, so walking `0..NextCoinId` gives a
+	/// deterministic order, unlike iterating the hashed `CoinMetadata` map.
+	pub fn coin_ids() -> impl Iterator<Item = CoinId> {
+		(0..NextCoinId::<T>::get()).filter(|coin_id| CoinMetadata::<T>::contains_key(coin_id))
+	}
+
+	/// All existing coins with their metadata, in ascending ID order
+	pub fn coins_iter() -> impl Iterator<Item = (CoinId, CoinInfoOf<T>)> {
+		(0..NextCoinId::<T>::get())
+			.filter_map(|coin_id| CoinMetadata::<T>::get(coin_id).map(|info| (coin_id, info)))
+	}
+
+	/// Management summary of a coin for admin UIs, or `None` if it doesn't exist.
+	///
+	/// Account lists are sorted so the result doesn't depend on storage hashing.
+	pub fn coin_dashboard(coin_id: CoinId) -> Option<CoinDashboard<T::AccountId>> {
+		let coin_info = CoinMetadata::<T>::get(coin_id)?;
+		let granted = |permissions: Vec<(T::AccountId, bool)>| {
+			let mut accounts: Vec<_> =
+				permissions.into_iter().filter_map(|(who, granted)| granted.then_some(who)).collect();
+			accounts.sort();
+			accounts
+		};
+		let mut pallet_grants: Vec<_> = PalletGrants::<T>::iter_prefix(coin_id).collect();
+		pallet_grants.sort_by(|a, b| a.0.cmp(&b.0));
+		let mut bridge_mint_caps: Vec<_> = BridgeMintCaps::<T>::iter_prefix(coin_id).collect();
+		bridge_mint_caps.sort();
+		let depositor = CoinDepositors::<T>::get(coin_id).unwrap_or_else(|| coin_info.owner.clone());
+
+		Some(CoinDashboard {
+			owner: coin_info.owner,
+			issuer: coin_info.issuer,
+			admin: coin_info.admin,
+			freezer: coin_info.freezer,
+			manager: CoinManagers::<T>::get(coin_id),
+			depositor,
+			minters: granted(MintPermissions::<T>::iter_prefix(coin_id).collect()),
+			burners: granted(BurnPermissions::<T>::iter_prefix(coin_id).collect()),
+			fee_config: coin_info.fee_config,
+			total_supply: Self::total_supply_of(coin_id),
+			holders: HolderCount::<T>::get(coin_id),
+			status: coin_info.status,
+			coin_account: Self::coin_account(coin_id),
+			pallet_grants,
+			bridge_mint_caps,
+		})
+	}
+
+	/// Check if an account has minting permission for a coin
+	pub fn has_mint_permission(coin_id: CoinId, account: &T::AccountId) -> bool {
+		MintPermissions::<T>::get(coin_id, account)
+	}
+
+    /// Check if an account has burning permission for a coin
+    pub fn has_burn_permission(coin_id: CoinId, account: &T::AccountId) -> bool {
+        BurnPermissions::<T>::get(coin_id, account)
+    }
+
+	/// What `who` may do with a coin through its roles and grants; nothing if the coin doesn't exist
+	pub fn permissions_of(coin_id: CoinId, who: &T::AccountId) -> CoinPermissions {
+		let Some(coin_info) = CoinMetadata::<T>::get(coin_id) else { return CoinPermissions::default() };
+		CoinPermissions {
+			can_mint: *who == coin_info.issuer || MintPermissions::<T>::get(coin_id, who),
+			can_burn: *who == coin_info.admin || BurnPermissions::<T>::get(coin_id, who),
+			can_modify_metadata: Self::is_owner_or_manager(coin_id, &coin_info.admin, who),
+			can_transfer_ownership: *who == coin_info.owner,
+		}
+	}
+
+	/// Ensure an optional fee coin exists, is live and is eligible to pay fees
+	///
+	/// Eligible coins are opted in by their owner and admitted by `FeeCoinAdmissionOrigin`.
+	pub fn ensure_fee_coin(coin_id: Option<CoinId>) -> DispatchResult {
+		if let Some(coin_id) = coin_id {
+			let coin_info = CoinMetadata::<T>::get(&coin_id)
+				.ok_or(Error::<T>::CoinNotFound)?;
+			ensure!(coin_info.fee_config.can_pay_tx_fees, Error::<T>::CannotPayFees);
+			ensure!(AdmittedFeeCoins::<T>::contains_key(coin_id), Error::<T>::CannotPayFees);
+			Self::ensure_live(coin_info.status)?;
+		}
+		Ok(())
+	}
+
+	/// Store a new fee configuration for a coin and emit `FeeConfigUpdated`
+	pub(crate) fn apply_fee_config(coin_id: CoinId, mut coin_info: CoinInfoOf<T>, fee_config: FeeConfig) {
+		coin_info.fee_config = fee_config.clone();
+		CoinMetadata::<T>::insert(&coin_id, &coin_info);
+
+		Self::deposit_event(Event::FeeConfigUpdated {
+			coin_id,
+			transfer_fee: fee_config.transfer_fee,
+			minimum_balance: fee_config.minimum_balance,
+			can_pay_tx_fees: fee_config.can_pay_tx_fees,
+			call_scope: fee_config.call_scope,
+		});
+	}
+
+	/// Move `amount` of a coin from `from` to `to`, charging the coin's transfer fee.
+	///
+	/// This is the origin-less core of the `transfer` extrinsic, meant for sibling pallets
+	/// (PoR, DEX, vesting, ...) that move multicoin balances on behalf of their users. It
+	/// performs every balance, status and fee-config check but no authorization; the caller
+	/// is responsible for having the right to move `from`'s funds. Transfers into a
+	/// blocked sink, and from or to a frozen account, are refused.
+	pub fn do_transfer(
+		coin_id: CoinId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: u128,
+	) -> DispatchResult {
+		ensure!(!BlockedSinks::<T>::contains_key(coin_id, to), Error::<T>::RecipientBlocked);
+		Self::ensure_not_frozen(coin_id, from)?;
+		Self::ensure_not_frozen(coin_id, to)?;
+		Self::transfer_inner(coin_id, from, to, amount)
+	}
+
+	/// Check whether `do_transfer` would succeed, without changing any state.
+	///
+	/// Runs the transfer, including fee, minimum-balance, lock, status and
+	/// `TransferHook` checks, in a storage layer that is always rolled back, and
+	/// reports what it would have cost the sender.
+	pub fn can_transfer(
+		coin_id: CoinId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: u128,
+	) -> Result<FeeBreakdown, DispatchError> {
+		with_transaction(|| {
+			let transfer_fee = CoinMetadata::<T>::get(coin_id).map_or(0, |info| info.fee_config.transfer_fee);
+			let royalty = Self::royalty_due(coin_id, from, to, amount).map_or(0, |(_, due)| due);
+			let result = Self::do_transfer(coin_id, from, to, amount).map(|()| FeeBreakdown {
+				amount,
+				transfer_fee,
+				royalty,
+				total: amount.saturating_add(transfer_fee).saturating_add(royalty),
+			});
+			TransactionOutcome::Rollback(result)
+		})
+	}
+
+	/// `do_transfer` without the blocked sink and frozen account checks, used by `force_transfer`
+	pub(crate) fn transfer_inner(
+		coin_id: CoinId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: u128,
+	) -> DispatchResult {
+		ensure!(amount > 0, Error::<T>::ZeroAmount);
+		ensure!(from != to, Error::<T>::TransferToSelf);
+		let coin_info = CoinMetadata::<T>::get(&coin_id)
+			.ok_or(Error::<T>::CoinNotFound)?;
+		Self::ensure_live(coin_info.status)?;
+		ensure!(Self::transfer_allowed(coin_id, from, to), Error::<T>::TransfersPaused);
+
+		// Settle pending decay before reading balances
+		Self::apply_decay(coin_id, from);
+		Self::apply_decay(coin_id, to);
+
+		// Work in stored units, which differ from amounts for rebasing coins
+		let shares = Self::to_shares(coin_id, amount);
+		let transfer_fee = coin_info.fee_config.transfer_fee;
+		let fee_shares = Self::to_shares(coin_id, transfer_fee);
+
+		// The royalty is paid by the sender on top of the amount, like the fee
+		let royalty = Self::royalty_due(coin_id, from, to, amount);
+		let royalty_shares = royalty.as_ref().map_or(0, |(_, due)| Self::to_shares(coin_id, *due));
+
+		// Calculate total amount to deduct (amount + fee + royalty)
+		let total_deduct = shares.checked_add(fee_shares)
+			.and_then(|total| total.checked_add(royalty_shares))
+			.ok_or(Error::<T>::Overflow)?;
+
+		// Check sender's balance
+		let from_balance = Balances::<T>::get(&coin_id, from);
+		ensure!(from_balance >= total_deduct, Error::<T>::InsufficientBalance);
+
+		// Check minimum balance requirement for sender after transfer
+		let new_from_balance = from_balance.saturating_sub(total_deduct);
+		Self::ensure_unlocked(coin_id, from, new_from_balance)?;
+		ensure!(
+			Self::to_amount(coin_id, new_from_balance) >= coin_info.fee_config.minimum_balance,
+			Error::<T>::BelowMinimumBalance
+		);
+
+		// Update recipient's balance; emptying the sender frees a holder slot
+		let to_balance = Balances::<T>::get(&coin_id, to);
+		if to_balance == 0 && shares > 0 && new_from_balance > 0 {
+			Self::ensure_holder_slot(coin_id)?;
+		}
+		let new_to_balance = to_balance.checked_add(shares)
+			.ok_or(Error::<T>::Overflow)?;
+
+		T::TransferHook::on_transfer(coin_id, from, to, amount)?;
+
+		// Apply transfer and fee (burn the fee for simplicity)
+		Self::set_balance(coin_id, from, new_from_balance);
+		Self::set_balance(coin_id, to, new_to_balance);
+		if let Some((beneficiary, due)) = royalty.filter(|_| royalty_shares > 0) {
+			Self::apply_decay(coin_id, &beneficiary);
+			let beneficiary_balance = Balances::<T>::get(&coin_id, &beneficiary);
+			if beneficiary_balance == 0 {
+				Self::ensure_holder_slot(coin_id)?;
+			}
+			let new_beneficiary_balance = beneficiary_balance.checked_add(royalty_shares)
+				.ok_or(Error::<T>::Overflow)?;
+			Self::set_balance(coin_id, &beneficiary, new_beneficiary_balance);
+			Self::deposit_event(Event::RoyaltyPaid {
+				coin_id,
+				from: from.clone(),
+				beneficiary,
+				amount: due,
+			});
+		}
+		if transfer_fee > 0 {
+			let current_supply = TotalSupply::<T>::get(&coin_id);
+			let new_supply = current_supply.saturating_sub(fee_shares);
+			TotalSupply::<T>::insert(&coin_id, new_supply);
+			Self::note_fee_burned(coin_id, transfer_fee);
+			// Emit burn event for fee
+			Self::deposit_event(Event::Burned {
+				coin_id,
+				from: from.clone(),
+				amount: transfer_fee,
+			});
+		}
+
+		Self::note_velocity(coin_id, from, amount);
+
+		Self::deposit_event(Event::Transfer {
+			coin_id,
+			from: from.clone(),
+			to: to.clone(),
+			amount,
+		});
+
+		Ok(())
+	}
+
+	/// Send each amount of `recipients` from `from`, returning the total sent.
+	///
+	/// Every part is checked and charged like `do_transfer`, but the sender's balance
+	/// is read and written once for the whole batch. The sender is debited before any
+	/// recipient is credited, so emptying its balance frees a holder slot first.
+	pub fn do_transfer_batch(
+		coin_id: CoinId,
+		from: &T::AccountId,
+		recipients: &[(T::AccountId, u128)],
+	) -> Result<u128, DispatchError> {
+		let coin_info = CoinMetadata::<T>::get(&coin_id)
+			.ok_or(Error::<T>::CoinNotFound)?;
+		Self::ensure_live(coin_info.status)?;
+		Self::ensure_not_frozen(coin_id, from)?;
+		Self::apply_decay(coin_id, from);
+
+		let transfer_fee = coin_info.fee_config.transfer_fee;
+		let fee_shares = Self::to_shares(coin_id, transfer_fee);
+
+		// Price every part before touching any balance
+		let mut total = 0u128;
+		let mut total_deduct = 0u128;
+		let mut parts = Vec::with_capacity(recipients.len());
+		for (to, amount) in recipients {
+			ensure!(*amount > 0, Error::<T>::ZeroAmount);
+			ensure!(from != to, Error::<T>::TransferToSelf);
+			ensure!(!BlockedSinks::<T>::contains_key(coin_id, to), Error::<T>::RecipientBlocked);
+			Self::ensure_not_frozen(coin_id, to)?;
+			ensure!(Self::transfer_allowed(coin_id, from, to), Error::<T>::TransfersPaused);
+
+			let shares = Self::to_shares(coin_id, *amount);
+			let royalty = Self::royalty_due(coin_id, from, to, *amount)
+				.map(|(beneficiary, due)| (beneficiary, due, Self::to_shares(coin_id, due)))
+				.filter(|(_, _, royalty_shares)| *royalty_shares > 0);
+			let royalty_shares = royalty.as_ref().map_or(0, |(_, _, royalty_shares)| *royalty_shares);
+			total = total.checked_add(*amount).ok_or(Error::<T>::Overflow)?;
+			total_deduct = total_deduct.checked_add(shares)
+				.and_then(|total| total.checked_add(fee_shares))
+				.and_then(|total| total.checked_add(royalty_shares))
+				.ok_or(Error::<T>::Overflow)?;
+			parts.push((to, *amount, shares, royalty));
+		}
+
+		// The sender's balance is read and written once for the whole batch
+		let from_balance = Balances::<T>::get(&coin_id, from);
+		ensure!(from_balance >= total_deduct, Error::<T>::InsufficientBalance);
+		let new_from_balance = from_balance.saturating_sub(total_deduct);
+		Self::ensure_unlocked(coin_id, from, new_from_balance)?;
+		ensure!(
+			Self::to_amount(coin_id, new_from_balance) >= coin_info.fee_config.minimum_balance,
+			Error::<T>::BelowMinimumBalance
+		);
+		Self::set_balance(coin_id, from, new_from_balance);
+
+		for (to, amount, shares, royalty) in parts {
+			T::TransferHook::on_transfer(coin_id, from, to, amount)?;
+			Self::credit_shares(coin_id, to, shares)?;
+			if let Some((beneficiary, due, royalty_shares)) = royalty {
+				Self::credit_shares(coin_id, &beneficiary, royalty_shares)?;
+				Self::deposit_event(Event::RoyaltyPaid {
+					coin_id,
+					from: from.clone(),
+					beneficiary,
+					amount: due,
+				});
+			}
+			Self::deposit_event(Event::Transfer {
+				coin_id,
+				from: from.clone(),
+				to: to.clone(),
+				amount,
+			});
+		}
+
+		// Fees of all parts are burned together
+		if transfer_fee > 0 {
+			let parts = recipients.len() as u128;
+			let fees = transfer_fee.saturating_mul(parts);
+			TotalSupply::<T>::mutate(&coin_id, |supply| {
+				*supply = supply.saturating_sub(fee_shares.saturating_mul(parts))
+			});
+			Self::note_fee_burned(coin_id, fees);
+			Self::deposit_event(Event::Burned {
+				coin_id,
+				from: from.clone(),
+				amount: fees,
+			});
+		}
+
+		Self::note_velocity(coin_id, from, total);
+
+		Ok(total)
+	}
+
+	/// Add `shares` to the balance of `who`, taking a holder slot if it had none
+	fn credit_shares(coin_id: CoinId, who: &T::AccountId, shares: u128) -> DispatchResult {
+		Self::apply_decay(coin_id, who);
+		let balance = Balances::<T>::get(&coin_id, who);
+		if balance == 0 {
+			Self::ensure_holder_slot(coin_id)?;
+		}
+		let new_balance = balance.checked_add(shares)
+			.ok_or(Error::<T>::Overflow)?;
+		Self::set_balance(coin_id, who, new_balance);
+		Ok(())
+	}
+
+	/// Add a transfer of `amount` sent by `from` to the coin's velocity metrics
+	/// of the current era, starting a new era entry when needed
+	pub(crate) fn note_velocity(coin_id: CoinId, from: &T::AccountId, amount: u128) {
+		let now = frame_system::Pallet::<T>::block_number();
+		let era: u32 = (now / T::VelocityEraLength::get().max(One::one())).saturated_into();
+		let new_sender = VelocitySenders::<T>::get(coin_id, from) != Some(era);
+		if new_sender {
+			VelocitySenders::<T>::insert(coin_id, from, era);
+		}
+
+		CoinVelocity::<T>::mutate(coin_id, |eras| {
+			if eras.last().map_or(true, |last| last.era != era) {
+				if eras.is_full() {
+					eras.remove(0);
+				}
+				let _ = eras.try_push(VelocityStats { era, ..Default::default() });
+			}
+			if let Some(current) = eras.last_mut() {
+				current.volume = current.volume.saturating_add(amount);
+				current.senders = current.senders.saturating_add(new_sender as u32);
+			}
+		});
+	}
+
+	/// Whether transfers of a coin between `from` and `to` may go ahead: always
+	/// unless the coin's transfers are paused, and then only if either side is
+	/// on the coin's `PausedExcept` allowlist
+	pub fn transfer_allowed(coin_id: CoinId, from: &T::AccountId, to: &T::AccountId) -> bool {
+		PausedExcept::<T>::get(coin_id)
+			.map_or(true, |allowlist| allowlist.contains(from) || allowlist.contains(to))
+	}
+
+	/// Royalty owed to a coin's beneficiary on a transfer of `amount`, rounded down.
+	///
+	/// Returns `None` when the coin has no royalty, or when either side of the
+	/// transfer is the beneficiary or exempt.
+	pub fn royalty_due(
+		coin_id: CoinId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: u128,
+	) -> Option<(T::AccountId, u128)> {
+		let royalty = Royalties::<T>::get(coin_id)?;
+		let exempt = |who: &T::AccountId| {
+			*who == royalty.beneficiary || RoyaltyExempt::<T>::contains_key(coin_id, who)
+		};
+		if exempt(from) || exempt(to) {
+			return None;
+		}
+		let due = multiply_by_rational_with_rounding(amount, royalty.bps.into(), 10_000, Rounding::Down).unwrap_or(0);
+		(due > 0).then_some((royalty.beneficiary, due))
+	}
+
+	/// Largest amount `from` can send to `to` in a single transfer.
+	///
+	/// Leaves the coin's minimum balance, the transfer fee and any royalty on top of the
+	/// amount, and never touches locked or held units. With `keep_alive`, one unit is also
+	/// left behind when the coin has no minimum balance and nothing is locked, so the
+	/// sender's balance doesn't drop to zero.
+	pub fn transferable_amount(coin_id: CoinId, from: &T::AccountId, to: &T::AccountId, keep_alive: bool) -> u128 {
+		let Some(coin_info) = CoinMetadata::<T>::get(coin_id) else { return 0 };
+		let locked = Self::locked_shares(coin_id, from);
+		let unlocked = Self::spendable_shares(coin_id, from).saturating_sub(locked);
+		let floor = match coin_info.fee_config.minimum_balance {
+			0 if keep_alive && locked == 0 => 1,
+			minimum => minimum,
+		};
+		let available = Self::to_amount(coin_id, unlocked)
+			.saturating_sub(floor)
+			.saturating_sub(coin_info.fee_config.transfer_fee);
+
+		// The royalty comes on top of the amount, so find the largest amount with
+		// `amount + royalty(amount) <= available`; rounding the royalty down can
+		// leave room for one more unit
+		if Self::royalty_due(coin_id, from, to, available).is_none() {
+			return available;
+		}
+		let bps = Royalties::<T>::get(coin_id).map_or(0, |royalty| u128::from(royalty.bps));
+		let fits = |amount: u128| {
+			let royalty = Self::royalty_due(coin_id, from, to, amount).map_or(0, |(_, due)| due);
+			amount.saturating_add(royalty) <= available
+		};
+		let amount = multiply_by_rational_with_rounding(available, 10_000, 10_000 + bps, Rounding::Down).unwrap_or(0);
+		if fits(amount + 1) { amount + 1 } else { amount }
+	}
+
+	/// Weight charged for running `T::TransferHook` once, capped at `T::MaxTransferHookWeight`
+	pub fn transfer_hook_weight() -> Weight {
+		let hook = T::TransferHook::weight().min(T::MaxTransferHookWeight::get());
+		T::WeightInfo::transfer_hook().saturating_add(hook)
+	}
+
+	/// Mint `amount` of a coin to `to`, respecting `MaxSupply`.
+	///
+	/// Origin-less core of the `mint` extrinsic for sibling pallets. No mint permission is
+	/// checked; the caller decides who may trigger the mint.
+	pub fn do_mint(coin_id: CoinId, to: &T::AccountId, amount: u128) -> DispatchResult {
+		Self::ensure_not_frozen(coin_id, to)?;
+		Self::mint_inner(coin_id, to, amount)
+	}
+
+	/// `do_mint` without refusing frozen recipients, for `force_mint`
+	pub(crate) fn mint_inner(coin_id: CoinId, to: &T::AccountId, amount: u128) -> DispatchResult {
+		Self::mint_unlocked(coin_id, to, amount)?;
+
+		// Lock the new coins, extending any running lock-up to cover them too
+		if let Some(period) = MintLockPeriods::<T>::get(coin_id) {
+			let shares = Self::to_shares(coin_id, amount);
+			let locked = Self::mint_locked_shares(coin_id, to).saturating_add(shares);
+			let until = frame_system::Pallet::<T>::block_number().saturating_add(period);
+			MintLocks::<T>::insert(coin_id, to, MintLock { amount: locked, until });
+			Self::deposit_event(Event::MintLocked {
+				coin_id,
+				who: to.clone(),
+				amount: Self::to_amount(coin_id, locked),
+				until,
+			});
+		}
+
+		Ok(())
+	}
+
+	/// `mint_inner` without the coin's mint lock-up, for coins burnt again in the same call
+	pub(crate) fn mint_unlocked(coin_id: CoinId, to: &T::AccountId, amount: u128) -> DispatchResult {
+		ensure!(amount > 0, Error::<T>::ZeroAmount);
+		let coin_info = CoinMetadata::<T>::get(&coin_id)
+			.ok_or(Error::<T>::CoinNotFound)?;
+		Self::ensure_live(coin_info.status)?;
+		Self::apply_decay(coin_id, to);
+
+		// Check if minting would exceed max supply
+		let shares = Self::to_shares(coin_id, amount);
+		let current_supply = TotalSupply::<T>::get(&coin_id);
+		let new_supply = current_supply.checked_add(shares)
+			.ok_or(Error::<T>::Overflow)?;
+		ensure!(
+			Self::to_amount(coin_id, new_supply) <= T::MaxSupply::get(),
+			Error::<T>::ExceedsMaxSupply
+		);
+
+		// Update balance and total supply
+		let current_balance = Balances::<T>::get(&coin_id, to);
+		if current_balance == 0 && shares > 0 {
+			Self::ensure_holder_slot(coin_id)?;
+		}
+		let new_balance = current_balance.checked_add(shares)
+			.ok_or(Error::<T>::Overflow)?;
+
+		Self::set_balance(coin_id, to, new_balance);
+		TotalSupply::<T>::insert(&coin_id, new_supply);
+
+		Self::deposit_event(Event::Minted {
+			coin_id,
+			to: to.clone(),
+			amount,
+		});
+
+		Ok(())
+	}
+
+	/// Burn `amount` of a coin from `from`, reducing total supply.
+	///
+	/// Origin-less core of the `burn` extrinsic for sibling pallets. No burn permission is
+	/// checked; the caller is responsible for having the right to destroy `from`'s funds.
+	pub fn do_burn(coin_id: CoinId, from: &T::AccountId, amount: u128) -> DispatchResult {
+		Self::ensure_not_frozen(coin_id, from)?;
+		Self::burn_inner(coin_id, from, amount)
+	}
+
+	/// `do_burn` without refusing frozen accounts, for `force_burn`
+	pub(crate) fn burn_inner(coin_id: CoinId, from: &T::AccountId, amount: u128) -> DispatchResult {
+		ensure!(amount > 0, Error::<T>::ZeroAmount);
+		let coin_info = CoinMetadata::<T>::get(&coin_id)
+			.ok_or(Error::<T>::CoinNotFound)?;
+		Self::ensure_live(coin_info.status)?;
+		Self::apply_decay(coin_id, from);
+
+		// Check balance
+		let shares = Self::to_shares(coin_id, amount);
+		let current_balance = Balances::<T>::get(&coin_id, from);
+		ensure!(current_balance >= shares, Error::<T>::InsufficientBalance);
+
+		// Update balance and total supply
+		let new_balance = current_balance.saturating_sub(shares);
+		Self::ensure_unlocked(coin_id, from, new_balance)?;
+		let current_supply = TotalSupply::<T>::get(&coin_id);
+		let new_supply = current_supply.saturating_sub(shares);
+
+		Self::set_balance(coin_id, from, new_balance);
+		TotalSupply::<T>::insert(&coin_id, new_supply);
+
+		Self::deposit_event(Event::Burned {
+			coin_id,
+			from: from.clone(),
+			amount,
+		});
+
+		Ok(())
+	}
+
+	/// Stored balance of `who` with any pending decay deducted, without writing it
+	pub fn spendable_shares(coin_id: CoinId, who: &T::AccountId) -> u128 {
+		let balance = Balances::<T>::get(coin_id, who);
+		match (DecayRates::<T>::get(coin_id), LastTouched::<T>::get(coin_id, who)) {
+			(Some(decay), Some(last)) => {
+				let now = frame_system::Pallet::<T>::block_number();
+				Self::decay_since(&decay, balance, last, now).0
+			},
+			_ => balance,
+		}
+	}
+
+	/// Settle pending decay of `who`'s balance of a decaying coin, burning the
+	/// decayed part and restarting the account's clock. No-op for other coins.
+	pub(crate) fn apply_decay(coin_id: CoinId, who: &T::AccountId) {
+		let Some(decay) = DecayRates::<T>::get(coin_id) else { return };
+		let now = frame_system::Pallet::<T>::block_number();
+		let balance = Balances::<T>::get(coin_id, who);
+
+		let (decayed, touched) = match LastTouched::<T>::get(coin_id, who) {
+			Some(last) if balance > 0 => Self::decay_since(&decay, balance, last, now),
+			_ => (balance, now),
+		};
+		if decayed < balance {
+			let amount = balance - decayed;
+			Self::set_balance(coin_id, who, decayed);
+			TotalSupply::<T>::mutate(coin_id, |supply| *supply = supply.saturating_sub(amount));
+			Self::deposit_event(Event::Decayed { coin_id, who: who.clone(), amount });
+		}
+		LastTouched::<T>::insert(coin_id, who, touched);
+	}
+
+	/// Balance left after decaying `balance` from block `last` to `now`, and the
+	/// block the account's clock moves to (only whole periods are applied)
+	fn decay_since(
+		decay: &DecayConfigOf<T>,
+		balance: u128,
+		last: BlockNumberFor<T>,
+		now: BlockNumberFor<T>,
+	) -> (u128, BlockNumberFor<T>) {
+		if decay.period.is_zero() {
+			return (balance, now);
+		}
+		let periods = now.saturating_sub(last) / decay.period;
+		let touched = last.saturating_add(periods.saturating_mul(decay.period));
+		let factor = decay.rate.left_from_one().saturating_pow(periods.saturated_into::<u32>() as usize);
+		(factor.mul_floor(balance), touched)
+	}
+
+	/// Take `shares` stored units out of `who`'s balance without changing the supply,
+	/// e.g. to hold them in a pallet-managed ledger such as the staking pool
+	pub(crate) fn debit(coin_id: CoinId, who: &T::AccountId, shares: u128) -> DispatchResult {
+		Self::apply_decay(coin_id, who);
+		let balance = Balances::<T>::get(coin_id, who);
+		ensure!(balance >= shares, Error::<T>::InsufficientBalance);
+		Self::ensure_unlocked(coin_id, who, balance - shares)?;
+		Self::set_balance(coin_id, who, balance - shares);
+		Ok(())
+	}
+
+	/// Account holding native currency paid into launch sales
+	pub fn account_id() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	/// Account holding coin transaction fees between withdrawal and settlement
+	pub fn fee_escrow_account() -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating(b"fee-escrow")
+	}
+
+	/// Keyless owner of renounced coins, see `renounce_ownership`
+	pub fn no_owner_account() -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating(b"no-owner")
+	}
+
+	/// Stable per-coin pot account, see `coin_sub_account`
+	pub fn coin_account(coin_id: CoinId) -> T::AccountId {
+		coin_sub_account(T::PalletId::get(), coin_id)
+	}
+
+	/// Take `cost` of `payment` from `who` into the pallet's custody.
+	///
+	/// Returns the units now held: native units, or stored units of a payment coin.
+	pub(crate) fn collect_payment(
+		payment: PaymentAsset,
+		who: &T::AccountId,
+		cost: u128,
+	) -> Result<u128, DispatchError> {
+		match payment {
+			PaymentAsset::Native => {
+				let value: DepositBalanceOf<T> = cost.try_into().map_err(|_| Error::<T>::Overflow)?;
+				T::Currency::transfer(who, &Self::account_id(), value, ExistenceRequirement::KeepAlive)?;
+				Ok(cost)
+			},
+			PaymentAsset::Coin(coin_id) => {
+				let coin_info = CoinMetadata::<T>::get(&coin_id)
+					.ok_or(Error::<T>::CoinNotFound)?;
+				Self::ensure_live(coin_info.status)?;
+				let shares = Self::to_shares(coin_id, cost);
+				Self::debit(coin_id, who, shares)?;
+				Ok(shares)
+			},
+		}
+	}
+
+	/// Pay a sale's collected proceeds to its owner, returning the amount paid
+	pub(crate) fn pay_out_proceeds(sale: &mut SaleOf<T>) -> Result<u128, DispatchError> {
+		let proceeds = core::mem::take(&mut sale.proceeds);
+		if proceeds == 0 {
+			return Ok(0);
+		}
+		Self::release_payment(sale.payment, &sale.owner, proceeds)?;
+		Ok(Self::payment_amount(sale.payment, proceeds))
+	}
+
+	/// Count an auction, sale or ticket of `coin_id` paid in `payment` against both coins
+	pub(crate) fn add_references(coin_id: CoinId, payment: PaymentAsset) {
+		CoinReferences::<T>::mutate(coin_id, |count| count.saturating_inc());
+		if let PaymentAsset::Coin(payment_coin) = payment {
+			CoinReferences::<T>::mutate(payment_coin, |count| count.saturating_inc());
+		}
+	}
+
+	/// Release the references `add_references` counted
+	pub(crate) fn remove_references(coin_id: CoinId, payment: PaymentAsset) {
+		let release = |count: &mut Option<u32>| *count = count.map(|c| c.saturating_sub(1)).filter(|c| *c > 0);
+		CoinReferences::<T>::mutate_exists(coin_id, release);
+		if let PaymentAsset::Coin(payment_coin) = payment {
+			CoinReferences::<T>::mutate_exists(payment_coin, release);
+		}
+	}
+
+	/// Pay `units` of `payment` held in the pallet's custody to `to`
+	pub(crate) fn release_payment(payment: PaymentAsset, to: &T::AccountId, units: u128) -> DispatchResult {
+		match payment {
+			PaymentAsset::Native => {
+				let value: DepositBalanceOf<T> = units.try_into().map_err(|_| Error::<T>::Overflow)?;
+				T::Currency::transfer(&Self::account_id(), to, value, ExistenceRequirement::AllowDeath)
+			},
+			PaymentAsset::Coin(coin_id) => {
+				Self::ensure_coin_live(coin_id)?;
+				Self::credit(coin_id, to, units)
+			},
+		}
+	}
+
+	/// Custody units (native units or stored units of a coin) of an `amount` of `payment`
+	pub(crate) fn payment_units(payment: PaymentAsset, amount: u128) -> u128 {
+		match payment {
+			PaymentAsset::Native => amount,
+			PaymentAsset::Coin(coin_id) => Self::to_shares(coin_id, amount),
+		}
+	}
+
+	/// Amount of `payment` held as `units` in custody
+	pub(crate) fn payment_amount(payment: PaymentAsset, units: u128) -> u128 {
+		match payment {
+			PaymentAsset::Native => units,
+			PaymentAsset::Coin(coin_id) => Self::to_amount(coin_id, units),
+		}
+	}
+
+	/// Ensure leaving `who` with `remaining` stored units keeps its vote lock covered
+	pub fn ensure_unlocked(coin_id: CoinId, who: &T::AccountId, remaining: u128) -> DispatchResult {
+		ensure!(remaining >= Self::locked_shares(coin_id, who), Error::<T>::BalanceLocked);
+		Ok(())
+	}
+
+	/// Stored units of `who`'s balance locked for voting or by a mint lock-up,
+	/// plus its labeled sub-balances and holds.
+	///
+	/// The vote and mint locks overlap, so the larger of the two applies.
+	pub fn locked_shares(coin_id: CoinId, who: &T::AccountId) -> u128 {
+		let vote_locked = VoteLocks::<T>::get(coin_id, who).map_or(0, |lock| lock.amount);
+		vote_locked
+			.max(Self::mint_locked_shares(coin_id, who))
+			.saturating_add(Self::labeled_shares(coin_id, who))
+			.saturating_add(Self::held_shares(coin_id, who))
+	}
+
+	/// Stored units of `who`'s balance on hold for any reason
+	pub fn held_shares(coin_id: CoinId, who: &T::AccountId) -> u128 {
+		Holds::<T>::get(coin_id, who).iter().fold(0u128, |total, (_, held)| total.saturating_add(*held))
+	}
+
+	/// Amount of a coin `who` has on hold for `reason`
+	pub fn balance_on_hold(reason: &T::RuntimeHoldReason, coin_id: CoinId, who: &T::AccountId) -> u128 {
+		Holds::<T>::get(coin_id, who)
+			.iter()
+			.find(|(r, _)| r == reason)
+			.map_or(0, |(_, held)| Self::to_amount(coin_id, *held))
+	}
+
+	/// Put `amount` of `who`'s spendable balance of `coin_id` on hold for `reason`.
+	///
+	/// Held coins stay in `who`'s balance but can't be spent until released.
+	pub fn hold(reason: &T::RuntimeHoldReason, coin_id: CoinId, who: &T::AccountId, amount: u128) -> DispatchResult {
+		ensure!(amount > 0, Error::<T>::ZeroAmount);
+		Self::apply_decay(coin_id, who);
+		let shares = Self::to_shares(coin_id, amount);
+		let spendable = Balances::<T>::get(coin_id, who).saturating_sub(Self::locked_shares(coin_id, who));
+		ensure!(spendable >= shares, Error::<T>::InsufficientBalance);
+
+		Holds::<T>::try_mutate(coin_id, who, |holds| -> DispatchResult {
+			match holds.iter_mut().find(|(r, _)| r == reason) {
+				Some((_, held)) => *held = held.saturating_add(shares),
+				None => holds.try_push((*reason, shares)).map_err(|_| Error::<T>::TooManyHolds)?,
+			}
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::Held { coin_id, who: who.clone(), reason: *reason, amount });
+		Ok(())
+	}
+
+	/// Release up to `amount` of what `who` has on hold for `reason`, returning the
+	/// amount released
+	pub fn release(reason: &T::RuntimeHoldReason, coin_id: CoinId, who: &T::AccountId, amount: u128) -> u128 {
+		let shares = Self::to_shares(coin_id, amount);
+		let mut holds = Holds::<T>::get(coin_id, who);
+		let Some(index) = holds.iter().position(|(r, _)| r == reason) else {
+			return 0;
+		};
+		let released = shares.min(holds[index].1);
+		holds[index].1 -= released;
+		if holds[index].1 == 0 {
+			holds.remove(index);
+		}
+		Holds::<T>::set(coin_id, who, holds);
+
+		let amount = Self::to_amount(coin_id, released);
+		if amount > 0 {
+			Self::deposit_event(Event::Released { coin_id, who: who.clone(), reason: *reason, amount });
+		}
+		amount
+	}
+
+	/// Pay `amount` that `source` has on hold for `reason` to `dest`, e.g. to settle
+	/// an escrow. No transfer fee is charged.
+	pub fn transfer_on_hold(
+		reason: &T::RuntimeHoldReason,
+		coin_id: CoinId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: u128,
+	) -> DispatchResult {
+		ensure!(Self::balance_on_hold(reason, coin_id, source) >= amount, Error::<T>::InsufficientBalance);
+		Self::ensure_coin_live(coin_id)?;
+		Self::release(reason, coin_id, source, amount);
+		let shares = Self::to_shares(coin_id, amount);
+		Self::debit(coin_id, source, shares)?;
+		Self::credit(coin_id, dest, shares)?;
+
+		Self::deposit_event(Event::Transfer { coin_id, from: source.clone(), to: dest.clone(), amount });
+		Ok(())
+	}
+
+	/// Stored units of `who`'s balance held in labeled sub-balances
+	pub fn labeled_shares(coin_id: CoinId, who: &T::AccountId) -> u128 {
+		SubBalances::<T>::get(coin_id, who).iter().fold(0u128, |total, (_, held)| total.saturating_add(*held))
+	}
+
+	/// Amount of a coin `who` holds under `label`
+	pub fn sub_balance_of(coin_id: CoinId, who: &T::AccountId, label: &[u8]) -> u128 {
+		SubBalances::<T>::get(coin_id, who)
+			.iter()
+			.find(|(l, _)| l.as_slice() == label)
+			.map_or(0, |(_, held)| Self::to_amount(coin_id, *held))
+	}
+
+	/// Stored units of `who`'s balance minted less than the coin's lock-up period ago
+	pub fn mint_locked_shares(coin_id: CoinId, who: &T::AccountId) -> u128 {
+		let now = frame_system::Pallet::<T>::block_number();
+		MintLocks::<T>::get(coin_id, who).filter(|lock| now < lock.until).map_or(0, |lock| lock.amount)
+	}
+
+	/// Voting power of `who` in a coin: its vote-locked amount times the lock's
+	/// conviction multiplier. Governance pallets can use this to weigh votes.
+	pub fn voting_power(coin_id: CoinId, who: &T::AccountId) -> u128 {
+		VoteLocks::<T>::get(coin_id, who)
+			.map_or(0, |lock| lock.conviction.votes(Self::to_amount(coin_id, lock.amount)))
+	}
+
+	/// Return `shares` stored units to `who`'s balance without changing the supply
+	pub(crate) fn credit(coin_id: CoinId, who: &T::AccountId, shares: u128) -> DispatchResult {
+		Self::apply_decay(coin_id, who);
+		let current = Balances::<T>::get(coin_id, who);
+		if current == 0 && shares > 0 {
+			Self::ensure_holder_slot(coin_id)?;
+		}
+		let balance = current
+			.checked_add(shares)
+			.ok_or(Error::<T>::Overflow)?;
+		Self::set_balance(coin_id, who, balance);
+		Ok(())
+	}
+
+	/// Ensure one more account may start holding the coin
+	pub(crate) fn ensure_holder_slot(coin_id: CoinId) -> DispatchResult {
+		if let Some(max) = MaxHolders::<T>::get(coin_id) {
+			ensure!(HolderCount::<T>::get(coin_id) < max, Error::<T>::TooManyHolders);
+		}
+		Ok(())
+	}
+
+	/// Accrue rewards of a staking pool up to the current block
+	pub(crate) fn accrue_rewards(pool: &mut RewardPoolOf<T>) {
+		let now = frame_system::Pallet::<T>::block_number();
+		if now <= pool.last_update {
+			return;
+		}
+		if pool.total_staked > 0 {
+			let blocks: u128 = now.saturating_sub(pool.last_update).saturated_into();
+			let reward = blocks.saturating_mul(pool.reward_per_block).min(pool.remaining_rewards);
+			pool.reward_per_share = pool.reward_per_share
+				.saturating_add(FixedU128::saturating_from_rational(reward, pool.total_staked));
+			pool.remaining_rewards = pool.remaining_rewards.saturating_sub(reward);
+		}
+		pool.last_update = now;
+	}
+
+	/// Settle `who`'s rewards in a coin's staking pool, then let `f` change the
+	/// stake and pool. Empty stakes are removed.
+	pub(crate) fn update_stake(
+		coin_id: CoinId,
+		who: &T::AccountId,
+		f: impl FnOnce(&mut RewardPoolOf<T>, &mut StakeInfo) -> DispatchResult,
+	) -> DispatchResult {
+		let mut pool = RewardPools::<T>::get(coin_id);
+		let mut stake = Stakes::<T>::get(coin_id, who);
+		Self::accrue_rewards(&mut pool);
+
+		let earned = pool.reward_per_share.saturating_mul_int(stake.amount);
+		stake.unclaimed = stake.unclaimed.saturating_add(earned.saturating_sub(stake.reward_debt));
+		f(&mut pool, &mut stake)?;
+		stake.reward_debt = pool.reward_per_share.saturating_mul_int(stake.amount);
+
+		RewardPools::<T>::insert(coin_id, pool);
+		if stake.amount == 0 && stake.unclaimed == 0 {
+			Stakes::<T>::remove(coin_id, who);
+		} else {
+			Stakes::<T>::insert(coin_id, who, stake);
+		}
+		Ok(())
+	}
+
+	/// Write an account balance, removing the entry once it reaches zero
+	pub(crate) fn set_balance(coin_id: CoinId, who: &T::AccountId, amount: u128) {
+		let tracks_activity = DormancyConfigs::<T>::contains_key(coin_id);
+		match (Balances::<T>::get(coin_id, who) > 0, amount > 0) {
+			(false, true) => HolderCount::<T>::mutate(coin_id, |count| *count = count.saturating_add(1)),
+			(true, false) => HolderCount::<T>::mutate(coin_id, |count| *count = count.saturating_sub(1)),
+			_ => {},
+		}
+		if amount == 0 {
+			Balances::<T>::remove(coin_id, who);
+			LastTouched::<T>::remove(coin_id, who);
+			if tracks_activity {
+				LastActivity::<T>::remove(coin_id, who);
+			}
+		} else {
+			Balances::<T>::insert(coin_id, who, amount);
+			if tracks_activity {
+				LastActivity::<T>::insert(coin_id, who, frame_system::Pallet::<T>::block_number());
+			}
+		}
+	}
+
+	/// Remove zero balance entries left by older runtimes, resuming where the
+	/// previous block stopped and visiting as many entries as `limit` allows
+	pub(crate) fn sweep_zero_balances(limit: Weight) -> Weight {
+		let base = T::DbWeight::get().reads_writes(1, 1);
+		let per_entry = T::WeightInfo::sweep_zero_balance();
+		if !limit.all_gte(base.saturating_add(per_entry)) {
+			return Weight::zero();
+		}
+
+		let mut iter = match ZeroBalanceSweep::<T>::get() {
+			SweepState::Done => return T::DbWeight::get().reads(1),
+			SweepState::NotStarted => Balances::<T>::iter(),
+			SweepState::InProgress(cursor) => Balances::<T>::iter_from(cursor.into_inner()),
+		};
+
+		let mut used = base;
+		let state = loop {
+			if !limit.all_gte(used.saturating_add(per_entry)) {
+				// Out of weight; an oversized key can't be stored, so start over next time
+				break SweepCursor::try_from(iter.last_raw_key().to_vec())
+					.map_or(SweepState::NotStarted, SweepState::InProgress);
+			}
+			match iter.next() {
+				Some((coin_id, who, balance)) => {
+					used = used.saturating_add(per_entry);
+					if balance == 0 {
+						Balances::<T>::remove(coin_id, &who);
+					}
+				},
+				None => break SweepState::Done,
+			}
+		};
+		ZeroBalanceSweep::<T>::put(state);
+
+		used
+	}
+
+	/// Remove per-account entries left behind by emptied accounts, visiting at
+	/// most `MaxCleanupScan` entries and as many as `limit` allows.
+	///
+	/// Makes one pass through the maps of `DustMap`, resuming where the previous
+	/// block stopped, and stays idle once the pass is done. A `CleanupReport`
+	/// event is emitted for blocks that removed entries or finished the pass.
+	pub(crate) fn cleanup_dust(limit: Weight) -> Weight {
+		let base = T::DbWeight::get().reads_writes(1, 1);
+		let per_entry = T::WeightInfo::cleanup_dust_entry();
+		let budget = limit
+			.saturating_sub(base)
+			.checked_div_per_component(&per_entry)
+			.unwrap_or(0)
+			.min(T::MaxCleanupScan::get().into()) as u32;
+		if budget == 0 {
+			return Weight::zero();
+		}
+
+		let mut cursor = match DustCleanup::<T>::get() {
+			DustCleanupState::Done => return T::DbWeight::get().reads(1),
+			DustCleanupState::NotStarted => DustCursor::default(),
+			DustCleanupState::InProgress(cursor) => cursor,
+		};
+		let (mut scanned, mut removed) = (0u32, 0u32);
+		let state = loop {
+			let left = budget - scanned;
+			let after = cursor.after.take().map(|key| key.into_inner());
+			let (visited, cleaned, stopped_at) = match cursor.map {
+				DustMap::LastTouched => Self::scan_dust(
+					after.map_or_else(LastTouched::<T>::iter, LastTouched::<T>::iter_from),
+					left,
+					|coin_id, who, _| Balances::<T>::get(coin_id, who) == 0,
+					|coin_id, who| LastTouched::<T>::remove(coin_id, who),
+				),
+				DustMap::DormancyWarnings => Self::scan_dust(
+					after.map_or_else(DormancyWarnings::<T>::iter, DormancyWarnings::<T>::iter_from),
+					left,
+					|coin_id, who, _| Balances::<T>::get(coin_id, who) == 0,
+					|coin_id, who| DormancyWarnings::<T>::remove(coin_id, who),
+				),
+				DustMap::SubBalances => Self::scan_dust(
+					after.map_or_else(SubBalances::<T>::iter, SubBalances::<T>::iter_from),
+					left,
+					|_, _, labels| labels.is_empty(),
+					|coin_id, who| SubBalances::<T>::remove(coin_id, who),
+				),
+				DustMap::Holds => Self::scan_dust(
+					after.map_or_else(Holds::<T>::iter, Holds::<T>::iter_from),
+					left,
+					|_, _, holds| holds.is_empty(),
+					|coin_id, who| Holds::<T>::remove(coin_id, who),
+				),
+			};
+			scanned += visited;
+			removed += cleaned;
+			if let Some(key) = stopped_at {
+				cursor.after = Some(key);
+				break DustCleanupState::InProgress(cursor);
+			}
+			// Finished this map; the pass is done after the last one
+			let Some(next) = cursor.map.next() else { break DustCleanupState::Done };
+			cursor.map = next;
+			if scanned == budget {
+				break DustCleanupState::InProgress(cursor);
+			}
+		};
+		let finished = state == DustCleanupState::Done;
+		DustCleanup::<T>::put(state);
+
+		if removed > 0 || finished {
+			Self::deposit_event(Event::CleanupReport { scanned, removed });
+		}
+		base.saturating_add(per_entry.saturating_mul(scanned.into()))
+	}
+
+	/// Visit up to `budget` entries of `iter`, removing those that are dust.
+	///
+	/// Returns the entries visited and removed, and the raw key to resume after
+	/// when the budget ran out before the end of the map. An oversized key
+	/// can't be stored, so the map is then treated as finished.
+	fn scan_dust<V>(
+		mut iter: PrefixIterator<(CoinId, T::AccountId, V)>,
+		budget: u32,
+		is_dust: impl Fn(CoinId, &T::AccountId, &V) -> bool,
+		remove: impl Fn(CoinId, &T::AccountId),
+	) -> (u32, u32, Option<SweepCursor>) {
+		let (mut visited, mut removed) = (0u32, 0u32);
+		while visited < budget {
+			let Some((coin_id, who, value)) = iter.next() else {
+				return (visited, removed, None);
+			};
+			visited += 1;
+			if is_dust(coin_id, &who, &value) {
+				remove(coin_id, &who);
+				removed += 1;
+			}
+		}
+		(visited, removed, SweepCursor::try_from(iter.last_raw_key().to_vec()).ok())
+	}
+
+	/// Ensure `origin` is either `T::ForceOrigin` or the signed `owner`.
+	///
+	/// Returns the signer, or `None` when the call came from `T::ForceOrigin`.
+	pub(crate) fn ensure_owner_or_force(
+		origin: OriginFor<T>,
+		owner: &T::AccountId,
+	) -> Result<Option<T::AccountId>, DispatchError> {
+		match T::ForceOrigin::try_origin(origin) {
+			Ok(_) => Ok(None),
+			Err(origin) => {
+				let who = ensure_signed(origin)?;
+				ensure!(&who == owner, Error::<T>::NotAuthorized);
+				Ok(Some(who))
+			},
+		}
+	}
+
+	/// Ensure `origin` is `T::ForceOrigin`, the signed `owner` or the coin's manager.
+	///
+	/// Returns the signer, or `None` when the call came from `T::ForceOrigin`.
+	pub(crate) fn ensure_manager_or_force(
+		origin: OriginFor<T>,
+		coin_id: CoinId,
+		owner: &T::AccountId,
+	) -> Result<Option<T::AccountId>, DispatchError> {
+		match T::ForceOrigin::try_origin(origin) {
+			Ok(_) => Ok(None),
+			Err(origin) => {
+				let who = ensure_signed(origin)?;
+				ensure!(Self::is_owner_or_manager(coin_id, owner, &who), Error::<T>::NotAuthorized);
+				Ok(Some(who))
+			},
+		}
+	}
+
+	/// Whether `who` is the coin's `owner` or its manager
+	pub fn is_owner_or_manager(coin_id: CoinId, owner: &T::AccountId, who: &T::AccountId) -> bool {
+		who == owner || CoinManagers::<T>::get(coin_id).as_ref() == Some(who)
+	}
+
+	/// Ensure `who`'s balance of a coin isn't frozen
+	pub fn ensure_not_frozen(coin_id: CoinId, who: &T::AccountId) -> DispatchResult {
+		ensure!(!FrozenAccounts::<T>::contains_key(coin_id, who), Error::<T>::AccountFrozen);
+		Ok(())
+	}
+
+	/// Ensure a coin exists and its balances may move
+	pub fn ensure_coin_live(coin_id: CoinId) -> DispatchResult {
+		let coin_info = CoinMetadata::<T>::get(coin_id).ok_or(Error::<T>::CoinNotFound)?;
+		Self::ensure_live(coin_info.status)
+	}
+
+	/// Ensure balances of a coin with the given status may move
+	pub fn ensure_live(status: CoinStatus) -> DispatchResult {
+		match status {
+			CoinStatus::Live => Ok(()),
+			CoinStatus::Frozen => Err(Error::<T>::CoinFrozen.into()),
+			CoinStatus::Destroying => Err(Error::<T>::CoinDestroying.into()),
+		}
+	}
+
+	/// Ensure a coin with the given status can still be administered
+	pub fn ensure_not_destroying(status: CoinStatus) -> DispatchResult {
+		ensure!(!status.is_destroying(), Error::<T>::CoinDestroying);
+		Ok(())
+	}
+
+	/// Mint `amount` of `coin_id` to `to` on behalf of a bridge pallet account
+	///
+	/// Meant to be called by other pallets. The bridge needs a mint permission and
+	/// an owner-configured cap, which bounds its mints per `BridgeMintWindow`.
+	pub fn bridge_mint(bridge: &T::AccountId, coin_id: CoinId, to: &T::AccountId, amount: u128) -> DispatchResult {
+		ensure!(MintPermissions::<T>::get(&coin_id, bridge), Error::<T>::NoMintPermission);
+		let cap = BridgeMintCaps::<T>::get(coin_id, bridge).ok_or(Error::<T>::BridgeMintCapExceeded)?;
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let (window_start, minted) = match BridgeMinted::<T>::get(coin_id, bridge) {
+			Some((start, minted)) if now < start.saturating_add(T::BridgeMintWindow::get()) => (start, minted),
+			_ => (now, 0),
+		};
+		let minted = minted.checked_add(amount).ok_or(Error::<T>::Overflow)?;
+		ensure!(minted <= cap, Error::<T>::BridgeMintCapExceeded);
+
+		Self::do_mint(coin_id, to, amount)?;
+		BridgeMinted::<T>::insert(coin_id, bridge, (window_start, minted));
+
+		Self::deposit_event(Event::BridgeMinted { coin_id, bridge: bridge.clone(), to: to.clone(), amount });
+
+		Ok(())
+	}
+
+	/// Median of the fresh price feeds of `coin_id`, if at least `min_feeds` exist
+	pub fn median_price(coin_id: CoinId) -> Option<FixedU128> {
+		let config = OracleConfigs::<T>::get(coin_id)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		let mut prices: Vec<FixedU128> = PriceFeeders::<T>::get(coin_id)
+			.iter()
+			.filter_map(|feeder| PriceFeeds::<T>::get(coin_id, feeder))
+			.filter(|feed| now < feed.at.saturating_add(config.validity))
+			.map(|feed| feed.price)
+			.collect();
+		if prices.is_empty() || (prices.len() as u32) < config.min_feeds {
+			return None;
+		}
+
+		prices.sort();
+		let mid = prices.len() / 2;
+		if prices.len() % 2 == 0 {
+			let (low, high) = (prices[mid - 1].into_inner(), prices[mid].into_inner());
+			Some(FixedU128::from_inner(low / 2 + high / 2 + (low % 2 + high % 2) / 2))
+		} else {
+			Some(prices[mid])
+		}
+	}
+
+	/// Whether `price` is further than `max_deviation` of `median` away from it
+	pub fn price_deviates(median: FixedU128, price: FixedU128, max_deviation: Perbill) -> bool {
+		let (median, price) = (median.into_inner(), price.into_inner());
+		median.abs_diff(price) > max_deviation.mul_floor(median)
+	}
+
+	/// Amount of `coin_id` worth a native `amount` at the median price, rounded up.
+	///
+	/// Coins without a published price convert one to one.
+	pub fn native_to_coin(coin_id: CoinId, amount: u128) -> u128 {
+		match Self::median_price(coin_id) {
+			Some(price) => multiply_by_rational_with_rounding(amount, FixedU128::DIV, price.into_inner(), Rounding::Up)
+				.unwrap_or(u128::MAX),
+			None => amount,
+		}
+	}
+
+	/// Amount of `coin_id` charged for a native `fee`: its oracle value scaled by
+	/// the coin's fee multiplier
+	pub fn coin_fee(coin_id: CoinId, fee: u128) -> u128 {
+		let amount = Self::native_to_coin(coin_id, fee);
+		FeeMultipliers::<T>::get(coin_id).map_or(amount, |multiplier| multiplier.saturating_mul_int(amount))
+	}
+
+	/// Record a transaction fee of native value `fee`, paid in `coin_id` or natively
+	pub fn note_fee_paid(coin_id: Option<CoinId>, fee: u128) {
+		TotalFeeUsage::<T>::mutate(|total| *total = total.saturating_add(fee));
+		if let Some(coin_id) = coin_id {
+			FeeUsage::<T>::mutate(coin_id, |usage| *usage = usage.saturating_add(fee));
+		}
+	}
+
+	/// Move fee multipliers by `FeeMultiplierAdjustment` based on last block's fees.
+	///
+	/// Multipliers of coins that paid more than `FeeShareTarget` of all fees rise;
+	/// all others fall back towards one.
+	pub(crate) fn adjust_fee_multipliers() -> Weight {
+		let total = TotalFeeUsage::<T>::take();
+		let usage: sp_std::collections::btree_map::BTreeMap<CoinId, u128> = FeeUsage::<T>::drain().collect();
+		let mut coins: sp_std::collections::btree_set::BTreeSet<CoinId> = FeeMultipliers::<T>::iter_keys().collect();
+		coins.extend(usage.keys());
+
+		let target = T::FeeShareTarget::get().mul_floor(total);
+		let step = FixedU128::from(T::FeeMultiplierAdjustment::get());
+		for coin_id in coins.iter() {
+			let multiplier = FeeMultipliers::<T>::get(coin_id).unwrap_or_else(FixedU128::one);
+			let used = usage.get(coin_id).copied().unwrap_or_default();
+			let next = if used > target {
+				multiplier.saturating_add(multiplier.saturating_mul(step))
+			} else {
+				multiplier.saturating_sub(multiplier.saturating_mul(step)).max(FixedU128::one())
+			};
+			if next == FixedU128::one() {
+				FeeMultipliers::<T>::remove(coin_id);
+			} else {
+				FeeMultipliers::<T>::insert(coin_id, next);
+			}
+		}
+
+		let touched = coins.len() as u64;
+		T::DbWeight::get().reads_writes(2 + usage.len() as u64 + touched, 2 + usage.len() as u64 + touched)
+	}
+
+	/// Native value of `amount` of the stablecoin `coin_id` at its oracle price
+	pub fn stable_value(coin_id: CoinId, amount: u128) -> Result<u128, DispatchError> {
+		let price = Self::median_price(coin_id).ok_or(Error::<T>::PriceUnavailable)?;
+		Ok(price.saturating_mul_int(amount))
+	}
+
+	/// Collateral value over debt value of a position, or `None` if it owes nothing
+	pub fn collateral_ratio(
+		coin_id: CoinId,
+		config: &StableConfig,
+		position: &CollateralPosition,
+	) -> Result<Option<FixedU128>, DispatchError> {
+		if position.debt == 0 {
+			return Ok(None);
+		}
+		let collateral = match config.collateral {
+			PaymentAsset::Native => position.collateral,
+			PaymentAsset::Coin(collateral_id) => {
+				let price = Self::median_price(collateral_id).ok_or(Error::<T>::PriceUnavailable)?;
+				price.saturating_mul_int(Self::to_amount(collateral_id, position.collateral))
+			},
+		};
+		let debt = Self::stable_value(coin_id, position.debt)?;
+		Ok(Some(FixedU128::checked_from_rational(collateral, debt).unwrap_or(FixedU128::from_inner(u128::MAX))))
+	}
+
+	/// Ensure a position is at or above the minimum collateral ratio
+	pub(crate) fn ensure_collateralized(
+		coin_id: CoinId,
+		config: &StableConfig,
+		position: &CollateralPosition,
+	) -> DispatchResult {
+		let ratio = Self::collateral_ratio(coin_id, config, position)?;
+		ensure!(ratio.map_or(true, |ratio| ratio >= config.min_ratio), Error::<T>::CollateralRatioTooLow);
+		Ok(())
+	}
+
+	/// Custody units of `collateral` worth a native `value` at its oracle price, rounded down
+	pub(crate) fn value_to_units(collateral: PaymentAsset, value: u128) -> Result<u128, DispatchError> {
+		match collateral {
+			PaymentAsset::Native => Ok(value),
+			PaymentAsset::Coin(coin_id) => {
+				let price = Self::median_price(coin_id).ok_or(Error::<T>::PriceUnavailable)?;
+				let amount = multiply_by_rational_with_rounding(value, FixedU128::DIV, price.into_inner(), Rounding::Down)
+					.unwrap_or(u128::MAX);
+				Ok(Self::to_shares(coin_id, amount))
+			},
+		}
+	}
+
+	/// Current discount of a liquidation auction: grows linearly from zero to the
+	/// auction's maximum discount over `LiquidationAuctionDuration`
+	pub fn auction_discount(auction: &LiquidationAuctionOf<T>) -> Perbill {
+		let now = frame_system::Pallet::<T>::block_number();
+		let elapsed: u128 = now.saturating_sub(auction.started_at).saturated_into();
+		let duration: u128 = T::LiquidationAuctionDuration::get().saturated_into();
+		auction.max_discount.saturating_mul(Perbill::from_rational(elapsed, duration))
+	}
+
+	/// Store a collateral position, removing it once it is empty
+	pub(crate) fn store_position(coin_id: CoinId, who: &T::AccountId, position: CollateralPosition) {
+		if position == CollateralPosition::default() {
+			CollateralPositions::<T>::remove(coin_id, who);
+		} else {
+			CollateralPositions::<T>::insert(coin_id, who, position);
+		}
+	}
+
+	/// Record that `amount` of a coin was burned as a transfer or transaction fee
+	pub(crate) fn note_fee_burned(coin_id: CoinId, amount: u128) {
+		if amount == 0 {
+			return;
+		}
+		FeesBurnedSinceCheckpoint::<T>::mutate(coin_id, |burned| *burned = burned.saturating_add(amount));
+		TotalFeesBurned::<T>::mutate(coin_id, |burned| *burned = burned.saturating_add(amount));
+		Self::deposit_event(Event::FeeBurned { coin_id, amount });
+	}
+
+	/// Whether `call` dispatches a wrapped call, here or in another pallet
+	pub fn is_wrapper_call(call: &T::RuntimeCall) -> bool {
+		if T::ExternalWrappers::contains(call) {
+			return true;
+		}
+		// RuntimeCall is opaque to the pallet, so decode our own calls from its
+		// encoding: [pallet_index, call_index, ...params]
+		let encoded = codec::Encode::encode(call);
+		if encoded.len() < 2 || encoded[0] as usize != <Pallet<T> as PalletInfoAccess>::index() {
+			return false;
+		}
+		matches!(
+			<Call<T> as codec::Decode>::decode(&mut &encoded[1..]),
+			Ok(Call::call_with_fee_coin { .. } | Call::flash_mint { .. })
+		)
+	}
+
+	/// Ensure a wrapper called with `origin` may dispatch `call`
+	///
+	/// Nested wrappers would let one extrinsic stack fee coins and flash mints, and
+	/// `call` is checked against the origin's filter before anything is minted for it.
+	pub(crate) fn ensure_wrappable(origin: &OriginFor<T>, call: &T::RuntimeCall) -> DispatchResult {
+		ensure!(!Self::is_wrapper_call(call), Error::<T>::NestedWrapperCall);
+		ensure!(origin.filter_call(call), frame_system::Error::<T>::CallFiltered);
+		Ok(())
+	}
+
+	/// Remove up to `budget` per-account entries of a coin, one map after another
+	///
+	/// Each removal takes one off `budget`. Returns whether no entries are left, so
+	/// a zero budget only checks.
+	pub(crate) fn remove_coin_accounts(coin_id: CoinId, budget: &mut u32) -> bool {
+		fn drain<K>(mut keys: impl Iterator<Item = K>, budget: &mut u32, remove: impl Fn(K)) -> bool {
+			while *budget > 0 {
+				let Some(key) = keys.next() else { return true };
+				remove(key);
+				*budget -= 1;
+			}
+			keys.next().is_none()
+		}
+
+		// Later maps are only visited once the earlier ones are empty
+		macro_rules! drain_maps {
+			($($map:ident),+ $(,)?) => {
+				true $(&& drain($map::<T>::iter_key_prefix(coin_id), budget, |key| $map::<T>::remove(coin_id, key)))+
+			};
+		}
+
+		drain_maps!(
+			Balances,
+			MintPermissions,
+			BurnPermissions,
+			LastTouched,
+			Stakes,
+			VoteLocks,
+			BlockedSinks,
+			LastActivity,
+			DormancyWarnings,
+			RoyaltyExempt,
+			MintLocks,
+			SubBalances,
+			LastDrip,
+			PalletGrants,
+			BridgeMintCaps,
+			BridgeMinted,
+			PriceFeeds,
+			CollateralPositions,
+			Holds,
+			VelocitySenders,
+			Approvals,
+			ApprovalNonces,
+			FrozenAccounts,
+			HolderCheckpoints,
+		)
+	}
+
+	/// Deduplicated, ascending accounts of a `create_coin` grant list, without the
+	/// creator who already holds the permission
+	fn initial_grants(
+		accounts: impl IntoIterator<Item = T::AccountId>,
+		creator: &T::AccountId,
+	) -> sp_std::collections::btree_set::BTreeSet<T::AccountId> {
+		accounts.into_iter().filter(|account| account != creator).collect()
+	}
+
+	/// Repatriate a coin's creation `deposit` from its depositor to `to`, keeping it reserved
+	fn move_deposit(coin_id: CoinId, deposit: DepositBalanceOf<T>, to: &T::AccountId) -> DispatchResult {
+		let Some(from) = CoinDepositors::<T>::get(coin_id) else { return Ok(()) };
+		if from == *to {
+			return Ok(());
+		}
+		T::Currency::repatriate_reserved(&from, to, deposit, BalanceStatus::Reserved)?;
+		CoinDepositors::<T>::insert(coin_id, to);
+		Self::deposit_event(Event::CoinDepositMoved { coin_id, from, to: to.clone(), amount: deposit });
+		Ok(())
+	}
+
+	/// Draw `count` holders of a coin, each with odds proportional to its balance.
+	///
+	/// Meant for lotteries and airdrops of other pallets. Draws are independent, so a
+	/// holder may come up more than once. Each draw picks a point of the coin's latest
+	/// complete holder snapshot, seeded by `T::Randomness` with `subject` and the draw
+	/// index, finds its segment by binary search and reads at most
+	/// `HolderCheckpointInterval` live balances of that segment; balances that changed since the snapshot shift the odds within their
+	/// segment, and a draw past a segment's live balances yields no holder. Nothing is
+	/// drawn for coins without a snapshot. See `sample_holders_weight` for its cost.
+	pub fn sample_holders(coin_id: CoinId, count: u32, subject: &[u8]) -> Vec<T::AccountId> {
+		use frame_support::traits::Randomness;
+		use sp_runtime::traits::TrailingZeroInput;
+
+		let Some(snapshot) = HolderSnapshots::<T>::get(coin_id) else { return Vec::new() };
+		if snapshot.total == 0 {
+			return Vec::new();
+		}
+		let interval = T::HolderCheckpointInterval::get() as usize;
+		(0..count)
+			.filter_map(|draw| {
+				let (seed, _) = T::Randomness::random(&codec::Encode::encode(&(subject, draw)));
+				let point = <u128 as codec::Decode>::decode(&mut TrailingZeroInput::new(seed.as_ref()))
+					.unwrap_or_default() % snapshot.total;
+				// Last segment starting at or before the point
+				let (mut low, mut high, mut checkpoint) = (0u32, snapshot.segments, None);
+				while low < high {
+					let middle = low + (high - low) / 2;
+					let candidate = HolderCheckpoints::<T>::get(coin_id, (snapshot.slot, middle))?;
+					if candidate.cumulative <= point {
+						checkpoint = Some(candidate);
+						low = middle + 1;
+					} else {
+						high = middle;
+					}
+				}
+				let checkpoint = checkpoint?;
+				let balances = match &checkpoint.after {
+					Some(after) => Balances::<T>::iter_prefix_from(coin_id, after.to_vec()),
+					None => Balances::<T>::iter_prefix(coin_id),
+				};
+				let mut cumulative = checkpoint.cumulative;
+				balances.take(interval).find_map(|(who, balance)| {
+					cumulative = cumulative.saturating_add(balance);
+					(cumulative > point).then_some(who)
+				})
+			})
+			.collect()
+	}
+
+	/// Upper bound of the weight of `sample_holders` drawing `count` holders
+	pub fn sample_holders_weight(count: u32) -> Weight {
+		// The binary search reads at most one checkpoint per bit of the segment index
+		let per_draw = u64::from(T::HolderCheckpointInterval::get()).saturating_add(u64::from(u32::BITS));
+		T::DbWeight::get().reads(per_draw.saturating_mul(count.into()).saturating_add(1))
+	}
+
+	/// Byte length of a coin's optional metadata, which `MetadataDepositPerByte` is charged for
+	pub fn metadata_bytes(coin_id: CoinId) -> u32 {
+		let localized = CoinLocalizations::<T>::get(coin_id)
+			.iter()
+			.map(|entry| codec::Encode::encoded_size(&entry))
+			.sum::<usize>();
+		let extended = ExtendedMetadata::<T>::get(coin_id).map_or(0, |metadata| codec::Encode::encoded_size(&metadata));
+		localized.saturating_add(extended).saturated_into()
+	}
+
+	/// Return the held metadata deposit of a coin and reserve the one its current
+	/// optional metadata needs from `payer`
+	pub(crate) fn update_metadata_deposit(coin_id: CoinId, payer: &T::AccountId) -> DispatchResult {
+		if let Some(old) = MetadataDeposits::<T>::take(coin_id) {
+			T::Currency::unreserve(&old.depositor, old.amount);
+		}
+		let amount = T::MetadataDepositPerByte::get().saturating_mul(Self::metadata_bytes(coin_id).into());
+		if !amount.is_zero() {
+			T::Currency::reserve(payer, amount).map_err(|_| Error::<T>::InsufficientBalance)?;
+			MetadataDeposits::<T>::insert(coin_id, MetadataDeposit { depositor: payer.clone(), amount });
+		}
+		Ok(())
+	}
+
+}
+
+impl<T: Config> PriceOracle for Pallet<T> {
+	fn price_of_symbol(symbol: &[u8]) -> Option<FixedU128> {
+		let symbol: BoundedVec<u8, T::MaxSymbolLength> = symbol.to_vec().try_into().ok()?;
+		Self::median_price(SymbolToId::<T>::get(symbol)?)
+	}
+
+	fn accepts_price(symbol: &[u8], price: FixedU128) -> bool {
+		let Ok(symbol) = BoundedVec::<u8, T::MaxSymbolLength>::try_from(symbol.to_vec()) else {
+			return true;
+		};
+		let Some(coin_id) = SymbolToId::<T>::get(symbol) else {
+			return true;
+		};
+		match (Self::median_price(coin_id), OracleConfigs::<T>::get(coin_id)) {
+			(Some(median), Some(config)) => !Self::price_deviates(median, price, config.max_deviation),
+			_ => true,
+		}
+	}
+}
+
+/// Coins are paused while frozen, being destroyed or with their transfers paused;
+/// the pallet has no global pause
+impl<T: Config> PausedQuery for Pallet<T> {
+	fn is_globally_paused() -> bool {
+		false
+	}
+
+	fn is_coin_paused(symbol: &[u8]) -> bool {
+		let Ok(symbol) = BoundedVec::<u8, T::MaxSymbolLength>::try_from(symbol.to_vec()) else {
+			return false;
+		};
+		let Some(coin_id) = SymbolToId::<T>::get(symbol) else {
+			return false;
+		};
+		let live = CoinMetadata::<T>::get(coin_id).is_some_and(|info| info.status == CoinStatus::Live);
+		!live || PausedExcept::<T>::contains_key(coin_id)
+	}
+}
+
+/// Supply of a coin by symbol, in coin amounts
+impl<T: Config> SupplyQuery for Pallet<T> {
+	fn supply_of_symbol(symbol: &[u8]) -> Option<u128> {
+		Self::get_coin_id_by_symbol(symbol).map(Self::total_supply_of)
+	}
+}
+
+/// Coin by symbol and the mint and burn permissions `account` holds on it
+impl<T: Config> CoinMapping<T::AccountId> for Pallet<T> {
+	fn mapped_coin(symbol: &[u8], account: &T::AccountId) -> Option<MappedCoin> {
+		let coin_id = Self::get_coin_id_by_symbol(symbol)?;
+		let coin_info = CoinMetadata::<T>::get(coin_id)?;
+		Some(MappedCoin {
+			coin_id,
+			destroying: coin_info.status.is_destroying(),
+			can_mint: Self::has_mint_permission(coin_id, account),
+			can_burn: Self::has_burn_permission(coin_id, account),
+		})
+	}
+}
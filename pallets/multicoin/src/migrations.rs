@@ -0,0 +1,263 @@
+//! Storage migrations for the multi-coin pallet.
+
+use super::*;
+use codec::{Decode, Encode};
+use frame_support::{
+	migrations::VersionedMigration,
+	traits::{Get, UncheckedOnRuntimeUpgrade},
+	weights::Weight,
+};
+use sp_runtime::RuntimeDebug;
+
+/// Migration from storage version 1 to 2: adds `status` to `CoinInfo` and
+/// `call_scope` to its `FeeConfig`, and seeds `HolderCount`.
+pub mod v2 {
+	use super::*;
+
+	/// `CoinInfo` layout used by storage version 1
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+	pub struct OldCoinInfo<Symbol, Name, AccountId, Balance, FeeConfig> {
+		pub symbol: Symbol,
+		pub name: Name,
+		pub decimals: u8,
+		pub owner: AccountId,
+		pub deposit: Balance,
+		pub fee_config: FeeConfig,
+	}
+
+	/// `FeeConfig` layout used by storage version 1
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+	pub struct OldFeeConfig {
+		pub transfer_fee: u128,
+		pub minimum_balance: u128,
+		pub can_pay_tx_fees: bool,
+	}
+
+	type OldCoinInfoOf<T> = OldCoinInfo<
+		BoundedVec<u8, <T as Config>::MaxSymbolLength>,
+		BoundedVec<u8, <T as Config>::MaxNameLength>,
+		<T as frame_system::Config>::AccountId,
+		DepositBalanceOf<T>,
+		OldFeeConfig,
+	>;
+
+	/// Translates every stored coin to the new layout, marking it `Live` and
+	/// letting it pay fees for any call, then counts each coin's holders.
+	pub struct InnerMigrateV1ToV2<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut translated = 0u64;
+			// Written in the layout of storage version 3, which `v4` then extends
+			v4::CoinMetadata::<T>::translate::<OldCoinInfoOf<T>, _>(|_, old| {
+				translated += 1;
+				Some(v4::OldCoinInfo {
+					symbol: old.symbol,
+					name: old.name,
+					decimals: old.decimals,
+					owner: old.owner,
+					deposit: old.deposit,
+					fee_config: FeeConfig {
+						transfer_fee: old.fee_config.transfer_fee,
+						minimum_balance: old.fee_config.minimum_balance,
+						can_pay_tx_fees: old.fee_config.can_pay_tx_fees,
+						call_scope: FeeCallScope::Any,
+					},
+					status: CoinStatus::Live,
+				})
+			});
+
+			let mut holders = 0u64;
+			for (coin_id, _, balance) in Balances::<T>::iter() {
+				if balance > 0 {
+					holders += 1;
+					HolderCount::<T>::mutate(coin_id, |count| *count = count.saturating_add(1));
+				}
+			}
+
+			T::DbWeight::get().reads_writes(translated + holders * 2, translated + holders)
+		}
+	}
+
+	/// [`InnerMigrateV1ToV2`] wrapped so it only runs on storage version 1.
+	pub type MigrateV1ToV2<T> = VersionedMigration<
+		1,
+		2,
+		InnerMigrateV1ToV2<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+/// Migration from storage version 2 to 3: admits every coin that could pay fees
+/// before admission by `FeeCoinAdmissionOrigin` was required.
+pub mod v3 {
+	use super::*;
+
+	/// Admits every coin with `can_pay_tx_fees` set as fee coin.
+	pub struct InnerMigrateV2ToV3<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV2ToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let (mut coins, mut admitted) = (0u64, 0u64);
+			for (coin_id, coin_info) in CoinMetadata::<T>::iter() {
+				coins += 1;
+				if coin_info.fee_config.can_pay_tx_fees {
+					admitted += 1;
+					AdmittedFeeCoins::<T>::insert(coin_id, ());
+				}
+			}
+
+			T::DbWeight::get().reads_writes(coins, admitted)
+		}
+	}
+
+	/// [`InnerMigrateV2ToV3`] wrapped so it only runs on storage version 2.
+	pub type MigrateV2ToV3<T> = VersionedMigration<
+		2,
+		3,
+		InnerMigrateV2ToV3<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+/// Migration from storage version 3 to 4: adds the `issuer`, `admin` and
+/// `freezer` roles to `CoinInfo`, all held by the coin owner.
+pub mod v4 {
+	use super::*;
+	use frame_support::{storage_alias, Blake2_128Concat};
+
+	/// `CoinInfo` layout used by storage versions 2 and 3
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+	pub struct OldCoinInfo<Symbol, Name, AccountId, Balance, FeeConfig> {
+		pub symbol: Symbol,
+		pub name: Name,
+		pub decimals: u8,
+		pub owner: AccountId,
+		pub deposit: Balance,
+		pub fee_config: FeeConfig,
+		pub status: CoinStatus,
+	}
+
+	pub(super) type OldCoinInfoOf<T> = OldCoinInfo<
+		BoundedVec<u8, <T as Config>::MaxSymbolLength>,
+		BoundedVec<u8, <T as Config>::MaxNameLength>,
+		<T as frame_system::Config>::AccountId,
+		DepositBalanceOf<T>,
+		FeeConfig,
+	>;
+
+	/// `CoinMetadata` as stored by storage versions 2 and 3
+	#[storage_alias]
+	pub(super) type CoinMetadata<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, CoinId, OldCoinInfoOf<T>>;
+
+	/// Translates every stored coin to the new layout, giving each role to its owner.
+	pub struct InnerMigrateV3ToV4<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV3ToV4<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut translated = 0u64;
+			crate::CoinMetadata::<T>::translate::<OldCoinInfoOf<T>, _>(|_, old| {
+				translated += 1;
+				Some(CoinInfo {
+					symbol: old.symbol,
+					name: old.name,
+					decimals: old.decimals,
+					issuer: old.owner.clone(),
+					admin: old.owner.clone(),
+					freezer: old.owner.clone(),
+					owner: old.owner,
+					deposit: old.deposit,
+					fee_config: old.fee_config,
+					status: old.status,
+				})
+			});
+
+			T::DbWeight::get().reads_writes(translated, translated)
+		}
+	}
+
+	/// [`InnerMigrateV3ToV4`] wrapped so it only runs on storage version 3.
+	pub type MigrateV3ToV4<T> = VersionedMigration<
+		3,
+		4,
+		InnerMigrateV3ToV4<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+/// Migration from storage version 4 to 5: records the owner as depositor of
+/// every coin and seeds `TotalCoinDeposits`.
+pub mod v5 {
+	use super::*;
+
+	/// Credits each coin's deposit to its owner, which was the account refunded on
+	/// destruction before depositors were tracked.
+	pub struct InnerMigrateV4ToV5<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV4ToV5<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut coins = 0u64;
+			let mut total = DepositBalanceOf::<T>::zero();
+			for (coin_id, coin_info) in CoinMetadata::<T>::iter() {
+				coins += 1;
+				CoinDepositors::<T>::insert(coin_id, coin_info.owner);
+				total = total.saturating_add(coin_info.deposit);
+			}
+			TotalCoinDeposits::<T>::put(total);
+
+			T::DbWeight::get().reads_writes(coins, coins + 1)
+		}
+	}
+
+	/// [`InnerMigrateV4ToV5`] wrapped so it only runs on storage version 4.
+	pub type MigrateV4ToV5<T> = VersionedMigration<
+		4,
+		5,
+		InnerMigrateV4ToV5<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+/// Migration from storage version 5 to 6: seeds `CoinReferences` from the
+/// liquidation auctions, launch sales and transfer tickets already open.
+pub mod v6 {
+	use super::*;
+
+	/// Counts the liquidation auctions, launch sales and transfer tickets already
+	/// referencing each coin, which `start_destroy` now checks.
+	pub struct InnerMigrateV5ToV6<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV5ToV6<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut reads = 0u64;
+			for (_, auction) in LiquidationAuctions::<T>::iter() {
+				reads += 1;
+				Pallet::<T>::add_references(auction.coin_id, auction.collateral_asset);
+			}
+			for (_, sale) in Sales::<T>::iter() {
+				reads += 1;
+				Pallet::<T>::add_references(sale.coin_id, sale.payment);
+			}
+			for (_, ticket) in TransferTickets::<T>::iter() {
+				reads += 1;
+				Pallet::<T>::add_references(ticket.coin_id, PaymentAsset::Native);
+			}
+
+			T::DbWeight::get().reads_writes(reads.saturating_mul(2), reads.saturating_mul(2))
+		}
+	}
+
+	/// [`InnerMigrateV5ToV6`] wrapped so it only runs on storage version 5.
+	pub type MigrateV5ToV6<T> = VersionedMigration<
+		5,
+		6,
+		InnerMigrateV5ToV6<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
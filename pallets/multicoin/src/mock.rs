@@ -1,99 +1,216 @@
-use crate as pallet_multicoin;
-use frame_support::{
-	derive_impl,
-	parameter_types,
-	traits::{ConstU32, ConstU64, ConstU128},
-};
-use sp_core::H256;
-use sp_runtime::{
-	traits::{BlakeTwo256, IdentityLookup}, 
-	BuildStorage,
-};
-
-type Block = frame_system::mocking::MockBlock<Test>;
-
-// Configure a mock runtime to test the pallet.
-frame_support::construct_runtime!(
-	pub enum Test {
-		System: frame_system,
-		Balances: pallet_balances,
-		MultiCoin: pallet_multicoin,
-	}
-);
-
-#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
-impl frame_system::Config for Test {
-	type BaseCallFilter = frame_support::traits::Everything;
-	type BlockWeights = ();
-	type BlockLength = ();
-	type DbWeight = ();
-	type RuntimeOrigin = RuntimeOrigin;
-	type RuntimeCall = RuntimeCall;
-	type Nonce = u64;
-	type Hash = H256;
-	type Hashing = BlakeTwo256;
-	type AccountId = u64;
-	type Lookup = IdentityLookup<Self::AccountId>;
-	type Block = Block;
-	type RuntimeEvent = RuntimeEvent;
-	type BlockHashCount = ConstU64<250>;
-	type Version = ();
-	type PalletInfo = PalletInfo;
-	type AccountData = pallet_balances::AccountData<u128>;
-	type OnNewAccount = ();
-	type OnKilledAccount = ();
-	type SystemWeightInfo = ();
-	type SS58Prefix = ();
-	type OnSetCode = ();
-	type MaxConsumers = ConstU32<16>;
-}
-
-#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
-impl pallet_balances::Config for Test {
-	type Balance = u128;
-	type DustRemoval = ();
-	type RuntimeEvent = RuntimeEvent;
-	type ExistentialDeposit = ConstU128<1>;
-	type AccountStore = System;
-	type WeightInfo = ();
-	type MaxLocks = ConstU32<10>;
-	type MaxReserves = ();
-	type ReserveIdentifier = [u8; 8];
-	type RuntimeHoldReason = ();
-	type RuntimeFreezeReason = ();
-	type FreezeIdentifier = ();
-	type MaxFreezes = ();
-}
-
-parameter_types! {
-	pub const MaxSymbolLength: u32 = 32;
-	pub const MaxNameLength: u32 = 64;
-	pub const MaxCoins: u32 = 1000;
-	pub const CoinDeposit: u128 = 10;
-	pub const MaxSupply: u128 = 1_000_000_000_000;
-}
-
-impl pallet_multicoin::Config for Test {
-	type RuntimeEvent = RuntimeEvent;
-	type WeightInfo = ();
-	type Currency = Balances;
-	type MaxSymbolLength = MaxSymbolLength;
-	type MaxNameLength = MaxNameLength;
-	type MaxCoins = MaxCoins;
-	type CoinDeposit = CoinDeposit;
-	type MaxSupply = MaxSupply;
-}
-
-// Build genesis storage according to the mock runtime.
-pub fn new_test_ext() -> sp_io::TestExternalities {
-	let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
-	
-	pallet_balances::GenesisConfig::<Test> {
-		balances: vec![(1, 1000), (2, 1000), (3, 1000)],
-		..Default::default()
-	}
-	.assimilate_storage(&mut storage)
-	.unwrap();
-
-	storage.into()
-}
+use crate as pallet_multicoin;
+use frame_support::{
+	derive_impl,
+	parameter_types,
+	traits::{ConstU8, ConstU32, ConstU64, ConstU128, Contains, ExistenceRequirement, Nothing},
+	weights::{IdentityFee, Weight},
+	PalletId,
+};
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup}, 
+	BuildStorage, DispatchError, DispatchResult, Perbill, RuntimeDebug,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test {
+		System: frame_system,
+		Balances: pallet_balances,
+		MultiCoin: pallet_multicoin,
+		TransactionPayment: pallet_transaction_payment,
+	}
+);
+
+/// Call filter blocking `remark_with_event`, to check wrappers can't get around it
+pub struct TestCallFilter;
+
+impl Contains<RuntimeCall> for TestCallFilter {
+	fn contains(call: &RuntimeCall) -> bool {
+		!matches!(call, RuntimeCall::System(frame_system::Call::remark_with_event { .. }))
+	}
+}
+
+/// Randomness derived from the subject alone, so draws are reproducible
+pub struct TestRandomness;
+
+impl frame_support::traits::Randomness<H256, u64> for TestRandomness {
+	fn random(subject: &[u8]) -> (H256, u64) {
+		(H256(sp_io::hashing::blake2_256(subject)), System::block_number())
+	}
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type BaseCallFilter = TestCallFilter;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u128>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type Balance = u128;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ConstU32<10>;
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type RuntimeHoldReason = ();
+	type RuntimeFreezeReason = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+}
+
+impl pallet_transaction_payment::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type OnChargeTransaction = pallet_multicoin::MultiCoinFeeAdapter<Test>;
+	type OperationalFeeMultiplier = ConstU8<5>;
+	type WeightToFee = IdentityFee<u128>;
+	type LengthToFee = IdentityFee<u128>;
+	type FeeMultiplierUpdate = ();
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const MaxSymbolLength: u32 = 32;
+	pub const MaxNameLength: u32 = 64;
+	pub const MaxCoins: u32 = 1000;
+	pub const CoinDeposit: u128 = 10;
+	pub const MaxSupply: u128 = 1_000_000_000_000;
+	pub const MultiCoinPalletId: PalletId = PalletId(*b"py/mcoin");
+	pub const MaxTransferHookWeight: Weight = Weight::from_parts(1_000_000, 1_000);
+	pub const FeeShareTarget: Perbill = Perbill::from_percent(50);
+	pub const FeeMultiplierAdjustment: Perbill = Perbill::from_percent(10);
+	pub const KeeperReward: Perbill = Perbill::from_percent(1);
+	pub const NativeFeeExistence: ExistenceRequirement = ExistenceRequirement::KeepAlive;
+	pub const VestingHoldReason: TestHoldReason = TestHoldReason::Vesting;
+}
+
+/// Hold reasons of the consumer pallets of the mock runtime
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum TestHoldReason {
+	Escrow,
+	Bridge,
+	Vesting,
+}
+
+/// Account receiving transaction fees paid in coins
+pub const FEE_BENEFICIARY: u64 = 14;
+
+/// Pays transaction fees paid in coins to `FEE_BENEFICIARY`
+pub struct MockFeeBeneficiary;
+
+impl pallet_multicoin::OnCoinUnbalanced<Test> for MockFeeBeneficiary {
+	fn on_unbalanced(fee: pallet_multicoin::NegativeImbalance<Test>) {
+		let _ = MultiCoin::resolve(&FEE_BENEFICIARY, fee);
+	}
+}
+
+/// Account that `MockTransferHook` refuses to pay into
+pub const HOOK_REJECTED: u64 = 13;
+
+/// Transfer hook rejecting transfers into `HOOK_REJECTED`
+pub struct MockTransferHook;
+
+impl pallet_multicoin::TransferHook<u64> for MockTransferHook {
+	fn on_transfer(_: pallet_multicoin::CoinId, _: &u64, to: &u64, _: u128) -> DispatchResult {
+		if *to == HOOK_REJECTED {
+			return Err(DispatchError::Other("rejected by transfer hook"));
+		}
+		Ok(())
+	}
+
+	fn weight() -> Weight {
+		Weight::from_parts(500_000, 100)
+	}
+}
+
+impl pallet_multicoin::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Currency = Balances;
+	type MaxSymbolLength = MaxSymbolLength;
+	type MaxNameLength = MaxNameLength;
+	type MaxCoins = MaxCoins;
+	type CoinDeposit = CoinDeposit;
+	type MaxSupply = MaxSupply;
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type FeeCoinAdmissionOrigin = frame_system::EnsureRoot<u64>;
+	type VoteLockingPeriod = ConstU64<10>;
+	type BridgeMintWindow = ConstU64<100>;
+	type PalletId = MultiCoinPalletId;
+	type MaxSplitRecipients = ConstU32<16>;
+	type MaxBatchRecipients = ConstU32<16>;
+	type MaxInitialMinters = ConstU32<4>;
+	type MaxInitialBurners = ConstU32<4>;
+	type MaxPriceFeeders = ConstU32<5>;
+	type FeeShareTarget = FeeShareTarget;
+	type FeeMultiplierAdjustment = FeeMultiplierAdjustment;
+	type LiquidationAuctionDuration = ConstU64<10>;
+	type KeeperReward = KeeperReward;
+	type TransferHook = MockTransferHook;
+	type MaxTransferHookWeight = MaxTransferHookWeight;
+	type ExternalFeeCoin = ();
+	type FeeSponsor = ();
+	type ExternalWrappers = Nothing;
+	type NativeFeeExistence = NativeFeeExistence;
+	type OnCoinFee = MockFeeBeneficiary;
+	type RuntimeHoldReason = TestHoldReason;
+	type MaxHolds = ConstU32<2>;
+	type MaxSupplyCheckpoints = ConstU32<2>;
+	type SupplyCheckpointInterval = ConstU64<10>;
+	type MaxCleanupScan = ConstU32<3>;
+	type MaxPauseExceptions = ConstU32<2>;
+	type VelocityEraLength = ConstU64<10>;
+	type MaxVelocityEras = ConstU32<3>;
+	type RemoveItemsLimit = ConstU32<2>;
+	type Randomness = TestRandomness;
+	type HolderCheckpointInterval = ConstU32<2>;
+	type MaxHolderCheckpoints = ConstU32<8>;
+	type MaxLocales = ConstU32<2>;
+	type MaxDescriptionLength = ConstU32<64>;
+	type MaxUriLength = ConstU32<32>;
+	type MaxFeePreferenceBatch = ConstU32<3>;
+	type MetadataDepositPerByte = ConstU128<1>;
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(1, 1000), (2, 1000), (3, 1000)],
+		..Default::default()
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+
+	storage.into()
+}
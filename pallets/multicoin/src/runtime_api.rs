@@ -0,0 +1,25 @@
+//! Runtime API of the multi-coin pallet, for wallets and admin UIs that would
+//! otherwise issue many storage queries per coin.
+
+use crate::{CoinDashboard, CoinId, FeeBreakdown, VelocityStats};
+use codec::Codec;
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// Queries over the coins of the multi-coin pallet
+	pub trait MultiCoinApi<AccountId> where AccountId: Codec {
+		/// Management summary of `coin_id`: owner, roles, permissions, fee config,
+		/// supply, holders, status and reserve linkage; `None` if it doesn't exist
+		fn coin_dashboard(coin_id: CoinId) -> Option<CoinDashboard<AccountId>>;
+
+		/// Spendable balances of `account` in each of `coin_ids`, in the same order
+		fn balances_many(account: AccountId, coin_ids: Vec<CoinId>) -> Vec<u128>;
+
+		/// Dry run of a transfer: the fees it would cost, or the error it would fail with
+		fn can_transfer(coin_id: CoinId, from: AccountId, to: AccountId, amount: u128) -> Result<FeeBreakdown, DispatchError>;
+
+		/// Transfer volume and distinct senders of `coin_id` over its latest eras, oldest first
+		fn velocity(coin_id: CoinId) -> Vec<VelocityStats>;
+	}
+}
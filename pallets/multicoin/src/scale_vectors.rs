@@ -0,0 +1,1618 @@
+//! Golden SCALE encodings of every call and event of the pallet.
+//!
+//! SDK authors can check their codecs against these vectors; run
+//! `cargo test -p pallet-multicoin scale_vectors -- --nocapture` to print them.
+//! Calls are encoded as pallet calls (call index followed by the arguments) and
+//! events as pallet events, so neither includes the runtime's pallet index,
+//! except for the inner call of `call_with_fee_coin`, which is a full mock
+//! `RuntimeCall` (`MultiCoin` is pallet 2). Account IDs and block numbers are
+//! `u64` in the mock runtime.
+//!
+//! A failing vector means the wire format changed and downstream codecs and
+//! metadata consumers need updating along with it.
+
+use crate::{
+	mock::*, Call, Conviction, DecayConfig, DormancyConfig, Event, FaucetConfig, FeeCallScope,
+	FeeChangeControl, FeeChangeGuard, FeeConfig, OracleConfig, PaymentAsset, RecoveryConfig,
+	RoyaltyConfig, StableConfig,
+};
+use codec::Encode;
+use scale_info::{TypeDef, TypeInfo};
+use sp_runtime::{BoundedVec, FixedU128, Perbill, Permill};
+
+/// A variant name, its encoding and the expected encoding in hex
+type Vector = (&'static str, Vec<u8>, &'static str);
+
+fn hex(bytes: &[u8]) -> String {
+	bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Names of the variants `E` declares in metadata
+fn variant_names<E: TypeInfo>() -> Vec<&'static str> {
+	match E::type_info().type_def {
+		TypeDef::Variant(def) => def.variants.iter().map(|v| v.name).collect(),
+		_ => panic!("expected an enum"),
+	}
+}
+
+/// Print every vector, then check that all declared variants are covered and
+/// that no encoding changed
+fn check_vectors(kind: &str, vectors: Vec<Vector>, declared: Vec<&'static str>) {
+	let mut mismatches = Vec::new();
+	for (name, encoded, expected) in &vectors {
+		let actual = hex(encoded);
+		println!("{kind} {name} 0x{actual}");
+		if actual != *expected {
+			mismatches.push(format!("{name}: expected 0x{expected}, got 0x{actual}"));
+		}
+	}
+
+	let covered: Vec<&str> = vectors.iter().map(|(name, ..)| *name).collect();
+	let missing: Vec<&str> = declared.into_iter().filter(|name| !covered.contains(name)).collect();
+	assert!(missing.is_empty(), "{kind} variants without a golden vector: {missing:?}");
+	assert!(mismatches.is_empty(), "{kind} encodings changed:\n{}", mismatches.join("\n"));
+}
+
+#[test]
+fn call_encodings_match_golden_vectors() {
+	let vectors: Vec<Vector> = vec![
+		(
+			"create_coin",
+			Call::<Test>::create_coin {
+				symbol: b"TST".to_vec(),
+				name: b"Test Coin".to_vec(),
+				decimals: 18,
+				initial_supply: 1_000_000,
+				initial_minters: Some(BoundedVec::truncate_from(vec![2])),
+				initial_burners: None,
+				can_pay_tx_fees: true,
+				dormancy: Some(DormancyConfig { period: 100, grace: 10, beneficiary: 3 }),
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"000c545354245465737420436f696e1240420f000000000000000000000000000104020000000000000000010164000000000000000a00000000000000030000000000000000",
+		),
+		(
+			"transfer",
+			Call::<Test>::transfer {
+				coin_id: 1,
+				to: 2,
+				amount: 1_000,
+				tx_fee_coin: Some(0),
+			}
+			.encode(),
+			"01010000000200000000000000e80300000000000000000000000000000100000000",
+		),
+		(
+			"mint",
+			Call::<Test>::mint { coin_id: 1, to: 2, amount: 500, tx_fee_coin: None }.encode(),
+			"02010000000200000000000000f401000000000000000000000000000000",
+		),
+		(
+			"burn",
+			Call::<Test>::burn { coin_id: 1, amount: 500, tx_fee_coin: None }.encode(),
+			"0301000000f401000000000000000000000000000000",
+		),
+		(
+			"transfer_ownership",
+			Call::<Test>::transfer_ownership {
+				coin_id: 1,
+				new_owner: 2,
+				revoke_old_permissions: true,
+				move_deposit: false,
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"04010000000200000000000000010000",
+		),
+		(
+			"set_mint_permission",
+			Call::<Test>::set_mint_permission {
+				coin_id: 1,
+				account: 2,
+				can_mint: true,
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"050100000002000000000000000100",
+		),
+		(
+			"set_burn_permission",
+			Call::<Test>::set_burn_permission {
+				coin_id: 1,
+				account: 2,
+				can_burn: false,
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"060100000002000000000000000000",
+		),
+		(
+			"set_fee_config",
+			Call::<Test>::set_fee_config {
+				coin_id: 1,
+				transfer_fee: 10,
+				minimum_balance: 5,
+				can_pay_tx_fees: true,
+				call_scope: FeeCallScope::OwnPalletOnly,
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"07010000000a00000000000000000000000000000005000000000000000000000000000000010100",
+		),
+		(
+			"set_preferred_fee_coin",
+			Call::<Test>::set_preferred_fee_coin { coin_id: Some(1), tx_fee_coin: None }.encode(),
+			"08010100000000",
+		),
+		(
+			"call_with_fee_coin",
+			Call::<Test>::call_with_fee_coin {
+				tx_fee_coin: Some(0),
+				call: Box::new(RuntimeCall::MultiCoin(Call::claim_rewards { coin_id: 0, tx_fee_coin: None })),
+			}
+			.encode(),
+			"09010000000002180000000000",
+		),
+		(
+			"set_fee_guard",
+			Call::<Test>::set_fee_guard {
+				coin_id: 1,
+				guard: Some(FeeChangeGuard { threshold: 100, control: FeeChangeControl::Delay(10) }),
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"0a010000000164000000000000000000000000000000000a0000000000000000",
+		),
+		(
+			"approve_fee_change",
+			Call::<Test>::approve_fee_change { coin_id: 1, tx_fee_coin: None }.encode(),
+			"0b0100000000",
+		),
+		(
+			"enact_fee_change",
+			Call::<Test>::enact_fee_change { coin_id: 1, tx_fee_coin: None }.encode(),
+			"0c0100000000",
+		),
+		(
+			"cancel_fee_change",
+			Call::<Test>::cancel_fee_change { coin_id: 1, tx_fee_coin: None }.encode(),
+			"0d0100000000",
+		),
+		(
+			"freeze_coin",
+			Call::<Test>::freeze_coin { coin_id: 1, tx_fee_coin: None }.encode(),
+			"0e0100000000",
+		),
+		(
+			"thaw_coin",
+			Call::<Test>::thaw_coin { coin_id: 1, tx_fee_coin: None }.encode(),
+			"0f0100000000",
+		),
+		(
+			"force_transfer",
+			Call::<Test>::force_transfer {
+				coin_id: 1,
+				from: 2,
+				to: 3,
+				amount: 100,
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"1001000000020000000000000003000000000000006400000000000000000000000000000000",
+		),
+		(
+			"set_coin_manager",
+			Call::<Test>::set_coin_manager {
+				coin_id: 1,
+				manager: Some(2),
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"110100000001020000000000000000",
+		),
+		(
+			"set_accrual_index",
+			Call::<Test>::set_accrual_index {
+				coin_id: 1,
+				index: FixedU128::from_u32(2),
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"12010000000000c84e676dc11b000000000000000000",
+		),
+		(
+			"set_decay_rate",
+			Call::<Test>::set_decay_rate {
+				coin_id: 1,
+				decay: Some(DecayConfig { rate: Perbill::from_parts(1000), period: 100 }),
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"130100000001e8030000640000000000000000",
+		),
+		(
+			"set_reward_rate",
+			Call::<Test>::set_reward_rate {
+				coin_id: 1,
+				reward_per_block: 10,
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"14010000000a00000000000000000000000000000000",
+		),
+		(
+			"fund_rewards",
+			Call::<Test>::fund_rewards { coin_id: 1, amount: 1_000, tx_fee_coin: None }.encode(),
+			"1501000000e803000000000000000000000000000000",
+		),
+		(
+			"stake",
+			Call::<Test>::stake { coin_id: 1, amount: 100, tx_fee_coin: None }.encode(),
+			"16010000006400000000000000000000000000000000",
+		),
+		(
+			"unstake",
+			Call::<Test>::unstake { coin_id: 1, amount: 100, tx_fee_coin: None }.encode(),
+			"17010000006400000000000000000000000000000000",
+		),
+		(
+			"claim_rewards",
+			Call::<Test>::claim_rewards { coin_id: 1, tx_fee_coin: None }.encode(),
+			"180100000000",
+		),
+		(
+			"lock_for_vote",
+			Call::<Test>::lock_for_vote {
+				coin_id: 1,
+				amount: 100,
+				conviction: Conviction::Locked2x,
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"1901000000640000000000000000000000000000000200",
+		),
+		(
+			"unlock_vote",
+			Call::<Test>::unlock_vote { coin_id: 1, tx_fee_coin: None }.encode(),
+			"1a0100000000",
+		),
+		(
+			"open_sale",
+			Call::<Test>::open_sale {
+				coin_id: 1,
+				amount: 1_000,
+				payment: PaymentAsset::Coin(2),
+				price: FixedU128::from_u32(2),
+				per_account_cap: 100,
+				duration: 50,
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"1b01000000e803000000000000000000000000000001020000000000c84e676dc11b000000000000000064000000000000000000000000000000320000000000000000",
+		),
+		(
+			"buy",
+			Call::<Test>::buy { sale_id: 0, amount: 10, tx_fee_coin: None }.encode(),
+			"1c000000000a00000000000000000000000000000000",
+		),
+		(
+			"withdraw_proceeds",
+			Call::<Test>::withdraw_proceeds { sale_id: 0, tx_fee_coin: None }.encode(),
+			"1d0000000000",
+		),
+		(
+			"close_sale",
+			Call::<Test>::close_sale { sale_id: 0, tx_fee_coin: None }.encode(),
+			"1e0000000000",
+		),
+		(
+			"place_order",
+			Call::<Test>::place_order {
+				sell_coin: 1,
+				sell_amount: 100,
+				buy_coin: 2,
+				price: FixedU128::from_u32(2),
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"1f0100000064000000000000000000000000000000020000000000c84e676dc11b000000000000000000",
+		),
+		(
+			"fill_order",
+			Call::<Test>::fill_order { order_id: 0, amount: 10, tx_fee_coin: None }.encode(),
+			"20000000000a00000000000000000000000000000000",
+		),
+		(
+			"cancel_order",
+			Call::<Test>::cancel_order { order_id: 0, tx_fee_coin: None }.encode(),
+			"210000000000",
+		),
+		(
+			"create_subscription",
+			Call::<Test>::create_subscription {
+				coin_id: 1,
+				to: 2,
+				amount: 10,
+				period: 100,
+				count: 12,
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"220100000002000000000000000a00000000000000000000000000000064000000000000000c00000000",
+		),
+		(
+			"claim_due",
+			Call::<Test>::claim_due { subscription_id: 0, tx_fee_coin: None }.encode(),
+			"230000000000",
+		),
+		(
+			"cancel_subscription",
+			Call::<Test>::cancel_subscription { subscription_id: 0, tx_fee_coin: None }.encode(),
+			"240000000000",
+		),
+		(
+			"transfer_split",
+			Call::<Test>::transfer_split {
+				coin_id: 1,
+				recipients: BoundedVec::truncate_from(vec![(2, Permill::from_parts(600000)), (3, Permill::from_parts(400000))]),
+				amount: 100,
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"2501000000080200000000000000c02709000300000000000000801a06006400000000000000000000000000000000",
+		),
+		(
+			"set_sink_blocked",
+			Call::<Test>::set_sink_blocked {
+				coin_id: 1,
+				who: 2,
+				blocked: true,
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"260100000002000000000000000100",
+		),
+		(
+			"set_recovery",
+			Call::<Test>::set_recovery {
+				config: Some(RecoveryConfig { recoverer: 2, delay: 10 }),
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"270102000000000000000a0000000000000000",
+		),
+		(
+			"announce_recovery",
+			Call::<Test>::announce_recovery { lost: 1, tx_fee_coin: None }.encode(),
+			"28010000000000000000",
+		),
+		(
+			"cancel_recovery",
+			Call::<Test>::cancel_recovery { tx_fee_coin: None }.encode(),
+			"2900",
+		),
+		(
+			"recover_balance",
+			Call::<Test>::recover_balance { lost: 1, coin_id: 1, tx_fee_coin: None }.encode(),
+			"2a01000000000000000100000000",
+		),
+		(
+			"warn_dormant",
+			Call::<Test>::warn_dormant { coin_id: 1, who: 2, tx_fee_coin: None }.encode(),
+			"2b01000000020000000000000000",
+		),
+		(
+			"sweep_dormant",
+			Call::<Test>::sweep_dormant { coin_id: 1, who: 2, tx_fee_coin: None }.encode(),
+			"2c01000000020000000000000000",
+		),
+		(
+			"set_max_holders",
+			Call::<Test>::set_max_holders {
+				coin_id: 1,
+				max_holders: Some(100),
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"2d01000000016400000000",
+		),
+		(
+			"set_royalty",
+			Call::<Test>::set_royalty {
+				coin_id: 1,
+				royalty: Some(RoyaltyConfig { bps: 500, beneficiary: 3 }),
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"2e0100000001f401030000000000000000",
+		),
+		(
+			"set_royalty_exempt",
+			Call::<Test>::set_royalty_exempt {
+				coin_id: 1,
+				who: 2,
+				exempt: true,
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"2f0100000002000000000000000100",
+		),
+		(
+			"set_mint_lock_period",
+			Call::<Test>::set_mint_lock_period {
+				coin_id: 1,
+				period: Some(10),
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"3001000000010a0000000000000000",
+		),
+		(
+			"move_between_labels",
+			Call::<Test>::move_between_labels {
+				coin_id: 1,
+				from: None,
+				to: Some(BoundedVec::truncate_from(b"savings".to_vec())),
+				amount: 600,
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"310100000000011c736176696e67735802000000000000000000000000000000",
+		),
+		(
+			"set_faucet",
+			Call::<Test>::set_faucet {
+				coin_id: 1,
+				config: Some(FaucetConfig { amount: 50, cooldown: 10 }),
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"320100000001320000000000000000000000000000000a0000000000000000",
+		),
+		(
+			"drip",
+			Call::<Test>::drip { coin_id: 1, tx_fee_coin: None }.encode(),
+			"330100000000",
+		),
+		(
+			"grant_pallet_permission",
+			Call::<Test>::grant_pallet_permission {
+				coin_id: 1,
+				pallet_id: *b"py/bridg",
+				mint: true,
+				burn: false,
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"340100000070792f6272696467010000",
+		),
+		(
+			"set_bridge_mint_cap",
+			Call::<Test>::set_bridge_mint_cap {
+				coin_id: 1,
+				bridge: 5,
+				cap: Some(100),
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"35010000000500000000000000016400000000000000000000000000000000",
+		),
+		(
+			"set_oracle_config",
+			Call::<Test>::set_oracle_config {
+				coin_id: 1,
+				config: Some(OracleConfig { min_feeds: 2, max_deviation: Perbill::from_parts(100000000), validity: 10 }),
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"3601000000010200000000e1f5050a0000000000000000",
+		),
+		(
+			"set_price_feeder",
+			Call::<Test>::set_price_feeder {
+				coin_id: 1,
+				feeder: 2,
+				enabled: true,
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"370100000002000000000000000100",
+		),
+		(
+			"feed_price",
+			Call::<Test>::feed_price {
+				coin_id: 1,
+				price: FixedU128::from_u32(2),
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"38010000000000c84e676dc11b000000000000000000",
+		),
+		(
+			"propose_fee_coin",
+			Call::<Test>::propose_fee_coin { coin_id: 1, tx_fee_coin: None }.encode(),
+			"390100000000",
+		),
+		(
+			"approve_fee_coin",
+			Call::<Test>::approve_fee_coin { coin_id: 1, tx_fee_coin: None }.encode(),
+			"3a0100000000",
+		),
+		(
+			"revoke_fee_coin",
+			Call::<Test>::revoke_fee_coin { coin_id: 1, tx_fee_coin: None }.encode(),
+			"3b0100000000",
+		),
+		(
+			"set_fee_payment_suspended",
+			Call::<Test>::set_fee_payment_suspended { suspended: true, tx_fee_coin: None }.encode(),
+			"3c0100",
+		),
+		(
+			"set_stable_config",
+			Call::<Test>::set_stable_config {
+				coin_id: 1,
+				config: Some(StableConfig { collateral: PaymentAsset::Coin(2), min_ratio: FixedU128::from_rational(3, 2), liquidation_ratio: FixedU128::from_rational(6, 5), liquidation_penalty: Perbill::from_parts(100000000) }),
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"3d010000000101020000000000167b0d12d114000000000000000000007862a441a710000000000000000000e1f50500",
+		),
+		(
+			"deposit_collateral",
+			Call::<Test>::deposit_collateral {
+				coin_id: 1,
+				amount: 300,
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"3e010000002c01000000000000000000000000000000",
+		),
+		(
+			"withdraw_collateral",
+			Call::<Test>::withdraw_collateral {
+				coin_id: 1,
+				amount: 100,
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"3f010000006400000000000000000000000000000000",
+		),
+		(
+			"mint_stable",
+			Call::<Test>::mint_stable { coin_id: 1, amount: 400, tx_fee_coin: None }.encode(),
+			"40010000009001000000000000000000000000000000",
+		),
+		(
+			"repay_stable",
+			Call::<Test>::repay_stable { coin_id: 1, amount: 50, tx_fee_coin: None }.encode(),
+			"41010000003200000000000000000000000000000000",
+		),
+		(
+			"liquidate",
+			Call::<Test>::liquidate { coin_id: 1, who: 2, tx_fee_coin: None }.encode(),
+			"4201000000020000000000000000",
+		),
+		(
+			"bid_liquidation",
+			Call::<Test>::bid_liquidation { auction_id: 0, tx_fee_coin: None }.encode(),
+			"430000000000",
+		),
+		(
+			"set_flash_mint_fee",
+			Call::<Test>::set_flash_mint_fee {
+				coin_id: 1,
+				fee: Some(Permill::from_parts(10000)),
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"4401000000011027000000",
+		),
+		(
+			"flash_mint",
+			Call::<Test>::flash_mint {
+				coin_id: 1,
+				amount: 1000,
+				call: Box::new(RuntimeCall::MultiCoin(Call::claim_rewards { coin_id: 0, tx_fee_coin: None })),
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"4501000000e80300000000000000000000000000000218000000000000",
+		),
+		(
+			"create_transfer_ticket",
+			Call::<Test>::create_transfer_ticket {
+				coin_id: 1,
+				to: 5,
+				amount: 100,
+				expiry: 10,
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"46010000000500000000000000640000000000000000000000000000000a0000000000000000",
+		),
+		(
+			"claim_ticket",
+			Call::<Test>::claim_ticket { ticket_id: 0, tx_fee_coin: None }.encode(),
+			"470000000000",
+		),
+		(
+			"refund_ticket",
+			Call::<Test>::refund_ticket { ticket_id: 1, tx_fee_coin: None }.encode(),
+			"480100000000",
+		),
+		(
+			"checkpoint_supply",
+			Call::<Test>::checkpoint_supply { coin_id: 1, tx_fee_coin: None }.encode(),
+			"490100000000",
+		),
+		(
+			"set_transfer_pause",
+			Call::<Test>::set_transfer_pause {
+				coin_id: 1,
+				except: Some(vec![3, 5]),
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"4a0100000001080300000000000000050000000000000000",
+		),
+		(
+			"set_legal_document",
+			Call::<Test>::set_legal_document {
+				coin_id: 1,
+				hash: Some(BoundedVec::truncate_from(b"QmHash".to_vec())),
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"4b010000000118516d4861736800",
+		),
+		(
+			"approve",
+			Call::<Test>::approve {
+				coin_id: 0,
+				delegate: 2,
+				amount: 100,
+				expires_at: Some(5),
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"4c0000000002000000000000006400000000000000000000000000000001050000000000000000",
+		),
+		(
+			"transfer_from",
+			Call::<Test>::transfer_from {
+				coin_id: 0,
+				owner: 1,
+				to: 3,
+				amount: 60,
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"4d00000000010000000000000003000000000000003c00000000000000000000000000000000",
+		),
+		(
+			"cancel_approval",
+			Call::<Test>::cancel_approval { coin_id: 0, delegate: 2, tx_fee_coin: None }.encode(),
+			"4e00000000020000000000000000",
+		),
+		(
+			"invalidate_approvals",
+			Call::<Test>::invalidate_approvals { coin_id: 0, tx_fee_coin: None }.encode(),
+			"4f0000000000",
+		),
+		(
+			"freeze_account",
+			Call::<Test>::freeze_account { coin_id: 1, who: 2, tx_fee_coin: None }.encode(),
+			"5001000000020000000000000000",
+		),
+		(
+			"thaw_account",
+			Call::<Test>::thaw_account { coin_id: 1, who: 2, tx_fee_coin: None }.encode(),
+			"5101000000020000000000000000",
+		),
+		(
+			"start_destroy",
+			Call::<Test>::start_destroy { coin_id: 1, tx_fee_coin: None }.encode(),
+			"520100000000",
+		),
+		(
+			"destroy_accounts",
+			Call::<Test>::destroy_accounts { coin_id: 1, tx_fee_coin: None }.encode(),
+			"530100000000",
+		),
+		(
+			"finish_destroy",
+			Call::<Test>::finish_destroy { coin_id: 1, tx_fee_coin: None }.encode(),
+			"540100000000",
+		),
+		(
+			"set_team",
+			Call::<Test>::set_team {
+				coin_id: 1,
+				issuer: 2,
+				admin: 3,
+				freezer: 4,
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"550100000002000000000000000300000000000000040000000000000000",
+		),
+		(
+			"transfer_batch",
+			Call::<Test>::transfer_batch {
+				coin_id: 1,
+				recipients: vec![(2, 10), (3, 20)].try_into().unwrap(),
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"56010000000802000000000000000a00000000000000000000000000000003000000000000001400000000000000000000000000000000",
+		),
+		(
+			"renounce_ownership",
+			Call::<Test>::renounce_ownership { coin_id: 1, tx_fee_coin: None }.encode(),
+			"570100000000",
+		),
+		(
+			"transfer_keep_alive",
+			Call::<Test>::transfer_keep_alive {
+				coin_id: 1,
+				to: 2,
+				amount: 10,
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"580100000002000000000000000a00000000000000000000000000000000",
+		),
+		(
+			"transfer_all",
+			Call::<Test>::transfer_all {
+				coin_id: 1,
+				dest: 2,
+				keep_alive: true,
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"590100000002000000000000000100",
+		),
+		(
+			"force_mint",
+			Call::<Test>::force_mint { coin_id: 1, to: 2, amount: 10, tx_fee_coin: None }.encode(),
+			"5a0100000002000000000000000a00000000000000000000000000000000",
+		),
+		(
+			"force_burn",
+			Call::<Test>::force_burn { coin_id: 1, from: 2, amount: 10, tx_fee_coin: None }.encode(),
+			"5b0100000002000000000000000a00000000000000000000000000000000",
+		),
+		(
+			"checkpoint_holders",
+			Call::<Test>::checkpoint_holders {
+				coin_id: 1,
+				segments: 4,
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"5c010000000400000000",
+		),
+		(
+			"set_metadata",
+			Call::<Test>::set_metadata {
+				coin_id: 1,
+				symbol: b"NEW".to_vec(),
+				name: b"New Coin".to_vec(),
+				decimals: 6,
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"5d010000000c4e4557204e657720436f696e0600",
+		),
+		(
+			"set_localization",
+			Call::<Test>::set_localization {
+				coin_id: 1,
+				locale: b"fr".to_vec(),
+				name: Some(b"Piece".to_vec()),
+				description: None,
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"5e01000000086672011450696563650000",
+		),
+		(
+			"set_extended_metadata",
+			Call::<Test>::set_extended_metadata {
+				coin_id: 1,
+				description: Some(b"A coin".to_vec()),
+				logo_uri: None,
+				homepage: Some(b"https://coin.io".to_vec()),
+				external_reference: None,
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"5f0100000001184120636f696e00013c68747470733a2f2f636f696e2e696f0000",
+		),
+		(
+			"set_fee_operator",
+			Call::<Test>::set_fee_operator {
+				operator: 2,
+				authorized: true,
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"6002000000000000000100",
+		),
+		(
+			"set_preferred_fee_coin_for",
+			Call::<Test>::set_preferred_fee_coin_for {
+				accounts: vec![1, 3].try_into().unwrap(),
+				coin_id: Some(1),
+				tx_fee_coin: None,
+			}
+			.encode(),
+			"610801000000000000000300000000000000010100000000",
+		),
+	];
+
+	check_vectors("call", vectors, variant_names::<Call<Test>>());
+}
+
+#[test]
+fn event_encodings_match_golden_vectors() {
+	let vectors: Vec<Vector> = vec![
+		(
+			"CoinCreated",
+			Event::<Test>::CoinCreated {
+				coin_id: 1,
+				symbol: b"TST".to_vec(),
+				name: b"Test Coin".to_vec(),
+				creator: 1,
+				initial_supply: 1_000_000,
+			}
+			.encode(),
+			"00010000000c545354245465737420436f696e010000000000000040420f00000000000000000000000000",
+		),
+		(
+			"Transfer",
+			Event::<Test>::Transfer { coin_id: 1, from: 1, to: 2, amount: 1_000 }.encode(),
+			"010100000001000000000000000200000000000000e8030000000000000000000000000000",
+		),
+		(
+			"Minted",
+			Event::<Test>::Minted { coin_id: 1, to: 2, amount: 500 }.encode(),
+			"02010000000200000000000000f4010000000000000000000000000000",
+		),
+		(
+			"Burned",
+			Event::<Test>::Burned { coin_id: 1, from: 1, amount: 500 }.encode(),
+			"03010000000100000000000000f4010000000000000000000000000000",
+		),
+		(
+			"OwnershipTransferred",
+			Event::<Test>::OwnershipTransferred { coin_id: 1, old_owner: 1, new_owner: 2 }.encode(),
+			"040100000001000000000000000200000000000000",
+		),
+		(
+			"MintPermissionSet",
+			Event::<Test>::MintPermissionSet { coin_id: 1, account: 2, can_mint: true }.encode(),
+			"0501000000020000000000000001",
+		),
+		(
+			"MetadataUpdated",
+			Event::<Test>::MetadataUpdated { coin_id: 1 }.encode(),
+			"0601000000",
+		),
+		(
+			"BurnPermissionSet",
+			Event::<Test>::BurnPermissionSet { coin_id: 1, account: 2, can_burn: false }.encode(),
+			"0701000000020000000000000000",
+		),
+		(
+			"FeeConfigUpdated",
+			Event::<Test>::FeeConfigUpdated {
+				coin_id: 1,
+				transfer_fee: 10,
+				minimum_balance: 5,
+				can_pay_tx_fees: true,
+				call_scope: FeeCallScope::OwnPalletOnly,
+			}
+			.encode(),
+			"08010000000a000000000000000000000000000000050000000000000000000000000000000101",
+		),
+		(
+			"PreferredFeeCoinSet",
+			Event::<Test>::PreferredFeeCoinSet { account: 1, coin_id: Some(1) }.encode(),
+			"0901000000000000000101000000",
+		),
+		(
+			"FeeGuardSet",
+			Event::<Test>::FeeGuardSet {
+				coin_id: 1,
+				guard: Some(FeeChangeGuard { threshold: 100, control: FeeChangeControl::Delay(10) }),
+			}
+			.encode(),
+			"0a010000000164000000000000000000000000000000000a00000000000000",
+		),
+		(
+			"FeeChangeAnnounced",
+			Event::<Test>::FeeChangeAnnounced {
+				coin_id: 1,
+				fee_config: FeeConfig { transfer_fee: 10, minimum_balance: 5, can_pay_tx_fees: false, call_scope: FeeCallScope::Any },
+				enact_after: 20,
+			}
+			.encode(),
+			"0b010000000a0000000000000000000000000000000500000000000000000000000000000000001400000000000000",
+		),
+		(
+			"FeeChangeCancelled",
+			Event::<Test>::FeeChangeCancelled { coin_id: 1 }.encode(),
+			"0c01000000",
+		),
+		(
+			"CoinFrozen",
+			Event::<Test>::CoinFrozen { coin_id: 1 }.encode(),
+			"0d01000000",
+		),
+		(
+			"CoinThawed",
+			Event::<Test>::CoinThawed { coin_id: 1 }.encode(),
+			"0e01000000",
+		),
+		(
+			"CoinManagerSet",
+			Event::<Test>::CoinManagerSet { coin_id: 1, manager: None }.encode(),
+			"0f0100000000",
+		),
+		(
+			"AccrualIndexUpdated",
+			Event::<Test>::AccrualIndexUpdated {
+				coin_id: 1,
+				index: FixedU128::from_u32(2),
+			}
+			.encode(),
+			"10010000000000c84e676dc11b0000000000000000",
+		),
+		(
+			"DecayRateSet",
+			Event::<Test>::DecayRateSet {
+				coin_id: 1,
+				decay: Some(DecayConfig { rate: Perbill::from_parts(1000), period: 100 }),
+			}
+			.encode(),
+			"110100000001e80300006400000000000000",
+		),
+		(
+			"Decayed",
+			Event::<Test>::Decayed { coin_id: 1, who: 2, amount: 3 }.encode(),
+			"1201000000020000000000000003000000000000000000000000000000",
+		),
+		(
+			"RewardRateSet",
+			Event::<Test>::RewardRateSet { coin_id: 1, reward_per_block: 10 }.encode(),
+			"13010000000a000000000000000000000000000000",
+		),
+		(
+			"RewardPoolFunded",
+			Event::<Test>::RewardPoolFunded { coin_id: 1, funder: 1, amount: 1_000 }.encode(),
+			"14010000000100000000000000e8030000000000000000000000000000",
+		),
+		(
+			"Staked",
+			Event::<Test>::Staked { coin_id: 1, who: 1, amount: 100 }.encode(),
+			"1501000000010000000000000064000000000000000000000000000000",
+		),
+		(
+			"Unstaked",
+			Event::<Test>::Unstaked { coin_id: 1, who: 1, amount: 100 }.encode(),
+			"1601000000010000000000000064000000000000000000000000000000",
+		),
+		(
+			"RewardsClaimed",
+			Event::<Test>::RewardsClaimed { coin_id: 1, who: 1, amount: 7 }.encode(),
+			"1701000000010000000000000007000000000000000000000000000000",
+		),
+		(
+			"VoteLocked",
+			Event::<Test>::VoteLocked {
+				coin_id: 1,
+				who: 1,
+				amount: 100,
+				conviction: Conviction::Locked2x,
+				unlock_at: 21,
+			}
+			.encode(),
+			"1801000000010000000000000064000000000000000000000000000000021500000000000000",
+		),
+		(
+			"VoteUnlocked",
+			Event::<Test>::VoteUnlocked { coin_id: 1, who: 1 }.encode(),
+			"19010000000100000000000000",
+		),
+		(
+			"SaleOpened",
+			Event::<Test>::SaleOpened {
+				sale_id: 0,
+				coin_id: 1,
+				owner: 1,
+				amount: 1_000,
+				payment: PaymentAsset::Native,
+				price: FixedU128::from_u32(2),
+				end: 51,
+			}
+			.encode(),
+			"1a00000000010000000100000000000000e8030000000000000000000000000000000000c84e676dc11b00000000000000003300000000000000",
+		),
+		(
+			"SalePurchase",
+			Event::<Test>::SalePurchase { sale_id: 0, buyer: 2, amount: 10, cost: 20 }.encode(),
+			"1b0000000002000000000000000a00000000000000000000000000000014000000000000000000000000000000",
+		),
+		(
+			"SaleProceedsWithdrawn",
+			Event::<Test>::SaleProceedsWithdrawn { sale_id: 0, amount: 20 }.encode(),
+			"1c0000000014000000000000000000000000000000",
+		),
+		(
+			"SaleClosed",
+			Event::<Test>::SaleClosed { sale_id: 0, burned: 990 }.encode(),
+			"1d00000000de030000000000000000000000000000",
+		),
+		(
+			"OrderPlaced",
+			Event::<Test>::OrderPlaced {
+				order_id: 0,
+				maker: 1,
+				sell_coin: 1,
+				sell_amount: 100,
+				buy_coin: 2,
+				price: FixedU128::from_u32(2),
+			}
+			.encode(),
+			"1e0000000001000000000000000100000064000000000000000000000000000000020000000000c84e676dc11b0000000000000000",
+		),
+		(
+			"OrderFilled",
+			Event::<Test>::OrderFilled { order_id: 0, taker: 2, amount: 10, cost: 20 }.encode(),
+			"1f0000000002000000000000000a00000000000000000000000000000014000000000000000000000000000000",
+		),
+		(
+			"OrderCancelled",
+			Event::<Test>::OrderCancelled { order_id: 0, refunded: 90 }.encode(),
+			"20000000005a000000000000000000000000000000",
+		),
+		(
+			"SubscriptionCreated",
+			Event::<Test>::SubscriptionCreated {
+				subscription_id: 0,
+				coin_id: 1,
+				payer: 1,
+				payee: 2,
+				amount: 10,
+				period: 100,
+				count: 12,
+			}
+			.encode(),
+			"210000000001000000010000000000000002000000000000000a00000000000000000000000000000064000000000000000c000000",
+		),
+		(
+			"SubscriptionCharged",
+			Event::<Test>::SubscriptionCharged {
+				subscription_id: 0,
+				periods: 2,
+				amount: 20,
+			}
+			.encode(),
+			"22000000000200000014000000000000000000000000000000",
+		),
+		(
+			"SubscriptionCancelled",
+			Event::<Test>::SubscriptionCancelled { subscription_id: 0 }.encode(),
+			"2300000000",
+		),
+		(
+			"SplitTransferred",
+			Event::<Test>::SplitTransferred {
+				coin_id: 1,
+				from: 1,
+				amount: 100,
+				recipients: 2,
+			}
+			.encode(),
+			"240100000001000000000000006400000000000000000000000000000002000000",
+		),
+		(
+			"SinkBlockedSet",
+			Event::<Test>::SinkBlockedSet { coin_id: 1, who: 2, blocked: true }.encode(),
+			"2501000000020000000000000001",
+		),
+		(
+			"RecoverySet",
+			Event::<Test>::RecoverySet {
+				who: 1,
+				config: Some(RecoveryConfig { recoverer: 2, delay: 10 }),
+			}
+			.encode(),
+			"2601000000000000000102000000000000000a00000000000000",
+		),
+		(
+			"RecoveryAnnounced",
+			Event::<Test>::RecoveryAnnounced { lost: 1, recoverer: 2, executable_at: 11 }.encode(),
+			"27010000000000000002000000000000000b00000000000000",
+		),
+		(
+			"RecoveryCancelled",
+			Event::<Test>::RecoveryCancelled { lost: 1 }.encode(),
+			"280100000000000000",
+		),
+		(
+			"Recovered",
+			Event::<Test>::Recovered { coin_id: 1, lost: 1, recoverer: 2, amount: 1_000 }.encode(),
+			"290100000001000000000000000200000000000000e8030000000000000000000000000000",
+		),
+		(
+			"DormancyConfigured",
+			Event::<Test>::DormancyConfigured {
+				coin_id: 1,
+				config: DormancyConfig { period: 100, grace: 10, beneficiary: 3 },
+			}
+			.encode(),
+			"2a0100000064000000000000000a000000000000000300000000000000",
+		),
+		(
+			"DormancyWarning",
+			Event::<Test>::DormancyWarning { coin_id: 1, who: 2, sweepable_at: 111 }.encode(),
+			"2b0100000002000000000000006f00000000000000",
+		),
+		(
+			"DormantSwept",
+			Event::<Test>::DormantSwept {
+				coin_id: 1,
+				who: 2,
+				beneficiary: 3,
+				amount: 500,
+			}
+			.encode(),
+			"2c0100000002000000000000000300000000000000f4010000000000000000000000000000",
+		),
+		(
+			"MaxHoldersSet",
+			Event::<Test>::MaxHoldersSet { coin_id: 1, max_holders: None }.encode(),
+			"2d0100000000",
+		),
+		(
+			"RoyaltySet",
+			Event::<Test>::RoyaltySet {
+				coin_id: 1,
+				royalty: Some(RoyaltyConfig { bps: 500, beneficiary: 3 }),
+			}
+			.encode(),
+			"2e0100000001f4010300000000000000",
+		),
+		(
+			"RoyaltyExemptSet",
+			Event::<Test>::RoyaltyExemptSet { coin_id: 1, who: 2, exempt: true }.encode(),
+			"2f01000000020000000000000001",
+		),
+		(
+			"RoyaltyPaid",
+			Event::<Test>::RoyaltyPaid { coin_id: 1, from: 1, beneficiary: 3, amount: 10 }.encode(),
+			"3001000000010000000000000003000000000000000a000000000000000000000000000000",
+		),
+		(
+			"MintLockPeriodSet",
+			Event::<Test>::MintLockPeriodSet { coin_id: 1, period: Some(10) }.encode(),
+			"3101000000010a00000000000000",
+		),
+		(
+			"MintLocked",
+			Event::<Test>::MintLocked { coin_id: 1, who: 2, amount: 50, until: 11 }.encode(),
+			"32010000000200000000000000320000000000000000000000000000000b00000000000000",
+		),
+		(
+			"MovedBetweenLabels",
+			Event::<Test>::MovedBetweenLabels {
+				coin_id: 1,
+				who: 1,
+				from: None,
+				to: Some(BoundedVec::truncate_from(b"savings".to_vec())),
+				amount: 600,
+			}
+			.encode(),
+			"3301000000010000000000000000011c736176696e677358020000000000000000000000000000",
+		),
+		(
+			"FaucetSet",
+			Event::<Test>::FaucetSet {
+				coin_id: 1,
+				config: Some(FaucetConfig { amount: 50, cooldown: 10 }),
+			}
+			.encode(),
+			"340100000001320000000000000000000000000000000a00000000000000",
+		),
+		(
+			"Dripped",
+			Event::<Test>::Dripped { coin_id: 1, who: 2, amount: 50 }.encode(),
+			"3501000000020000000000000032000000000000000000000000000000",
+		),
+		(
+			"PalletPermissionGranted",
+			Event::<Test>::PalletPermissionGranted {
+				coin_id: 1,
+				pallet_id: *b"py/bridg",
+				account: 7,
+				mint: true,
+				burn: false,
+			}
+			.encode(),
+			"360100000070792f627269646707000000000000000100",
+		),
+		(
+			"BridgeMintCapSet",
+			Event::<Test>::BridgeMintCapSet { coin_id: 1, bridge: 5, cap: Some(100) }.encode(),
+			"370100000005000000000000000164000000000000000000000000000000",
+		),
+		(
+			"BridgeMinted",
+			Event::<Test>::BridgeMinted { coin_id: 1, bridge: 5, to: 3, amount: 60 }.encode(),
+			"3801000000050000000000000003000000000000003c000000000000000000000000000000",
+		),
+		(
+			"OracleConfigSet",
+			Event::<Test>::OracleConfigSet {
+				coin_id: 1,
+				config: Some(OracleConfig { min_feeds: 2, max_deviation: Perbill::from_parts(100000000), validity: 10 }),
+			}
+			.encode(),
+			"3901000000010200000000e1f5050a00000000000000",
+		),
+		(
+			"PriceFeederSet",
+			Event::<Test>::PriceFeederSet { coin_id: 1, feeder: 2, enabled: true }.encode(),
+			"3a01000000020000000000000001",
+		),
+		(
+			"PriceFed",
+			Event::<Test>::PriceFed {
+				coin_id: 1,
+				feeder: 2,
+				price: FixedU128::from_u32(2),
+			}
+			.encode(),
+			"3b0100000002000000000000000000c84e676dc11b0000000000000000",
+		),
+		(
+			"FeeCoinProposed",
+			Event::<Test>::FeeCoinProposed { coin_id: 1, proposer: 1 }.encode(),
+			"3c010000000100000000000000",
+		),
+		(
+			"FeeCoinAdmitted",
+			Event::<Test>::FeeCoinAdmitted { coin_id: 1 }.encode(),
+			"3d01000000",
+		),
+		(
+			"FeeCoinRevoked",
+			Event::<Test>::FeeCoinRevoked { coin_id: 1 }.encode(),
+			"3e01000000",
+		),
+		(
+			"FeePaymentSuspendedSet",
+			Event::<Test>::FeePaymentSuspendedSet { suspended: true }.encode(),
+			"3f01",
+		),
+		(
+			"FeeShortfall",
+			Event::<Test>::FeeShortfall { who: 2, coin_id: Some(1), amount: 5 }.encode(),
+			"400200000000000000010100000005000000000000000000000000000000",
+		),
+		(
+			"Slashed",
+			Event::<Test>::Slashed { coin_id: 1, who: 1, amount: 500 }.encode(),
+			"41010000000100000000000000f4010000000000000000000000000000",
+		),
+		(
+			"StableConfigSet",
+			Event::<Test>::StableConfigSet {
+				coin_id: 1,
+				config: Some(StableConfig { collateral: PaymentAsset::Coin(2), min_ratio: FixedU128::from_rational(3, 2), liquidation_ratio: FixedU128::from_rational(6, 5), liquidation_penalty: Perbill::from_parts(100000000) }),
+			}
+			.encode(),
+			"42010000000101020000000000167b0d12d114000000000000000000007862a441a710000000000000000000e1f505",
+		),
+		(
+			"CollateralDeposited",
+			Event::<Test>::CollateralDeposited { coin_id: 1, who: 2, amount: 300 }.encode(),
+			"430100000002000000000000002c010000000000000000000000000000",
+		),
+		(
+			"CollateralWithdrawn",
+			Event::<Test>::CollateralWithdrawn { coin_id: 1, who: 2, amount: 100 }.encode(),
+			"4401000000020000000000000064000000000000000000000000000000",
+		),
+		(
+			"StableMinted",
+			Event::<Test>::StableMinted { coin_id: 1, who: 2, amount: 400 }.encode(),
+			"4501000000020000000000000090010000000000000000000000000000",
+		),
+		(
+			"StableRepaid",
+			Event::<Test>::StableRepaid { coin_id: 1, who: 2, amount: 50 }.encode(),
+			"4601000000020000000000000032000000000000000000000000000000",
+		),
+		(
+			"LiquidationStarted",
+			Event::<Test>::LiquidationStarted {
+				auction_id: 0,
+				coin_id: 1,
+				who: 2,
+				keeper: 3,
+				collateral: 300,
+				debt: 400,
+			}
+			.encode(),
+			"470000000001000000020000000000000003000000000000002c01000000000000000000000000000090010000000000000000000000000000",
+		),
+		(
+			"LiquidationSettled",
+			Event::<Test>::LiquidationSettled {
+				auction_id: 0,
+				bidder: 1,
+				collateral: 280,
+				keeper_reward: 2,
+			}
+			.encode(),
+			"480000000001000000000000001801000000000000000000000000000002000000000000000000000000000000",
+		),
+		(
+			"FlashMintFeeSet",
+			Event::<Test>::FlashMintFeeSet {
+				coin_id: 1,
+				fee: Some(Permill::from_parts(10000)),
+			}
+			.encode(),
+			"49010000000110270000",
+		),
+		(
+			"FlashMinted",
+			Event::<Test>::FlashMinted { coin_id: 1, who: 2, amount: 1000, fee: 10 }.encode(),
+			"4a010000000200000000000000e80300000000000000000000000000000a000000000000000000000000000000",
+		),
+		(
+			"Held",
+			Event::<Test>::Held {
+				coin_id: 1,
+				who: 1,
+				reason: TestHoldReason::Escrow,
+				amount: 600,
+			}
+			.encode(),
+			"4b0100000001000000000000000058020000000000000000000000000000",
+		),
+		(
+			"Released",
+			Event::<Test>::Released {
+				coin_id: 1,
+				who: 1,
+				reason: TestHoldReason::Bridge,
+				amount: 100,
+			}
+			.encode(),
+			"4c0100000001000000000000000164000000000000000000000000000000",
+		),
+		(
+			"TransferTicketCreated",
+			Event::<Test>::TransferTicketCreated {
+				ticket_id: 0,
+				coin_id: 1,
+				from: 1,
+				to: 5,
+				amount: 100,
+				expiry: 10,
+			}
+			.encode(),
+			"4d000000000100000001000000000000000500000000000000640000000000000000000000000000000a00000000000000",
+		),
+		(
+			"TransferTicketClaimed",
+			Event::<Test>::TransferTicketClaimed { ticket_id: 0, to: 5, amount: 100 }.encode(),
+			"4e00000000050000000000000064000000000000000000000000000000",
+		),
+		(
+			"TransferTicketRefunded",
+			Event::<Test>::TransferTicketRefunded { ticket_id: 1, to: 1, amount: 200 }.encode(),
+			"4f010000000100000000000000c8000000000000000000000000000000",
+		),
+		(
+			"SupplyCheckpointed",
+			Event::<Test>::SupplyCheckpointed {
+				coin_id: 1,
+				total_supply: 990,
+				holders: 3,
+				fees_burned: 10,
+			}
+			.encode(),
+			"5001000000de030000000000000000000000000000030000000a000000000000000000000000000000",
+		),
+		(
+			"FeeBurned",
+			Event::<Test>::FeeBurned { coin_id: 1, amount: 5 }.encode(),
+			"510100000005000000000000000000000000000000",
+		),
+		(
+			"CleanupReport",
+			Event::<Test>::CleanupReport { scanned: 3, removed: 2 }.encode(),
+			"520300000002000000",
+		),
+		(
+			"TransferPauseSet",
+			Event::<Test>::TransferPauseSet { coin_id: 1, except: Some(vec![3, 5]) }.encode(),
+			"5301000000010803000000000000000500000000000000",
+		),
+		(
+			"LegalDocumentSet",
+			Event::<Test>::LegalDocumentSet {
+				coin_id: 1,
+				hash: Some(BoundedVec::truncate_from(b"QmHash".to_vec())),
+			}
+			.encode(),
+			"54010000000118516d48617368",
+		),
+		(
+			"Approved",
+			Event::<Test>::Approved {
+				coin_id: 0,
+				owner: 1,
+				delegate: 2,
+				amount: 100,
+				expires_at: Some(5),
+			}
+			.encode(),
+			"55000000000100000000000000020000000000000064000000000000000000000000000000010500000000000000",
+		),
+		(
+			"TransferredApproved",
+			Event::<Test>::TransferredApproved {
+				coin_id: 0,
+				owner: 1,
+				delegate: 2,
+				destination: 3,
+				amount: 60,
+			}
+			.encode(),
+			"56000000000100000000000000020000000000000003000000000000003c000000000000000000000000000000",
+		),
+		(
+			"ApprovalCancelled",
+			Event::<Test>::ApprovalCancelled { coin_id: 0, owner: 1, delegate: 2 }.encode(),
+			"570000000001000000000000000200000000000000",
+		),
+		(
+			"ApprovalsInvalidated",
+			Event::<Test>::ApprovalsInvalidated { coin_id: 0, owner: 1, nonce: 1 }.encode(),
+			"5800000000010000000000000001000000",
+		),
+		(
+			"AccountFrozen",
+			Event::<Test>::AccountFrozen { coin_id: 1, who: 2 }.encode(),
+			"59010000000200000000000000",
+		),
+		(
+			"AccountThawed",
+			Event::<Test>::AccountThawed { coin_id: 1, who: 2 }.encode(),
+			"5a010000000200000000000000",
+		),
+		(
+			"CoinDestructionStarted",
+			Event::<Test>::CoinDestructionStarted { coin_id: 1 }.encode(),
+			"5b01000000",
+		),
+		(
+			"CoinAccountsDestroyed",
+			Event::<Test>::CoinAccountsDestroyed {
+				coin_id: 1,
+				removed: 2,
+				complete: false,
+			}
+			.encode(),
+			"5c010000000200000000",
+		),
+		(
+			"CoinDestroyed",
+			Event::<Test>::CoinDestroyed { coin_id: 1, owner: 1, deposit: 10 }.encode(),
+			"5d0100000001000000000000000a000000000000000000000000000000",
+		),
+		(
+			"TeamChanged",
+			Event::<Test>::TeamChanged { coin_id: 1, issuer: 2, admin: 3, freezer: 4 }.encode(),
+			"5e01000000020000000000000003000000000000000400000000000000",
+		),
+		(
+			"BatchTransferred",
+			Event::<Test>::BatchTransferred {
+				coin_id: 1,
+				from: 1,
+				recipients: 2,
+				total: 30,
+			}
+			.encode(),
+			"5f010000000100000000000000020000001e000000000000000000000000000000",
+		),
+		(
+			"OwnershipRenounced",
+			Event::<Test>::OwnershipRenounced { coin_id: 1, old_owner: 1 }.encode(),
+			"60010000000100000000000000",
+		),
+		(
+			"CoinDepositMoved",
+			Event::<Test>::CoinDepositMoved { coin_id: 1, from: 1, to: 2, amount: 10 }.encode(),
+			"6101000000010000000000000002000000000000000a000000000000000000000000000000",
+		),
+		(
+			"ForcedTransfer",
+			Event::<Test>::ForcedTransfer { coin_id: 1, from: 1, to: 2, amount: 10 }.encode(),
+			"6201000000010000000000000002000000000000000a000000000000000000000000000000",
+		),
+		(
+			"ForcedMint",
+			Event::<Test>::ForcedMint { coin_id: 1, to: 2, amount: 10 }.encode(),
+			"630100000002000000000000000a000000000000000000000000000000",
+		),
+		(
+			"ForcedBurn",
+			Event::<Test>::ForcedBurn { coin_id: 1, from: 2, amount: 10 }.encode(),
+			"640100000002000000000000000a000000000000000000000000000000",
+		),
+		(
+			"HolderSnapshotUpdated",
+			Event::<Test>::HolderSnapshotUpdated {
+				coin_id: 1,
+				checkpoints: 2,
+				total: 1000,
+				complete: true,
+			}
+			.encode(),
+			"650100000002000000e803000000000000000000000000000001",
+		),
+		(
+			"MetadataSet",
+			Event::<Test>::MetadataSet {
+				coin_id: 1,
+				symbol: b"NEW".to_vec(),
+				name: b"New Coin".to_vec(),
+				decimals: 6,
+			}
+			.encode(),
+			"66010000000c4e4557204e657720436f696e06",
+		),
+		(
+			"LocalizationSet",
+			Event::<Test>::LocalizationSet {
+				coin_id: 1,
+				locale: b"fr".to_vec(),
+				name: Some(b"Piece".to_vec()),
+				description: None,
+			}
+			.encode(),
+			"67010000000866720114506965636500",
+		),
+		(
+			"ExtendedMetadataSet",
+			Event::<Test>::ExtendedMetadataSet {
+				coin_id: 1,
+				description: Some(b"A coin".to_vec()),
+				logo_uri: None,
+				homepage: Some(b"https://coin.io".to_vec()),
+				external_reference: None,
+			}
+			.encode(),
+			"680100000001184120636f696e00013c68747470733a2f2f636f696e2e696f00",
+		),
+		(
+			"FeeOperatorSet",
+			Event::<Test>::FeeOperatorSet { account: 1, operator: 2, authorized: true }.encode(),
+			"690100000000000000020000000000000001",
+		),
+	];
+
+	check_vectors("event", vectors, variant_names::<Event<Test>>());
+}
@@ -1,10 +1,13 @@
-use frame_support::{ensure, traits::Currency};
+use codec::{Decode, Encode};
+use frame_support::{ensure, traits::{Currency, Get, PalletInfoAccess}};
 use pallet_transaction_payment::OnChargeTransaction;
 use sp_runtime::{
-    traits::{SaturatedConversion, Zero},
+    traits::{SaturatedConversion, Saturating, Zero},
     transaction_validity::{InvalidTransaction, TransactionValidityError},
 };
-use crate::{Balances, CoinMetadata, Config, CoinId, PreferredFeeCoin};
+use crate::{
+    Balances, CoinMetadata, Config, CoinId, FeeCallScope, NegativeImbalance, OnCoinUnbalanced, PreferredFeeCoin,
+};
 
 // Custom OnChargeTransaction implementation for multi-coin fee payments
 pub struct MultiCoinFeeAdapter<T: Config + pallet_transaction_payment::Config> {
@@ -16,7 +19,7 @@ where
     <T::Currency as Currency<T::AccountId>>::Balance: Into<u128>,
 {
     type Balance = <T::Currency as Currency<T::AccountId>>::Balance;
-    type LiquidityInfo = Option<(T::AccountId, Option<CoinId>, u128)>; // Stores (who, coin_id, fee)
+    type LiquidityInfo = Option<(T::AccountId, Option<CoinId>, u128)>; // Stores (who, coin_id, fee in that coin)
 
     fn can_withdraw_fee(
         who: &T::AccountId,
@@ -37,14 +40,21 @@ where
             // Validate the selected coin can pay fees
             let coin_info = CoinMetadata::<T>::get(&coin_id).ok_or(InvalidTransaction::Payment)?;
             ensure!(coin_info.fee_config.can_pay_tx_fees, InvalidTransaction::Payment);
+            ensure!(crate::AdmittedFeeCoins::<T>::contains_key(coin_id), InvalidTransaction::Payment);
+            ensure!(coin_info.status.is_live(), InvalidTransaction::Payment);
+            ensure!(Self::call_in_scope(call, coin_info.fee_config.call_scope), InvalidTransaction::Payment);
 
-            // Check balance
-            let current_balance = Balances::<T>::get(&coin_id, who);
-            ensure!(current_balance >= fee_u128, InvalidTransaction::Payment);
+            // Check balance (stored in shares for rebasing coins, net of pending decay)
+            let current_balance = crate::Pallet::<T>::spendable_shares(coin_id, who);
+            let coin_fee = crate::Pallet::<T>::coin_fee(coin_id, fee_u128);
+            let fee_shares = crate::Pallet::<T>::to_shares(coin_id, coin_fee);
+            let unlocked = current_balance.saturating_sub(crate::Pallet::<T>::locked_shares(coin_id, who));
+            ensure!(unlocked >= fee_shares, InvalidTransaction::Payment);
             Ok(())
         } else {
-            // Fall back to native currency (Balances pallet)
-            let balance = T::Currency::free_balance(who);
+            // Fall back to native currency (Balances pallet), paid by a sponsor if any
+            let payer = Self::native_payer(who, fee);
+            let balance = T::Currency::free_balance(&payer);
             ensure!(balance >= fee, InvalidTransaction::Payment);
             Ok(())
         }
@@ -68,36 +78,38 @@ where
         if let Some(coin_id) = selected_coin {
             let coin_info = CoinMetadata::<T>::get(&coin_id).ok_or(InvalidTransaction::Payment)?;
             ensure!(coin_info.fee_config.can_pay_tx_fees, InvalidTransaction::Payment);
+            ensure!(crate::AdmittedFeeCoins::<T>::contains_key(coin_id), InvalidTransaction::Payment);
+            ensure!(coin_info.status.is_live(), InvalidTransaction::Payment);
+            ensure!(Self::call_in_scope(call, coin_info.fee_config.call_scope), InvalidTransaction::Payment);
 
-            let current_balance = Balances::<T>::get(&coin_id, who);
-            ensure!(current_balance >= fee_u128, InvalidTransaction::Payment);
-
-            // Deduct fee from multicoin balance
-            let new_balance = current_balance
-                .checked_sub(fee_u128)
-                .ok_or(InvalidTransaction::Payment)?;
-            Balances::<T>::insert(&coin_id, who, new_balance);
+            // Fees are priced in native units; convert at the coin's oracle price and multiplier.
+            // The fee is held in escrow and only burned once the final fee is known.
+            let coin_fee = crate::Pallet::<T>::coin_fee(coin_id, fee_u128);
+            let mut fee = Self::debit_coin_fee(coin_id, who, coin_fee)?;
+            let escrow = crate::Pallet::<T>::fee_escrow_account();
+            let escrowed = Balances::<T>::get(&coin_id, &escrow);
+            crate::Pallet::<T>::set_balance(coin_id, &escrow, escrowed.saturating_add(fee.take()));
 
-            // Burn the fee (reduce total supply)
-            let current_supply = crate::TotalSupply::<T>::get(&coin_id);
-            let new_supply = current_supply.saturating_sub(fee_u128);
-            crate::TotalSupply::<T>::insert(&coin_id, new_supply);
-
-            Ok(Some((who.clone(), Some(coin_id), fee_u128)))
+            Ok(Some((who.clone(), Some(coin_id), coin_fee)))
         } else {
-            // Use native currency (Balances pallet)
-            let balance = T::Currency::free_balance(who);
+            // Use native currency (Balances pallet), paid by a sponsor if any
+            let payer = Self::native_payer(who, fee);
+            let balance = T::Currency::free_balance(&payer);
             ensure!(balance >= fee, InvalidTransaction::Payment);
             
             T::Currency::withdraw(
-                who,
+                &payer,
                 fee,
                 frame_support::traits::WithdrawReasons::FEE,
-                frame_support::traits::ExistenceRequirement::KeepAlive,
+                T::NativeFeeExistence::get(),
             )
             .map_err(|_| InvalidTransaction::Payment)?;
+            if payer != *who {
+                T::FeeSponsor::on_sponsored(who);
+            }
             
-            Ok(Some((who.clone(), None, fee_u128)))
+            // Refunds go back to whoever paid
+            Ok(Some((payer, None, fee_u128)))
         }
     }
 
@@ -110,28 +122,68 @@ where
         liquidity_info: Self::LiquidityInfo,
     ) -> Result<(), TransactionValidityError> {
         if let Some((who, coin_id, paid_fee)) = liquidity_info {
-            let corrected_fee_u128: u128 = corrected_fee.saturated_into();
-            if corrected_fee_u128 < paid_fee {
-                let refund = paid_fee - corrected_fee_u128;
-                if let Some(coin_id) = coin_id {
-                    // Refund to multicoin balance
-                    let current_balance = Balances::<T>::get(&coin_id, &who);
-                    let new_balance = current_balance
-                        .checked_add(refund)
-                        .ok_or(InvalidTransaction::Payment)?;
-                    Balances::<T>::insert(&coin_id, &who, new_balance);
-
-                    // Increase total supply (un-burn the excess fee)
-                    let current_supply = crate::TotalSupply::<T>::get(&coin_id);
-                    let new_supply = current_supply
-                        .checked_add(refund)
-                        .ok_or(InvalidTransaction::Payment)?;
-                    crate::TotalSupply::<T>::insert(&coin_id, new_supply);
-                } else {
-                    // Refund to native currency
-                    let refund_balance: <T::Currency as Currency<T::AccountId>>::Balance = 
-                        refund.try_into().map_err(|_| InvalidTransaction::Payment)?;
-                    T::Currency::deposit_creating(&who, refund_balance);
+            // A call that destroyed its own fee coin swept the escrowed fee with it
+            if coin_id.map_or(false, |coin_id| !CoinMetadata::<T>::contains_key(coin_id)) {
+                return Ok(());
+            }
+            crate::Pallet::<T>::note_fee_paid(coin_id, corrected_fee.saturated_into());
+
+            // `paid_fee` is in units of the coin that paid it
+            let corrected_fee_u128: u128 = match coin_id {
+                Some(coin_id) => crate::Pallet::<T>::coin_fee(coin_id, corrected_fee.saturated_into()),
+                None => corrected_fee.saturated_into(),
+            };
+            let refund = paid_fee.saturating_sub(corrected_fee_u128);
+            let extra = corrected_fee_u128.saturating_sub(paid_fee);
+
+            if let Some(coin_id) = coin_id {
+                // Release the escrowed fee, or what `destroy_accounts` left of it
+                let escrow = crate::Pallet::<T>::fee_escrow_account();
+                let escrowed = Balances::<T>::get(&coin_id, &escrow);
+                let paid_shares = crate::Pallet::<T>::to_shares(coin_id, paid_fee).min(escrowed);
+                crate::Pallet::<T>::set_balance(coin_id, &escrow, escrowed.saturating_sub(paid_shares));
+                let fee = NegativeImbalance::<T>::new(coin_id, paid_shares);
+
+                // Refund the excess; a refund that can't be paid back goes with the fee
+                let (refund, mut fee) = fee.split(crate::Pallet::<T>::to_shares(coin_id, refund));
+                if let Err(unpaid) = crate::Pallet::<T>::resolve(&who, refund) {
+                    fee = fee.merge(unpaid).unwrap_or_else(|(fee, _)| fee);
+                }
+
+                // Charge an upward correction, or record it if the payer can't cover it
+                if extra > 0 {
+                    match Self::debit_coin_fee(coin_id, &who, extra) {
+                        Ok(extra) => fee = fee.merge(extra).unwrap_or_else(|(fee, _)| fee),
+                        Err(_) => crate::Pallet::<T>::deposit_event(crate::Event::FeeShortfall {
+                            who,
+                            coin_id: Some(coin_id),
+                            amount: extra,
+                        }),
+                    }
+                }
+                T::OnCoinFee::on_unbalanced(fee);
+            } else if refund > 0 {
+                // Refund to native currency
+                let refund_balance: <T::Currency as Currency<T::AccountId>>::Balance = 
+                    refund.try_into().map_err(|_| InvalidTransaction::Payment)?;
+                T::Currency::deposit_creating(&who, refund_balance);
+            } else if extra > 0 {
+                // Charge the difference, or record it if the payer can't cover it
+                let charged = extra
+                    .try_into()
+                    .ok()
+                    .and_then(|extra| {
+                        T::Currency::withdraw(
+                            &who,
+                            extra,
+                            frame_support::traits::WithdrawReasons::FEE,
+                            T::NativeFeeExistence::get(),
+                        )
+                        .ok()
+                    })
+                    .is_some();
+                if !charged {
+                    crate::Pallet::<T>::deposit_event(crate::Event::FeeShortfall { who, coin_id: None, amount: extra });
                 }
             }
         }
@@ -145,81 +197,18 @@ where
 {
     /// Determine which coin to use for fee payment by extracting from RuntimeCall
     fn determine_fee_coin(call: &T::RuntimeCall, who: &T::AccountId) -> Option<CoinId> {
-        // CRITICAL: Pattern matching RuntimeCall from within a pallet is not directly possible
-        // because RuntimeCall is generated by construct_runtime! and is opaque to individual pallets.
-        
-        // WORKAROUND: Use SCALE encoding/decoding to inspect the call
-        use codec::{Decode, Encode};
-        
-        // Encode the call to bytes
-        let encoded = call.encode();
-        
-        // The encoded format is: [pallet_index, call_index, ...params]
-        // We need to check if this is one of our multi-coin calls
-        
-        if encoded.len() >= 2 {
-            // Check pallet and call indices (these depend on your runtime configuration)
-            // You'll need to find the correct indices for your pallet in the runtime
-            
-            // Attempt to decode as our pallet calls
-            // This is fragile and runtime-specific!
-            
-            // For transfer_with_immediate_fee_coin (call_index = 13)
-            if let Ok(call_data) = <crate::Call<T> as Decode>::decode(&mut &encoded[1..]) {
-                match call_data {
-                    crate::Call::create_coin { tx_fee_coin, .. } => {
-                        if tx_fee_coin.is_some() {
-                            return tx_fee_coin;
-                        }
-                    },
-                    crate::Call::mint { tx_fee_coin, .. } => {
-                        if tx_fee_coin.is_some() {
-                            return tx_fee_coin;
-                        }
-                    },
-                    crate::Call::burn { tx_fee_coin, .. } => {
-                        if tx_fee_coin.is_some() {
-                            return tx_fee_coin;
-                        }
-                    },
-                    crate::Call::transfer { tx_fee_coin, .. } => {
-                        if tx_fee_coin.is_some() {
-                            return tx_fee_coin;
-                        }
-                    },
-                    crate::Call::set_fee_config { tx_fee_coin, .. } => {
-                        if tx_fee_coin.is_some() {
-                            return tx_fee_coin;
-                        }
-                    },
-                    crate::Call::transfer_ownership { tx_fee_coin, .. } => {
-                        if tx_fee_coin.is_some() {
-                            return tx_fee_coin;
-                        }
-                    },
-                    crate::Call::set_burn_permission { tx_fee_coin, .. } => {
-                        if tx_fee_coin.is_some() {
-                            return tx_fee_coin;
-                        }
-                    },
-                    crate::Call::set_mint_permission { tx_fee_coin, .. } => {
-                        if tx_fee_coin.is_some() {
-                            return tx_fee_coin;
-                        }
-                    },
-                    crate::Call::set_preferred_fee_coin { tx_fee_coin, .. } => {
-                        if tx_fee_coin.is_some() {
-                            return tx_fee_coin;
-                        }
-                    },
-                    crate::Call::call_with_fee_coin { tx_fee_coin, .. } => {
-                        if tx_fee_coin.is_some() {
-                            return tx_fee_coin;
-                        }
-                    },
-                    _ => {}
-                }
-            }
+        // Emergency switch: everyone pays in the native currency
+        if crate::FeePaymentSuspended::<T>::get() {
+            return None;
+        }
+
+        if let Some(coin_id) = Self::as_pallet_call(call).and_then(|call_data| call_data.fee_coin()) {
+            return Some(coin_id);
+        }
+
+        // Calls of other pallets may carry a fee coin too, e.g. bridge requests
+        if let Some(coin_id) = T::ExternalFeeCoin::fee_coin(call) {
+            return Some(coin_id);
         }
         
         // Fall back to session fee coin
@@ -227,16 +216,205 @@ where
             return Some(session_coin);
         }*/
         
-        // Fall back to preferred fee coin
-        PreferredFeeCoin::<T>::get(who)
+        // Fall back to preferred fee coin, unless its call scope excludes this call
+        // or the coin was destroyed since
+        PreferredFeeCoin::<T>::get(who).filter(|coin_id| {
+            CoinMetadata::<T>::get(coin_id)
+                .map_or(false, |coin_info| Self::call_in_scope(call, coin_info.fee_config.call_scope))
+        })
+    }
+
+    /// Deduct a fee of `amount` from `who`'s balance of `coin_id`; the caller
+    /// decides where the returned imbalance goes
+    fn debit_coin_fee(
+        coin_id: CoinId,
+        who: &T::AccountId,
+        amount: u128,
+    ) -> Result<NegativeImbalance<T>, TransactionValidityError> {
+        // Balances are stored in shares for rebasing coins
+        let fee_shares = crate::Pallet::<T>::to_shares(coin_id, amount);
+        crate::Pallet::<T>::withdraw(coin_id, who, fee_shares).map_err(|_| InvalidTransaction::Payment.into())
+    }
+
+    /// Account paying a native `fee` for `who`: its sponsor if it has one that can
+    /// afford the fee without being reaped, otherwise `who` itself
+    fn native_payer(who: &T::AccountId, fee: <T::Currency as Currency<T::AccountId>>::Balance) -> T::AccountId {
+        T::FeeSponsor::sponsor_of(who)
+            .filter(|sponsor| {
+                T::Currency::free_balance(sponsor).saturating_sub(T::Currency::minimum_balance()) >= fee
+            })
+            .unwrap_or_else(|| who.clone())
+    }
+
+    /// Decode `call` as a call of this pallet, if it is one
+    fn as_pallet_call(call: &T::RuntimeCall) -> Option<crate::Call<T>> {
+        // CRITICAL: Pattern matching RuntimeCall from within a pallet is not directly possible
+        // because RuntimeCall is generated by construct_runtime! and is opaque to individual pallets.
+        
+        // WORKAROUND: Use SCALE encoding/decoding to inspect the call.
+        // The encoded format is: [pallet_index, call_index, ...params]
+        let encoded = call.encode();
+        let pallet_index = <crate::Pallet<T> as PalletInfoAccess>::index();
+        if encoded.len() < 2 || encoded[0] as usize != pallet_index {
+            return None;
+        }
+        
+        <crate::Call<T> as Decode>::decode(&mut &encoded[1..]).ok()
+    }
+
+    /// Whether a coin with the given call scope may pay the fee for `call`
+    fn call_in_scope(call: &T::RuntimeCall, scope: FeeCallScope) -> bool {
+        match scope {
+            FeeCallScope::Any => true,
+            FeeCallScope::OwnPalletOnly => match Self::as_pallet_call(call) {
+                // The wrappers dispatch an arbitrary call, so judge the inner one
+                Some(crate::Call::call_with_fee_coin { call: inner, .. }) |
+                Some(crate::Call::flash_mint { call: inner, .. }) => Self::call_in_scope(&inner, scope),
+                Some(_) => true,
+                None => false,
+            },
+        }
     }
 }
 
+/// Fee coin selection for calls of other pallets that carry their own `tx_fee_coin`
+pub trait ExternalFeeCoin<Call> {
+    /// The fee coin `call` asks to pay with, if it is a call this source knows
+    fn fee_coin(call: &Call) -> Option<CoinId>;
+}
+
+impl<Call> ExternalFeeCoin<Call> for () {
+    fn fee_coin(_call: &Call) -> Option<CoinId> {
+        None
+    }
+}
+
+/// Third party paying native transaction fees on behalf of some accounts
+pub trait FeeSponsor<AccountId> {
+    /// Account that pays `who`'s next native fee, if `who` is sponsored
+    fn sponsor_of(who: &AccountId) -> Option<AccountId>;
+
+    /// Record that the sponsor of `who` paid one fee
+    fn on_sponsored(who: &AccountId);
+}
+
+impl<AccountId> FeeSponsor<AccountId> for () {
+    fn sponsor_of(_who: &AccountId) -> Option<AccountId> {
+        None
+    }
+
+    fn on_sponsored(_who: &AccountId) {}
+}
+
 /// Helper trait for calls that specify a fee coin
 pub trait CallWithFeeCoin<T: Config> {
     fn fee_coin(&self) -> Option<CoinId>;
 }
 
-// You would implement this for your runtime calls that support fee coin selection
-// This is a placeholder - actual implementation depends on your call structure
+impl<T: Config> CallWithFeeCoin<T> for crate::Call<T> {
+    /// The `tx_fee_coin` argument of the call, if it carries one
+    fn fee_coin(&self) -> Option<CoinId> {
+        match self {
+            crate::Call::create_coin { tx_fee_coin, .. } |
+            crate::Call::transfer { tx_fee_coin, .. } |
+            crate::Call::mint { tx_fee_coin, .. } |
+            crate::Call::burn { tx_fee_coin, .. } |
+            crate::Call::transfer_ownership { tx_fee_coin, .. } |
+            crate::Call::set_mint_permission { tx_fee_coin, .. } |
+            crate::Call::set_burn_permission { tx_fee_coin, .. } |
+            crate::Call::set_fee_config { tx_fee_coin, .. } |
+            crate::Call::set_preferred_fee_coin { tx_fee_coin, .. } |
+            crate::Call::call_with_fee_coin { tx_fee_coin, .. } |
+            crate::Call::set_fee_guard { tx_fee_coin, .. } |
+            crate::Call::approve_fee_change { tx_fee_coin, .. } |
+            crate::Call::enact_fee_change { tx_fee_coin, .. } |
+            crate::Call::cancel_fee_change { tx_fee_coin, .. } |
+            crate::Call::freeze_coin { tx_fee_coin, .. } |
+            crate::Call::thaw_coin { tx_fee_coin, .. } |
+            crate::Call::force_transfer { tx_fee_coin, .. } |
+            crate::Call::set_coin_manager { tx_fee_coin, .. } |
+            crate::Call::set_accrual_index { tx_fee_coin, .. } |
+            crate::Call::set_decay_rate { tx_fee_coin, .. } |
+            crate::Call::set_reward_rate { tx_fee_coin, .. } |
+            crate::Call::fund_rewards { tx_fee_coin, .. } |
+            crate::Call::stake { tx_fee_coin, .. } |
+            crate::Call::unstake { tx_fee_coin, .. } |
+            crate::Call::claim_rewards { tx_fee_coin, .. } |
+            crate::Call::lock_for_vote { tx_fee_coin, .. } |
+            crate::Call::unlock_vote { tx_fee_coin, .. } |
+            crate::Call::open_sale { tx_fee_coin, .. } |
+            crate::Call::buy { tx_fee_coin, .. } |
+            crate::Call::withdraw_proceeds { tx_fee_coin, .. } |
+            crate::Call::close_sale { tx_fee_coin, .. } |
+            crate::Call::place_order { tx_fee_coin, .. } |
+            crate::Call::fill_order { tx_fee_coin, .. } |
+            crate::Call::cancel_order { tx_fee_coin, .. } |
+            crate::Call::create_subscription { tx_fee_coin, .. } |
+            crate::Call::claim_due { tx_fee_coin, .. } |
+            crate::Call::cancel_subscription { tx_fee_coin, .. } |
+            crate::Call::transfer_split { tx_fee_coin, .. } |
+            crate::Call::set_sink_blocked { tx_fee_coin, .. } |
+            crate::Call::set_recovery { tx_fee_coin, .. } |
+            crate::Call::announce_recovery { tx_fee_coin, .. } |
+            crate::Call::cancel_recovery { tx_fee_coin, .. } |
+            crate::Call::recover_balance { tx_fee_coin, .. } |
+            crate::Call::warn_dormant { tx_fee_coin, .. } |
+            crate::Call::sweep_dormant { tx_fee_coin, .. } |
+            crate::Call::set_max_holders { tx_fee_coin, .. } |
+            crate::Call::set_royalty { tx_fee_coin, .. } |
+            crate::Call::set_royalty_exempt { tx_fee_coin, .. } |
+            crate::Call::set_mint_lock_period { tx_fee_coin, .. } |
+            crate::Call::move_between_labels { tx_fee_coin, .. } |
+            crate::Call::set_faucet { tx_fee_coin, .. } |
+            crate::Call::drip { tx_fee_coin, .. } |
+            crate::Call::grant_pallet_permission { tx_fee_coin, .. } |
+            crate::Call::set_bridge_mint_cap { tx_fee_coin, .. } |
+            crate::Call::set_oracle_config { tx_fee_coin, .. } |
+            crate::Call::set_price_feeder { tx_fee_coin, .. } |
+            crate::Call::feed_price { tx_fee_coin, .. } |
+            crate::Call::propose_fee_coin { tx_fee_coin, .. } |
+            crate::Call::approve_fee_coin { tx_fee_coin, .. } |
+            crate::Call::revoke_fee_coin { tx_fee_coin, .. } |
+            crate::Call::set_fee_payment_suspended { tx_fee_coin, .. } |
+            crate::Call::set_stable_config { tx_fee_coin, .. } |
+            crate::Call::deposit_collateral { tx_fee_coin, .. } |
+            crate::Call::withdraw_collateral { tx_fee_coin, .. } |
+            crate::Call::mint_stable { tx_fee_coin, .. } |
+            crate::Call::repay_stable { tx_fee_coin, .. } |
+            crate::Call::liquidate { tx_fee_coin, .. } |
+            crate::Call::bid_liquidation { tx_fee_coin, .. } |
+            crate::Call::set_flash_mint_fee { tx_fee_coin, .. } |
+            crate::Call::flash_mint { tx_fee_coin, .. } |
+            crate::Call::create_transfer_ticket { tx_fee_coin, .. } |
+            crate::Call::claim_ticket { tx_fee_coin, .. } |
+            crate::Call::refund_ticket { tx_fee_coin, .. } |
+            crate::Call::checkpoint_supply { tx_fee_coin, .. } |
+            crate::Call::set_transfer_pause { tx_fee_coin, .. } |
+            crate::Call::set_legal_document { tx_fee_coin, .. } |
+            crate::Call::approve { tx_fee_coin, .. } |
+            crate::Call::transfer_from { tx_fee_coin, .. } |
+            crate::Call::cancel_approval { tx_fee_coin, .. } |
+            crate::Call::invalidate_approvals { tx_fee_coin, .. } |
+            crate::Call::freeze_account { tx_fee_coin, .. } |
+            crate::Call::thaw_account { tx_fee_coin, .. } |
+            crate::Call::start_destroy { tx_fee_coin, .. } |
+            crate::Call::destroy_accounts { tx_fee_coin, .. } |
+            crate::Call::finish_destroy { tx_fee_coin, .. } |
+            crate::Call::set_team { tx_fee_coin, .. } |
+            crate::Call::transfer_batch { tx_fee_coin, .. } |
+            crate::Call::renounce_ownership { tx_fee_coin, .. } |
+            crate::Call::transfer_keep_alive { tx_fee_coin, .. } |
+            crate::Call::transfer_all { tx_fee_coin, .. } |
+            crate::Call::force_mint { tx_fee_coin, .. } |
+            crate::Call::force_burn { tx_fee_coin, .. } |
+            crate::Call::checkpoint_holders { tx_fee_coin, .. } |
+            crate::Call::set_metadata { tx_fee_coin, .. } |
+            crate::Call::set_localization { tx_fee_coin, .. } |
+            crate::Call::set_extended_metadata { tx_fee_coin, .. } |
+            crate::Call::set_fee_operator { tx_fee_coin, .. } |
+            crate::Call::set_preferred_fee_coin_for { tx_fee_coin, .. } => *tx_fee_coin,
+            _ => None,
+        }
+    }
+}
 
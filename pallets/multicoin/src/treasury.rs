@@ -0,0 +1,43 @@
+//! Paying out of an account in any coin, for treasury spends and bounty payouts.
+//!
+//! `PayFromAccount` implements `frame_support`'s `Pay` with the coin as asset kind,
+//! so it can serve as the `Paymaster` of `pallet-treasury` (and bounties built on it)
+//! and governance can approve spends denominated in any multicoin coin.
+
+use crate::{CoinId, Config, Pallet};
+use frame_support::traits::{
+	tokens::{Pay, PaymentStatus},
+	TypedGet,
+};
+use sp_runtime::DispatchError;
+use sp_std::marker::PhantomData;
+
+/// Pays coins out of the account `A`, e.g. the treasury account.
+///
+/// Payments are plain transfers that either happen in full or fail, so there is
+/// nothing to track afterwards: every payment reports as successful.
+pub struct PayFromAccount<T, A>(PhantomData<(T, A)>);
+
+impl<T: Config, A: TypedGet<Type = T::AccountId>> Pay for PayFromAccount<T, A> {
+	type Balance = u128;
+	type Beneficiary = T::AccountId;
+	type AssetKind = CoinId;
+	type Id = ();
+	type Error = DispatchError;
+
+	fn pay(who: &Self::Beneficiary, asset_kind: Self::AssetKind, amount: Self::Balance) -> Result<Self::Id, Self::Error> {
+		Pallet::<T>::do_transfer(asset_kind, &A::get(), who, amount)
+	}
+
+	fn check_payment(_id: Self::Id) -> PaymentStatus {
+		PaymentStatus::Success
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn ensure_successful(_who: &Self::Beneficiary, asset_kind: Self::AssetKind, amount: Self::Balance) {
+		let _ = Pallet::<T>::do_mint(asset_kind, &A::get(), amount);
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn ensure_concluded(_id: Self::Id) {}
+}
@@ -1,155 +1,886 @@
-//! Types used by the multi-coin pallet.
-
-use codec::{Decode, Encode, MaxEncodedLen};
-use scale_info::TypeInfo;
-use sp_runtime::RuntimeDebug;
-use sp_std::vec::Vec;
-
-/// Type alias for coin identifiers
-pub type CoinId = u32;
-
-/// Information about a coin
-#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-pub struct CoinInfo<Symbol, Name, AccountId, Balance, FeeConfig> {
-	/// The coin symbol (e.g., "BTC", "ETH")
-	pub symbol: Symbol,
-	/// The coin name (e.g., "Bitcoin", "Ethereum") 
-	pub name: Name,
-	/// Number of decimal places
-	pub decimals: u8,
-	/// The account that owns this coin
-	pub owner: AccountId,
-	/// Deposit paid for creating this coin
-	pub deposit: Balance,
-	pub fee_config: FeeConfig, // New: Add fee configuration
-}
-
-/// Coin creation parameters
-#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
-pub struct CreateCoinParams {
-	/// The coin symbol
-	pub symbol: Vec<u8>,
-	/// The coin name
-	pub name: Vec<u8>,
-	/// Number of decimal places
-	pub decimals: u8,
-	/// Initial supply to mint to creator
-	pub initial_supply: u128,
-}
-
-/// Transfer parameters
-#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
-pub struct TransferParams<AccountId> {
-	/// The coin to transfer
-	pub coin_id: CoinId,
-	/// The recipient
-	pub to: AccountId,
-	/// The amount to transfer
-	pub amount: u128,
-}
-
-/// Mint parameters
-#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
-pub struct MintParams<AccountId> {
-	/// The coin to mint
-	pub coin_id: CoinId,
-	/// The account to mint to
-	pub to: AccountId,
-	/// The amount to mint
-	pub amount: u128,
-}
-
-/// Burn parameters
-#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
-pub struct BurnParams {
-	/// The coin to burn
-	pub coin_id: CoinId,
-	/// The amount to burn
-	pub amount: u128,
-}
-
-/// Balance information for an account
-#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Default)]
-pub struct AccountBalance {
-	/// Free balance
-	pub free: u128,
-	/// Reserved balance
-	pub reserved: u128,
-	/// Frozen balance
-	pub frozen: u128,
-}
-
-impl AccountBalance {
-	/// Get the total balance (free + reserved)
-	pub fn total(&self) -> u128 {
-		self.free.saturating_add(self.reserved)
-	}
-
-	/// Get the usable balance (free - frozen)
-	pub fn usable(&self) -> u128 {
-		self.free.saturating_sub(self.frozen)
-	}
-}
-
-/// Coin statistics
-#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Default)]
-pub struct CoinStats {
-	/// Total supply
-	pub total_supply: u128,
-	/// Number of holders
-	pub holders: u32,
-	/// Number of transfers
-	pub transfers: u64,
-	/// Total minted
-	pub total_minted: u128,
-	/// Total burned
-	pub total_burned: u128,
-}
-
-/// Role permissions for a coin
-#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Default)]
-pub struct CoinPermissions {
-	/// Can mint new coins
-	pub can_mint: bool,
-	/// Can burn coins
-	pub can_burn: bool,
-	/// Can modify metadata
-	pub can_modify_metadata: bool,
-	/// Can transfer ownership
-	pub can_transfer_ownership: bool,
-}
-
-/// Fee configuration for a coin
-#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
-pub struct FeeConfig {
-	/// Transfer fee per transaction
-	pub transfer_fee: u128,
-	/// Minimum balance required
-	pub minimum_balance: u128,
-	/// Whether this coin can be used to pay transaction fees
-	pub can_pay_tx_fees: bool,
-}
-
-#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Default)]
-pub struct TransferFeeConfig {
-    /// Fixed fee per transfer
-    pub fixed_fee: u128,
-    /// Percentage fee (in basis points, 10000 = 100%)
-    pub percentage_fee: u16,
-    /// Minimum fee
-    pub minimum_fee: u128,
-    /// Maximum fee
-    pub maximum_fee: u128,
-}
-
-// Define a transaction extension for coin selection
-#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
-pub struct CoinSelection {
-    pub coin_id: CoinId,
-}
-
-/*#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
-pub struct SelectFeeCoin<Balance> {
-    pub tip: Balance,  // Tip for priority (can be Compact<Balance> if needed)
-    pub coin_id: Option<CoinId>,  // User-selected coin for fees; None = fallback to Balances
-}*/
+//! Types used by the multi-coin pallet.
+
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::{dispatch::DispatchResult, weights::Weight, PalletId};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{AccountIdConversion, ConstU32},
+	BoundedVec, FixedU128, Perbill, RuntimeDebug,
+};
+use sp_std::vec::Vec;
+
+/// Type alias for coin identifiers
+pub type CoinId = u32;
+
+/// Sub-account of `pallet_id` holding the pots of `coin_id` (fee treasury, sale
+/// escrow, dividend pool, ...).
+///
+/// It encodes as `modl ++ pallet_id ++ "coin" ++ coin_id`. Distinct coins get
+/// distinct accounts whenever `AccountId` is at least 20 bytes long, and no
+/// account derived from a key pair can equal one.
+pub fn coin_sub_account<AccountId: Encode + Decode>(pallet_id: PalletId, coin_id: CoinId) -> AccountId {
+	pallet_id.into_sub_account_truncating((*b"coin", coin_id))
+}
+
+/// Information about a coin
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CoinInfo<Symbol, Name, AccountId, Balance, FeeConfig> {
+	/// The coin symbol (e.g., "BTC", "ETH")
+	pub symbol: Symbol,
+	/// The coin name (e.g., "Bitcoin", "Ethereum") 
+	pub name: Name,
+	/// Number of decimal places
+	pub decimals: u8,
+	/// The account that owns this coin
+	pub owner: AccountId,
+	/// Account that may mint the coin, besides accounts granted a mint permission
+	pub issuer: AccountId,
+	/// Account that may burn its own coins and grant mint and burn permissions
+	pub admin: AccountId,
+	/// Account that may freeze and thaw the coin and its holders
+	pub freezer: AccountId,
+	/// Deposit paid for creating this coin
+	pub deposit: Balance,
+	pub fee_config: FeeConfig, // New: Add fee configuration
+	/// Lifecycle status of the coin
+	pub status: CoinStatus,
+}
+
+/// Lifecycle status of a coin
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+pub enum CoinStatus {
+	/// The coin is fully operational
+	#[default]
+	Live,
+	/// Transfers, mints and burns are halted; admin operations remain available
+	Frozen,
+	/// The coin is being torn down; no operation other than destruction is allowed
+	Destroying,
+}
+
+impl CoinStatus {
+	/// Whether balances of the coin may move
+	pub fn is_live(&self) -> bool {
+		matches!(self, CoinStatus::Live)
+	}
+
+	/// Whether the coin is being destroyed
+	pub fn is_destroying(&self) -> bool {
+		matches!(self, CoinStatus::Destroying)
+	}
+}
+
+/// Coin creation parameters
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct CreateCoinParams {
+	/// The coin symbol
+	pub symbol: Vec<u8>,
+	/// The coin name
+	pub name: Vec<u8>,
+	/// Number of decimal places
+	pub decimals: u8,
+	/// Initial supply to mint to creator
+	pub initial_supply: u128,
+}
+
+/// Transfer parameters
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct TransferParams<AccountId> {
+	/// The coin to transfer
+	pub coin_id: CoinId,
+	/// The recipient
+	pub to: AccountId,
+	/// The amount to transfer
+	pub amount: u128,
+}
+
+/// Mint parameters
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct MintParams<AccountId> {
+	/// The coin to mint
+	pub coin_id: CoinId,
+	/// The account to mint to
+	pub to: AccountId,
+	/// The amount to mint
+	pub amount: u128,
+}
+
+/// Burn parameters
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct BurnParams {
+	/// The coin to burn
+	pub coin_id: CoinId,
+	/// The amount to burn
+	pub amount: u128,
+}
+
+/// Balance information for an account
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Default)]
+pub struct AccountBalance {
+	/// Free balance
+	pub free: u128,
+	/// Reserved balance
+	pub reserved: u128,
+	/// Frozen balance
+	pub frozen: u128,
+}
+
+impl AccountBalance {
+	/// Get the total balance (free + reserved)
+	pub fn total(&self) -> u128 {
+		self.free.saturating_add(self.reserved)
+	}
+
+	/// Get the usable balance (free - frozen)
+	pub fn usable(&self) -> u128 {
+		self.free.saturating_sub(self.frozen)
+	}
+}
+
+/// Coin statistics
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Default)]
+pub struct CoinStats {
+	/// Total supply
+	pub total_supply: u128,
+	/// Number of holders
+	pub holders: u32,
+	/// Number of transfers
+	pub transfers: u64,
+	/// Total minted
+	pub total_minted: u128,
+	/// Total burned
+	pub total_burned: u128,
+}
+
+/// What an account may do with a coin, through its roles and grants; see
+/// `Pallet::permissions_of`
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Default)]
+pub struct CoinPermissions {
+	/// Can mint new coins
+	pub can_mint: bool,
+	/// Can burn coins
+	pub can_burn: bool,
+	/// Can modify metadata
+	pub can_modify_metadata: bool,
+	/// Can transfer ownership
+	pub can_transfer_ownership: bool,
+}
+
+/// Fee configuration for a coin
+#[derive(
+	Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default,
+)]
+pub struct FeeConfig {
+	/// Transfer fee per transaction
+	pub transfer_fee: u128,
+	/// Minimum balance required
+	pub minimum_balance: u128,
+	/// Whether this coin can be used to pay transaction fees
+	pub can_pay_tx_fees: bool,
+	/// Which calls this coin may pay transaction fees for
+	pub call_scope: FeeCallScope,
+}
+
+/// Calls a coin may be used to pay transaction fees for
+#[derive(
+	Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+	Default,
+)]
+pub enum FeeCallScope {
+	/// Any call in the runtime
+	#[default]
+	Any,
+	/// Only calls of the multi-coin pallet
+	OwnPalletOnly,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Default)]
+pub struct TransferFeeConfig {
+    /// Fixed fee per transfer
+    pub fixed_fee: u128,
+    /// Percentage fee (in basis points, 10000 = 100%)
+    pub percentage_fee: u16,
+    /// Minimum fee
+    pub minimum_fee: u128,
+    /// Maximum fee
+    pub maximum_fee: u128,
+}
+
+// Define a transaction extension for coin selection
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct CoinSelection {
+    pub coin_id: CoinId,
+}
+
+/*#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct SelectFeeCoin<Balance> {
+    pub tip: Balance,  // Tip for priority (can be Compact<Balance> if needed)
+    pub coin_id: Option<CoinId>,  // User-selected coin for fees; None = fallback to Balances
+}*/
+
+/// How sensitive fee configuration changes must be confirmed
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum FeeChangeControl<AccountId, BlockNumber> {
+	/// Sensitive changes are announced and may be enacted after this many blocks
+	Delay(BlockNumber),
+	/// Sensitive changes must be approved by this second account
+	Approver(AccountId),
+}
+
+/// Per-coin dual-control rule for `set_fee_config`
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct FeeChangeGuard<AccountId, BlockNumber> {
+	/// Increases of `transfer_fee` or `minimum_balance` larger than this are sensitive
+	pub threshold: u128,
+	/// Confirmation required for sensitive changes
+	pub control: FeeChangeControl<AccountId, BlockNumber>,
+}
+
+impl<AccountId: PartialEq, BlockNumber: PartialOrd> FeeChangeGuard<AccountId, BlockNumber> {
+	/// Whether moving from `old` to `new` requires confirmation under this guard
+	pub fn is_sensitive(&self, old: &FeeConfig, new: &FeeConfig) -> bool {
+		new.transfer_fee.saturating_sub(old.transfer_fee) > self.threshold ||
+			new.minimum_balance.saturating_sub(old.minimum_balance) > self.threshold ||
+			new.can_pay_tx_fees != old.can_pay_tx_fees ||
+			new.call_scope != old.call_scope
+	}
+
+	/// Whether this guard is at least as strict as `other`
+	pub fn is_at_least_as_strict_as(&self, other: &Self) -> bool {
+		let control_ok = match (&self.control, &other.control) {
+			(FeeChangeControl::Delay(new), FeeChangeControl::Delay(old)) => new >= old,
+			(FeeChangeControl::Approver(new), FeeChangeControl::Approver(old)) => new == old,
+			_ => false,
+		};
+		control_ok && self.threshold <= other.threshold
+	}
+}
+
+/// A sensitive fee configuration change awaiting confirmation
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PendingFeeChange<BlockNumber> {
+	/// The configuration that will be applied
+	pub fee_config: FeeConfig,
+	/// First block at which a delayed change may be enacted
+	pub enact_after: BlockNumber,
+}
+
+/// Raw storage key the zero balance sweep resumes after
+pub type SweepCursor = BoundedVec<u8, ConstU32<256>>;
+
+/// Progress of the `on_idle` sweep removing legacy zero balance entries
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+pub enum SweepState {
+	/// The sweep has not started yet
+	#[default]
+	NotStarted,
+	/// The sweep stopped after the given raw `Balances` key
+	InProgress(SweepCursor),
+	/// Every entry has been visited
+	Done,
+}
+
+/// Per-account maps scanned by the `on_idle` cleanup for entries of emptied accounts
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+pub enum DustMap {
+	/// Decay clocks of accounts without a balance
+	#[default]
+	LastTouched,
+	/// Dormancy warnings of accounts without a balance
+	DormancyWarnings,
+	/// Empty labeled sub-balance lists
+	SubBalances,
+	/// Empty hold lists
+	Holds,
+}
+
+impl DustMap {
+	/// The map scanned after this one, or `None` after the last
+	pub fn next(self) -> Option<Self> {
+		match self {
+			Self::LastTouched => Some(Self::DormancyWarnings),
+			Self::DormancyWarnings => Some(Self::SubBalances),
+			Self::SubBalances => Some(Self::Holds),
+			Self::Holds => None,
+		}
+	}
+}
+
+/// Start of a segment of a holder snapshot
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct HolderCheckpoint {
+	/// Raw `Balances` key the segment starts after, or `None` for the coin's first holder
+	pub after: Option<SweepCursor>,
+	/// Stored units held by the holders before the segment
+	pub cumulative: u128,
+}
+
+/// Cumulative balances of a coin's holders, every `HolderCheckpointInterval` holders
+///
+/// The segments live in `HolderCheckpoints`, so a snapshot can cover any number of
+/// holders. Snapshots alternate between two slots there, so that a snapshot being
+/// built doesn't overwrite the one still drawn from.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+pub struct HolderSnapshot {
+	/// Slot of `HolderCheckpoints` holding the segments
+	pub slot: u8,
+	/// Number of segments, stored in `Balances` order
+	pub segments: u32,
+	/// Stored units held by all holders covered so far
+	pub total: u128,
+	/// Raw `Balances` key the walk resumes after, while the snapshot is being built
+	pub after: Option<SweepCursor>,
+}
+
+/// Position of the `on_idle` cleanup: the map being scanned and the raw key
+/// it stopped after, if any
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+pub struct DustCursor {
+	/// Map being scanned
+	pub map: DustMap,
+	/// Last raw key visited in `map`, or `None` to start at its beginning
+	pub after: Option<SweepCursor>,
+}
+
+/// Progress of the `on_idle` cleanup of per-account entries of emptied accounts
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+pub enum DustCleanupState {
+	/// The cleanup has not started yet
+	#[default]
+	NotStarted,
+	/// The cleanup stopped at the given position
+	InProgress(DustCursor),
+	/// Every map has been visited
+	Done,
+}
+
+/// Per-coin demurrage: balances lose `rate` of their value every `period` blocks
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct DecayConfig<BlockNumber> {
+	/// Share of the balance lost per period
+	pub rate: Perbill,
+	/// Length of a decay period in blocks
+	pub period: BlockNumber,
+}
+
+/// Owner-funded staking reward pool of a coin.
+///
+/// All quantities are in stored units, which equal amounts unless the coin is rebasing.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+pub struct RewardPool<BlockNumber> {
+	/// Sum of all stakes
+	pub total_staked: u128,
+	/// Rewards accrued to stakers per block while funds remain
+	pub reward_per_block: u128,
+	/// Funded rewards not yet accrued to stakers
+	pub remaining_rewards: u128,
+	/// Rewards accrued per staked unit since the pool was created
+	pub reward_per_share: FixedU128,
+	/// Block up to which rewards have been accrued
+	pub last_update: BlockNumber,
+}
+
+/// An account's stake in a coin's reward pool, in stored units
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+pub struct StakeInfo {
+	/// Amount staked
+	pub amount: u128,
+	/// Rewards already accounted for at the pool's current `reward_per_share`
+	pub reward_debt: u128,
+	/// Rewards earned but not yet claimed
+	pub unclaimed: u128,
+}
+
+/// How long coins are locked for a vote, and the resulting vote multiplier
+#[derive(
+	Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, RuntimeDebug, TypeInfo,
+	MaxEncodedLen, Default,
+)]
+pub enum Conviction {
+	/// 0.1x votes, no lock period
+	#[default]
+	None,
+	/// 1x votes, locked for one base period
+	Locked1x,
+	/// 2x votes, locked for 2 base periods
+	Locked2x,
+	/// 3x votes, locked for 4 base periods
+	Locked3x,
+	/// 4x votes, locked for 8 base periods
+	Locked4x,
+	/// 5x votes, locked for 16 base periods
+	Locked5x,
+	/// 6x votes, locked for 32 base periods
+	Locked6x,
+}
+
+impl Conviction {
+	/// Votes carried by `amount` locked at this conviction
+	pub fn votes(self, amount: u128) -> u128 {
+		match self {
+			Conviction::None => amount / 10,
+			Conviction::Locked1x => amount,
+			Conviction::Locked2x => amount.saturating_mul(2),
+			Conviction::Locked3x => amount.saturating_mul(3),
+			Conviction::Locked4x => amount.saturating_mul(4),
+			Conviction::Locked5x => amount.saturating_mul(5),
+			Conviction::Locked6x => amount.saturating_mul(6),
+		}
+	}
+
+	/// Number of base lock periods this conviction locks coins for
+	pub fn lock_periods(self) -> u32 {
+		match self {
+			Conviction::None => 0,
+			Conviction::Locked1x => 1,
+			Conviction::Locked2x => 2,
+			Conviction::Locked3x => 4,
+			Conviction::Locked4x => 8,
+			Conviction::Locked5x => 16,
+			Conviction::Locked6x => 32,
+		}
+	}
+}
+
+/// Coins locked for voting, in stored units
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct VoteLock<BlockNumber> {
+	/// Amount locked
+	pub amount: u128,
+	/// Conviction the coins are locked with
+	pub conviction: Conviction,
+	/// First block at which the lock may be removed
+	pub unlock_at: BlockNumber,
+}
+
+/// Type alias for launch sale identifiers
+pub type SaleId = u32;
+
+/// Asset a launch sale is priced in
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum PaymentAsset {
+	/// The native currency
+	Native,
+	/// Another multi-coin coin
+	Coin(CoinId),
+}
+
+/// Collateralized minting settings of a stablecoin
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct StableConfig {
+	/// Asset locked as collateral
+	pub collateral: PaymentAsset,
+	/// Collateral value over debt value a position needs after minting or withdrawing
+	pub min_ratio: FixedU128,
+	/// Collateral ratio below which a position can be liquidated
+	pub liquidation_ratio: FixedU128,
+	/// Collateral a liquidator receives on top of the repaid debt's value
+	pub liquidation_penalty: Perbill,
+}
+
+/// Collateral locked and stablecoins minted against it by one account
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+pub struct CollateralPosition {
+	/// Collateral held by the pallet: native units, or stored units of a collateral coin
+	pub collateral: u128,
+	/// Stablecoins minted and not repaid yet
+	pub debt: u128,
+}
+
+/// Dutch auction of a liquidated collateral position
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct LiquidationAuction<AccountId, BlockNumber> {
+	/// Stablecoin the debt is in
+	pub coin_id: CoinId,
+	/// Owner of the liquidated position, who gets unsold collateral back
+	pub owner: AccountId,
+	/// Account that started the auction and earns the keeper reward
+	pub keeper: AccountId,
+	/// Asset of the collateral
+	pub collateral_asset: PaymentAsset,
+	/// Collateral on sale: native units, or stored units of a collateral coin
+	pub collateral: u128,
+	/// Stablecoins a bidder must repay
+	pub debt: u128,
+	/// Discount on the collateral reached once the auction ran its full duration
+	pub max_discount: Perbill,
+	/// Block the auction started in
+	pub started_at: BlockNumber,
+}
+
+/// Coins set aside for a recipient to claim before an expiry block
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct TransferTicket<AccountId, BlockNumber> {
+	/// Coin of the ticket
+	pub coin_id: CoinId,
+	/// Account that created the ticket and gets it refunded
+	pub from: AccountId,
+	/// Account that can claim the ticket
+	pub to: AccountId,
+	/// Stored units set aside
+	pub shares: u128,
+	/// First block in which the ticket can no longer be claimed
+	pub expiry: BlockNumber,
+}
+
+/// Allowance of a delegate to spend an owner's coins
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Approval<BlockNumber> {
+	/// Amount the delegate may still transfer
+	pub amount: u128,
+	/// Last block at which the allowance can be used, if it expires
+	pub expires_at: Option<BlockNumber>,
+	/// Owner's approval nonce when it was granted; bumping the nonce voids it
+	pub nonce: u32,
+}
+
+/// Transfer activity of a coin during one era of `VelocityEraLength` blocks
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+pub struct VelocityStats {
+	/// Index of the era: the block number divided by the era length
+	pub era: u32,
+	/// Total amount transferred in the era
+	pub volume: u128,
+	/// Number of distinct accounts that sent the coin in the era
+	pub senders: u32,
+}
+
+/// Supply report of a coin recorded by `checkpoint_supply`
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct SupplyCheckpoint<BlockNumber> {
+	/// Block the checkpoint was recorded in
+	pub block: BlockNumber,
+	/// Total supply at the checkpoint
+	pub total_supply: u128,
+	/// Number of holders at the checkpoint
+	pub holders: u32,
+	/// Fees burned since the previous checkpoint
+	pub fees_burned: u128,
+}
+
+/// Management summary of a coin, as returned by the `MultiCoinApi` runtime API
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct CoinDashboard<AccountId> {
+	/// Owner of the coin
+	pub owner: AccountId,
+	/// Account minting the coin
+	pub issuer: AccountId,
+	/// Account managing mint and burn permissions
+	pub admin: AccountId,
+	/// Account freezing the coin and its holders
+	pub freezer: AccountId,
+	/// Secondary account allowed to perform admin calls, if any
+	pub manager: Option<AccountId>,
+	/// Account whose reserved balance backs the creation deposit
+	pub depositor: AccountId,
+	/// Accounts allowed to mint, in ascending order
+	pub minters: Vec<AccountId>,
+	/// Accounts allowed to burn, in ascending order
+	pub burners: Vec<AccountId>,
+	/// Current fee configuration
+	pub fee_config: FeeConfig,
+	/// Total supply
+	pub total_supply: u128,
+	/// Number of accounts holding a non-zero balance
+	pub holders: u32,
+	/// Lifecycle status
+	pub status: CoinStatus,
+	/// Per-coin pot account, see `coin_sub_account`
+	pub coin_account: AccountId,
+	/// Pallet accounts granted mint or burn permissions, with their `PalletId` bytes
+	pub pallet_grants: Vec<(AccountId, [u8; 8])>,
+	/// Bridge accounts minting against reserves, with their per-window mint caps
+	pub bridge_mint_caps: Vec<(AccountId, u128)>,
+}
+
+/// What a transfer would cost the sender, as reported by `can_transfer`
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+pub struct FeeBreakdown {
+	/// Amount received by the recipient
+	pub amount: u128,
+	/// Transfer fee, burned
+	pub transfer_fee: u128,
+	/// Royalty paid to the coin's royalty beneficiary
+	pub royalty: u128,
+	/// Total debited from the sender
+	pub total: u128,
+}
+
+/// A launch sale of a coin
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Sale<AccountId, BlockNumber> {
+	/// Coin being sold
+	pub coin_id: CoinId,
+	/// Account that opened the sale and receives the proceeds
+	pub owner: AccountId,
+	/// Asset buyers pay with
+	pub payment: PaymentAsset,
+	/// Payment asset units per unit of the sold coin
+	pub price: FixedU128,
+	/// Stored units of the sold coin still for sale
+	pub remaining: u128,
+	/// Most a single account may buy; zero for no limit
+	pub per_account_cap: u128,
+	/// Last block at which the sale accepts purchases
+	pub end: BlockNumber,
+	/// Proceeds not yet withdrawn, in stored units of a payment coin or native units
+	pub proceeds: u128,
+}
+
+/// Type alias for OTC order identifiers
+pub type OrderId = u32;
+
+/// A fixed-price OTC order selling one coin for another
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Order<AccountId> {
+	/// Account that placed the order and receives the payment
+	pub maker: AccountId,
+	/// Coin offered
+	pub sell_coin: CoinId,
+	/// Stored units of `sell_coin` still held in escrow
+	pub remaining: u128,
+	/// Coin asked in return
+	pub buy_coin: CoinId,
+	/// Units of `buy_coin` per unit of `sell_coin`
+	pub price: FixedU128,
+}
+
+/// Type alias for subscription identifiers
+pub type SubscriptionId = u32;
+
+/// A recurring payment from a payer to a payee
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Subscription<AccountId, BlockNumber> {
+	/// Account charged
+	pub payer: AccountId,
+	/// Account paid
+	pub payee: AccountId,
+	/// Coin the subscription is billed in
+	pub coin_id: CoinId,
+	/// Amount charged per period
+	pub amount: u128,
+	/// Blocks between charges
+	pub period: BlockNumber,
+	/// Charges left before the subscription ends
+	pub remaining_count: u32,
+	/// Block at which the next charge becomes due
+	pub next_due: BlockNumber,
+}
+
+/// Recovery account designated by a holder
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RecoveryConfig<AccountId, BlockNumber> {
+	/// Account allowed to recover the holder's balances
+	pub recoverer: AccountId,
+	/// Blocks between announcing a recovery and being able to execute it
+	pub delay: BlockNumber,
+}
+
+/// Dormant balance reclamation settings, fixed when a coin is created
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct DormancyConfig<AccountId, BlockNumber> {
+	/// Blocks without balance activity after which an account may be warned
+	pub period: BlockNumber,
+	/// Blocks between the warning and the sweep
+	pub grace: BlockNumber,
+	/// Community account receiving swept balances
+	pub beneficiary: AccountId,
+}
+
+/// Locale code of localized coin metadata, e.g. "fr" or "pt-BR"
+pub type Locale = BoundedVec<u8, ConstU32<16>>;
+
+/// Localized name and description of a coin
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct LocalizedText<Name, Description> {
+	/// Localized coin name
+	pub name: Option<Name>,
+	/// Localized coin description
+	pub description: Option<Description>,
+}
+
+/// Optional metadata of a coin for wallets and explorers
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+pub struct ExtendedCoinMetadata<Description, Uri> {
+	/// Coin description
+	pub description: Option<Description>,
+	/// URI of the coin logo
+	pub logo_uri: Option<Uri>,
+	/// URL of the project homepage
+	pub homepage: Option<Uri>,
+	/// Reference to the coin on another chain, e.g. a CAIP-19 asset id
+	pub external_reference: Option<Uri>,
+}
+
+/// Deposit held for a coin's optional metadata
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct MetadataDeposit<AccountId, Balance> {
+	/// Account the deposit is reserved from
+	pub depositor: AccountId,
+	/// Reserved amount
+	pub amount: Balance,
+}
+
+/// Content hash of an off-chain document, e.g. an IPFS CID or a sha256 digest
+pub type DocumentHash = BoundedVec<u8, ConstU32<64>>;
+
+/// Name of a sub-balance, e.g. "trading" or "savings"
+pub type Label = BoundedVec<u8, ConstU32<32>>;
+
+/// Labeled sub-balances of one account in one coin, in stored units
+pub type LabeledBalances = BoundedVec<(Label, u128), ConstU32<16>>;
+
+/// Freshly minted coins that may not leave an account yet, in stored units
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct MintLock<BlockNumber> {
+	/// Amount locked
+	pub amount: u128,
+	/// First block at which the amount may be moved
+	pub until: BlockNumber,
+}
+
+/// Test network faucet settings of a coin
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct FaucetConfig<BlockNumber> {
+	/// Amount minted per drip
+	pub amount: u128,
+	/// Blocks an account must wait between drips
+	pub cooldown: BlockNumber,
+}
+
+/// Price oracle settings of a coin
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct OracleConfig<BlockNumber> {
+	/// Fresh feeds needed before a median price is published
+	pub min_feeds: u32,
+	/// Maximum deviation of a new feed, or a bridge ratio, from the median price
+	pub max_deviation: Perbill,
+	/// Blocks a feed stays fresh
+	pub validity: BlockNumber,
+}
+
+/// Price of one coin unit in native units, as reported by a feeder
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PriceFeed<BlockNumber> {
+	/// Reported price
+	pub price: FixedU128,
+	/// Block the price was reported at
+	pub at: BlockNumber,
+}
+
+/// Royalty paid to a coin's creator on secondary transfers
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RoyaltyConfig<AccountId> {
+	/// Royalty in basis points of the transferred amount, at most 10_000
+	pub bps: u16,
+	/// Account receiving the royalties
+	pub beneficiary: AccountId,
+}
+
+/// Aggregated coin prices for other pallets, e.g. bridges checking exchange ratios
+pub trait PriceOracle {
+	/// Median price of one unit of the coin with `symbol` in native units, if published
+	fn price_of_symbol(symbol: &[u8]) -> Option<FixedU128>;
+
+	/// Whether `price` is within the allowed deviation of the median price of the
+	/// coin with `symbol`; any price is accepted while no median is published
+	fn accepts_price(symbol: &[u8], price: FixedU128) -> bool;
+}
+
+impl PriceOracle for () {
+	fn price_of_symbol(_: &[u8]) -> Option<FixedU128> {
+		None
+	}
+
+	fn accepts_price(_: &[u8], _: FixedU128) -> bool {
+		true
+	}
+}
+
+/// Pause state of coins for other runtime components, e.g. a DEX halting the
+/// markets of a coin whose transfers or bridge are paused
+pub trait PausedQuery {
+	/// Whether everything the implementer governs is paused
+	fn is_globally_paused() -> bool;
+
+	/// Whether the coin with `symbol` is paused, on its own or globally
+	fn is_coin_paused(symbol: &[u8]) -> bool;
+}
+
+impl PausedQuery for () {
+	fn is_globally_paused() -> bool {
+		false
+	}
+
+	fn is_coin_paused(_: &[u8]) -> bool {
+		false
+	}
+}
+
+/// Paused wherever either component is, e.g. `(MultiCoin, ProofOfReserve)`
+impl<A: PausedQuery, B: PausedQuery> PausedQuery for (A, B) {
+	fn is_globally_paused() -> bool {
+		A::is_globally_paused() || B::is_globally_paused()
+	}
+
+	fn is_coin_paused(symbol: &[u8]) -> bool {
+		A::is_coin_paused(symbol) || B::is_coin_paused(symbol)
+	}
+}
+
+/// Supply of coins by symbol, e.g. for proof-of-reserve reports of wrapped coins
+pub trait SupplyQuery {
+	/// Total supply of the coin with `symbol`, if there is one
+	fn supply_of_symbol(symbol: &[u8]) -> Option<u128>;
+}
+
+impl SupplyQuery for () {
+	fn supply_of_symbol(_: &[u8]) -> Option<u128> {
+		None
+	}
+}
+
+/// Coin another pallet maps a name to by symbol, as seen from that pallet's account
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct MappedCoin {
+	/// Id of the coin with the symbol
+	pub coin_id: CoinId,
+	/// Whether the coin is being destroyed
+	pub destroying: bool,
+	/// Whether the account may mint the coin
+	pub can_mint: bool,
+	/// Whether the account may burn the coin
+	pub can_burn: bool,
+}
+
+/// Lookup of coins by symbol for pallets minting and burning them from their own
+/// account, e.g. to check their coin mappings in `try_state`
+pub trait CoinMapping<AccountId> {
+	/// The coin with `symbol` and what `account` may do with it, if there is one
+	fn mapped_coin(symbol: &[u8], account: &AccountId) -> Option<MappedCoin>;
+}
+
+impl<AccountId> CoinMapping<AccountId> for () {
+	fn mapped_coin(_: &[u8], _: &AccountId) -> Option<MappedCoin> {
+		None
+	}
+}
+
+/// Hook run on every coin transfer, e.g. to apply compliance filters
+pub trait TransferHook<AccountId> {
+	/// Called before a transfer is applied; returning an error rejects it
+	fn on_transfer(coin_id: CoinId, from: &AccountId, to: &AccountId, amount: u128) -> DispatchResult;
+
+	/// Worst-case weight of one `on_transfer` call, charged to every transfer
+	fn weight() -> Weight;
+}
+
+impl<AccountId> TransferHook<AccountId> for () {
+	fn on_transfer(_: CoinId, _: &AccountId, _: &AccountId, _: u128) -> DispatchResult {
+		Ok(())
+	}
+
+	fn weight() -> Weight {
+		Weight::zero()
+	}
+}
@@ -0,0 +1,84 @@
+//! `pallet-vesting` style schedules on any coin.
+//!
+//! `pallet-vesting` (and orml-vesting) lock a `LockableCurrency`, so they can't vest
+//! a multicoin coin directly. `CoinVesting` keeps their semantics on top of the
+//! `fungibles` traits: a vested transfer moves the coins with `fungibles::Mutate`
+//! and puts them on hold for the reason `R`, and `vest` shrinks that hold to what
+//! the schedules still lock at the current block. Like `pallet-vesting`'s `Vesting`
+//! storage, the schedules themselves are kept by the consumer pallet.
+
+use crate::{CoinId, Config, Error, Pallet};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	ensure,
+	traits::{
+		tokens::{fungibles, Preservation},
+		TypedGet,
+	},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::AtLeast32BitUnsigned,
+	DispatchResult, RuntimeDebug,
+};
+use sp_std::marker::PhantomData;
+
+/// Linear release of `locked` coins, `per_block` of them every block from
+/// `starting_block` on, as in `pallet-vesting`'s `VestingInfo`
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct VestingSchedule<BlockNumber> {
+	pub locked: u128,
+	pub per_block: u128,
+	pub starting_block: BlockNumber,
+}
+
+impl<BlockNumber: AtLeast32BitUnsigned + Copy> VestingSchedule<BlockNumber> {
+	/// Amount still locked at block `n`
+	pub fn locked_at(&self, n: BlockNumber) -> u128 {
+		let vested_blocks: u128 = n.saturating_sub(self.starting_block).unique_saturated_into();
+		self.locked.saturating_sub(vested_blocks.saturating_mul(self.per_block))
+	}
+}
+
+/// Vests coins of any coin under holds for the reason `R`.
+pub struct CoinVesting<T, R>(PhantomData<(T, R)>);
+
+impl<T: Config, R: TypedGet<Type = T::RuntimeHoldReason>> CoinVesting<T, R> {
+	/// Transfer `schedule.locked` of `coin_id` from `source` to `target` and hold it
+	/// there until it vests. Call transactionally: the transfer is kept if the hold
+	/// fails, e.g. because a transfer fee left `target` short.
+	pub fn vested_transfer(
+		coin_id: CoinId,
+		source: &T::AccountId,
+		target: &T::AccountId,
+		schedule: &VestingSchedule<BlockNumberFor<T>>,
+	) -> DispatchResult {
+		ensure!(schedule.locked > 0 && schedule.per_block > 0, Error::<T>::ZeroAmount);
+		<Pallet<T> as fungibles::Mutate<T::AccountId>>::transfer(
+			coin_id,
+			source,
+			target,
+			schedule.locked,
+			Preservation::Expendable,
+		)?;
+		Pallet::<T>::hold(&R::get(), coin_id, target, schedule.locked)
+	}
+
+	/// Amount of `coin_id` that `schedules` still lock for `who` at the current block
+	pub fn vesting_balance(coin_id: CoinId, who: &T::AccountId, schedules: &[VestingSchedule<BlockNumberFor<T>>]) -> u128 {
+		let now = frame_system::Pallet::<T>::block_number();
+		let locked = schedules.iter().fold(0u128, |total, schedule| total.saturating_add(schedule.locked_at(now)));
+		locked.min(Pallet::<T>::balance_on_hold(&R::get(), coin_id, who))
+	}
+
+	/// Release whatever `schedules` no longer lock, returning the amount released
+	pub fn vest(coin_id: CoinId, who: &T::AccountId, schedules: &[VestingSchedule<BlockNumberFor<T>>]) -> u128 {
+		let held = Pallet::<T>::balance_on_hold(&R::get(), coin_id, who);
+		let vested = held.saturating_sub(Self::vesting_balance(coin_id, who, schedules));
+		if vested == 0 {
+			return 0;
+		}
+		Pallet::<T>::release(&R::get(), coin_id, who, vested)
+	}
+}
@@ -1,296 +1,2203 @@
-//! Autogenerated weights for pallet_multi_coin
-//!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2024-01-01, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `substrate-node`, CPU: `<UNKNOWN>`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
-
-// Executed Command:
-// ./target/release/solochain-template-node
-// benchmark
-// pallet
-// --chain
-// dev
-// --pallet
-// pallet_multi_coin
-// --extrinsic
-// *
-// --steps=50
-// --repeat=20
-// --wasm-execution=compiled
-// --output
-// pallets/multi-coin/src/weights.rs
-
-#![cfg_attr(rustfmt, rustfmt_skip)]
-#![allow(unused_parens)]
-#![allow(unused_imports)]
-
-use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
-use core::marker::PhantomData;
-
-/// Weight functions needed for pallet_multi_coin.
-pub trait WeightInfo {
-	fn create_coin() -> Weight;
-	fn transfer() -> Weight;
-	fn mint() -> Weight;
-	fn burn() -> Weight;
-	fn transfer_ownership() -> Weight;
-	fn set_mint_permission() -> Weight;
-	fn set_metadata() -> Weight;
-	fn set_fee_config() -> Weight; // New
-	fn set_preferred_fee_coin() -> Weight; // New
-}
-
-/// Weights for pallet_multi_coin using the Substrate node and recommended hardware.
-pub struct SubstrateWeight<T>(PhantomData<T>);
-impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	/// Storage: MultiCoin CoinMetadata (r:1 w:1)
-	/// Proof: MultiCoin CoinMetadata (max_values: None, max_size: Some(1024), added: 3499, mode: MaxEncodedLen)
-	/// Storage: MultiCoin SymbolToId (r:1 w:1)
-	/// Proof: MultiCoin SymbolToId (max_values: None, max_size: Some(256), added: 2731, mode: MaxEncodedLen)
-	/// Storage: MultiCoin NextCoinId (r:1 w:1)
-	/// Proof: MultiCoin NextCoinId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	/// Storage: MultiCoin TotalSupply (r:0 w:1)
-	/// Proof: MultiCoin TotalSupply (max_values: None, max_size: Some(20), added: 2495, mode: MaxEncodedLen)
-	/// Storage: MultiCoin Balances (r:0 w:1)
-	/// Proof: MultiCoin Balances (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
-	/// Storage: MultiCoin MintPermissions (r:0 w:1)
-	/// Proof: MultiCoin MintPermissions (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
-	fn create_coin() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `76`
-		//  Estimated: `4489`
-		// Minimum execution time: 25_000_000 picoseconds.
-		Weight::from_parts(26_000_000, 4489)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
-	}
-
-	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
-	/// Proof: MultiCoin CoinMetadata (max_values: None, max_size: Some(1024), added: 3499, mode: MaxEncodedLen)
-	/// Storage: MultiCoin Balances (r:2 w:2)
-	/// Proof: MultiCoin Balances (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
-	fn transfer() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `187`
-		//  Estimated: `6076`
-		// Minimum execution time: 18_000_000 picoseconds.
-		Weight::from_parts(19_000_000, 6076)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
-	}
-
-	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
-	/// Proof: MultiCoin CoinMetadata (max_values: None, max_size: Some(1024), added: 3499, mode: MaxEncodedLen)
-	/// Storage: MultiCoin MintPermissions (r:1 w:0)
-	/// Proof: MultiCoin MintPermissions (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
-	/// Storage: MultiCoin TotalSupply (r:1 w:1)
-	/// Proof: MultiCoin TotalSupply (max_values: None, max_size: Some(20), added: 2495, mode: MaxEncodedLen)
-	/// Storage: MultiCoin Balances (r:1 w:1)
-	/// Proof: MultiCoin Balances (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
-	fn mint() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `223`
-		//  Estimated: `4489`
-		// Minimum execution time: 20_000_000 picoseconds.
-		Weight::from_parts(21_000_000, 4489)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
-	}
-
-	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
-	/// Proof: MultiCoin CoinMetadata (max_values: None, max_size: Some(1024), added: 3499, mode: MaxEncodedLen)
-	/// Storage: MultiCoin Balances (r:1 w:1)
-	/// Proof: MultiCoin Balances (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
-	/// Storage: MultiCoin TotalSupply (r:1 w:1)
-	/// Proof: MultiCoin TotalSupply (max_values: None, max_size: Some(20), added: 2495, mode: MaxEncodedLen)
-	fn burn() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `187`
-		//  Estimated: `4489`
-		// Minimum execution time: 16_000_000 picoseconds.
-		Weight::from_parts(17_000_000, 4489)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
-	}
-
-	/// Storage: MultiCoin CoinMetadata (r:1 w:1)
-	/// Proof: MultiCoin CoinMetadata (max_values: None, max_size: Some(1024), added: 3499, mode: MaxEncodedLen)
-	/// Storage: MultiCoin MintPermissions (r:1 w:1)
-	/// Proof: MultiCoin MintPermissions (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
-	/// Storage: MultiCoin MintPermissions (r:0 w:1)
-	/// Proof: MultiCoin MintPermissions (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
-	fn transfer_ownership() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `187`
-		//  Estimated: `4489`
-		// Minimum execution time: 15_000_000 picoseconds.
-		Weight::from_parts(16_000_000, 4489)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
-	}
-
-	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
-	/// Proof: MultiCoin CoinMetadata (max_values: None, max_size: Some(1024), added: 3499, mode: MaxEncodedLen)
-	/// Storage: MultiCoin MintPermissions (r:0 w:1)
-	/// Proof: MultiCoin MintPermissions (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
-	fn set_mint_permission() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `76`
-		//  Estimated: `4489`
-		// Minimum execution time: 12_000_000 picoseconds.
-		Weight::from_parts(13_000_000, 4489)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
-	}
-
-	/// Storage: MultiCoin CoinMetadata (r:1 w:1)
-	/// Proof: MultiCoin CoinMetadata (max_values: None, max_size: Some(1024), added: 3499, mode: MaxEncodedLen)
-	fn set_metadata() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `76`
-		//  Estimated: `4489`
-		// Minimum execution time: 10_000_000 picoseconds.
-		Weight::from_parts(11_000_000, 4489)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
-	}
-
-	fn set_fee_config() -> Weight {
-		// Similar to set_metadata: 1 read, 1 write
-		Weight::from_parts(11_000_000, 4489)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
-	}
-
-	fn set_preferred_fee_coin() -> Weight {
-		Weight::from_parts(10_000_000, 4000)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
-	}
-}
-
-// For backwards compatibility and tests
-impl WeightInfo for () {
-	/// Storage: MultiCoin CoinMetadata (r:1 w:1)
-	/// Proof: MultiCoin CoinMetadata (max_values: None, max_size: Some(1024), added: 3499, mode: MaxEncodedLen)
-	/// Storage: MultiCoin SymbolToId (r:1 w:1)
-	/// Proof: MultiCoin SymbolToId (max_values: None, max_size: Some(256), added: 2731, mode: MaxEncodedLen)
-	/// Storage: MultiCoin NextCoinId (r:1 w:1)
-	/// Proof: MultiCoin NextCoinId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	/// Storage: MultiCoin TotalSupply (r:0 w:1)
-	/// Proof: MultiCoin TotalSupply (max_values: None, max_size: Some(20), added: 2495, mode: MaxEncodedLen)
-	/// Storage: MultiCoin Balances (r:0 w:1)
-	/// Proof: MultiCoin Balances (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
-	/// Storage: MultiCoin MintPermissions (r:0 w:1)
-	/// Proof: MultiCoin MintPermissions (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
-	fn create_coin() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `76`
-		//  Estimated: `4489`
-		// Minimum execution time: 25_000_000 picoseconds.
-		Weight::from_parts(26_000_000, 4489)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
-	}
-
-	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
-	/// Proof: MultiCoin CoinMetadata (max_values: None, max_size: Some(1024), added: 3499, mode: MaxEncodedLen)
-	/// Storage: MultiCoin Balances (r:2 w:2)
-	/// Proof: MultiCoin Balances (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
-	fn transfer() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `187`
-		//  Estimated: `6076`
-		// Minimum execution time: 18_000_000 picoseconds.
-		Weight::from_parts(19_000_000, 6076)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
-	}
-
-	/// Storage: MultiCoin MintPermissions (r:1 w:0)
-	/// Proof: MultiCoin MintPermissions (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
-	/// Storage: MultiCoin TotalSupply (r:1 w:1)
-	/// Proof: MultiCoin TotalSupply (max_values: None, max_size: Some(20), added: 2495, mode: MaxEncodedLen)
-	/// Storage: MultiCoin Balances (r:1 w:1)
-	/// Proof: MultiCoin Balances (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
-	fn mint() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `223`
-		//  Estimated: `4489`
-		// Minimum execution time: 20_000_000 picoseconds.
-		Weight::from_parts(21_000_000, 4489)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
-	}
-
-	/// Storage: MultiCoin Balances (r:1 w:1)
-	/// Proof: MultiCoin Balances (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
-	/// Storage: MultiCoin TotalSupply (r:1 w:1)
-	/// Proof: MultiCoin TotalSupply (max_values: None, max_size: Some(20), added: 2495, mode: MaxEncodedLen)
-	fn burn() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `187`
-		//  Estimated: `4489`
-		// Minimum execution time: 16_000_000 picoseconds.
-		Weight::from_parts(17_000_000, 4489)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
-	}
-
-	/// Storage: MultiCoin CoinMetadata (r:1 w:1)
-	/// Proof: MultiCoin CoinMetadata (max_values: None, max_size: Some(1024), added: 3499, mode: MaxEncodedLen)
-	/// Storage: MultiCoin MintPermissions (r:1 w:1)
-	/// Proof: MultiCoin MintPermissions (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
-	/// Storage: MultiCoin MintPermissions (r:0 w:1)
-	/// Proof: MultiCoin MintPermissions (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
-	fn transfer_ownership() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `187`
-		//  Estimated: `4489`
-		// Minimum execution time: 15_000_000 picoseconds.
-		Weight::from_parts(16_000_000, 4489)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
-	}
-
-	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
-	/// Proof: MultiCoin CoinMetadata (max_values: None, max_size: Some(1024), added: 3499, mode: MaxEncodedLen)
-	/// Storage: MultiCoin MintPermissions (r:0 w:1)
-	/// Proof: MultiCoin MintPermissions (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
-	fn set_mint_permission() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `76`
-		//  Estimated: `4489`
-		// Minimum execution time: 12_000_000 picoseconds.
-		Weight::from_parts(13_000_000, 4489)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
-	}
-
-	/// Storage: MultiCoin CoinMetadata (r:1 w:1)
-	/// Proof: MultiCoin CoinMetadata (max_values: None, max_size: Some(1024), added: 3499, mode: MaxEncodedLen)
-	fn set_metadata() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `76`
-		//  Estimated: `4489`
-		// Minimum execution time: 10_000_000 picoseconds.
-		Weight::from_parts(11_000_000, 4489)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
-	}
-
-	fn set_fee_config() -> Weight {
-		// Similar to set_metadata: 1 read, 1 write
-		Weight::from_parts(11_000_000, 4489)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
-	}
-
-	fn set_preferred_fee_coin() -> Weight {
-		Weight::from_parts(10_000_000, 4000)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
-	}
-}
+//! Autogenerated weights for pallet_multi_coin
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2024-01-01, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `substrate-node`, CPU: `<UNKNOWN>`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/release/solochain-template-node
+// benchmark
+// pallet
+// --chain
+// dev
+// --pallet
+// pallet_multi_coin
+// --extrinsic
+// *
+// --steps=50
+// --repeat=20
+// --wasm-execution=compiled
+// --output
+// pallets/multi-coin/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_multi_coin.
+pub trait WeightInfo {
+	fn create_coin(m: u32, b: u32) -> Weight;
+	fn transfer() -> Weight;
+	fn mint() -> Weight;
+	fn burn() -> Weight;
+	fn transfer_ownership() -> Weight;
+	fn set_mint_permission() -> Weight;
+	fn set_metadata() -> Weight;
+	fn set_fee_config() -> Weight; // New
+	fn set_preferred_fee_coin() -> Weight; // New
+	fn set_fee_guard() -> Weight;
+	fn approve_fee_change() -> Weight;
+	fn enact_fee_change() -> Weight;
+	fn cancel_fee_change() -> Weight;
+	fn sweep_zero_balance() -> Weight;
+	fn freeze_coin() -> Weight;
+	fn thaw_coin() -> Weight;
+	fn force_transfer() -> Weight;
+	fn set_coin_manager() -> Weight;
+	fn set_accrual_index() -> Weight;
+	fn set_decay_rate() -> Weight;
+	fn set_reward_rate() -> Weight;
+	fn fund_rewards() -> Weight;
+	fn stake() -> Weight;
+	fn unstake() -> Weight;
+	fn claim_rewards() -> Weight;
+	fn lock_for_vote() -> Weight;
+	fn unlock_vote() -> Weight;
+	fn open_sale() -> Weight;
+	fn buy() -> Weight;
+	fn withdraw_proceeds() -> Weight;
+	fn close_sale() -> Weight;
+	fn place_order() -> Weight;
+	fn fill_order() -> Weight;
+	fn cancel_order() -> Weight;
+	fn create_subscription() -> Weight;
+	fn claim_due() -> Weight;
+	fn cancel_subscription() -> Weight;
+	fn transfer_split(n: u32) -> Weight;
+	fn set_sink_blocked() -> Weight;
+	fn set_recovery() -> Weight;
+	fn announce_recovery() -> Weight;
+	fn cancel_recovery() -> Weight;
+	fn recover_balance() -> Weight;
+	fn warn_dormant() -> Weight;
+	fn sweep_dormant() -> Weight;
+	fn set_max_holders() -> Weight;
+	fn transfer_hook() -> Weight;
+	fn set_royalty() -> Weight;
+	fn set_royalty_exempt() -> Weight;
+	fn set_mint_lock_period() -> Weight;
+	fn move_between_labels() -> Weight;
+	fn set_faucet() -> Weight;
+	fn drip() -> Weight;
+	fn grant_pallet_permission() -> Weight;
+	fn set_bridge_mint_cap() -> Weight;
+	fn set_oracle_config() -> Weight;
+	fn set_price_feeder() -> Weight;
+	fn feed_price() -> Weight;
+	fn propose_fee_coin() -> Weight;
+	fn approve_fee_coin() -> Weight;
+	fn revoke_fee_coin() -> Weight;
+	fn set_fee_payment_suspended() -> Weight;
+	fn set_stable_config() -> Weight;
+	fn deposit_collateral() -> Weight;
+	fn withdraw_collateral() -> Weight;
+	fn mint_stable() -> Weight;
+	fn repay_stable() -> Weight;
+	fn liquidate() -> Weight;
+	fn bid_liquidation() -> Weight;
+	fn set_flash_mint_fee() -> Weight;
+	fn flash_mint() -> Weight;
+	fn create_transfer_ticket() -> Weight;
+	fn claim_ticket() -> Weight;
+	fn refund_ticket() -> Weight;
+	fn checkpoint_supply() -> Weight;
+	fn balances_many(n: u32) -> Weight;
+	fn cleanup_dust_entry() -> Weight;
+	fn set_transfer_pause() -> Weight;
+	fn set_legal_document() -> Weight;
+	fn approve() -> Weight;
+	fn transfer_from() -> Weight;
+	fn cancel_approval() -> Weight;
+	fn invalidate_approvals() -> Weight;
+	fn freeze_account() -> Weight;
+	fn thaw_account() -> Weight;
+	fn start_destroy() -> Weight;
+	fn destroy_accounts(n: u32) -> Weight;
+	fn finish_destroy() -> Weight;
+	fn set_team() -> Weight;
+	fn transfer_batch(n: u32) -> Weight;
+	fn renounce_ownership() -> Weight;
+	fn transfer_keep_alive() -> Weight;
+	fn transfer_all() -> Weight;
+	fn force_mint() -> Weight;
+	fn force_burn() -> Weight;
+	fn checkpoint_holders(n: u32) -> Weight;
+	fn set_localization() -> Weight;
+	fn set_extended_metadata() -> Weight;
+	fn set_fee_operator() -> Weight;
+	fn set_preferred_fee_coin_for(n: u32) -> Weight;
+}
+
+/// Weights for pallet_multi_coin using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: MultiCoin CoinMetadata (r:1 w:1)
+	/// Proof: MultiCoin CoinMetadata (max_values: None, max_size: Some(1024), added: 3499, mode: MaxEncodedLen)
+	/// Storage: MultiCoin SymbolToId (r:1 w:1)
+	/// Proof: MultiCoin SymbolToId (max_values: None, max_size: Some(256), added: 2731, mode: MaxEncodedLen)
+	/// Storage: MultiCoin NextCoinId (r:1 w:1)
+	/// Proof: MultiCoin NextCoinId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: MultiCoin TotalSupply (r:0 w:1)
+	/// Proof: MultiCoin TotalSupply (max_values: None, max_size: Some(20), added: 2495, mode: MaxEncodedLen)
+	/// Storage: MultiCoin Balances (r:0 w:1)
+	/// Proof: MultiCoin Balances (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: MultiCoin MintPermissions (r:0 w:1)
+	/// Proof: MultiCoin MintPermissions (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: MultiCoin BurnPermissions (r:0 w:1)
+	/// Proof: MultiCoin BurnPermissions (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: MultiCoin CoinDepositors (r:0 w:1)
+	/// Storage: MultiCoin TotalCoinDeposits (r:1 w:1)
+	/// The range of component `m` is `[0, 32]`.
+	/// The range of component `b` is `[0, 32]`.
+	fn create_coin(m: u32, b: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `4489`
+		// Minimum execution time: 25_000_000 picoseconds.
+		Weight::from_parts(26_000_000, 4489)
+			.saturating_add(Weight::from_parts(2_000_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(2_000_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(b.into())))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Proof: MultiCoin CoinMetadata (max_values: None, max_size: Some(1024), added: 3499, mode: MaxEncodedLen)
+	/// Storage: MultiCoin Balances (r:2 w:2)
+	/// Proof: MultiCoin Balances (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: MultiCoin FeesBurnedSinceCheckpoint (r:1 w:1)
+	/// Storage: MultiCoin TotalFeesBurned (r:1 w:1)
+	/// Storage: MultiCoin PausedExcept (r:1 w:0)
+	/// Storage: MultiCoin VelocitySenders (r:1 w:1)
+	/// Storage: MultiCoin CoinVelocity (r:1 w:1)
+	/// Storage: MultiCoin FrozenAccounts (r:2 w:0)
+	fn transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `187`
+		//  Estimated: `6076`
+		// Minimum execution time: 18_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 6076)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Proof: MultiCoin CoinMetadata (max_values: None, max_size: Some(1024), added: 3499, mode: MaxEncodedLen)
+	/// Storage: MultiCoin MintPermissions (r:1 w:0)
+	/// Proof: MultiCoin MintPermissions (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: MultiCoin TotalSupply (r:1 w:1)
+	/// Proof: MultiCoin TotalSupply (max_values: None, max_size: Some(20), added: 2495, mode: MaxEncodedLen)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	/// Proof: MultiCoin Balances (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: MultiCoin FrozenAccounts (r:1 w:0)
+	fn mint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `223`
+		//  Estimated: `4489`
+		// Minimum execution time: 20_000_000 picoseconds.
+		Weight::from_parts(21_000_000, 4489)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Proof: MultiCoin CoinMetadata (max_values: None, max_size: Some(1024), added: 3499, mode: MaxEncodedLen)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	/// Proof: MultiCoin Balances (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: MultiCoin TotalSupply (r:1 w:1)
+	/// Proof: MultiCoin TotalSupply (max_values: None, max_size: Some(20), added: 2495, mode: MaxEncodedLen)
+	/// Storage: MultiCoin FrozenAccounts (r:1 w:0)
+	fn burn() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `187`
+		//  Estimated: `4489`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 4489)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:1)
+	/// Proof: MultiCoin CoinMetadata (max_values: None, max_size: Some(1024), added: 3499, mode: MaxEncodedLen)
+	/// Storage: MultiCoin MintPermissions (r:0 w:2)
+	/// Proof: MultiCoin MintPermissions (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: MultiCoin BurnPermissions (r:0 w:2)
+	/// Proof: MultiCoin BurnPermissions (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: MultiCoin CoinDepositors (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	fn transfer_ownership() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `187`
+		//  Estimated: `4489`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 4489)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Proof: MultiCoin CoinMetadata (max_values: None, max_size: Some(1024), added: 3499, mode: MaxEncodedLen)
+	/// Storage: MultiCoin MintPermissions (r:0 w:1)
+	/// Proof: MultiCoin MintPermissions (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	fn set_mint_permission() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `4489`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 4489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:1)
+	/// Proof: MultiCoin CoinMetadata (max_values: None, max_size: Some(1024), added: 3499, mode: MaxEncodedLen)
+	fn set_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `4489`
+		// Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 4489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	fn set_fee_config() -> Weight {
+		// Similar to set_metadata: 1 read, 1 write
+		Weight::from_parts(11_000_000, 4489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	fn set_preferred_fee_coin() -> Weight {
+		Weight::from_parts(10_000_000, 4000)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin FeeChangeGuards (r:1 w:1)
+	fn set_fee_guard() -> Weight {
+		Weight::from_parts(12_000_000, 4489)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:1)
+	/// Storage: MultiCoin FeeChangeGuards (r:1 w:0)
+	/// Storage: MultiCoin PendingFeeChanges (r:1 w:1)
+	fn approve_fee_change() -> Weight {
+		Weight::from_parts(16_000_000, 4489)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:1)
+	/// Storage: MultiCoin FeeChangeGuards (r:1 w:0)
+	/// Storage: MultiCoin PendingFeeChanges (r:1 w:1)
+	fn enact_fee_change() -> Weight {
+		Weight::from_parts(16_000_000, 4489)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin FeeChangeGuards (r:1 w:0)
+	/// Storage: MultiCoin PendingFeeChanges (r:1 w:1)
+	fn cancel_fee_change() -> Weight {
+		Weight::from_parts(12_000_000, 4489)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	fn sweep_zero_balance() -> Weight {
+		Weight::from_parts(6_000_000, 2543)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:1)
+	fn freeze_coin() -> Weight {
+		Weight::from_parts(12_000_000, 4489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:1)
+	fn thaw_coin() -> Weight {
+		Weight::from_parts(12_000_000, 4489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:2 w:2)
+	fn force_transfer() -> Weight {
+		Weight::from_parts(19_000_000, 6076)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin CoinManagers (r:0 w:1)
+	fn set_coin_manager() -> Weight {
+		Weight::from_parts(11_000_000, 3499)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin CoinManagers (r:1 w:0)
+	/// Storage: MultiCoin AccrualIndex (r:0 w:1)
+	fn set_accrual_index() -> Weight {
+		Weight::from_parts(12_000_000, 3499)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin CoinManagers (r:1 w:0)
+	/// Storage: MultiCoin AccrualIndex (r:1 w:0)
+	/// Storage: MultiCoin DecayRates (r:0 w:1)
+	fn set_decay_rate() -> Weight {
+		Weight::from_parts(12_000_000, 3499)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin CoinManagers (r:1 w:0)
+	/// Storage: MultiCoin RewardPools (r:1 w:1)
+	fn set_reward_rate() -> Weight {
+		Weight::from_parts(13_000_000, 3499)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin CoinManagers (r:1 w:0)
+	/// Storage: MultiCoin DecayRates (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	/// Storage: MultiCoin RewardPools (r:1 w:1)
+	fn fund_rewards() -> Weight {
+		Weight::from_parts(20_000_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin DecayRates (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	/// Storage: MultiCoin RewardPools (r:1 w:1)
+	/// Storage: MultiCoin Stakes (r:1 w:1)
+	fn stake() -> Weight {
+		Weight::from_parts(24_000_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin DecayRates (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	/// Storage: MultiCoin RewardPools (r:1 w:1)
+	/// Storage: MultiCoin Stakes (r:1 w:1)
+	fn unstake() -> Weight {
+		Weight::from_parts(24_000_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin DecayRates (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	/// Storage: MultiCoin RewardPools (r:1 w:1)
+	/// Storage: MultiCoin Stakes (r:1 w:1)
+	fn claim_rewards() -> Weight {
+		Weight::from_parts(24_000_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin AccrualIndex (r:1 w:0)
+	/// Storage: MultiCoin DecayRates (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:1 w:0)
+	/// Storage: MultiCoin VoteLocks (r:1 w:1)
+	fn lock_for_vote() -> Weight {
+		Weight::from_parts(16_000_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin VoteLocks (r:1 w:1)
+	fn unlock_vote() -> Weight {
+		Weight::from_parts(11_000_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:2 w:0)
+	/// Storage: MultiCoin DecayRates (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	/// Storage: MultiCoin VoteLocks (r:1 w:0)
+	/// Storage: MultiCoin NextSaleId (r:1 w:1)
+	/// Storage: MultiCoin Sales (r:0 w:1)
+	fn open_sale() -> Weight {
+		Weight::from_parts(30_000_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: MultiCoin Sales (r:1 w:1)
+	/// Storage: MultiCoin CoinMetadata (r:2 w:0)
+	/// Storage: MultiCoin SaleContributions (r:1 w:1)
+	/// Storage: MultiCoin System Account (r:2 w:2)
+	/// Storage: MultiCoin DecayRates (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:2 w:2)
+	fn buy() -> Weight {
+		Weight::from_parts(45_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+
+	/// Storage: MultiCoin Sales (r:1 w:1)
+	/// Storage: MultiCoin System Account (r:2 w:2)
+	fn withdraw_proceeds() -> Weight {
+		Weight::from_parts(30_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: MultiCoin Sales (r:1 w:1)
+	/// Storage: MultiCoin System Account (r:2 w:2)
+	/// Storage: MultiCoin TotalSupply (r:1 w:1)
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	fn close_sale() -> Weight {
+		Weight::from_parts(32_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:2 w:0)
+	/// Storage: MultiCoin DecayRates (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	/// Storage: MultiCoin VoteLocks (r:1 w:0)
+	/// Storage: MultiCoin NextOrderId (r:1 w:1)
+	/// Storage: MultiCoin Orders (r:0 w:1)
+	fn place_order() -> Weight {
+		Weight::from_parts(30_000_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: MultiCoin Orders (r:1 w:1)
+	/// Storage: MultiCoin CoinMetadata (r:2 w:0)
+	/// Storage: MultiCoin DecayRates (r:2 w:0)
+	/// Storage: MultiCoin Balances (r:3 w:3)
+	/// Storage: MultiCoin VoteLocks (r:1 w:0)
+	fn fill_order() -> Weight {
+		Weight::from_parts(40_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: MultiCoin Orders (r:1 w:1)
+	/// Storage: MultiCoin DecayRates (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	fn cancel_order() -> Weight {
+		Weight::from_parts(25_000_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin NextSubscriptionId (r:1 w:1)
+	/// Storage: MultiCoin Subscriptions (r:0 w:1)
+	fn create_subscription() -> Weight {
+		Weight::from_parts(18_000_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin Subscriptions (r:1 w:1)
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin DecayRates (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:2 w:2)
+	/// Storage: MultiCoin VoteLocks (r:1 w:0)
+	/// Storage: MultiCoin TotalSupply (r:1 w:1)
+	fn claim_due() -> Weight {
+		Weight::from_parts(42_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: MultiCoin Subscriptions (r:1 w:1)
+	fn cancel_subscription() -> Weight {
+		Weight::from_parts(15_000_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin DecayRates (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	fn transfer_split(n: u32) -> Weight {
+		Weight::from_parts(12_000_000, 3533)
+			.saturating_add(Weight::from_parts(25_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin BlockedSinks (r:0 w:1)
+	fn set_sink_blocked() -> Weight {
+		Weight::from_parts(16_000_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin RecoveryConfigs (r:0 w:1)
+	/// Storage: MultiCoin ActiveRecoveries (r:0 w:1)
+	fn set_recovery() -> Weight {
+		Weight::from_parts(14_000_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin RecoveryConfigs (r:1 w:0)
+	/// Storage: MultiCoin ActiveRecoveries (r:0 w:1)
+	fn announce_recovery() -> Weight {
+		Weight::from_parts(16_000_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin ActiveRecoveries (r:1 w:1)
+	fn cancel_recovery() -> Weight {
+		Weight::from_parts(14_000_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin RecoveryConfigs (r:1 w:0)
+	/// Storage: MultiCoin ActiveRecoveries (r:1 w:0)
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin DecayRates (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:2 w:2)
+	/// Storage: MultiCoin VoteLocks (r:1 w:0)
+	fn recover_balance() -> Weight {
+		Weight::from_parts(38_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin DormancyConfigs (r:1 w:0)
+	/// Storage: MultiCoin LastActivity (r:1 w:0)
+	/// Storage: MultiCoin DormancyWarnings (r:0 w:1)
+	fn warn_dormant() -> Weight {
+		Weight::from_parts(16_000_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin DormancyConfigs (r:1 w:0)
+	/// Storage: MultiCoin DormancyWarnings (r:1 w:1)
+	/// Storage: MultiCoin LastActivity (r:1 w:2)
+	/// Storage: MultiCoin DecayRates (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:2 w:2)
+	/// Storage: MultiCoin VoteLocks (r:1 w:0)
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	fn sweep_dormant() -> Weight {
+		Weight::from_parts(40_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin CoinManagers (r:1 w:0)
+	/// Storage: MultiCoin MaxHolders (r:0 w:1)
+	fn set_max_holders() -> Weight {
+		Weight::from_parts(16_000_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Overhead of invoking the transfer hook, excluding the hook's own weight
+	fn transfer_hook() -> Weight {
+		Weight::from_parts(2_000_000, 0)
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin Royalties (r:0 w:1)
+	fn set_royalty() -> Weight {
+		Weight::from_parts(16_000_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin RoyaltyExempt (r:0 w:1)
+	fn set_royalty_exempt() -> Weight {
+		Weight::from_parts(15_000_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin MintLockPeriods (r:0 w:1)
+	fn set_mint_lock_period() -> Weight {
+		Weight::from_parts(16_000_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin SubBalances (r:1 w:1)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	/// Storage: MultiCoin VoteLocks (r:1 w:0)
+	/// Storage: MultiCoin MintLocks (r:1 w:0)
+	/// Storage: MultiCoin DecayRates (r:1 w:0)
+	fn move_between_labels() -> Weight {
+		Weight::from_parts(24_000_000, 4016)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin Faucets (r:0 w:1)
+	fn set_faucet() -> Weight {
+		Weight::from_parts(16_000_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin Faucets (r:1 w:0)
+	/// Storage: MultiCoin LastDrip (r:1 w:1)
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin TotalSupply (r:1 w:1)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	/// Storage: MultiCoin MintLockPeriods (r:1 w:0)
+	/// Storage: MultiCoin HolderCount (r:1 w:1)
+	fn drip() -> Weight {
+		Weight::from_parts(30_000_000, 4489)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin CoinManagers (r:1 w:0)
+	/// Storage: MultiCoin MintPermissions (r:0 w:1)
+	/// Storage: MultiCoin BurnPermissions (r:0 w:1)
+	/// Storage: MultiCoin PalletGrants (r:0 w:1)
+	fn grant_pallet_permission() -> Weight {
+		Weight::from_parts(26_000_000, 3600)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin BridgeMintCaps (r:0 w:1)
+	fn set_bridge_mint_cap() -> Weight {
+		Weight::from_parts(18_000_000, 3600)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin OracleConfigs (r:0 w:1)
+	fn set_oracle_config() -> Weight {
+		Weight::from_parts(18_000_000, 3600)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin PriceFeeders (r:1 w:1)
+	/// Storage: MultiCoin PriceFeeds (r:0 w:1)
+	fn set_price_feeder() -> Weight {
+		Weight::from_parts(24_000_000, 4200)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin OracleConfigs (r:1 w:0)
+	/// Storage: MultiCoin PriceFeeders (r:1 w:0)
+	/// Storage: MultiCoin PriceFeeds (r:17 w:1)
+	fn feed_price() -> Weight {
+		Weight::from_parts(62_000_000, 12500)
+			.saturating_add(T::DbWeight::get().reads(19_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin AdmittedFeeCoins (r:1 w:0)
+	/// Storage: MultiCoin FeeCoinProposals (r:0 w:1)
+	fn propose_fee_coin() -> Weight {
+		Weight::from_parts(20_000_000, 3600)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin FeeCoinProposals (r:1 w:1)
+	/// Storage: MultiCoin AdmittedFeeCoins (r:0 w:1)
+	fn approve_fee_coin() -> Weight {
+		Weight::from_parts(16_000_000, 3500)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin AdmittedFeeCoins (r:1 w:1)
+	/// Storage: MultiCoin FeeCoinProposals (r:1 w:1)
+	fn revoke_fee_coin() -> Weight {
+		Weight::from_parts(17_000_000, 3500)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin FeePaymentSuspended (r:0 w:1)
+	fn set_fee_payment_suspended() -> Weight {
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin CollateralPositions (r:1 w:0)
+	/// Storage: MultiCoin StableConfigs (r:1 w:1)
+	fn set_stable_config() -> Weight {
+		Weight::from_parts(14_000_000, 3514)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin StableConfigs (r:1 w:0)
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	/// Storage: MultiCoin CollateralPositions (r:1 w:1)
+	fn deposit_collateral() -> Weight {
+		Weight::from_parts(24_000_000, 6076)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin StableConfigs (r:1 w:0)
+	/// Storage: MultiCoin CollateralPositions (r:1 w:1)
+	/// Storage: MultiCoin OracleConfigs (r:2 w:0)
+	/// Storage: MultiCoin PriceFeeders (r:2 w:0)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	fn withdraw_collateral() -> Weight {
+		Weight::from_parts(30_000_000, 6076)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin StableConfigs (r:1 w:0)
+	/// Storage: MultiCoin CollateralPositions (r:1 w:1)
+	/// Storage: MultiCoin OracleConfigs (r:2 w:0)
+	/// Storage: MultiCoin PriceFeeders (r:2 w:0)
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin TotalSupply (r:1 w:1)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	fn mint_stable() -> Weight {
+		Weight::from_parts(34_000_000, 6076)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: MultiCoin CollateralPositions (r:1 w:1)
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin TotalSupply (r:1 w:1)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	fn repay_stable() -> Weight {
+		Weight::from_parts(24_000_000, 6076)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: MultiCoin StableConfigs (r:1 w:0)
+	/// Storage: MultiCoin CollateralPositions (r:1 w:1)
+	/// Storage: MultiCoin OracleConfigs (r:2 w:0)
+	/// Storage: MultiCoin PriceFeeders (r:2 w:0)
+	/// Storage: MultiCoin NextAuctionId (r:1 w:1)
+	/// Storage: MultiCoin LiquidationAuctions (r:0 w:1)
+	fn liquidate() -> Weight {
+		Weight::from_parts(28_000_000, 3531)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: MultiCoin LiquidationAuctions (r:1 w:1)
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin TotalSupply (r:1 w:1)
+	/// Storage: MultiCoin OracleConfigs (r:2 w:0)
+	/// Storage: MultiCoin PriceFeeders (r:2 w:0)
+	/// Storage: MultiCoin Balances (r:3 w:3)
+	fn bid_liquidation() -> Weight {
+		Weight::from_parts(45_000_000, 8613)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin FlashMintFees (r:0 w:1)
+	fn set_flash_mint_fee() -> Weight {
+		Weight::from_parts(12_000_000, 4489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin FlashMintFees (r:1 w:0)
+	/// Storage: MultiCoin CoinMetadata (r:2 w:0)
+	/// Storage: MultiCoin TotalSupply (r:1 w:2)
+	/// Storage: MultiCoin Balances (r:1 w:2)
+	fn flash_mint() -> Weight {
+		Weight::from_parts(38_000_000, 4489)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin BlockedSinks (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	/// Storage: MultiCoin NextTicketId (r:1 w:1)
+	/// Storage: MultiCoin TransferTickets (r:0 w:1)
+	fn create_transfer_ticket() -> Weight {
+		Weight::from_parts(26_000_000, 4489)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: MultiCoin TransferTickets (r:1 w:1)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	fn claim_ticket() -> Weight {
+		Weight::from_parts(20_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin TransferTickets (r:1 w:1)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	fn refund_ticket() -> Weight {
+		Weight::from_parts(20_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin SupplyCheckpoints (r:1 w:1)
+	/// Storage: MultiCoin TotalSupply (r:1 w:0)
+	/// Storage: MultiCoin HolderCount (r:1 w:0)
+	/// Storage: MultiCoin FeesBurnedSinceCheckpoint (r:1 w:1)
+	fn checkpoint_supply() -> Weight {
+		Weight::from_parts(18_000_000, 3675)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin DecayRates (r:100 w:0)
+	/// Storage: MultiCoin LastTouched (r:100 w:0)
+	/// Storage: MultiCoin Balances (r:100 w:0)
+	/// Storage: MultiCoin AccrualIndex (r:100 w:0)
+	fn balances_many(n: u32) -> Weight {
+		Weight::from_parts(2_000_000, 0)
+			.saturating_add(Weight::from_parts(6_000_000, 2533).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+	}
+
+	/// Storage: MultiCoin LastTouched (r:1 w:1)
+	/// Storage: MultiCoin Balances (r:1 w:0)
+	fn cleanup_dust_entry() -> Weight {
+		Weight::from_parts(9_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin PausedExcept (r:0 w:1)
+	fn set_transfer_pause() -> Weight {
+		Weight::from_parts(14_000_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin LegalDocuments (r:0 w:1)
+	fn set_legal_document() -> Weight {
+		Weight::from_parts(14_000_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin ApprovalNonces (r:1 w:0)
+	/// Storage: MultiCoin Approvals (r:0 w:1)
+	fn approve() -> Weight {
+		Weight::from_parts(14_000_000, 3499)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:2 w:2)
+	/// Storage: MultiCoin FeesBurnedSinceCheckpoint (r:1 w:1)
+	/// Storage: MultiCoin TotalFeesBurned (r:1 w:1)
+	/// Storage: MultiCoin PausedExcept (r:1 w:0)
+	/// Storage: MultiCoin VelocitySenders (r:1 w:1)
+	/// Storage: MultiCoin CoinVelocity (r:1 w:1)
+	/// Storage: MultiCoin Approvals (r:1 w:1)
+	/// Storage: MultiCoin ApprovalNonces (r:1 w:0)
+	fn transfer_from() -> Weight {
+		Weight::from_parts(26_000_000, 6076)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+
+	/// Storage: MultiCoin Approvals (r:1 w:1)
+	fn cancel_approval() -> Weight {
+		Weight::from_parts(12_000_000, 3530)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin ApprovalNonces (r:1 w:1)
+	fn invalidate_approvals() -> Weight {
+		Weight::from_parts(12_000_000, 3499)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin FrozenAccounts (r:1 w:1)
+	fn freeze_account() -> Weight {
+		Weight::from_parts(16_000_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin FrozenAccounts (r:1 w:1)
+	fn thaw_account() -> Weight {
+		Weight::from_parts(16_000_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:1)
+	/// Storage: MultiCoin CollateralPositions (r:1 w:0)
+	/// Storage: MultiCoin CoinReferences (r:1 w:0)
+	fn start_destroy() -> Weight {
+		Weight::from_parts(18_000_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	fn destroy_accounts(n: u32) -> Weight {
+		Weight::from_parts(20_000_000, 3533)
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(24_u64))
+			.saturating_add(T::DbWeight::get().writes(0_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:1)
+	/// Storage: MultiCoin SymbolToId (r:0 w:1)
+	/// Storage: MultiCoin TotalSupply (r:0 w:1)
+	/// Storage: MultiCoin FeeUsage (r:1 w:1)
+	/// Storage: MultiCoin TotalFeeUsage (r:1 w:1)
+	/// Storage: MultiCoin CoinDepositors (r:1 w:1)
+	/// Storage: MultiCoin TotalCoinDeposits (r:1 w:1)
+	fn finish_destroy() -> Weight {
+		Weight::from_parts(95_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(28_u64))
+			.saturating_add(T::DbWeight::get().writes(34_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:1)
+	fn set_team() -> Weight {
+		Weight::from_parts(16_000_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	/// Storage: MultiCoin FrozenAccounts (r:1 w:0)
+	/// Storage: MultiCoin TotalSupply (r:1 w:1)
+	/// Storage: MultiCoin FeesBurnedSinceCheckpoint (r:1 w:1)
+	/// Storage: MultiCoin TotalFeesBurned (r:1 w:1)
+	/// Storage: MultiCoin VelocitySenders (r:1 w:1)
+	/// Storage: MultiCoin CoinVelocity (r:1 w:1)
+	fn transfer_batch(n: u32) -> Weight {
+		Weight::from_parts(20_000_000, 6076)
+			.saturating_add(Weight::from_parts(12_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().reads((8_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:1)
+	/// Storage: MultiCoin CoinManagers (r:1 w:1)
+	/// Storage: MultiCoin PendingFeeChanges (r:1 w:1)
+	/// Storage: MultiCoin MintPermissions (r:0 w:1)
+	/// Storage: MultiCoin BurnPermissions (r:0 w:1)
+	fn renounce_ownership() -> Weight {
+		Weight::from_parts(22_000_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:2 w:2)
+	/// Storage: MultiCoin FeesBurnedSinceCheckpoint (r:1 w:1)
+	/// Storage: MultiCoin TotalFeesBurned (r:1 w:1)
+	/// Storage: MultiCoin PausedExcept (r:1 w:0)
+	/// Storage: MultiCoin VelocitySenders (r:1 w:1)
+	/// Storage: MultiCoin CoinVelocity (r:1 w:1)
+	/// Storage: MultiCoin FrozenAccounts (r:2 w:0)
+	fn transfer_keep_alive() -> Weight {
+		Weight::from_parts(19_000_000, 6076)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:2 w:0)
+	/// Storage: MultiCoin Balances (r:2 w:2)
+	/// Storage: MultiCoin VoteLocks (r:1 w:0)
+	/// Storage: MultiCoin Holds (r:1 w:0)
+	/// Storage: MultiCoin Royalties (r:1 w:0)
+	/// Storage: MultiCoin FeesBurnedSinceCheckpoint (r:1 w:1)
+	/// Storage: MultiCoin TotalFeesBurned (r:1 w:1)
+	/// Storage: MultiCoin PausedExcept (r:1 w:0)
+	/// Storage: MultiCoin VelocitySenders (r:1 w:1)
+	/// Storage: MultiCoin CoinVelocity (r:1 w:1)
+	/// Storage: MultiCoin FrozenAccounts (r:2 w:0)
+	fn transfer_all() -> Weight {
+		Weight::from_parts(22_000_000, 6076)
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin TotalSupply (r:1 w:1)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	/// Storage: MultiCoin MintLockPeriods (r:1 w:0)
+	fn force_mint() -> Weight {
+		Weight::from_parts(20_000_000, 4489)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	/// Storage: MultiCoin TotalSupply (r:1 w:1)
+	fn force_burn() -> Weight {
+		Weight::from_parts(17_000_000, 4489)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin PendingHolderSnapshots (r:1 w:1)
+	/// Storage: MultiCoin HolderSnapshots (r:1 w:1)
+	/// Storage: MultiCoin Balances (r:1 w:0)
+	/// Storage: MultiCoin HolderCheckpoints (r:0 w:1)
+	fn checkpoint_holders(n: u32) -> Weight {
+		Weight::from_parts(15_000_000, 3533)
+			.saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin CoinManagers (r:1 w:0)
+	/// Storage: MultiCoin CoinLocalizations (r:1 w:1)
+	/// Storage: MultiCoin MetadataDeposits (r:1 w:1)
+	/// Storage: MultiCoin System Account (r:1 w:2)
+	fn set_localization() -> Weight {
+		Weight::from_parts(30_000_000, 12000)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin CoinManagers (r:1 w:0)
+	/// Storage: MultiCoin ExtendedMetadata (r:1 w:1)
+	/// Storage: MultiCoin CoinLocalizations (r:1 w:0)
+	/// Storage: MultiCoin MetadataDeposits (r:1 w:1)
+	/// Storage: MultiCoin System Account (r:1 w:2)
+	fn set_extended_metadata() -> Weight {
+		Weight::from_parts(30_000_000, 12000)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: MultiCoin FeeOperators (r:0 w:1)
+	fn set_fee_operator() -> Weight {
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin FeeOperators (r:1 w:0)
+	/// Storage: MultiCoin PreferredFeeCoin (r:0 w:1)
+	fn set_preferred_fee_coin_for(n: u32) -> Weight {
+		Weight::from_parts(10_000_000, 3533)
+			.saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(0_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+}
+
+/// Weights for tests only; runtimes should use `SubstrateWeight`
+impl WeightInfo for () {
+	/// Storage: MultiCoin CoinMetadata (r:1 w:1)
+	/// Proof: MultiCoin CoinMetadata (max_values: None, max_size: Some(1024), added: 3499, mode: MaxEncodedLen)
+	/// Storage: MultiCoin SymbolToId (r:1 w:1)
+	/// Proof: MultiCoin SymbolToId (max_values: None, max_size: Some(256), added: 2731, mode: MaxEncodedLen)
+	/// Storage: MultiCoin NextCoinId (r:1 w:1)
+	/// Proof: MultiCoin NextCoinId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: MultiCoin TotalSupply (r:0 w:1)
+	/// Proof: MultiCoin TotalSupply (max_values: None, max_size: Some(20), added: 2495, mode: MaxEncodedLen)
+	/// Storage: MultiCoin Balances (r:0 w:1)
+	/// Proof: MultiCoin Balances (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: MultiCoin MintPermissions (r:0 w:1)
+	/// Proof: MultiCoin MintPermissions (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: MultiCoin BurnPermissions (r:0 w:1)
+	/// Proof: MultiCoin BurnPermissions (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: MultiCoin CoinDepositors (r:0 w:1)
+	/// Storage: MultiCoin TotalCoinDeposits (r:1 w:1)
+	/// The range of component `m` is `[0, 32]`.
+	/// The range of component `b` is `[0, 32]`.
+	fn create_coin(m: u32, b: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `4489`
+		// Minimum execution time: 25_000_000 picoseconds.
+		Weight::from_parts(26_000_000, 4489)
+			.saturating_add(Weight::from_parts(2_000_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(2_000_000, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(m.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(b.into())))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Proof: MultiCoin CoinMetadata (max_values: None, max_size: Some(1024), added: 3499, mode: MaxEncodedLen)
+	/// Storage: MultiCoin Balances (r:2 w:2)
+	/// Proof: MultiCoin Balances (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: MultiCoin FeesBurnedSinceCheckpoint (r:1 w:1)
+	/// Storage: MultiCoin TotalFeesBurned (r:1 w:1)
+	/// Storage: MultiCoin PausedExcept (r:1 w:0)
+	/// Storage: MultiCoin VelocitySenders (r:1 w:1)
+	/// Storage: MultiCoin CoinVelocity (r:1 w:1)
+	/// Storage: MultiCoin FrozenAccounts (r:2 w:0)
+	fn transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `187`
+		//  Estimated: `6076`
+		// Minimum execution time: 18_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 6076)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+
+	/// Storage: MultiCoin MintPermissions (r:1 w:0)
+	/// Proof: MultiCoin MintPermissions (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: MultiCoin TotalSupply (r:1 w:1)
+	/// Proof: MultiCoin TotalSupply (max_values: None, max_size: Some(20), added: 2495, mode: MaxEncodedLen)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	/// Proof: MultiCoin Balances (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: MultiCoin FrozenAccounts (r:1 w:0)
+	fn mint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `223`
+		//  Estimated: `4489`
+		// Minimum execution time: 20_000_000 picoseconds.
+		Weight::from_parts(21_000_000, 4489)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	/// Proof: MultiCoin Balances (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: MultiCoin TotalSupply (r:1 w:1)
+	/// Proof: MultiCoin TotalSupply (max_values: None, max_size: Some(20), added: 2495, mode: MaxEncodedLen)
+	/// Storage: MultiCoin FrozenAccounts (r:1 w:0)
+	fn burn() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `187`
+		//  Estimated: `4489`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 4489)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:1)
+	/// Proof: MultiCoin CoinMetadata (max_values: None, max_size: Some(1024), added: 3499, mode: MaxEncodedLen)
+	/// Storage: MultiCoin MintPermissions (r:0 w:2)
+	/// Proof: MultiCoin MintPermissions (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: MultiCoin BurnPermissions (r:0 w:2)
+	/// Proof: MultiCoin BurnPermissions (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: MultiCoin CoinDepositors (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	fn transfer_ownership() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `187`
+		//  Estimated: `4489`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 4489)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Proof: MultiCoin CoinMetadata (max_values: None, max_size: Some(1024), added: 3499, mode: MaxEncodedLen)
+	/// Storage: MultiCoin MintPermissions (r:0 w:1)
+	/// Proof: MultiCoin MintPermissions (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	fn set_mint_permission() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `4489`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 4489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:1)
+	/// Proof: MultiCoin CoinMetadata (max_values: None, max_size: Some(1024), added: 3499, mode: MaxEncodedLen)
+	fn set_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `4489`
+		// Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 4489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	fn set_fee_config() -> Weight {
+		// Similar to set_metadata: 1 read, 1 write
+		Weight::from_parts(11_000_000, 4489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	fn set_preferred_fee_coin() -> Weight {
+		Weight::from_parts(10_000_000, 4000)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin FeeChangeGuards (r:1 w:1)
+	fn set_fee_guard() -> Weight {
+		Weight::from_parts(12_000_000, 4489)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:1)
+	/// Storage: MultiCoin FeeChangeGuards (r:1 w:0)
+	/// Storage: MultiCoin PendingFeeChanges (r:1 w:1)
+	fn approve_fee_change() -> Weight {
+		Weight::from_parts(16_000_000, 4489)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:1)
+	/// Storage: MultiCoin FeeChangeGuards (r:1 w:0)
+	/// Storage: MultiCoin PendingFeeChanges (r:1 w:1)
+	fn enact_fee_change() -> Weight {
+		Weight::from_parts(16_000_000, 4489)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin FeeChangeGuards (r:1 w:0)
+	/// Storage: MultiCoin PendingFeeChanges (r:1 w:1)
+	fn cancel_fee_change() -> Weight {
+		Weight::from_parts(12_000_000, 4489)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	fn sweep_zero_balance() -> Weight {
+		Weight::from_parts(6_000_000, 2543)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:1)
+	fn freeze_coin() -> Weight {
+		Weight::from_parts(12_000_000, 4489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:1)
+	fn thaw_coin() -> Weight {
+		Weight::from_parts(12_000_000, 4489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:2 w:2)
+	fn force_transfer() -> Weight {
+		Weight::from_parts(19_000_000, 6076)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin CoinManagers (r:0 w:1)
+	fn set_coin_manager() -> Weight {
+		Weight::from_parts(11_000_000, 3499)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin CoinManagers (r:1 w:0)
+	/// Storage: MultiCoin AccrualIndex (r:0 w:1)
+	fn set_accrual_index() -> Weight {
+		Weight::from_parts(12_000_000, 3499)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin CoinManagers (r:1 w:0)
+	/// Storage: MultiCoin AccrualIndex (r:1 w:0)
+	/// Storage: MultiCoin DecayRates (r:0 w:1)
+	fn set_decay_rate() -> Weight {
+		Weight::from_parts(12_000_000, 3499)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin CoinManagers (r:1 w:0)
+	/// Storage: MultiCoin RewardPools (r:1 w:1)
+	fn set_reward_rate() -> Weight {
+		Weight::from_parts(13_000_000, 3499)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin CoinManagers (r:1 w:0)
+	/// Storage: MultiCoin DecayRates (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	/// Storage: MultiCoin RewardPools (r:1 w:1)
+	fn fund_rewards() -> Weight {
+		Weight::from_parts(20_000_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin DecayRates (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	/// Storage: MultiCoin RewardPools (r:1 w:1)
+	/// Storage: MultiCoin Stakes (r:1 w:1)
+	fn stake() -> Weight {
+		Weight::from_parts(24_000_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin DecayRates (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	/// Storage: MultiCoin RewardPools (r:1 w:1)
+	/// Storage: MultiCoin Stakes (r:1 w:1)
+	fn unstake() -> Weight {
+		Weight::from_parts(24_000_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin DecayRates (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	/// Storage: MultiCoin RewardPools (r:1 w:1)
+	/// Storage: MultiCoin Stakes (r:1 w:1)
+	fn claim_rewards() -> Weight {
+		Weight::from_parts(24_000_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin AccrualIndex (r:1 w:0)
+	/// Storage: MultiCoin DecayRates (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:1 w:0)
+	/// Storage: MultiCoin VoteLocks (r:1 w:1)
+	fn lock_for_vote() -> Weight {
+		Weight::from_parts(16_000_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin VoteLocks (r:1 w:1)
+	fn unlock_vote() -> Weight {
+		Weight::from_parts(11_000_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:2 w:0)
+	/// Storage: MultiCoin DecayRates (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	/// Storage: MultiCoin VoteLocks (r:1 w:0)
+	/// Storage: MultiCoin NextSaleId (r:1 w:1)
+	/// Storage: MultiCoin Sales (r:0 w:1)
+	fn open_sale() -> Weight {
+		Weight::from_parts(30_000_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: MultiCoin Sales (r:1 w:1)
+	/// Storage: MultiCoin CoinMetadata (r:2 w:0)
+	/// Storage: MultiCoin SaleContributions (r:1 w:1)
+	/// Storage: MultiCoin System Account (r:2 w:2)
+	/// Storage: MultiCoin DecayRates (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:2 w:2)
+	fn buy() -> Weight {
+		Weight::from_parts(45_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+
+	/// Storage: MultiCoin Sales (r:1 w:1)
+	/// Storage: MultiCoin System Account (r:2 w:2)
+	fn withdraw_proceeds() -> Weight {
+		Weight::from_parts(30_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: MultiCoin Sales (r:1 w:1)
+	/// Storage: MultiCoin System Account (r:2 w:2)
+	/// Storage: MultiCoin TotalSupply (r:1 w:1)
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	fn close_sale() -> Weight {
+		Weight::from_parts(32_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:2 w:0)
+	/// Storage: MultiCoin DecayRates (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	/// Storage: MultiCoin VoteLocks (r:1 w:0)
+	/// Storage: MultiCoin NextOrderId (r:1 w:1)
+	/// Storage: MultiCoin Orders (r:0 w:1)
+	fn place_order() -> Weight {
+		Weight::from_parts(30_000_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: MultiCoin Orders (r:1 w:1)
+	/// Storage: MultiCoin CoinMetadata (r:2 w:0)
+	/// Storage: MultiCoin DecayRates (r:2 w:0)
+	/// Storage: MultiCoin Balances (r:3 w:3)
+	/// Storage: MultiCoin VoteLocks (r:1 w:0)
+	fn fill_order() -> Weight {
+		Weight::from_parts(40_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: MultiCoin Orders (r:1 w:1)
+	/// Storage: MultiCoin DecayRates (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	fn cancel_order() -> Weight {
+		Weight::from_parts(25_000_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin NextSubscriptionId (r:1 w:1)
+	/// Storage: MultiCoin Subscriptions (r:0 w:1)
+	fn create_subscription() -> Weight {
+		Weight::from_parts(18_000_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin Subscriptions (r:1 w:1)
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin DecayRates (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:2 w:2)
+	/// Storage: MultiCoin VoteLocks (r:1 w:0)
+	/// Storage: MultiCoin TotalSupply (r:1 w:1)
+	fn claim_due() -> Weight {
+		Weight::from_parts(42_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: MultiCoin Subscriptions (r:1 w:1)
+	fn cancel_subscription() -> Weight {
+		Weight::from_parts(15_000_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin DecayRates (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	fn transfer_split(n: u32) -> Weight {
+		Weight::from_parts(12_000_000, 3533)
+			.saturating_add(Weight::from_parts(25_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin BlockedSinks (r:0 w:1)
+	fn set_sink_blocked() -> Weight {
+		Weight::from_parts(16_000_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin RecoveryConfigs (r:0 w:1)
+	/// Storage: MultiCoin ActiveRecoveries (r:0 w:1)
+	fn set_recovery() -> Weight {
+		Weight::from_parts(14_000_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin RecoveryConfigs (r:1 w:0)
+	/// Storage: MultiCoin ActiveRecoveries (r:0 w:1)
+	fn announce_recovery() -> Weight {
+		Weight::from_parts(16_000_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin ActiveRecoveries (r:1 w:1)
+	fn cancel_recovery() -> Weight {
+		Weight::from_parts(14_000_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin RecoveryConfigs (r:1 w:0)
+	/// Storage: MultiCoin ActiveRecoveries (r:1 w:0)
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin DecayRates (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:2 w:2)
+	/// Storage: MultiCoin VoteLocks (r:1 w:0)
+	fn recover_balance() -> Weight {
+		Weight::from_parts(38_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin DormancyConfigs (r:1 w:0)
+	/// Storage: MultiCoin LastActivity (r:1 w:0)
+	/// Storage: MultiCoin DormancyWarnings (r:0 w:1)
+	fn warn_dormant() -> Weight {
+		Weight::from_parts(16_000_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin DormancyConfigs (r:1 w:0)
+	/// Storage: MultiCoin DormancyWarnings (r:1 w:1)
+	/// Storage: MultiCoin LastActivity (r:1 w:2)
+	/// Storage: MultiCoin DecayRates (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:2 w:2)
+	/// Storage: MultiCoin VoteLocks (r:1 w:0)
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	fn sweep_dormant() -> Weight {
+		Weight::from_parts(40_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin CoinManagers (r:1 w:0)
+	/// Storage: MultiCoin MaxHolders (r:0 w:1)
+	fn set_max_holders() -> Weight {
+		Weight::from_parts(16_000_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Overhead of invoking the transfer hook, excluding the hook's own weight
+	fn transfer_hook() -> Weight {
+		Weight::from_parts(2_000_000, 0)
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin Royalties (r:0 w:1)
+	fn set_royalty() -> Weight {
+		Weight::from_parts(16_000_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin RoyaltyExempt (r:0 w:1)
+	fn set_royalty_exempt() -> Weight {
+		Weight::from_parts(15_000_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin MintLockPeriods (r:0 w:1)
+	fn set_mint_lock_period() -> Weight {
+		Weight::from_parts(16_000_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin SubBalances (r:1 w:1)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	/// Storage: MultiCoin VoteLocks (r:1 w:0)
+	/// Storage: MultiCoin MintLocks (r:1 w:0)
+	/// Storage: MultiCoin DecayRates (r:1 w:0)
+	fn move_between_labels() -> Weight {
+		Weight::from_parts(24_000_000, 4016)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin Faucets (r:0 w:1)
+	fn set_faucet() -> Weight {
+		Weight::from_parts(16_000_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin Faucets (r:1 w:0)
+	/// Storage: MultiCoin LastDrip (r:1 w:1)
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin TotalSupply (r:1 w:1)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	/// Storage: MultiCoin MintLockPeriods (r:1 w:0)
+	/// Storage: MultiCoin HolderCount (r:1 w:1)
+	fn drip() -> Weight {
+		Weight::from_parts(30_000_000, 4489)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin CoinManagers (r:1 w:0)
+	/// Storage: MultiCoin MintPermissions (r:0 w:1)
+	/// Storage: MultiCoin BurnPermissions (r:0 w:1)
+	/// Storage: MultiCoin PalletGrants (r:0 w:1)
+	fn grant_pallet_permission() -> Weight {
+		Weight::from_parts(26_000_000, 3600)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin BridgeMintCaps (r:0 w:1)
+	fn set_bridge_mint_cap() -> Weight {
+		Weight::from_parts(18_000_000, 3600)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin OracleConfigs (r:0 w:1)
+	fn set_oracle_config() -> Weight {
+		Weight::from_parts(18_000_000, 3600)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin PriceFeeders (r:1 w:1)
+	/// Storage: MultiCoin PriceFeeds (r:0 w:1)
+	fn set_price_feeder() -> Weight {
+		Weight::from_parts(24_000_000, 4200)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin OracleConfigs (r:1 w:0)
+	/// Storage: MultiCoin PriceFeeders (r:1 w:0)
+	/// Storage: MultiCoin PriceFeeds (r:17 w:1)
+	fn feed_price() -> Weight {
+		Weight::from_parts(62_000_000, 12500)
+			.saturating_add(RocksDbWeight::get().reads(19_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin AdmittedFeeCoins (r:1 w:0)
+	/// Storage: MultiCoin FeeCoinProposals (r:0 w:1)
+	fn propose_fee_coin() -> Weight {
+		Weight::from_parts(20_000_000, 3600)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin FeeCoinProposals (r:1 w:1)
+	/// Storage: MultiCoin AdmittedFeeCoins (r:0 w:1)
+	fn approve_fee_coin() -> Weight {
+		Weight::from_parts(16_000_000, 3500)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin AdmittedFeeCoins (r:1 w:1)
+	/// Storage: MultiCoin FeeCoinProposals (r:1 w:1)
+	fn revoke_fee_coin() -> Weight {
+		Weight::from_parts(17_000_000, 3500)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin FeePaymentSuspended (r:0 w:1)
+	fn set_fee_payment_suspended() -> Weight {
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin CollateralPositions (r:1 w:0)
+	/// Storage: MultiCoin StableConfigs (r:1 w:1)
+	fn set_stable_config() -> Weight {
+		Weight::from_parts(14_000_000, 3514)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin StableConfigs (r:1 w:0)
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	/// Storage: MultiCoin CollateralPositions (r:1 w:1)
+	fn deposit_collateral() -> Weight {
+		Weight::from_parts(24_000_000, 6076)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin StableConfigs (r:1 w:0)
+	/// Storage: MultiCoin CollateralPositions (r:1 w:1)
+	/// Storage: MultiCoin OracleConfigs (r:2 w:0)
+	/// Storage: MultiCoin PriceFeeders (r:2 w:0)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	fn withdraw_collateral() -> Weight {
+		Weight::from_parts(30_000_000, 6076)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin StableConfigs (r:1 w:0)
+	/// Storage: MultiCoin CollateralPositions (r:1 w:1)
+	/// Storage: MultiCoin OracleConfigs (r:2 w:0)
+	/// Storage: MultiCoin PriceFeeders (r:2 w:0)
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin TotalSupply (r:1 w:1)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	fn mint_stable() -> Weight {
+		Weight::from_parts(34_000_000, 6076)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: MultiCoin CollateralPositions (r:1 w:1)
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin TotalSupply (r:1 w:1)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	fn repay_stable() -> Weight {
+		Weight::from_parts(24_000_000, 6076)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: MultiCoin StableConfigs (r:1 w:0)
+	/// Storage: MultiCoin CollateralPositions (r:1 w:1)
+	/// Storage: MultiCoin OracleConfigs (r:2 w:0)
+	/// Storage: MultiCoin PriceFeeders (r:2 w:0)
+	/// Storage: MultiCoin NextAuctionId (r:1 w:1)
+	/// Storage: MultiCoin LiquidationAuctions (r:0 w:1)
+	fn liquidate() -> Weight {
+		Weight::from_parts(28_000_000, 3531)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: MultiCoin LiquidationAuctions (r:1 w:1)
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin TotalSupply (r:1 w:1)
+	/// Storage: MultiCoin OracleConfigs (r:2 w:0)
+	/// Storage: MultiCoin PriceFeeders (r:2 w:0)
+	/// Storage: MultiCoin Balances (r:3 w:3)
+	fn bid_liquidation() -> Weight {
+		Weight::from_parts(45_000_000, 8613)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin FlashMintFees (r:0 w:1)
+	fn set_flash_mint_fee() -> Weight {
+		Weight::from_parts(12_000_000, 4489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin FlashMintFees (r:1 w:0)
+	/// Storage: MultiCoin CoinMetadata (r:2 w:0)
+	/// Storage: MultiCoin TotalSupply (r:1 w:2)
+	/// Storage: MultiCoin Balances (r:1 w:2)
+	fn flash_mint() -> Weight {
+		Weight::from_parts(38_000_000, 4489)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin BlockedSinks (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	/// Storage: MultiCoin NextTicketId (r:1 w:1)
+	/// Storage: MultiCoin TransferTickets (r:0 w:1)
+	fn create_transfer_ticket() -> Weight {
+		Weight::from_parts(26_000_000, 4489)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: MultiCoin TransferTickets (r:1 w:1)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	fn claim_ticket() -> Weight {
+		Weight::from_parts(20_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin TransferTickets (r:1 w:1)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	fn refund_ticket() -> Weight {
+		Weight::from_parts(20_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin SupplyCheckpoints (r:1 w:1)
+	/// Storage: MultiCoin TotalSupply (r:1 w:0)
+	/// Storage: MultiCoin HolderCount (r:1 w:0)
+	/// Storage: MultiCoin FeesBurnedSinceCheckpoint (r:1 w:1)
+	fn checkpoint_supply() -> Weight {
+		Weight::from_parts(18_000_000, 3675)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin DecayRates (r:100 w:0)
+	/// Storage: MultiCoin LastTouched (r:100 w:0)
+	/// Storage: MultiCoin Balances (r:100 w:0)
+	/// Storage: MultiCoin AccrualIndex (r:100 w:0)
+	fn balances_many(n: u32) -> Weight {
+		Weight::from_parts(2_000_000, 0)
+			.saturating_add(Weight::from_parts(6_000_000, 2533).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+	}
+
+	/// Storage: MultiCoin LastTouched (r:1 w:1)
+	/// Storage: MultiCoin Balances (r:1 w:0)
+	fn cleanup_dust_entry() -> Weight {
+		Weight::from_parts(9_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin PausedExcept (r:0 w:1)
+	fn set_transfer_pause() -> Weight {
+		Weight::from_parts(14_000_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin LegalDocuments (r:0 w:1)
+	fn set_legal_document() -> Weight {
+		Weight::from_parts(14_000_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin ApprovalNonces (r:1 w:0)
+	/// Storage: MultiCoin Approvals (r:0 w:1)
+	fn approve() -> Weight {
+		Weight::from_parts(14_000_000, 3499)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:2 w:2)
+	/// Storage: MultiCoin FeesBurnedSinceCheckpoint (r:1 w:1)
+	/// Storage: MultiCoin TotalFeesBurned (r:1 w:1)
+	/// Storage: MultiCoin PausedExcept (r:1 w:0)
+	/// Storage: MultiCoin VelocitySenders (r:1 w:1)
+	/// Storage: MultiCoin CoinVelocity (r:1 w:1)
+	/// Storage: MultiCoin Approvals (r:1 w:1)
+	/// Storage: MultiCoin ApprovalNonces (r:1 w:0)
+	fn transfer_from() -> Weight {
+		Weight::from_parts(26_000_000, 6076)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+
+	/// Storage: MultiCoin Approvals (r:1 w:1)
+	fn cancel_approval() -> Weight {
+		Weight::from_parts(12_000_000, 3530)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin ApprovalNonces (r:1 w:1)
+	fn invalidate_approvals() -> Weight {
+		Weight::from_parts(12_000_000, 3499)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin FrozenAccounts (r:1 w:1)
+	fn freeze_account() -> Weight {
+		Weight::from_parts(16_000_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin FrozenAccounts (r:1 w:1)
+	fn thaw_account() -> Weight {
+		Weight::from_parts(16_000_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:1)
+	/// Storage: MultiCoin CollateralPositions (r:1 w:0)
+	/// Storage: MultiCoin CoinReferences (r:1 w:0)
+	fn start_destroy() -> Weight {
+		Weight::from_parts(18_000_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	fn destroy_accounts(n: u32) -> Weight {
+		Weight::from_parts(20_000_000, 3533)
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(24_u64))
+			.saturating_add(RocksDbWeight::get().writes(0_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:1)
+	/// Storage: MultiCoin SymbolToId (r:0 w:1)
+	/// Storage: MultiCoin TotalSupply (r:0 w:1)
+	/// Storage: MultiCoin FeeUsage (r:1 w:1)
+	/// Storage: MultiCoin TotalFeeUsage (r:1 w:1)
+	/// Storage: MultiCoin CoinDepositors (r:1 w:1)
+	/// Storage: MultiCoin TotalCoinDeposits (r:1 w:1)
+	fn finish_destroy() -> Weight {
+		Weight::from_parts(95_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(28_u64))
+			.saturating_add(RocksDbWeight::get().writes(34_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:1)
+	fn set_team() -> Weight {
+		Weight::from_parts(16_000_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	/// Storage: MultiCoin FrozenAccounts (r:1 w:0)
+	/// Storage: MultiCoin TotalSupply (r:1 w:1)
+	/// Storage: MultiCoin FeesBurnedSinceCheckpoint (r:1 w:1)
+	/// Storage: MultiCoin TotalFeesBurned (r:1 w:1)
+	/// Storage: MultiCoin VelocitySenders (r:1 w:1)
+	/// Storage: MultiCoin CoinVelocity (r:1 w:1)
+	fn transfer_batch(n: u32) -> Weight {
+		Weight::from_parts(20_000_000, 6076)
+			.saturating_add(Weight::from_parts(12_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((8_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:1)
+	/// Storage: MultiCoin CoinManagers (r:1 w:1)
+	/// Storage: MultiCoin PendingFeeChanges (r:1 w:1)
+	/// Storage: MultiCoin MintPermissions (r:0 w:1)
+	/// Storage: MultiCoin BurnPermissions (r:0 w:1)
+	fn renounce_ownership() -> Weight {
+		Weight::from_parts(22_000_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:2 w:2)
+	/// Storage: MultiCoin FeesBurnedSinceCheckpoint (r:1 w:1)
+	/// Storage: MultiCoin TotalFeesBurned (r:1 w:1)
+	/// Storage: MultiCoin PausedExcept (r:1 w:0)
+	/// Storage: MultiCoin VelocitySenders (r:1 w:1)
+	/// Storage: MultiCoin CoinVelocity (r:1 w:1)
+	/// Storage: MultiCoin FrozenAccounts (r:2 w:0)
+	fn transfer_keep_alive() -> Weight {
+		Weight::from_parts(19_000_000, 6076)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:2 w:0)
+	/// Storage: MultiCoin Balances (r:2 w:2)
+	/// Storage: MultiCoin VoteLocks (r:1 w:0)
+	/// Storage: MultiCoin Holds (r:1 w:0)
+	/// Storage: MultiCoin Royalties (r:1 w:0)
+	/// Storage: MultiCoin FeesBurnedSinceCheckpoint (r:1 w:1)
+	/// Storage: MultiCoin TotalFeesBurned (r:1 w:1)
+	/// Storage: MultiCoin PausedExcept (r:1 w:0)
+	/// Storage: MultiCoin VelocitySenders (r:1 w:1)
+	/// Storage: MultiCoin CoinVelocity (r:1 w:1)
+	/// Storage: MultiCoin FrozenAccounts (r:2 w:0)
+	fn transfer_all() -> Weight {
+		Weight::from_parts(22_000_000, 6076)
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin TotalSupply (r:1 w:1)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	/// Storage: MultiCoin MintLockPeriods (r:1 w:0)
+	fn force_mint() -> Weight {
+		Weight::from_parts(20_000_000, 4489)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin Balances (r:1 w:1)
+	/// Storage: MultiCoin TotalSupply (r:1 w:1)
+	fn force_burn() -> Weight {
+		Weight::from_parts(17_000_000, 4489)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin PendingHolderSnapshots (r:1 w:1)
+	/// Storage: MultiCoin HolderSnapshots (r:1 w:1)
+	/// Storage: MultiCoin Balances (r:1 w:0)
+	/// Storage: MultiCoin HolderCheckpoints (r:0 w:1)
+	fn checkpoint_holders(n: u32) -> Weight {
+		Weight::from_parts(15_000_000, 3533)
+			.saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin CoinManagers (r:1 w:0)
+	/// Storage: MultiCoin CoinLocalizations (r:1 w:1)
+	/// Storage: MultiCoin MetadataDeposits (r:1 w:1)
+	/// Storage: MultiCoin System Account (r:1 w:2)
+	fn set_localization() -> Weight {
+		Weight::from_parts(30_000_000, 12000)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin CoinManagers (r:1 w:0)
+	/// Storage: MultiCoin ExtendedMetadata (r:1 w:1)
+	/// Storage: MultiCoin CoinLocalizations (r:1 w:0)
+	/// Storage: MultiCoin MetadataDeposits (r:1 w:1)
+	/// Storage: MultiCoin System Account (r:1 w:2)
+	fn set_extended_metadata() -> Weight {
+		Weight::from_parts(30_000_000, 12000)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: MultiCoin FeeOperators (r:0 w:1)
+	fn set_fee_operator() -> Weight {
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: MultiCoin CoinMetadata (r:1 w:0)
+	/// Storage: MultiCoin FeeOperators (r:1 w:0)
+	/// Storage: MultiCoin PreferredFeeCoin (r:0 w:1)
+	fn set_preferred_fee_coin_for(n: u32) -> Weight {
+		Weight::from_parts(10_000_000, 3533)
+			.saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(0_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+}
@@ -0,0 +1,553 @@
+//! Benchmarking setup for pallet-proof-of-reserve
+//!
+//! Benchmarks run against a worst-case pre-state: the bridged coin has ratio
+//! bounds, its wallet is bound to another account so deposits get flagged,
+//! recipients are newly endowed and withdrawals route dust, pay a tip and
+//! reward a registered processor.
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as ProofOfReserve;
+use frame_benchmarking::v2::*;
+use frame_support::{assert_ok, traits::{Currency, EnsureOrigin, Get}, BoundedVec};
+use frame_system::RawOrigin;
+use sp_runtime::{traits::{Saturating, Zero}, SaturatedConversion};
+use sp_std::{boxed::Box, vec::Vec};
+
+const SEED: u32 = 0;
+
+/// External transaction ID of every benchmarked deposit
+const TX_ID: &[u8] = b"benchmark-tx";
+
+/// Longest reserve, wallet or transaction proof measured
+const MAX_PROOF_LEN: u32 = 4_096;
+
+/// Give `who` plenty of native balance
+fn fund<T: Config>(who: &T::AccountId) {
+    let amount = T::Currency::minimum_balance().saturating_mul(1_000_000_000u32.into());
+    T::Currency::make_free_balance_be(who, amount);
+}
+
+/// External amount of a deposit paying out a hundred existential deposits at a ratio of 1
+fn deposit_amount<T: Config>() -> u128 {
+    T::Currency::minimum_balance().saturated_into::<u128>().saturating_mul(100).max(1)
+}
+
+fn bounded_coin<T: Config>(coin_name: &[u8]) -> BoundedVec<u8, T::MaxCoinNameLength> {
+    coin_name.to_vec().try_into().expect("benchmark coin name fits")
+}
+
+/// Bound the coin's ratios and bind its wallet to another account, so requests
+/// take every check and deposits from the wallet get flagged
+fn prepare_coin<T: Config>() -> (Vec<u8>, Vec<u8>) {
+    let (coin_name, wallet) = T::BenchmarkHelper::coin();
+    RatioBounds::<T>::insert(bounded_coin::<T>(&coin_name), (1, u128::MAX));
+    let owner: T::AccountId = account("wallet-owner", 0, SEED);
+    assert_ok!(ProofOfReserve::<T>::bind_wallet(RawOrigin::Signed(owner).into(), wallet.clone(), None));
+    (coin_name, wallet)
+}
+
+/// Request a deposit crediting `recipient`, with custody funded to pay it out
+fn request<T: Config>(recipient: &T::AccountId) -> T::Hash {
+    let submitter: T::AccountId = account("submitter", 0, SEED);
+    let (coin_name, wallet) = prepare_coin::<T>();
+    fund::<T>(&ProofOfReserve::<T>::account_id());
+    assert_ok!(ProofOfReserve::<T>::request_deposit(
+        RawOrigin::Signed(submitter).into(),
+        recipient.clone(),
+        TX_ID.to_vec(),
+        wallet,
+        coin_name,
+        deposit_amount::<T>(),
+        1,
+        None,
+    ));
+    *UserDeposits::<T>::get(recipient).last().expect("deposit was just requested")
+}
+
+/// Request a withdrawal of `user` to a registered address, tipped and leaving dust
+/// for the insurance fund once completed
+fn withdrawal<T: Config>(user: &T::AccountId) -> T::Hash {
+    let (coin_name, wallet) = prepare_coin::<T>();
+    let coin = bounded_coin::<T>(&coin_name);
+    fund::<T>(user);
+    fund::<T>(&ProofOfReserve::<T>::insurance_fund_account());
+    AddressBookRequired::<T>::insert(&coin, ());
+    RoundingPolicies::<T>::insert(&coin, RoundingPolicy {
+        rounding: Rounding::FavorReserve,
+        dust: DustHandling::InsuranceFund,
+    });
+    assert_ok!(ProofOfReserve::<T>::register_withdrawal_address(
+        RawOrigin::Signed(user.clone()).into(),
+        wallet.clone(),
+    ));
+    let usable_from = frame_system::Pallet::<T>::block_number().saturating_add(T::WithdrawalAddressDelay::get());
+    frame_system::Pallet::<T>::set_block_number(usable_from);
+
+    // Three times the deposit amount plus two, at a ratio of 3, leaves a dust of 2
+    let native_amount = deposit_amount::<T>().saturating_mul(3).saturating_add(2);
+    assert_ok!(ProofOfReserve::<T>::request_withdrawal(
+        RawOrigin::Signed(user.clone()).into(),
+        wallet,
+        coin_name,
+        native_amount.saturated_into(),
+        3,
+        None,
+    ));
+    let request_id = *UserWithdrawals::<T>::get(user).last().expect("withdrawal was just requested");
+
+    let tip = T::Currency::minimum_balance().saturating_mul(10u32.into());
+    assert_ok!(ProofOfReserve::<T>::tip_withdrawal(RawOrigin::Signed(user.clone()).into(), request_id, tip));
+    request_id
+}
+
+/// Register `n` withdrawal addresses other than the benchmarked wallet for `who`
+fn fill_address_book<T: Config>(who: &T::AccountId, n: u32) {
+    WithdrawalAddresses::<T>::mutate(who, |addresses| {
+        for i in 0..n {
+            let wallet = [b"filler-".as_slice(), &i.to_be_bytes()].concat();
+            let wallet = wallet.try_into().expect("filler wallet fits");
+            addresses.try_push((wallet, Zero::zero())).expect("within MaxWithdrawalAddresses");
+        }
+    });
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn request_deposit() {
+        let caller: T::AccountId = whitelisted_caller();
+        let recipient: T::AccountId = account("recipient", 0, SEED);
+        let (coin_name, wallet) = prepare_coin::<T>();
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller),
+            recipient.clone(),
+            TX_ID.to_vec(),
+            wallet,
+            coin_name,
+            deposit_amount::<T>(),
+            1,
+            None,
+        );
+
+        let request_id = *UserDeposits::<T>::get(&recipient).last().unwrap();
+        assert!(FlaggedDeposits::<T>::contains_key(request_id));
+    }
+
+    #[benchmark]
+    fn approve_deposit() {
+        let caller: T::AccountId = whitelisted_caller();
+        let recipient: T::AccountId = account("recipient", 0, SEED);
+        let request_id = request::<T>(&recipient);
+
+        // Worst case: the recipient is newly endowed and granted fee grace
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), request_id);
+
+        assert_eq!(DepositRequests::<T>::get(request_id).unwrap().status, DepositStatus::Approved);
+    }
+
+    #[benchmark]
+    fn reject_deposit() {
+        let caller: T::AccountId = whitelisted_caller();
+        let recipient: T::AccountId = account("recipient", 0, SEED);
+        let request_id = request::<T>(&recipient);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), request_id);
+
+        assert_eq!(DepositRequests::<T>::get(request_id).unwrap().status, DepositStatus::Rejected);
+    }
+
+    #[benchmark]
+    fn request_withdrawal() {
+        let caller: T::AccountId = whitelisted_caller();
+        let (coin_name, wallet) = prepare_coin::<T>();
+        let coin = bounded_coin::<T>(&coin_name);
+        fund::<T>(&caller);
+
+        // Worst case: the coin only withdraws to registered addresses and rounds up
+        AddressBookRequired::<T>::insert(&coin, ());
+        RoundingPolicies::<T>::insert(&coin, RoundingPolicy {
+            rounding: Rounding::FavorUser,
+            dust: DustHandling::Burn,
+        });
+        assert_ok!(ProofOfReserve::<T>::register_withdrawal_address(
+            RawOrigin::Signed(caller.clone()).into(),
+            wallet.clone(),
+        ));
+        let usable_from = frame_system::Pallet::<T>::block_number().saturating_add(T::WithdrawalAddressDelay::get());
+        frame_system::Pallet::<T>::set_block_number(usable_from);
+        let native_amount = deposit_amount::<T>().saturating_mul(3).saturating_add(2);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), wallet, coin_name, native_amount.saturated_into(), 3, None);
+
+        assert_eq!(UserWithdrawals::<T>::get(&caller).len(), 1);
+    }
+
+    #[benchmark]
+    fn complete_withdrawal() {
+        let caller: T::AccountId = whitelisted_caller();
+        let user: T::AccountId = account("user", 0, SEED);
+        let request_id = withdrawal::<T>(&user);
+        Processors::<T>::insert(&caller, ());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), request_id);
+
+        assert_eq!(WithdrawalRequests::<T>::get(request_id).unwrap().status, WithdrawalStatus::Completed);
+        assert!(!WithdrawalTips::<T>::contains_key(request_id));
+    }
+
+    #[benchmark]
+    fn reject_withdrawal() {
+        let caller: T::AccountId = whitelisted_caller();
+        let user: T::AccountId = account("user", 0, SEED);
+        let request_id = withdrawal::<T>(&user);
+
+        // Worst case: the tip is refunded
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), request_id);
+
+        assert_eq!(WithdrawalRequests::<T>::get(request_id).unwrap().status, WithdrawalStatus::Rejected);
+        assert!(!WithdrawalTips::<T>::contains_key(request_id));
+    }
+
+    #[benchmark]
+    fn call_multicoin() {
+        let caller: T::AccountId = whitelisted_caller();
+        let call: <T as frame_system::Config>::RuntimeCall =
+            frame_system::Call::<T>::remark { remark: Vec::new() }.into();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), Box::new(call));
+    }
+
+    #[benchmark]
+    fn register_withdrawal_address() {
+        let caller: T::AccountId = whitelisted_caller();
+        let (_, wallet) = T::BenchmarkHelper::coin();
+
+        // Worst case: the new address takes the last free slot
+        fill_address_book::<T>(&caller, T::MaxWithdrawalAddresses::get().saturating_sub(1));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), wallet);
+
+        assert_eq!(WithdrawalAddresses::<T>::get(&caller).len() as u32, T::MaxWithdrawalAddresses::get());
+    }
+
+    #[benchmark]
+    fn remove_withdrawal_address() {
+        let caller: T::AccountId = whitelisted_caller();
+        let (_, wallet) = T::BenchmarkHelper::coin();
+
+        // Worst case: the address is the last of a full address book
+        fill_address_book::<T>(&caller, T::MaxWithdrawalAddresses::get().saturating_sub(1));
+        assert_ok!(ProofOfReserve::<T>::register_withdrawal_address(
+            RawOrigin::Signed(caller.clone()).into(),
+            wallet.clone(),
+        ));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), wallet);
+
+        assert_eq!(WithdrawalAddresses::<T>::get(&caller).len() as u32, T::MaxWithdrawalAddresses::get() - 1);
+    }
+
+    #[benchmark]
+    fn set_address_book_required() -> Result<(), BenchmarkError> {
+        let origin = T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let (coin_name, _) = T::BenchmarkHelper::coin();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, coin_name.clone(), true);
+
+        assert!(AddressBookRequired::<T>::contains_key(bounded_coin::<T>(&coin_name)));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn xcm_deposit() -> Result<(), BenchmarkError> {
+        // Runtimes trusting no reserve can't receive these deposits at all
+        let origin = T::XcmDepositOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let recipient: T::AccountId = account("recipient", 0, SEED);
+        let (coin_name, _) = prepare_coin::<T>();
+        fund::<T>(&ProofOfReserve::<T>::account_id());
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, recipient.clone(), TX_ID.to_vec(), coin_name, deposit_amount::<T>(), 1);
+
+        let request_id = *UserDeposits::<T>::get(&recipient).last().unwrap();
+        assert_eq!(DepositRequests::<T>::get(request_id).unwrap().status, DepositStatus::Approved);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_approval_signers() -> Result<(), BenchmarkError> {
+        let origin = T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        // Worst case: a full signer set, each checked against all before it
+        let max = T::MaxApprovalSigners::get();
+        let signers: Vec<_> = (0..max).map(|i| T::BenchmarkHelper::sign_approval(i, b"").0).collect();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, signers, max);
+
+        assert_eq!(ApprovalThreshold::<T>::get(), max);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn approve_deposit_signed(s: Linear<1, { T::MaxApprovalSigners::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let recipient: T::AccountId = account("recipient", 0, SEED);
+        let request_id = request::<T>(&recipient);
+
+        // Every signer of the set signs, as the threshold asks
+        let payload = ProofOfReserve::<T>::approval_payload(&request_id);
+        let (signers, signatures): (Vec<_>, Vec<_>) = (0..s)
+            .map(|i| {
+                let (signer, signature) = T::BenchmarkHelper::sign_approval(i, &payload);
+                (signer, (i, signature))
+            })
+            .unzip();
+        ApprovalSigners::<T>::put(BoundedVec::try_from(signers).expect("within MaxApprovalSigners"));
+        ApprovalThreshold::<T>::put(s);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), request_id, signatures);
+
+        assert_eq!(DepositRequests::<T>::get(request_id).unwrap().status, DepositStatus::Approved);
+    }
+
+    #[benchmark]
+    fn set_processor() -> Result<(), BenchmarkError> {
+        let origin = T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let processor: T::AccountId = account("processor", 0, SEED);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, processor.clone(), true);
+
+        assert!(Processors::<T>::contains_key(&processor));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn claim_processor_reward() {
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&ProofOfReserve::<T>::fee_pot_account());
+        ProcessorRewards::<T>::insert(&caller, T::Currency::minimum_balance().saturating_mul(10u32.into()));
+
+        // Worst case: the processor is newly endowed
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()));
+
+        assert!(ProcessorRewards::<T>::get(&caller).is_zero());
+    }
+
+    #[benchmark]
+    fn attest_reserves(p: Linear<0, MAX_PROOF_LEN>) -> Result<(), BenchmarkError> {
+        let origin = T::CustodianOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let (coin_name, _) = T::BenchmarkHelper::coin();
+        let external_balance = deposit_amount::<T>();
+
+        // Runtimes without a light client can only self-report
+        let proof = T::BenchmarkHelper::reserve_proof(external_balance, p);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, coin_name.clone(), external_balance, proof);
+
+        assert!(ReserveAttestations::<T>::contains_key(bounded_coin::<T>(&coin_name)));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_rounding_policy() -> Result<(), BenchmarkError> {
+        let origin = T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let (coin_name, _) = T::BenchmarkHelper::coin();
+        let policy = RoundingPolicy { rounding: Rounding::FavorUser, dust: DustHandling::InsuranceFund };
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, coin_name.clone(), policy);
+
+        assert_eq!(RoundingPolicies::<T>::get(bounded_coin::<T>(&coin_name)), policy);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn tip_withdrawal() {
+        let user: T::AccountId = account("user", 0, SEED);
+        let request_id = withdrawal::<T>(&user);
+        let tip = T::Currency::minimum_balance().saturating_mul(10u32.into());
+
+        // Worst case: the request was tipped before
+        #[extrinsic_call]
+        _(RawOrigin::Signed(user), request_id, tip);
+
+        assert_eq!(WithdrawalTips::<T>::get(request_id), tip.saturating_mul(2u32.into()));
+    }
+
+    #[benchmark]
+    fn request_split_deposit(r: Linear<1, { T::MaxDepositRecipients::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let recipients: Vec<(T::AccountId, u32)> = (0..r).map(|i| (account("recipient", i, SEED), 1)).collect();
+        let recipients = BoundedVec::try_from(recipients).expect("within MaxDepositRecipients");
+        let first = recipients[0].0.clone();
+        let (coin_name, wallet) = prepare_coin::<T>();
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller),
+            recipients,
+            TX_ID.to_vec(),
+            wallet,
+            coin_name,
+            deposit_amount::<T>(),
+            1,
+            None,
+        );
+
+        let request_id = *UserDeposits::<T>::get(&first).last().unwrap();
+        assert_eq!(DepositSplits::<T>::get(request_id).unwrap().len() as u32, r);
+    }
+
+    #[benchmark]
+    fn set_bridge_pause() -> Result<(), BenchmarkError> {
+        let origin = T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let (coin_name, _) = T::BenchmarkHelper::coin();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, Some(coin_name.clone()), true);
+
+        assert!(PausedCoins::<T>::contains_key(bounded_coin::<T>(&coin_name)));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn record_reserve_reports(c: Linear<0, { T::MaxReportedCoins::get() }>) {
+        // Every coin was bridged and attested, so each takes both key scans and a report
+        let custodian: T::AccountId = account("custodian", 0, SEED);
+        for i in 0..c {
+            let coin = bounded_coin::<T>(&[b"C".as_slice(), &i.to_be_bytes()].concat());
+            CoinBridgeTotals::<T>::insert(&coin, BridgeTotals { deposited: 100, ..Default::default() });
+            ReserveAttestations::<T>::insert(&coin, ReserveAttestation {
+                custodian: custodian.clone(),
+                external_balance: 100,
+                kind: AttestationKind::SelfReported,
+                attested_at: Zero::zero(),
+            });
+        }
+
+        let reported;
+        #[block]
+        {
+            reported = ProofOfReserve::<T>::record_reserve_reports(1);
+        }
+
+        assert_eq!(reported, c);
+    }
+
+    #[benchmark]
+    fn set_ratio_bounds() -> Result<(), BenchmarkError> {
+        let origin = T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let (coin_name, _) = T::BenchmarkHelper::coin();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, coin_name.clone(), Some((1, u128::MAX)));
+
+        assert_eq!(RatioBounds::<T>::get(bounded_coin::<T>(&coin_name)), Some((1, u128::MAX)));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn bind_wallet(p: Linear<0, MAX_PROOF_LEN>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let (_, wallet) = T::BenchmarkHelper::coin();
+
+        // Worst case: the caller binds its wallet again, replacing the proof
+        assert_ok!(ProofOfReserve::<T>::bind_wallet(RawOrigin::Signed(caller.clone()).into(), wallet.clone(), None));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), wallet.clone(), Some(sp_std::vec![0u8; p as usize]));
+
+        let wallet: BoundedVec<u8, T::MaxWalletLength> = wallet.try_into().unwrap();
+        assert!(WalletOwners::<T>::get(&wallet).unwrap().proof_hash.is_some());
+    }
+
+    #[benchmark]
+    fn unbind_wallet() {
+        let caller: T::AccountId = whitelisted_caller();
+        let (_, wallet) = T::BenchmarkHelper::coin();
+        assert_ok!(ProofOfReserve::<T>::bind_wallet(RawOrigin::Signed(caller.clone()).into(), wallet.clone(), None));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), wallet.clone());
+
+        let wallet: BoundedVec<u8, T::MaxWalletLength> = wallet.try_into().unwrap();
+        assert!(!WalletOwners::<T>::contains_key(&wallet));
+    }
+
+    #[benchmark]
+    fn seal_event_digest() {
+        // Worst case: a peak for every bit of the event count
+        EraEvents::<T>::put(EventAccumulator {
+            events: u32::MAX,
+            peaks: sp_std::vec![T::Hash::default(); u32::BITS as usize],
+        });
+
+        #[block]
+        {
+            ProofOfReserve::<T>::seal_event_digest(0);
+        }
+
+        assert!(EventDigests::<T>::contains_key(0));
+    }
+
+    #[benchmark]
+    fn prove_deposit(p: Linear<0, MAX_PROOF_LEN>) -> Result<(), BenchmarkError> {
+        // Coins without a chain adapter can't be settled by proof at all
+        let proof = T::BenchmarkHelper::tx_proof(TX_ID, p).ok_or(BenchmarkError::Weightless)?;
+        let caller: T::AccountId = whitelisted_caller();
+        let recipient: T::AccountId = account("recipient", 0, SEED);
+        let request_id = request::<T>(&recipient);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), request_id, proof);
+
+        assert_eq!(DepositRequests::<T>::get(request_id).unwrap().status, DepositStatus::Approved);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn request_deposit_and_call() -> Result<(), BenchmarkError> {
+        // Runtimes forwarding no calls refuse these requests up front
+        let call = T::BenchmarkHelper::forward_call().ok_or(BenchmarkError::Weightless)?;
+        let caller: T::AccountId = whitelisted_caller();
+        let (coin_name, wallet) = prepare_coin::<T>();
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller.clone()),
+            TX_ID.to_vec(),
+            wallet,
+            coin_name,
+            deposit_amount::<T>(),
+            1,
+            Box::new(call),
+            None,
+        );
+
+        let request_id = *UserDeposits::<T>::get(&caller).last().unwrap();
+        assert!(DepositForwards::<T>::contains_key(request_id));
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(ProofOfReserve, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -2,16 +2,38 @@
 
 pub use pallet::*;
 
+pub mod runtime_api;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod scale_vectors;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::*;
+
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::pallet_prelude::*;
     use frame_system::pallet_prelude::*;
-    use frame_support::traits::{Currency, Get, tokens::fungible};
+    use frame_support::traits::{Contains, Currency, Get, OriginTrait, tokens::fungible};
+    use frame_support::{dispatch::GetDispatchInfo, storage::with_storage_layer};
     use sp_runtime::traits::Dispatchable;
     use sp_std::vec::Vec;
-    use sp_runtime::traits::{Zero, Saturating, Hash, AccountIdConversion};
+    use sp_runtime::traits::{Zero, Saturating, Hash, AccountIdConversion, IdentifyAccount, Verify};
+    use sp_runtime::SaturatedConversion;
     use frame_support::PalletId;
     use scale_info::prelude::boxed::Box;
+    use pallet_multicoin::{CoinId, CoinMapping, PausedQuery, PriceOracle, SupplyQuery};
+    use sp_std::collections::btree_set::BTreeSet;
+    use sp_runtime::{FixedPointNumber, FixedU128};
+    use crate::weights::WeightInfo;
 
     #[pallet::pallet]
     #[pallet::without_storage_info]
@@ -35,7 +57,85 @@ pub mod pallet {
         #[pallet::constant]
         type MaxCoinNameLength: Get<u32>;
 
+        /// Transactions of an account first endowed by a bridge deposit whose
+        /// fees are paid from the bridge fee pot
+        #[pallet::constant]
+        type FeeGraceTransactions: Get<u32>;
+
+        /// Aggregated prices that deposit and withdrawal ratios must agree with
+        type PriceOracle: PriceOracle;
+
+        /// Origin deciding which coins only withdraw to registered addresses
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Origin of deposits notified over XCM by a trusted reserve location,
+        /// yielding the reserve's sovereign account, e.g. `EnsureXcm` over the
+        /// trusted reserves converted with the runtime's `LocationToAccountId`
+        type XcmDepositOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+
+        /// Signature of an approval signer over a deposit request
+        type ApprovalSignature: Verify<Signer = Self::ApprovalSigner> + Parameter;
+
+        /// Public key of an approval signer, e.g. `sr25519::Public`
+        type ApprovalSigner: IdentifyAccount<AccountId = Self::ApprovalSigner> + Parameter;
+
+        /// Maximum number of keys in the approval signer set
+        #[pallet::constant]
+        type MaxApprovalSigners: Get<u32>;
+
+        /// Blocks before a newly registered withdrawal address can be used
+        #[pallet::constant]
+        type WithdrawalAddressDelay: Get<BlockNumberFor<Self>>;
+
+        /// Maximum withdrawal addresses an account can register
+        #[pallet::constant]
+        type MaxWithdrawalAddresses: Get<u32>;
+
+        /// Reward a registered processor earns from the fee pot per completed withdrawal
+        #[pallet::constant]
+        type ProcessorReward: Get<BalanceOf<Self>>;
+
+        /// Maximum recipients a deposit can be split across
+        #[pallet::constant]
+        type MaxDepositRecipients: Get<u32>;
+
+        /// Origin of custodians attesting external reserves, yielding the custodian
+        type CustodianOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+
+        /// Checks reserve proofs against an external light client, e.g. a BTC SPV header chain
+        type ReserveProofVerifier: ReserveProofVerifier;
+
+        /// Chain adapter of each coin, validating addresses and deposit transactions
+        type ChainAdapters: ChainAdapters;
+
+        /// On-chain supply of the wrapped coins and the coins their names map to,
+        /// usually `pallet_multicoin`
+        type CoinSupply: SupplyQuery + CoinMapping<Self::AccountId>;
+
+        /// Blocks per reporting era; the reserve report of every coin is stored as each era starts
+        #[pallet::constant]
+        type ReportEraLength: Get<BlockNumberFor<Self>>;
+
+        /// Most coins reported on per era
+        #[pallet::constant]
+        type MaxReportedCoins: Get<u32>;
+
+        /// Calls a deposit may be forwarded to on approval, e.g. staking or adding liquidity
+        type ForwardCallFilter: Contains<<Self as frame_system::Config>::RuntimeCall>;
+
+        /// Most weight a forwarded call may take; every deposit approval is charged for it
+        #[pallet::constant]
+        type MaxForwardWeight: Get<Weight>;
+
         type WeightInfo: WeightInfo;
+
+        /// Signatures, proofs and calls the benchmarks can't build from the config alone
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<
+            Self::ApprovalSigner,
+            Self::ApprovalSignature,
+            <Self as frame_system::Config>::RuntimeCall,
+        >;
     }
 
     /// Status of a deposit request
@@ -106,10 +206,233 @@ pub mod pallet {
         pub processed_by: Option<T::AccountId>,
     }
 
-    type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+    /// How the balance of a reserve attestation was established
+    #[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub enum AttestationKind {
+        /// Checked against a UTXO/balance proof by the light client
+        Verified,
+        /// Taken on the custodian's word, as no proof was given
+        SelfReported,
+    }
+
+    /// Latest external reserve balance attested by a custodian
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    #[scale_info(skip_type_params(T))]
+    pub struct ReserveAttestation<T: Config> {
+        /// Custodian that attested the reserves
+        pub custodian: T::AccountId,
+        /// Reserve balance in external coin (in smallest unit)
+        pub external_balance: u128,
+        /// Whether the balance was proven or self-reported
+        pub kind: AttestationKind,
+        /// Block number when attested
+        pub attested_at: BlockNumberFor<T>,
+    }
+
+    /// Direction the external amount of a withdrawal is rounded in
+    #[derive(Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo)]
+    pub enum Rounding {
+        /// Round down: the user burns the remainder of the division by the ratio
+        #[default]
+        FavorReserve,
+        /// Round up: the reserve pays out the remainder
+        FavorUser,
+    }
+
+    /// What happens to native dust left over when rounding in favor of the reserve
+    #[derive(Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo)]
+    pub enum DustHandling {
+        /// Burn it along with the withdrawn amount
+        #[default]
+        Burn,
+        /// Move it to the insurance fund
+        InsuranceFund,
+    }
+
+    /// How conversions of a coin between external and native amounts are rounded.
+    ///
+    /// Deposits convert exactly (`external_amount * ratio`); only withdrawals divide.
+    #[derive(Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo)]
+    pub struct RoundingPolicy {
+        pub rounding: Rounding,
+        pub dust: DustHandling,
+    }
+
+    /// Running bridge totals of a coin
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug, TypeInfo)]
+    pub struct BridgeTotals {
+        /// External amount of all approved deposits
+        pub deposited: u128,
+        /// External amount of all completed withdrawals
+        pub withdrawn: u128,
+        /// Deposit requests awaiting a validator
+        pub pending_deposits: u32,
+        /// Withdrawal requests awaiting a processor
+        pub pending_withdrawals: u32,
+        /// Approved deposits and completed withdrawals
+        pub processed: u32,
+        /// Blocks from request to payout, summed over all processed requests
+        pub processing_blocks: u64,
+    }
+
+    /// Public proof-of-reserve status of a coin, as served by the runtime API
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct BridgeStats<BlockNumber> {
+        /// External amount of all approved deposits
+        pub total_deposited: u128,
+        /// External amount of all completed withdrawals
+        pub total_withdrawn: u128,
+        /// Deposit requests awaiting a validator
+        pub pending_deposits: u32,
+        /// Withdrawal requests awaiting a processor
+        pub pending_withdrawals: u32,
+        /// Average blocks from request to payout
+        pub average_processing_blocks: u64,
+        /// Attested reserves over outstanding bridged coins, if both are known
+        pub reserve_ratio: Option<FixedU128>,
+        /// Block of the latest reserve attestation
+        pub last_attestation: Option<BlockNumber>,
+    }
+
+    /// Whether the bridged supply of a coin is accounted for and backed
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub enum ReserveStatus {
+        /// On-chain supply matches the bridge's net mint and reserves cover it
+        Consistent,
+        /// No reserves were attested yet
+        Unattested,
+        /// Attested reserves fall short of the bridged supply
+        Undercollateralized,
+        /// On-chain supply differs from what the bridge minted net of withdrawals
+        SupplyMismatch,
+    }
+
+    /// Why a bridged coin's name doesn't map to a multicoin coin the custody
+    /// account can mint and burn
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub enum CoinMappingIssue {
+        /// No coin has the name as its symbol
+        NotFound,
+        /// The coin is being destroyed
+        Destroying,
+        /// The custody account may not mint the coin
+        NoMintPermission,
+        /// The custody account may not burn the coin
+        NoBurnPermission,
+    }
+
+    /// Accounting of one wrapped coin across the multicoin and bridge pallets
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct ReserveReport<BlockNumber> {
+        /// Supply of the wrapped coin in `pallet_multicoin`, if there is such a coin
+        pub onchain_supply: Option<u128>,
+        /// External amount of approved deposits minus completed withdrawals
+        pub bridge_net_minted: u128,
+        /// Latest attested external reserve balance
+        pub attested_reserve: Option<u128>,
+        /// Block of the latest reserve attestation
+        pub attested_at: Option<BlockNumber>,
+        /// How supply, bridge accounting and reserves compare
+        pub status: ReserveStatus,
+    }
+
+    /// External wallet bound to an on-chain account
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct WalletBinding<AccountId, Hash, BlockNumber> {
+        /// Account deposits from the wallet are expected to credit
+        pub owner: AccountId,
+        /// Hash of the ownership proof given at registration, e.g. a message
+        /// signed by the wallet, for validators to check off-chain
+        pub proof_hash: Option<Hash>,
+        /// Block the wallet was bound at
+        pub bound_at: BlockNumber,
+    }
+
+    /// Merkle mountain range over the bridge events of the current era
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Default)]
+    pub struct EventAccumulator<Hash> {
+        /// Events added so far
+        pub events: u32,
+        /// Roots of the range's perfect subtrees, largest first
+        pub peaks: Vec<Hash>,
+    }
+
+    /// Commitment to all bridge events of an era
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct EventDigest<Hash> {
+        /// Root over the hashes of the era's encoded events, in deposit order
+        pub root: Hash,
+        /// Number of events in the era
+        pub events: u32,
+    }
+
+    /// Verifies reserve proofs of external chains
+    pub trait ReserveProofVerifier {
+        /// Whether `proof` shows the reserves of `coin_name` hold `external_balance`,
+        /// checked against the light client's best known header chain
+        fn verify(coin_name: &[u8], external_balance: u128, proof: &[u8]) -> bool;
+    }
+
+    /// No light client: every proof is rejected, so only self-reported attestations are possible
+    impl ReserveProofVerifier for () {
+        fn verify(_coin_name: &[u8], _external_balance: u128, _proof: &[u8]) -> bool {
+            false
+        }
+    }
+
+    /// Rules of one external chain
+    ///
+    /// A new chain is supported by implementing this trait and returning the adapter
+    /// from the runtime's `ChainAdapters` for the coins that live on it.
+    pub trait ChainAdapter {
+        /// Whether `address` is a well-formed address on the chain
+        fn validate_address(&self, address: &[u8]) -> bool;
+
+        /// Confirmations `proof` shows for transaction `tx_id` sending `amount` from
+        /// `wallet` to custody, or `None` if the proof doesn't verify
+        fn verify_tx_proof(&self, tx_id: &[u8], wallet: &[u8], amount: u128, proof: &[u8]) -> Option<u32>;
+
+        /// Confirmations a deposit transaction needs before it can be settled by proof
+        fn confirmation_depth(&self) -> u32;
+    }
+
+    /// Chain adapter registry, keyed by coin name
+    pub trait ChainAdapters {
+        /// Adapter of the chain the coin named `coin_name` lives on, if there is one
+        fn adapter(coin_name: &[u8]) -> Option<&'static dyn ChainAdapter>;
+    }
+
+    /// No adapters: addresses go unchecked and deposits are settled by validators only
+    impl ChainAdapters for () {
+        fn adapter(_coin_name: &[u8]) -> Option<&'static dyn ChainAdapter> {
+            None
+        }
+    }
+
+    /// Runtime-specific inputs of the benchmarks
+    #[cfg(feature = "runtime-benchmarks")]
+    pub trait BenchmarkHelper<Signer, Signature, RuntimeCall> {
+        /// Name of a coin to bridge and a valid wallet on its chain
+        fn coin() -> (Vec<u8>, Vec<u8>);
+
+        /// Approval signer `index` and its signature over `payload`
+        fn sign_approval(index: u32, payload: &[u8]) -> (Signer, Signature);
+
+        /// Proof of about `len` bytes that `ReserveProofVerifier` accepts for
+        /// `external_balance` of `coin()`; `None` if the runtime verifies no proofs
+        fn reserve_proof(external_balance: u128, len: u32) -> Option<Vec<u8>>;
+
+        /// Proof of about `len` bytes that transaction `tx_id` of `coin()` is confirmed
+        /// deep enough; `None` if the coin has no chain adapter
+        fn tx_proof(tx_id: &[u8], len: u32) -> Option<Vec<u8>>;
+
+        /// A call passing `ForwardCallFilter`; `None` if it lets no call through
+        fn forward_call() -> Option<RuntimeCall>;
+    }
+
+    pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
     #[pallet::event]
-    #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         /// User submitted deposit request
         DepositRequested {
@@ -154,6 +477,117 @@ pub mod pallet {
             request_id: T::Hash,
             processor: T::AccountId,
         },
+        /// Account first endowed by a deposit gets its next fees paid by the fee pot
+        FeeGraceGranted {
+            who: T::AccountId,
+            transactions: u32,
+        },
+        /// User registered a withdrawal address, usable from block `usable_from`
+        WithdrawalAddressRegistered {
+            who: T::AccountId,
+            external_wallet: BoundedVec<u8, T::MaxWalletLength>,
+            usable_from: BlockNumberFor<T>,
+        },
+        /// User removed a withdrawal address
+        WithdrawalAddressRemoved {
+            who: T::AccountId,
+            external_wallet: BoundedVec<u8, T::MaxWalletLength>,
+        },
+        /// Withdrawals of a coin were restricted to registered addresses, or opened up again
+        AddressBookRequirementSet {
+            coin_name: BoundedVec<u8, T::MaxCoinNameLength>,
+            required: bool,
+        },
+        /// A trusted reserve notified a verified deposit over XCM; it is approved right away
+        XcmDepositReceived {
+            request_id: T::Hash,
+            reserve: T::AccountId,
+            recipient: T::AccountId,
+            external_tx_id: BoundedVec<u8, T::MaxTxIdLength>,
+            coin_name: BoundedVec<u8, T::MaxCoinNameLength>,
+            external_amount: u128,
+            native_amount: BalanceOf<T>,
+        },
+        /// Approval signer set updated; a threshold of 0 disables signed approvals
+        ApprovalSignersSet {
+            signers: BoundedVec<T::ApprovalSigner, T::MaxApprovalSigners>,
+            threshold: u32,
+        },
+        /// Processor registered for or removed from withdrawal rewards
+        ProcessorSet { who: T::AccountId, registered: bool },
+        /// Registered processor earned a reward for completing a withdrawal
+        ProcessorRewardAccrued { processor: T::AccountId, request_id: T::Hash, amount: BalanceOf<T> },
+        /// Processor claimed its accrued rewards from the fee pot
+        ProcessorRewardClaimed { processor: T::AccountId, amount: BalanceOf<T> },
+        /// Custodian attested the external reserves of a coin
+        ReservesAttested {
+            coin_name: BoundedVec<u8, T::MaxCoinNameLength>,
+            custodian: T::AccountId,
+            external_balance: u128,
+            kind: AttestationKind,
+        },
+        /// Rounding policy of a coin changed
+        RoundingPolicySet {
+            coin_name: BoundedVec<u8, T::MaxCoinNameLength>,
+            policy: RoundingPolicy,
+        },
+        /// Rounding dust of a completed withdrawal was moved to the insurance fund
+        WithdrawalDustRouted { request_id: T::Hash, amount: BalanceOf<T> },
+        /// User raised the priority tip of a pending withdrawal
+        WithdrawalTipped { request_id: T::Hash, tip: BalanceOf<T> },
+        /// Priority tip of a completed withdrawal was paid to its processor
+        WithdrawalTipPaid { request_id: T::Hash, processor: T::AccountId, tip: BalanceOf<T> },
+        /// Deposit request splits its native amount across recipients by share
+        DepositSplitRequested {
+            request_id: T::Hash,
+            recipients: BoundedVec<(T::AccountId, u32), T::MaxDepositRecipients>,
+        },
+        /// Bridge paused or resumed, for one coin or for all of them
+        BridgePauseSet {
+            coin_name: Option<BoundedVec<u8, T::MaxCoinNameLength>>,
+            paused: bool,
+        },
+        /// Reserve reports of a new era were stored
+        ReserveReportsRecorded { era: u32, coins: u32 },
+        /// Accepted exchange ratio range of a coin changed
+        RatioBoundsSet {
+            coin_name: BoundedVec<u8, T::MaxCoinNameLength>,
+            bounds: Option<(u128, u128)>,
+        },
+        /// User bound an external wallet to its account
+        WalletBound {
+            who: T::AccountId,
+            external_wallet: BoundedVec<u8, T::MaxWalletLength>,
+            proof_hash: Option<T::Hash>,
+        },
+        /// User unbound an external wallet from its account
+        WalletUnbound {
+            who: T::AccountId,
+            external_wallet: BoundedVec<u8, T::MaxWalletLength>,
+        },
+        /// Deposit from a bound wallet credits another account than its owner
+        DepositWalletMismatch {
+            request_id: T::Hash,
+            wallet_owner: T::AccountId,
+            recipient: T::AccountId,
+        },
+        /// Digest of an era's bridge events was stored
+        BridgeEventsDigested {
+            era: u32,
+            root: T::Hash,
+            events: u32,
+        },
+        /// A deposit transaction was proven to the coin's chain adapter
+        DepositProven {
+            request_id: T::Hash,
+            prover: T::AccountId,
+            confirmations: u32,
+        },
+        /// The follow-up call of an approved deposit was dispatched for its recipient
+        DepositForwarded {
+            request_id: T::Hash,
+            result: DispatchResult,
+        },
     }
 
     #[pallet::error]
@@ -176,6 +610,60 @@ pub mod pallet {
         DataTooLong,
         /// User cannot approve own request
         CannotApproveOwnRequest,
+        /// Ratio deviates too far from the oracle price of the coin
+        RatioDeviatesFromOracle,
+        /// Withdrawal address already registered
+        AddressAlreadyRegistered,
+        /// Account registered `MaxWithdrawalAddresses` addresses already
+        TooManyWithdrawalAddresses,
+        /// The coin only withdraws to registered addresses and this one isn't
+        AddressNotRegistered,
+        /// Withdrawal address is still within its confirmation delay
+        AddressNotConfirmed,
+        /// A deposit with this external transaction ID was already notified over XCM
+        DepositAlreadyNotified,
+        /// Too many approval signers or signatures
+        TooManyApprovalSigners,
+        /// Approval signer listed twice
+        DuplicateApprovalSigner,
+        /// Threshold must be between 1 and the number of signers, or 0 without signers
+        InvalidApprovalThreshold,
+        /// No approval signer set is registered
+        SignedApprovalDisabled,
+        /// Signatures must come from distinct signers, listed by ascending index
+        InvalidApprovalSignature,
+        /// Fewer valid signatures than the approval threshold
+        NotEnoughApprovalSignatures,
+        /// Processor has no accrued rewards
+        NoRewardToClaim,
+        /// Split deposits need recipients with non-zero shares, each listed once
+        InvalidSplit,
+        /// The bridge is paused for this coin
+        BridgePaused,
+        /// Reserve proof does not verify against the light client
+        InvalidReserveProof,
+        /// `call_multicoin` can't dispatch another wrapper call
+        NestedWrapperCall,
+        /// Ratio is outside the bounds set for the coin
+        RatioOutOfBounds,
+        /// Ratio bounds need `0 < min <= max`
+        InvalidRatioBounds,
+        /// Wallet is bound to another account
+        WalletAlreadyBound,
+        /// Wallet is not bound to the caller
+        WalletNotBound,
+        /// Address is not valid on the coin's chain
+        InvalidAddress,
+        /// The coin has no chain adapter to check proofs against
+        NoChainAdapter,
+        /// Transaction proof does not verify against the chain adapter
+        InvalidTxProof,
+        /// The transaction has fewer confirmations than the chain requires
+        NotEnoughConfirmations,
+        /// The call isn't one deposits may be forwarded to
+        ForwardCallNotAllowed,
+        /// The call may take more than `MaxForwardWeight`
+        ForwardCallTooHeavy,
     }
 
     /// Pending deposit requests
@@ -230,6 +718,209 @@ pub mod pallet {
     #[pallet::storage]
     pub type RequestCounter<T: Config> = StorageValue<_, u64, ValueQuery>;
 
+    /// Remaining fee-sponsored transactions of newly bridged accounts
+    #[pallet::storage]
+    #[pallet::getter(fn fee_grace)]
+    pub type FeeGrace<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Withdrawal addresses registered by each user, with the block they become usable at
+    #[pallet::storage]
+    #[pallet::getter(fn withdrawal_addresses)]
+    pub type WithdrawalAddresses<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<(BoundedVec<u8, T::MaxWalletLength>, BlockNumberFor<T>), T::MaxWithdrawalAddresses>,
+        ValueQuery
+    >;
+
+    /// Keys whose signatures can approve deposits without on-chain votes
+    #[pallet::storage]
+    #[pallet::getter(fn approval_signers)]
+    pub type ApprovalSigners<T: Config> =
+        StorageValue<_, BoundedVec<T::ApprovalSigner, T::MaxApprovalSigners>, ValueQuery>;
+
+    /// Number of signer signatures approving a deposit; 0 while signed approvals are off
+    #[pallet::storage]
+    #[pallet::getter(fn approval_threshold)]
+    pub type ApprovalThreshold<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Processors earning a reward per completed withdrawal
+    #[pallet::storage]
+    #[pallet::getter(fn is_processor)]
+    pub type Processors<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+    /// Rewards processors accrued and have not claimed yet
+    #[pallet::storage]
+    #[pallet::getter(fn processor_rewards)]
+    pub type ProcessorRewards<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+    /// Rounding policy per coin; coins without one round in favor of the reserve and burn dust
+    #[pallet::storage]
+    #[pallet::getter(fn rounding_policy)]
+    pub type RoundingPolicies<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxCoinNameLength>,
+        RoundingPolicy,
+        ValueQuery
+    >;
+
+    /// Priority tips attached to pending withdrawals, held in the tip pot
+    #[pallet::storage]
+    #[pallet::getter(fn withdrawal_tip)]
+    pub type WithdrawalTips<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, BalanceOf<T>, ValueQuery>;
+
+    /// Recipients and shares of pending split deposits
+    #[pallet::storage]
+    #[pallet::getter(fn deposit_split)]
+    pub type DepositSplits<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::Hash,
+        BoundedVec<(T::AccountId, u32), T::MaxDepositRecipients>,
+        OptionQuery
+    >;
+
+    /// Whether the bridge is paused for every coin
+    #[pallet::storage]
+    #[pallet::getter(fn bridge_paused)]
+    pub type BridgePaused<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    /// Coins the bridge is paused for
+    #[pallet::storage]
+    pub type PausedCoins<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxCoinNameLength>,
+        (),
+        OptionQuery
+    >;
+
+    /// Running bridge totals per coin
+    #[pallet::storage]
+    #[pallet::getter(fn bridge_totals)]
+    pub type CoinBridgeTotals<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxCoinNameLength>,
+        BridgeTotals,
+        ValueQuery
+    >;
+
+    /// Latest reserve attestation per coin
+    #[pallet::storage]
+    #[pallet::getter(fn reserve_attestation)]
+    pub type ReserveAttestations<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxCoinNameLength>,
+        ReserveAttestation<T>,
+        OptionQuery
+    >;
+
+    /// Deposits notified over XCM by coin and external transaction ID, against replays
+    #[pallet::storage]
+    pub type XcmDeposits<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxCoinNameLength>,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxTxIdLength>,
+        T::Hash,
+        OptionQuery
+    >;
+
+    /// Coins whose withdrawals may only go to registered, confirmed addresses
+    #[pallet::storage]
+    #[pallet::getter(fn address_book_required)]
+    pub type AddressBookRequired<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxCoinNameLength>,
+        (),
+        OptionQuery
+    >;
+
+    /// Smallest and largest exchange ratio accepted for a coin, against mistyped ratios
+    #[pallet::storage]
+    #[pallet::getter(fn ratio_bounds)]
+    pub type RatioBounds<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxCoinNameLength>,
+        (u128, u128),
+        OptionQuery
+    >;
+
+    /// On-chain owners of external wallets
+    #[pallet::storage]
+    #[pallet::getter(fn wallet_binding)]
+    pub type WalletOwners<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxWalletLength>,
+        WalletBinding<T::AccountId, T::Hash, BlockNumberFor<T>>,
+        OptionQuery
+    >;
+
+    /// Deposits whose recipient isn't the owner of the wallet they came from,
+    /// with that owner, for validators to review
+    #[pallet::storage]
+    #[pallet::getter(fn flagged_deposit)]
+    pub type FlaggedDeposits<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, T::AccountId, OptionQuery>;
+
+    /// Calls dispatched for the recipient of a pending deposit once it is approved
+    #[pallet::storage]
+    #[pallet::getter(fn deposit_forward)]
+    pub type DepositForwards<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::Hash, <T as frame_system::Config>::RuntimeCall, OptionQuery>;
+
+    /// Bridge events of the current era, folded in as they are deposited
+    #[pallet::storage]
+    pub type EraEvents<T: Config> = StorageValue<_, EventAccumulator<T::Hash>, ValueQuery>;
+
+    /// Digest of each past era's bridge events, for light clients and auditors
+    #[pallet::storage]
+    #[pallet::getter(fn event_digest)]
+    pub type EventDigests<T: Config> = StorageMap<_, Twox64Concat, u32, EventDigest<T::Hash>, OptionQuery>;
+
+    /// Reserve reports by era and coin, kept for historical audits
+    #[pallet::storage]
+    pub type ReserveReports<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        u32,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxCoinNameLength>,
+        ReserveReport<BlockNumberFor<T>>,
+        OptionQuery
+    >;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Seal the event digest of the era that ended and store the reserve
+        /// reports of the era that starts
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            let era_length = T::ReportEraLength::get();
+            if era_length.is_zero() || !(now % era_length).is_zero() {
+                return Weight::zero();
+            }
+            let era: u32 = (now / era_length).saturated_into();
+            Self::seal_event_digest(era.saturating_sub(1));
+            T::WeightInfo::seal_event_digest()
+                .saturating_add(T::WeightInfo::record_reserve_reports(Self::record_reserve_reports(era)))
+        }
+
+        /// Every bridged coin maps to a multicoin coin the custody account can mint and burn
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+            ensure!(Self::coin_mapping_issues().is_empty(), "bridged coin without a usable multicoin coin");
+            Ok(())
+        }
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// User submits deposit request after sending coins to custody wallet
@@ -243,113 +934,41 @@ pub mod pallet {
             coin_name: Vec<u8>,
             external_amount: u128,
             ratio: u128,
+            tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
         ) -> DispatchResult {
             let submitter = ensure_signed(origin)?;
+            // Charged by the multicoin fee adapter through `BridgeFeeCoin`
+            let _ = tx_fee_coin;
 
-            // Validate inputs
-            ensure!(external_amount > 0, Error::<T>::InvalidAmount);
-            ensure!(ratio > 0, Error::<T>::InvalidRatio);
-
-            // Create bounded vectors
-            let bounded_tx_id: BoundedVec<u8, T::MaxTxIdLength> = external_tx_id.try_into()
-                .map_err(|_| Error::<T>::DataTooLong)?;
-            let bounded_wallet: BoundedVec<u8, T::MaxWalletLength> = external_wallet.try_into()
-                .map_err(|_| Error::<T>::DataTooLong)?;
-            let bounded_coin_name: BoundedVec<u8, T::MaxCoinNameLength> = coin_name.try_into()
-                .map_err(|_| Error::<T>::DataTooLong)?;
-
-            // Calculate native amount: external_amount * ratio
-            let native_amount_u128 = external_amount.checked_mul(ratio)
-                .ok_or(Error::<T>::ArithmeticOverflow)?;
-            let native_amount: BalanceOf<T> = native_amount_u128.try_into()
-                .map_err(|_| Error::<T>::ArithmeticOverflow)?;
-
-            // Generate request ID
-            let counter = RequestCounter::<T>::get();
-            RequestCounter::<T>::put(counter.saturating_add(1));
-            let current_block = <frame_system::Pallet<T>>::block_number();
-            let request_id = <T::Hashing as Hash>::hash_of(&(&submitter, counter, current_block));
-
-            // Create deposit request
-            let request = DepositRequest {
-                submitter: submitter.clone(),
-                recipient: onchain_account.clone(),
-                external_tx_id: bounded_tx_id.clone(),
-                external_wallet: bounded_wallet.clone(),
-                coin_name: bounded_coin_name.clone(),
+            Self::create_deposit(
+                submitter,
+                onchain_account,
+                external_tx_id,
+                external_wallet,
+                coin_name,
                 external_amount,
                 ratio,
-                native_amount,
-                status: DepositStatus::Pending,
-                submitted_at: current_block,
-                approved_by: None,
-            };
+            )?;
 
-            // Store request
-            DepositRequests::<T>::insert(&request_id, &request);
-
-            // Track user's deposits (track by recipient account)
-            UserDeposits::<T>::try_mutate(&onchain_account, |deposits| {
-                deposits.try_push(request_id)
-                    .map_err(|_| Error::<T>::ArithmeticOverflow)
-            })?;
-
-            Self::deposit_event(Event::DepositRequested {
-                request_id,
-                submitter,
-                recipient: onchain_account,
-                external_tx_id: bounded_tx_id,
-                external_wallet: bounded_wallet,
-                coin_name: bounded_coin_name,
-                external_amount,
-                native_amount,
-            });
-
-            Ok(())
-        }
+            Ok(())
+        }
 
         /// Validator approves deposit and transfers tokens from custody
         #[pallet::call_index(1)]
-        #[pallet::weight(T::WeightInfo::approve_deposit())]
+        #[pallet::weight(T::WeightInfo::approve_deposit().saturating_add(T::MaxForwardWeight::get()))]
         pub fn approve_deposit(
             origin: OriginFor<T>,
             request_id: T::Hash,
         ) -> DispatchResult {
             let validator = ensure_signed(origin)?;
 
-            let mut request = DepositRequests::<T>::get(&request_id)
+            let request = DepositRequests::<T>::get(&request_id)
                 .ok_or(Error::<T>::RequestNotFound)?;
 
             ensure!(request.status == DepositStatus::Pending, Error::<T>::AlreadyProcessed);
             ensure!(request.submitter != validator, Error::<T>::CannotApproveOwnRequest);
 
-            // Get custody account
-            let custody_account = Self::account_id();
-
-            // Transfer tokens from custody to recipient
-            T::Currency::transfer(
-                &custody_account,
-                &request.recipient,
-                request.native_amount,
-                frame_support::traits::ExistenceRequirement::AllowDeath,
-            )?;
-
-            // Update request status
-            request.status = DepositStatus::Approved;
-            request.approved_by = Some(validator.clone());
-            DepositRequests::<T>::insert(&request_id, &request);
-
-            // Update total minted
-            TotalMinted::<T>::mutate(|total| *total = total.saturating_add(request.native_amount));
-
-            Self::deposit_event(Event::DepositApproved {
-                request_id,
-                user: request.recipient.clone(),
-                validator,
-                native_amount: request.native_amount,
-            });
-
-            Ok(())
+            Self::settle_deposit(request_id, request, validator)
         }
 
         /// Validator rejects deposit request
@@ -369,6 +988,9 @@ pub mod pallet {
             // Update status
             request.status = DepositStatus::Rejected;
             DepositRequests::<T>::insert(&request_id, &request);
+            DepositSplits::<T>::remove(&request_id);
+            DepositForwards::<T>::remove(&request_id);
+            CoinBridgeTotals::<T>::mutate(&request.coin_name, |totals| totals.pending_deposits.saturating_dec());
 
             Self::deposit_event(Event::DepositRejected {
                 request_id,
@@ -387,12 +1009,18 @@ pub mod pallet {
             coin_name: Vec<u8>,
             native_amount: BalanceOf<T>,
             ratio: u128,
+            tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
         ) -> DispatchResult {
             let user = ensure_signed(origin)?;
+            // Charged by the multicoin fee adapter through `BridgeFeeCoin`
+            let _ = tx_fee_coin;
 
             // Validate inputs
+            Self::ensure_bridge_open(&coin_name)?;
             ensure!(!native_amount.is_zero(), Error::<T>::InvalidAmount);
             ensure!(ratio > 0, Error::<T>::InvalidRatio);
+            Self::ensure_ratio_accepted(&coin_name, ratio)?;
+            Self::ensure_valid_address(&coin_name, &external_wallet)?;
 
             // Check user has sufficient balance
             let user_balance = T::Currency::free_balance(&user);
@@ -403,18 +1031,20 @@ pub mod pallet {
                 .map_err(|_| Error::<T>::DataTooLong)?;
             let bounded_coin_name: BoundedVec<u8, T::MaxCoinNameLength> = coin_name.try_into()
                 .map_err(|_| Error::<T>::DataTooLong)?;
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            Self::ensure_withdrawal_address(&user, &bounded_coin_name, &bounded_wallet, current_block)?;
 
-            // Calculate external amount: native_amount / ratio
+            // Calculate external amount: native_amount / ratio, rounded per the coin's policy
             let native_amount_u128: u128 = native_amount.try_into()
                 .map_err(|_| Error::<T>::ArithmeticOverflow)?;
-            let external_amount = native_amount_u128.checked_div(ratio)
-                .ok_or(Error::<T>::ArithmeticOverflow)?;
+            let external_amount = match RoundingPolicies::<T>::get(&bounded_coin_name).rounding {
+                Rounding::FavorReserve => native_amount_u128.checked_div(ratio),
+                Rounding::FavorUser => Some(native_amount_u128.div_ceil(ratio)),
+            }
+            .ok_or(Error::<T>::ArithmeticOverflow)?;
 
             // Generate request ID
-            let counter = RequestCounter::<T>::get();
-            RequestCounter::<T>::put(counter.saturating_add(1));
-            let current_block = <frame_system::Pallet<T>>::block_number();
-            let request_id = <T::Hashing as Hash>::hash_of(&(&user, counter, current_block));
+            let request_id = Self::next_request_id(&user, current_block);
 
             // Create withdrawal request
             let request = WithdrawalRequest {
@@ -431,6 +1061,7 @@ pub mod pallet {
 
             // Store request
             WithdrawalRequests::<T>::insert(&request_id, &request);
+            CoinBridgeTotals::<T>::mutate(&bounded_coin_name, |totals| totals.pending_withdrawals.saturating_inc());
 
             // Track user's withdrawals
             UserWithdrawals::<T>::try_mutate(&user, |withdrawals| {
@@ -462,12 +1093,27 @@ pub mod pallet {
                 .ok_or(Error::<T>::RequestNotFound)?;
 
             ensure!(request.status == WithdrawalStatus::Pending, Error::<T>::AlreadyProcessed);
+            Self::ensure_bridge_open(&request.coin_name)?;
+
+            // Route rounding dust to the insurance fund if the coin asks for it; dust
+            // the fund can't take (e.g. below the existential deposit) is burned
+            let dust = match RoundingPolicies::<T>::get(&request.coin_name).dust {
+                DustHandling::Burn => Zero::zero(),
+                DustHandling::InsuranceFund => Self::withdrawal_dust(&request),
+            };
+            let routed = !dust.is_zero() && T::Currency::transfer(
+                &request.user,
+                &Self::insurance_fund_account(),
+                dust,
+                frame_support::traits::ExistenceRequirement::KeepAlive,
+            ).is_ok();
+            let burned = if routed { request.native_amount.saturating_sub(dust) } else { request.native_amount };
 
             // Burn tokens from user's account
             // This decreases total issuance
             let _negative_imbalance = T::Currency::withdraw(
                 &request.user,
-                request.native_amount,
+                burned,
                 frame_support::traits::WithdrawReasons::TRANSFER,
                 frame_support::traits::ExistenceRequirement::KeepAlive,
             )?;
@@ -476,17 +1122,43 @@ pub mod pallet {
             request.status = WithdrawalStatus::Completed;
             request.processed_by = Some(processor.clone());
             WithdrawalRequests::<T>::insert(&request_id, &request);
+            CoinBridgeTotals::<T>::mutate(&request.coin_name, |totals| {
+                totals.pending_withdrawals.saturating_dec();
+                totals.withdrawn = totals.withdrawn.saturating_add(request.external_amount);
+                Self::note_processed(totals, request.submitted_at);
+            });
 
             // Update total burned
-            TotalBurned::<T>::mutate(|total| *total = total.saturating_add(request.native_amount));
+            TotalBurned::<T>::mutate(|total| *total = total.saturating_add(burned));
 
+            if routed {
+                Self::deposit_event(Event::WithdrawalDustRouted { request_id, amount: dust });
+            }
             Self::deposit_event(Event::WithdrawalCompleted {
                 request_id,
                 user: request.user.clone(),
-                processor,
+                processor: processor.clone(),
                 native_amount: request.native_amount,
             });
 
+            let tip = WithdrawalTips::<T>::take(&request_id);
+            if !tip.is_zero() {
+                T::Currency::transfer(
+                    &Self::tip_pot_account(),
+                    &processor,
+                    tip,
+                    frame_support::traits::ExistenceRequirement::AllowDeath,
+                )?;
+                Self::deposit_event(Event::WithdrawalTipPaid { request_id, processor: processor.clone(), tip });
+            }
+
+            // Registered processors are paid for running the payout infrastructure
+            let reward = T::ProcessorReward::get();
+            if Processors::<T>::contains_key(&processor) && !reward.is_zero() {
+                ProcessorRewards::<T>::mutate(&processor, |total| *total = total.saturating_add(reward));
+                Self::deposit_event(Event::ProcessorRewardAccrued { processor, request_id, amount: reward });
+            }
+
             Ok(())
         }
 
@@ -507,6 +1179,18 @@ pub mod pallet {
             // Update status
             request.status = WithdrawalStatus::Rejected;
             WithdrawalRequests::<T>::insert(&request_id, &request);
+            CoinBridgeTotals::<T>::mutate(&request.coin_name, |totals| totals.pending_withdrawals.saturating_dec());
+
+            // Refund the priority tip
+            let tip = WithdrawalTips::<T>::take(&request_id);
+            if !tip.is_zero() {
+                T::Currency::transfer(
+                    &Self::tip_pot_account(),
+                    &request.user,
+                    tip,
+                    frame_support::traits::ExistenceRequirement::AllowDeath,
+                )?;
+            }
 
             Self::deposit_event(Event::WithdrawalRejected {
                 request_id,
@@ -517,6 +1201,7 @@ pub mod pallet {
         }
         /// Execute any pallet call with a specific fee coin
 		/// This is a wrapper that temporarily sets the fee coin for one transaction
+		/// `call` can't be another `call_multicoin` and must pass the origin's call filter
 		#[pallet::call_index(6)] // Adjust index as needed
 		#[pallet::weight(T::WeightInfo::call_multicoin())]
 		pub fn call_multicoin(
@@ -524,11 +1209,629 @@ pub mod pallet {
 			call: Box<T::RuntimeCall>,
 		) -> DispatchResult {
 			let _who = ensure_signed(origin.clone())?;
+			ensure!(!BridgeWrapperCall::<T>::contains(&call), Error::<T>::NestedWrapperCall);
+			ensure!(origin.filter_call(&call), frame_system::Error::<T>::CallFiltered);
 			// Execute the call
 			let result = call.dispatch(origin);
 			result.map(|_| ()).map_err(|e| e.error)
 		}
 
+        /// User registers an external wallet to withdraw to
+        ///
+        /// The address can be used once `WithdrawalAddressDelay` blocks have
+        /// passed, so an attacker who takes over the account can't drain it to a
+        /// fresh address before the owner notices.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::register_withdrawal_address())]
+        pub fn register_withdrawal_address(
+            origin: OriginFor<T>,
+            external_wallet: Vec<u8>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let bounded_wallet: BoundedVec<u8, T::MaxWalletLength> = external_wallet.try_into()
+                .map_err(|_| Error::<T>::DataTooLong)?;
+            let usable_from = <frame_system::Pallet<T>>::block_number()
+                .saturating_add(T::WithdrawalAddressDelay::get());
+
+            WithdrawalAddresses::<T>::try_mutate(&who, |addresses| {
+                ensure!(
+                    !addresses.iter().any(|(wallet, _)| *wallet == bounded_wallet),
+                    Error::<T>::AddressAlreadyRegistered
+                );
+                addresses.try_push((bounded_wallet.clone(), usable_from))
+                    .map_err(|_| Error::<T>::TooManyWithdrawalAddresses)
+            })?;
+
+            Self::deposit_event(Event::WithdrawalAddressRegistered {
+                who,
+                external_wallet: bounded_wallet,
+                usable_from,
+            });
+
+            Ok(())
+        }
+
+        /// User removes a registered withdrawal address
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::remove_withdrawal_address())]
+        pub fn remove_withdrawal_address(
+            origin: OriginFor<T>,
+            external_wallet: Vec<u8>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let bounded_wallet: BoundedVec<u8, T::MaxWalletLength> = external_wallet.try_into()
+                .map_err(|_| Error::<T>::DataTooLong)?;
+
+            WithdrawalAddresses::<T>::try_mutate_exists(&who, |addresses| {
+                let list = addresses.as_mut().ok_or(Error::<T>::AddressNotRegistered)?;
+                let index = list.iter().position(|(wallet, _)| *wallet == bounded_wallet)
+                    .ok_or(Error::<T>::AddressNotRegistered)?;
+                list.remove(index);
+                if list.is_empty() {
+                    *addresses = None;
+                }
+                Ok::<_, Error<T>>(())
+            })?;
+
+            Self::deposit_event(Event::WithdrawalAddressRemoved {
+                who,
+                external_wallet: bounded_wallet,
+            });
+
+            Ok(())
+        }
+
+        /// Admin restricts withdrawals of a coin to registered addresses, or lifts the restriction
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::set_address_book_required())]
+        pub fn set_address_book_required(
+            origin: OriginFor<T>,
+            coin_name: Vec<u8>,
+            required: bool,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let bounded_coin_name: BoundedVec<u8, T::MaxCoinNameLength> = coin_name.try_into()
+                .map_err(|_| Error::<T>::DataTooLong)?;
+            AddressBookRequired::<T>::set(&bounded_coin_name, required.then_some(()));
+
+            Self::deposit_event(Event::AddressBookRequirementSet {
+                coin_name: bounded_coin_name,
+                required,
+            });
+
+            Ok(())
+        }
+
+        /// Trusted reserve notifies a verified deposit over XCM
+        ///
+        /// Extends the bridge to chains whose reserve attests deposits itself: the
+        /// deposit is recorded as a request submitted and approved by the
+        /// reserve's sovereign account and paid out from custody right away.
+        /// Each external transaction ID is accepted once per coin.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::xcm_deposit())]
+        pub fn xcm_deposit(
+            origin: OriginFor<T>,
+            recipient: T::AccountId,
+            external_tx_id: Vec<u8>,
+            coin_name: Vec<u8>,
+            external_amount: u128,
+            ratio: u128,
+        ) -> DispatchResult {
+            let reserve = T::XcmDepositOrigin::ensure_origin(origin)?;
+
+            // Validate inputs
+            Self::ensure_bridge_open(&coin_name)?;
+            ensure!(external_amount > 0, Error::<T>::InvalidAmount);
+            ensure!(ratio > 0, Error::<T>::InvalidRatio);
+            Self::ensure_ratio_accepted(&coin_name, ratio)?;
+
+            let bounded_tx_id: BoundedVec<u8, T::MaxTxIdLength> = external_tx_id.try_into()
+                .map_err(|_| Error::<T>::DataTooLong)?;
+            let bounded_coin_name: BoundedVec<u8, T::MaxCoinNameLength> = coin_name.try_into()
+                .map_err(|_| Error::<T>::DataTooLong)?;
+            ensure!(
+                !XcmDeposits::<T>::contains_key(&bounded_coin_name, &bounded_tx_id),
+                Error::<T>::DepositAlreadyNotified
+            );
+
+            let native_amount_u128 = external_amount.checked_mul(ratio)
+                .ok_or(Error::<T>::ArithmeticOverflow)?;
+            let native_amount: BalanceOf<T> = native_amount_u128.try_into()
+                .map_err(|_| Error::<T>::ArithmeticOverflow)?;
+
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            let request_id = Self::next_request_id(&reserve, current_block);
+            let request = DepositRequest {
+                submitter: reserve.clone(),
+                recipient: recipient.clone(),
+                external_tx_id: bounded_tx_id.clone(),
+                external_wallet: BoundedVec::default(),
+                coin_name: bounded_coin_name.clone(),
+                external_amount,
+                ratio,
+                native_amount,
+                status: DepositStatus::Pending,
+                submitted_at: current_block,
+                approved_by: None,
+            };
+
+            XcmDeposits::<T>::insert(&bounded_coin_name, &bounded_tx_id, request_id);
+            CoinBridgeTotals::<T>::mutate(&bounded_coin_name, |totals| totals.pending_deposits.saturating_inc());
+            UserDeposits::<T>::try_mutate(&recipient, |deposits| {
+                deposits.try_push(request_id)
+                    .map_err(|_| Error::<T>::ArithmeticOverflow)
+            })?;
+
+            Self::deposit_event(Event::XcmDepositReceived {
+                request_id,
+                reserve: reserve.clone(),
+                recipient,
+                external_tx_id: bounded_tx_id,
+                coin_name: bounded_coin_name,
+                external_amount,
+                native_amount,
+            });
+
+            Self::settle_deposit(request_id, request, reserve)
+        }
+
+        /// Admin registers the signer set approving deposits by signature
+        ///
+        /// Passing no signers and a threshold of 0 turns signed approvals off.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::set_approval_signers())]
+        pub fn set_approval_signers(
+            origin: OriginFor<T>,
+            signers: Vec<T::ApprovalSigner>,
+            threshold: u32,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                threshold as usize <= signers.len() && (threshold == 0) == signers.is_empty(),
+                Error::<T>::InvalidApprovalThreshold
+            );
+            for (i, signer) in signers.iter().enumerate() {
+                ensure!(!signers[..i].contains(signer), Error::<T>::DuplicateApprovalSigner);
+            }
+            let signers: BoundedVec<T::ApprovalSigner, T::MaxApprovalSigners> = signers.try_into()
+                .map_err(|_| Error::<T>::TooManyApprovalSigners)?;
+
+            ApprovalSigners::<T>::put(&signers);
+            ApprovalThreshold::<T>::put(threshold);
+
+            Self::deposit_event(Event::ApprovalSignersSet { signers, threshold });
+
+            Ok(())
+        }
+
+        /// Approve a deposit with signatures of the registered signer set
+        ///
+        /// For high-volume bridges whose validators sign request hashes off-chain:
+        /// any account relays `(signer index, signature)` pairs over
+        /// `approval_payload(request_id)` in one extrinsic instead of each
+        /// validator voting on-chain. The relayer is recorded as approver.
+        #[pallet::call_index(12)]
+        #[pallet::weight(
+            T::WeightInfo::approve_deposit_signed(signatures.len() as u32).saturating_add(T::MaxForwardWeight::get())
+        )]
+        pub fn approve_deposit_signed(
+            origin: OriginFor<T>,
+            request_id: T::Hash,
+            signatures: Vec<(u32, T::ApprovalSignature)>,
+        ) -> DispatchResult {
+            let relayer = ensure_signed(origin)?;
+
+            let request = DepositRequests::<T>::get(&request_id)
+                .ok_or(Error::<T>::RequestNotFound)?;
+            ensure!(request.status == DepositStatus::Pending, Error::<T>::AlreadyProcessed);
+
+            let threshold = ApprovalThreshold::<T>::get();
+            ensure!(threshold > 0, Error::<T>::SignedApprovalDisabled);
+            ensure!(
+                signatures.len() <= T::MaxApprovalSigners::get() as usize,
+                Error::<T>::TooManyApprovalSigners
+            );
+
+            let signers = ApprovalSigners::<T>::get();
+            let payload = Self::approval_payload(&request_id);
+            let mut next_index = 0;
+            for (index, signature) in &signatures {
+                ensure!(*index >= next_index, Error::<T>::InvalidApprovalSignature);
+                let signer = signers.get(*index as usize).ok_or(Error::<T>::InvalidApprovalSignature)?;
+                ensure!(signature.verify(&payload[..], signer), Error::<T>::InvalidApprovalSignature);
+                next_index = index.saturating_add(1);
+            }
+            ensure!(signatures.len() >= threshold as usize, Error::<T>::NotEnoughApprovalSignatures);
+
+            Self::settle_deposit(request_id, request, relayer)
+        }
+
+        /// Admin registers or removes a withdrawal processor
+        ///
+        /// Rewards already accrued stay claimable after removal.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::set_processor())]
+        pub fn set_processor(
+            origin: OriginFor<T>,
+            who: T::AccountId,
+            registered: bool,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            if registered {
+                Processors::<T>::insert(&who, ());
+            } else {
+                Processors::<T>::remove(&who);
+            }
+
+            Self::deposit_event(Event::ProcessorSet { who, registered });
+
+            Ok(())
+        }
+
+        /// Processor claims its accrued withdrawal rewards from the fee pot
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::claim_processor_reward())]
+        pub fn claim_processor_reward(origin: OriginFor<T>) -> DispatchResult {
+            let processor = ensure_signed(origin)?;
+
+            let amount = ProcessorRewards::<T>::take(&processor);
+            ensure!(!amount.is_zero(), Error::<T>::NoRewardToClaim);
+
+            T::Currency::transfer(
+                &Self::fee_pot_account(),
+                &processor,
+                amount,
+                frame_support::traits::ExistenceRequirement::AllowDeath,
+            )?;
+
+            Self::deposit_event(Event::ProcessorRewardClaimed { processor, amount });
+
+            Ok(())
+        }
+
+        /// Custodian attests the external reserves held for a coin
+        ///
+        /// With a `proof`, the balance must verify against the light client and the
+        /// attestation is stored as `Verified`; without one it is `SelfReported`.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::attest_reserves(proof.as_ref().map_or(0, |proof| proof.len() as u32)))]
+        pub fn attest_reserves(
+            origin: OriginFor<T>,
+            coin_name: Vec<u8>,
+            external_balance: u128,
+            proof: Option<Vec<u8>>,
+        ) -> DispatchResult {
+            let custodian = T::CustodianOrigin::ensure_origin(origin)?;
+
+            let kind = match proof {
+                Some(proof) => {
+                    ensure!(
+                        T::ReserveProofVerifier::verify(&coin_name, external_balance, &proof),
+                        Error::<T>::InvalidReserveProof
+                    );
+                    AttestationKind::Verified
+                },
+                None => AttestationKind::SelfReported,
+            };
+            let bounded_coin_name: BoundedVec<u8, T::MaxCoinNameLength> = coin_name.try_into()
+                .map_err(|_| Error::<T>::DataTooLong)?;
+
+            ReserveAttestations::<T>::insert(&bounded_coin_name, ReserveAttestation {
+                custodian: custodian.clone(),
+                external_balance,
+                kind: kind.clone(),
+                attested_at: <frame_system::Pallet<T>>::block_number(),
+            });
+
+            Self::deposit_event(Event::ReservesAttested {
+                coin_name: bounded_coin_name,
+                custodian,
+                external_balance,
+                kind,
+            });
+
+            Ok(())
+        }
+
+        /// Admin sets how withdrawals of a coin are rounded and where their dust goes
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::set_rounding_policy())]
+        pub fn set_rounding_policy(
+            origin: OriginFor<T>,
+            coin_name: Vec<u8>,
+            policy: RoundingPolicy,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let bounded_coin_name: BoundedVec<u8, T::MaxCoinNameLength> = coin_name.try_into()
+                .map_err(|_| Error::<T>::DataTooLong)?;
+
+            RoundingPolicies::<T>::set(&bounded_coin_name, policy);
+
+            Self::deposit_event(Event::RoundingPolicySet { coin_name: bounded_coin_name, policy });
+
+            Ok(())
+        }
+
+        /// User adds a priority tip to a pending withdrawal
+        ///
+        /// Processors see pending withdrawals ordered by tip through
+        /// `pending_withdrawals_by_tip`. The tip is held in the tip pot, paid to
+        /// the processor completing the withdrawal and refunded if it's rejected.
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::tip_withdrawal())]
+        pub fn tip_withdrawal(
+            origin: OriginFor<T>,
+            request_id: T::Hash,
+            tip: BalanceOf<T>,
+        ) -> DispatchResult {
+            let user = ensure_signed(origin)?;
+
+            let request = WithdrawalRequests::<T>::get(&request_id)
+                .ok_or(Error::<T>::RequestNotFound)?;
+            ensure!(request.user == user, Error::<T>::NotAuthorized);
+            ensure!(request.status == WithdrawalStatus::Pending, Error::<T>::AlreadyProcessed);
+            ensure!(!tip.is_zero(), Error::<T>::InvalidAmount);
+
+            T::Currency::transfer(
+                &user,
+                &Self::tip_pot_account(),
+                tip,
+                frame_support::traits::ExistenceRequirement::KeepAlive,
+            )?;
+            let tip = WithdrawalTips::<T>::mutate(&request_id, |total| {
+                *total = total.saturating_add(tip);
+                *total
+            });
+
+            Self::deposit_event(Event::WithdrawalTipped { request_id, tip });
+
+            Ok(())
+        }
+
+        /// Submitter requests a deposit crediting several accounts
+        ///
+        /// For exchanges sweeping one external transaction that covers many user
+        /// credits: on approval the native amount is split across `recipients` in
+        /// proportion to their shares. The first recipient is the request's
+        /// recipient and receives the rounding remainder.
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::request_split_deposit(recipients.len() as u32))]
+        pub fn request_split_deposit(
+            origin: OriginFor<T>,
+            recipients: BoundedVec<(T::AccountId, u32), T::MaxDepositRecipients>,
+            external_tx_id: Vec<u8>,
+            external_wallet: Vec<u8>,
+            coin_name: Vec<u8>,
+            external_amount: u128,
+            ratio: u128,
+            tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+        ) -> DispatchResult {
+            let submitter = ensure_signed(origin)?;
+            // Charged by the multicoin fee adapter through `BridgeFeeCoin`
+            let _ = tx_fee_coin;
+
+            ensure!(
+                !recipients.is_empty() && recipients.iter().all(|(_, share)| *share > 0),
+                Error::<T>::InvalidSplit
+            );
+            for (i, (who, _)) in recipients.iter().enumerate() {
+                ensure!(!recipients[..i].iter().any(|(other, _)| other == who), Error::<T>::InvalidSplit);
+            }
+
+            let request_id = Self::create_deposit(
+                submitter,
+                recipients[0].0.clone(),
+                external_tx_id,
+                external_wallet,
+                coin_name,
+                external_amount,
+                ratio,
+            )?;
+
+            // Track the deposit for the other recipients too
+            for (who, _) in recipients.iter().skip(1) {
+                UserDeposits::<T>::try_mutate(who, |deposits| {
+                    deposits.try_push(request_id)
+                        .map_err(|_| Error::<T>::ArithmeticOverflow)
+                })?;
+            }
+            DepositSplits::<T>::insert(&request_id, &recipients);
+
+            Self::deposit_event(Event::DepositSplitRequested { request_id, recipients });
+
+            Ok(())
+        }
+
+        /// Admin pauses or resumes the bridge for one coin, or for all coins without `coin_name`
+        ///
+        /// While paused no deposits or withdrawals of the coin are requested,
+        /// approved or completed; rejections still go through.
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::set_bridge_pause())]
+        pub fn set_bridge_pause(
+            origin: OriginFor<T>,
+            coin_name: Option<Vec<u8>>,
+            paused: bool,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let bounded_coin_name = coin_name
+                .map(BoundedVec::<u8, T::MaxCoinNameLength>::try_from)
+                .transpose()
+                .map_err(|_| Error::<T>::DataTooLong)?;
+            match (&bounded_coin_name, paused) {
+                (None, paused) => BridgePaused::<T>::put(paused),
+                (Some(coin_name), true) => PausedCoins::<T>::insert(coin_name, ()),
+                (Some(coin_name), false) => PausedCoins::<T>::remove(coin_name),
+            }
+
+            Self::deposit_event(Event::BridgePauseSet { coin_name: bounded_coin_name, paused });
+
+            Ok(())
+        }
+
+        /// Admin sets the range of exchange ratios accepted for a coin, or removes it
+        ///
+        /// Deposits and withdrawals with a ratio outside `(min, max)` are rejected
+        /// before a validator ever sees them.
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::set_ratio_bounds())]
+        pub fn set_ratio_bounds(
+            origin: OriginFor<T>,
+            coin_name: Vec<u8>,
+            bounds: Option<(u128, u128)>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let bounded_coin_name: BoundedVec<u8, T::MaxCoinNameLength> = coin_name.try_into()
+                .map_err(|_| Error::<T>::DataTooLong)?;
+            if let Some((min, max)) = bounds {
+                ensure!(min > 0 && min <= max, Error::<T>::InvalidRatioBounds);
+            }
+            RatioBounds::<T>::set(&bounded_coin_name, bounds);
+
+            Self::deposit_event(Event::RatioBoundsSet { coin_name: bounded_coin_name, bounds });
+
+            Ok(())
+        }
+
+        /// User binds an external wallet to its account, watch-only
+        ///
+        /// Deposits from a bound wallet to another recipient are flagged in
+        /// `FlaggedDeposits`. `proof` (e.g. a message signed by the wallet) is only
+        /// hashed; validators check it off-chain. Binding again replaces the proof.
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::bind_wallet(proof.as_ref().map_or(0, |proof| proof.len() as u32)))]
+        pub fn bind_wallet(
+            origin: OriginFor<T>,
+            external_wallet: Vec<u8>,
+            proof: Option<Vec<u8>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let bounded_wallet: BoundedVec<u8, T::MaxWalletLength> = external_wallet.try_into()
+                .map_err(|_| Error::<T>::DataTooLong)?;
+            if let Some(binding) = WalletOwners::<T>::get(&bounded_wallet) {
+                ensure!(binding.owner == who, Error::<T>::WalletAlreadyBound);
+            }
+            let proof_hash = proof.map(|proof| <T::Hashing as Hash>::hash(&proof));
+
+            WalletOwners::<T>::insert(&bounded_wallet, WalletBinding {
+                owner: who.clone(),
+                proof_hash,
+                bound_at: <frame_system::Pallet<T>>::block_number(),
+            });
+
+            Self::deposit_event(Event::WalletBound { who, external_wallet: bounded_wallet, proof_hash });
+
+            Ok(())
+        }
+
+        /// User unbinds one of its external wallets
+        #[pallet::call_index(22)]
+        #[pallet::weight(T::WeightInfo::unbind_wallet())]
+        pub fn unbind_wallet(
+            origin: OriginFor<T>,
+            external_wallet: Vec<u8>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let bounded_wallet: BoundedVec<u8, T::MaxWalletLength> = external_wallet.try_into()
+                .map_err(|_| Error::<T>::DataTooLong)?;
+            ensure!(
+                WalletOwners::<T>::get(&bounded_wallet).is_some_and(|binding| binding.owner == who),
+                Error::<T>::WalletNotBound
+            );
+            WalletOwners::<T>::remove(&bounded_wallet);
+
+            Self::deposit_event(Event::WalletUnbound { who, external_wallet: bounded_wallet });
+
+            Ok(())
+        }
+
+        /// Anyone settles a pending deposit by proving its transaction
+        ///
+        /// The coin's chain adapter checks `proof` and the confirmations it shows
+        /// against the chain's confirmation depth, so no validator is needed.
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::prove_deposit(proof.len() as u32).saturating_add(T::MaxForwardWeight::get()))]
+        pub fn prove_deposit(
+            origin: OriginFor<T>,
+            request_id: T::Hash,
+            proof: Vec<u8>,
+        ) -> DispatchResult {
+            let prover = ensure_signed(origin)?;
+
+            let request = DepositRequests::<T>::get(&request_id)
+                .ok_or(Error::<T>::RequestNotFound)?;
+
+            ensure!(request.status == DepositStatus::Pending, Error::<T>::AlreadyProcessed);
+            let adapter = T::ChainAdapters::adapter(&request.coin_name).ok_or(Error::<T>::NoChainAdapter)?;
+            let confirmations = adapter
+                .verify_tx_proof(
+                    &request.external_tx_id,
+                    &request.external_wallet,
+                    request.external_amount,
+                    &proof,
+                )
+                .ok_or(Error::<T>::InvalidTxProof)?;
+            ensure!(confirmations >= adapter.confirmation_depth(), Error::<T>::NotEnoughConfirmations);
+
+            Self::deposit_event(Event::DepositProven { request_id, prover: prover.clone(), confirmations });
+
+            Self::settle_deposit(request_id, request, prover)
+        }
+
+        /// User submits a deposit to itself along with a call to dispatch on its
+        /// behalf once the deposit is approved, e.g. to stake or add liquidity, so
+        /// bridged funds are put to work in one step.
+        ///
+        /// The call must pass `ForwardCallFilter` and take at most `MaxForwardWeight`.
+        /// It runs right after the payout, with the submitter's signed origin; if it
+        /// fails, its effects are undone and the deposit stays credited.
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::request_deposit_and_call())]
+        pub fn request_deposit_and_call(
+            origin: OriginFor<T>,
+            external_tx_id: Vec<u8>,
+            external_wallet: Vec<u8>,
+            coin_name: Vec<u8>,
+            external_amount: u128,
+            ratio: u128,
+            call: Box<<T as frame_system::Config>::RuntimeCall>,
+            tx_fee_coin: Option<CoinId>, // Fee coin for THIS transaction
+        ) -> DispatchResult {
+            let submitter = ensure_signed(origin)?;
+            // Charged by the multicoin fee adapter through `BridgeFeeCoin`
+            let _ = tx_fee_coin;
+
+            ensure!(T::ForwardCallFilter::contains(&call), Error::<T>::ForwardCallNotAllowed);
+            ensure!(
+                call.get_dispatch_info().call_weight.all_lte(T::MaxForwardWeight::get()),
+                Error::<T>::ForwardCallTooHeavy
+            );
+
+            let request_id = Self::create_deposit(
+                submitter.clone(),
+                submitter,
+                external_tx_id,
+                external_wallet,
+                coin_name,
+                external_amount,
+                ratio,
+            )?;
+            DepositForwards::<T>::insert(&request_id, *call);
+
+            Ok(())
+        }
+
     }
 
     impl<T: Config> Pallet<T> {
@@ -537,6 +1840,432 @@ pub mod pallet {
             T::PalletId::get().into_account_truncating()
         }
 
+        /// Ensure `ratio` lies within the bounds set for the coin named `coin_name`
+        /// and agrees with its oracle price, if the oracle publishes one
+        fn ensure_ratio_accepted(coin_name: &[u8], ratio: u128) -> DispatchResult {
+            let bounded_coin_name = BoundedVec::<u8, T::MaxCoinNameLength>::try_from(coin_name.to_vec());
+            if let Some((min, max)) = bounded_coin_name.ok().and_then(RatioBounds::<T>::get) {
+                ensure!(min <= ratio && ratio <= max, Error::<T>::RatioOutOfBounds);
+            }
+            ensure!(
+                T::PriceOracle::accepts_price(coin_name, FixedU128::saturating_from_integer(ratio)),
+                Error::<T>::RatioDeviatesFromOracle
+            );
+            Ok(())
+        }
+
+        /// Ensure `wallet` is a valid address on the chain of the coin named
+        /// `coin_name`; coins without a chain adapter accept any address
+        fn ensure_valid_address(coin_name: &[u8], wallet: &[u8]) -> DispatchResult {
+            if let Some(adapter) = T::ChainAdapters::adapter(coin_name) {
+                ensure!(adapter.validate_address(wallet), Error::<T>::InvalidAddress);
+            }
+            Ok(())
+        }
+
+        /// Ensure `user` may withdraw `coin_name` to `wallet` at block `now`: any
+        /// wallet unless the coin requires registered addresses, and then only a
+        /// wallet registered at least `WithdrawalAddressDelay` blocks ago
+        fn ensure_withdrawal_address(
+            user: &T::AccountId,
+            coin_name: &BoundedVec<u8, T::MaxCoinNameLength>,
+            wallet: &BoundedVec<u8, T::MaxWalletLength>,
+            now: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            if !AddressBookRequired::<T>::contains_key(coin_name) {
+                return Ok(());
+            }
+            let usable_from = WithdrawalAddresses::<T>::get(user)
+                .into_iter()
+                .find_map(|(registered, usable_from)| (registered == *wallet).then_some(usable_from))
+                .ok_or(Error::<T>::AddressNotRegistered)?;
+            ensure!(now >= usable_from, Error::<T>::AddressNotConfirmed);
+            Ok(())
+        }
+
+        /// Pay out an approved deposit from custody and record its approval by `validator`
+        fn settle_deposit(
+            request_id: T::Hash,
+            mut request: DepositRequest<T>,
+            validator: T::AccountId,
+        ) -> DispatchResult {
+            Self::ensure_bridge_open(&request.coin_name)?;
+
+            // Get custody account
+            let custody_account = Self::account_id();
+
+            // Accounts arriving through the bridge may have no native coins for fees
+            let mut first_endowed = Vec::new();
+
+            // Transfer tokens from custody to the recipients
+            for (recipient, amount) in Self::deposit_payouts(&request_id, &request) {
+                if amount.is_zero() {
+                    continue;
+                }
+                if T::Currency::total_balance(&recipient).is_zero() {
+                    first_endowed.push(recipient.clone());
+                }
+                T::Currency::transfer(
+                    &custody_account,
+                    &recipient,
+                    amount,
+                    frame_support::traits::ExistenceRequirement::AllowDeath,
+                )?;
+            }
+
+            // Update request status
+            request.status = DepositStatus::Approved;
+            request.approved_by = Some(validator.clone());
+            DepositRequests::<T>::insert(&request_id, &request);
+            CoinBridgeTotals::<T>::mutate(&request.coin_name, |totals| {
+                totals.pending_deposits.saturating_dec();
+                totals.deposited = totals.deposited.saturating_add(request.external_amount);
+                Self::note_processed(totals, request.submitted_at);
+            });
+
+            // Update total minted
+            TotalMinted::<T>::mutate(|total| *total = total.saturating_add(request.native_amount));
+
+            Self::deposit_event(Event::DepositApproved {
+                request_id,
+                user: request.recipient.clone(),
+                validator,
+                native_amount: request.native_amount,
+            });
+
+            let transactions = T::FeeGraceTransactions::get();
+            if transactions > 0 {
+                for who in first_endowed {
+                    FeeGrace::<T>::insert(&who, transactions);
+                    Self::deposit_event(Event::FeeGraceGranted { who, transactions });
+                }
+            }
+
+            // Put the deposit to work: a failing call is undone as a whole, leaving
+            // the deposit credited to the recipient
+            if let Some(call) = DepositForwards::<T>::take(&request_id) {
+                let origin: T::RuntimeOrigin = frame_system::RawOrigin::Signed(request.recipient).into();
+                let result = if T::ForwardCallFilter::contains(&call) && origin.filter_call(&call) {
+                    with_storage_layer(|| call.dispatch(origin).map(|_| ()).map_err(|e| e.error))
+                } else {
+                    Err(frame_system::Error::<T>::CallFiltered.into())
+                };
+                Self::deposit_event(Event::DepositForwarded { request_id, result });
+            }
+
+            Ok(())
+        }
+
+        /// Record a pending deposit of `submitter` crediting `onchain_account`, returning its ID
+        fn create_deposit(
+            submitter: T::AccountId,
+            onchain_account: T::AccountId,
+            external_tx_id: Vec<u8>,
+            external_wallet: Vec<u8>,
+            coin_name: Vec<u8>,
+            external_amount: u128,
+            ratio: u128,
+        ) -> Result<T::Hash, DispatchError> {
+            // Validate inputs
+            Self::ensure_bridge_open(&coin_name)?;
+            ensure!(external_amount > 0, Error::<T>::InvalidAmount);
+            ensure!(ratio > 0, Error::<T>::InvalidRatio);
+            Self::ensure_ratio_accepted(&coin_name, ratio)?;
+            Self::ensure_valid_address(&coin_name, &external_wallet)?;
+
+            // Create bounded vectors
+            let bounded_tx_id: BoundedVec<u8, T::MaxTxIdLength> = external_tx_id.try_into()
+                .map_err(|_| Error::<T>::DataTooLong)?;
+            let bounded_wallet: BoundedVec<u8, T::MaxWalletLength> = external_wallet.try_into()
+                .map_err(|_| Error::<T>::DataTooLong)?;
+            let bounded_coin_name: BoundedVec<u8, T::MaxCoinNameLength> = coin_name.try_into()
+                .map_err(|_| Error::<T>::DataTooLong)?;
+
+            // Calculate native amount: external_amount * ratio
+            let native_amount_u128 = external_amount.checked_mul(ratio)
+                .ok_or(Error::<T>::ArithmeticOverflow)?;
+            let native_amount: BalanceOf<T> = native_amount_u128.try_into()
+                .map_err(|_| Error::<T>::ArithmeticOverflow)?;
+
+            // Generate request ID
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            let request_id = Self::next_request_id(&submitter, current_block);
+
+            // Create deposit request
+            let request = DepositRequest {
+                submitter: submitter.clone(),
+                recipient: onchain_account.clone(),
+                external_tx_id: bounded_tx_id.clone(),
+                external_wallet: bounded_wallet.clone(),
+                coin_name: bounded_coin_name.clone(),
+                external_amount,
+                ratio,
+                native_amount,
+                status: DepositStatus::Pending,
+                submitted_at: current_block,
+                approved_by: None,
+            };
+
+            // Store request
+            DepositRequests::<T>::insert(&request_id, &request);
+            CoinBridgeTotals::<T>::mutate(&bounded_coin_name, |totals| totals.pending_deposits.saturating_inc());
+
+            // Track user's deposits (track by recipient account)
+            UserDeposits::<T>::try_mutate(&onchain_account, |deposits| {
+                deposits.try_push(request_id)
+                    .map_err(|_| Error::<T>::ArithmeticOverflow)
+            })?;
+
+            Self::deposit_event(Event::DepositRequested {
+                request_id,
+                submitter,
+                recipient: onchain_account.clone(),
+                external_tx_id: bounded_tx_id,
+                external_wallet: bounded_wallet.clone(),
+                coin_name: bounded_coin_name,
+                external_amount,
+                native_amount,
+            });
+
+            // Deposits from a bound wallet are expected to credit its owner
+            if let Some(binding) = WalletOwners::<T>::get(&bounded_wallet) {
+                if binding.owner != onchain_account {
+                    FlaggedDeposits::<T>::insert(&request_id, &binding.owner);
+                    Self::deposit_event(Event::DepositWalletMismatch {
+                        request_id,
+                        wallet_owner: binding.owner,
+                        recipient: onchain_account,
+                    });
+                }
+            }
+
+            Ok(request_id)
+        }
+
+        /// Recipients of a deposit and their amounts: its split if it has one, else its recipient.
+        ///
+        /// Each split recipient gets its share rounded down; the remainder goes to the first one.
+        fn deposit_payouts(request_id: &T::Hash, request: &DepositRequest<T>) -> Vec<(T::AccountId, BalanceOf<T>)> {
+            let Some(splits) = DepositSplits::<T>::take(request_id) else {
+                return sp_std::vec![(request.recipient.clone(), request.native_amount)];
+            };
+            let native: u128 = request.native_amount.saturated_into();
+            let total: u128 = splits.iter().map(|(_, share)| *share as u128).sum();
+            let mut payouts: Vec<_> = splits
+                .into_iter()
+                .map(|(who, share)| {
+                    let share = share as u128;
+                    (who, native / total * share + native % total * share / total)
+                })
+                .collect();
+            let paid: u128 = payouts.iter().map(|(_, amount)| amount).sum();
+            payouts[0].1 += native - paid;
+            payouts.into_iter().map(|(who, amount)| (who, amount.saturated_into())).collect()
+        }
+
+        /// Decode `call` as a call of this pallet, if it is one
+        fn as_pallet_call(call: &<T as frame_system::Config>::RuntimeCall) -> Option<Call<T>> {
+            // RuntimeCall is opaque to the pallet, so decode our own calls from its
+            // encoding: [pallet_index, call_index, ...params]
+            let encoded = call.encode();
+            let pallet_index = <Pallet<T> as PalletInfoAccess>::index();
+            if encoded.len() < 2 || encoded[0] as usize != pallet_index {
+                return None;
+            }
+            <Call<T> as Decode>::decode(&mut &encoded[1..]).ok()
+        }
+
+        /// Ensure the bridge is running for `coin_name`
+        fn ensure_bridge_open(coin_name: &[u8]) -> DispatchResult {
+            ensure!(!<Self as PausedQuery>::is_coin_paused(coin_name), Error::<T>::BridgePaused);
+            Ok(())
+        }
+
+        /// Count a request submitted at `submitted_at` and paid out now as processed
+        fn note_processed(totals: &mut BridgeTotals, submitted_at: BlockNumberFor<T>) {
+            let blocks = <frame_system::Pallet<T>>::block_number().saturating_sub(submitted_at);
+            totals.processed.saturating_inc();
+            totals.processing_blocks = totals.processing_blocks.saturating_add(blocks.saturated_into());
+        }
+
+        /// Bridge totals, reserve ratio and latest attestation of `coin_name`
+        pub fn bridge_stats(coin_name: &[u8]) -> Option<BridgeStats<BlockNumberFor<T>>> {
+            let coin_name: BoundedVec<u8, T::MaxCoinNameLength> = coin_name.to_vec().try_into().ok()?;
+            let totals = CoinBridgeTotals::<T>::get(&coin_name);
+            let attestation = ReserveAttestations::<T>::get(&coin_name);
+            if totals == BridgeTotals::default() && attestation.is_none() {
+                return None;
+            }
+
+            let outstanding = totals.deposited.saturating_sub(totals.withdrawn);
+            Some(BridgeStats {
+                total_deposited: totals.deposited,
+                total_withdrawn: totals.withdrawn,
+                pending_deposits: totals.pending_deposits,
+                pending_withdrawals: totals.pending_withdrawals,
+                average_processing_blocks: totals.processing_blocks.checked_div(totals.processed.into()).unwrap_or(0),
+                reserve_ratio: attestation.as_ref().and_then(|attestation| {
+                    FixedU128::checked_from_rational(attestation.external_balance, outstanding)
+                }),
+                last_attestation: attestation.map(|attestation| attestation.attested_at),
+            })
+        }
+
+        /// Message approval signers sign to approve the deposit `request_id`
+        pub fn approval_payload(request_id: &T::Hash) -> Vec<u8> {
+            (b"por/approve", request_id).encode()
+        }
+
+        /// Derive the ID of a new request by `who` from the request counter
+        fn next_request_id(who: &T::AccountId, now: BlockNumberFor<T>) -> T::Hash {
+            let counter = RequestCounter::<T>::get();
+            RequestCounter::<T>::put(counter.saturating_add(1));
+            <T::Hashing as Hash>::hash_of(&(who, counter, now))
+        }
+
+        /// Native amount a withdrawal burns beyond its external amount at its ratio
+        fn withdrawal_dust(request: &WithdrawalRequest<T>) -> BalanceOf<T> {
+            let paid_out = request.external_amount.saturating_mul(request.ratio);
+            request.native_amount.saturating_sub(BalanceOf::<T>::saturated_from(paid_out))
+        }
+
+        /// On-chain supply, bridge net mint and attested reserves of `coin_name`;
+        /// `None` if the coin was never bridged nor attested
+        pub fn reserve_report(coin_name: &[u8]) -> Option<ReserveReport<BlockNumberFor<T>>> {
+            let coin_name: BoundedVec<u8, T::MaxCoinNameLength> = coin_name.to_vec().try_into().ok()?;
+            let totals = CoinBridgeTotals::<T>::get(&coin_name);
+            let attestation = ReserveAttestations::<T>::get(&coin_name);
+            if totals == BridgeTotals::default() && attestation.is_none() {
+                return None;
+            }
+
+            let onchain_supply = T::CoinSupply::supply_of_symbol(&coin_name);
+            let bridge_net_minted = totals.deposited.saturating_sub(totals.withdrawn);
+            let backing_needed = onchain_supply.unwrap_or(0).max(bridge_net_minted);
+            let status = match &attestation {
+                None => ReserveStatus::Unattested,
+                Some(attestation) if attestation.external_balance < backing_needed =>
+                    ReserveStatus::Undercollateralized,
+                Some(_) if onchain_supply.is_some_and(|supply| supply != bridge_net_minted) =>
+                    ReserveStatus::SupplyMismatch,
+                Some(_) => ReserveStatus::Consistent,
+            };
+            Some(ReserveReport {
+                onchain_supply,
+                bridge_net_minted,
+                attested_reserve: attestation.as_ref().map(|attestation| attestation.external_balance),
+                attested_at: attestation.map(|attestation| attestation.attested_at),
+                status,
+            })
+        }
+
+        /// Bridged coins whose names don't map to a live multicoin coin the custody
+        /// account may mint and burn, with the first issue found for each
+        pub fn coin_mapping_issues() -> Vec<(Vec<u8>, CoinMappingIssue)> {
+            let custody = Self::account_id();
+            CoinBridgeTotals::<T>::iter_keys()
+                .filter_map(|coin_name| {
+                    let issue = match T::CoinSupply::mapped_coin(&coin_name, &custody) {
+                        None => CoinMappingIssue::NotFound,
+                        Some(coin) if coin.destroying => CoinMappingIssue::Destroying,
+                        Some(coin) if !coin.can_mint => CoinMappingIssue::NoMintPermission,
+                        Some(coin) if !coin.can_burn => CoinMappingIssue::NoBurnPermission,
+                        Some(_) => return None,
+                    };
+                    Some((coin_name.into_inner(), issue))
+                })
+                .collect()
+        }
+
+        /// Reserve report of `coin_name` stored at the start of `era`
+        pub fn reserve_report_at(era: u32, coin_name: &[u8]) -> Option<ReserveReport<BlockNumberFor<T>>> {
+            let coin_name: BoundedVec<u8, T::MaxCoinNameLength> = coin_name.to_vec().try_into().ok()?;
+            ReserveReports::<T>::get(era, coin_name)
+        }
+
+        /// Store the report of every bridged or attested coin for `era`, up to
+        /// `MaxReportedCoins` of them, returning how many were stored
+        pub(crate) fn record_reserve_reports(era: u32) -> u32 {
+            let max = T::MaxReportedCoins::get() as usize;
+            let coins: BTreeSet<BoundedVec<u8, T::MaxCoinNameLength>> = CoinBridgeTotals::<T>::iter_keys()
+                .take(max)
+                .chain(ReserveAttestations::<T>::iter_keys().take(max))
+                .collect();
+
+            let mut reported = 0u32;
+            for coin_name in coins.into_iter().take(max) {
+                if let Some(report) = Self::reserve_report(&coin_name) {
+                    ReserveReports::<T>::insert(era, &coin_name, report);
+                    reported.saturating_inc();
+                }
+            }
+            Self::deposit_event(Event::ReserveReportsRecorded { era, coins: reported });
+            reported
+        }
+
+        /// Deposit an event, folding it into the current era's event digest
+        pub(super) fn deposit_event(event: Event<T>) {
+            Self::note_event(&event);
+            let event = <<T as Config>::RuntimeEvent as From<Event<T>>>::from(event);
+            frame_system::Pallet::<T>::deposit_event(event);
+        }
+
+        /// Add the hash of `event` as the next leaf of the era's Merkle mountain range
+        fn note_event(event: &Event<T>) {
+            EraEvents::<T>::mutate(|accumulator| {
+                // Each trailing one bit of the count is a peak of the new leaf's height to merge with
+                let mut node = <T::Hashing as Hash>::hash_of(event);
+                for _ in 0..accumulator.events.trailing_ones() {
+                    if let Some(peak) = accumulator.peaks.pop() {
+                        node = <T::Hashing as Hash>::hash_of(&(peak, node));
+                    }
+                }
+                accumulator.peaks.push(node);
+                accumulator.events.saturating_inc();
+            });
+        }
+
+        /// Root of a Merkle mountain range: its peaks folded from the smallest, as
+        /// `hash(peak, root of the smaller peaks)`. `None` for an empty range.
+        pub fn bag_peaks(peaks: &[T::Hash]) -> Option<T::Hash> {
+            peaks.iter().rev().copied().reduce(|root, peak| <T::Hashing as Hash>::hash_of(&(peak, root)))
+        }
+
+        /// Store the digest of `era`'s bridge events and start the next era's range.
+        ///
+        /// The digest event is the first leaf of the next era, chaining the digests.
+        pub(crate) fn seal_event_digest(era: u32) {
+            let accumulator = EraEvents::<T>::take();
+            let Some(root) = Self::bag_peaks(&accumulator.peaks) else { return };
+            EventDigests::<T>::insert(era, EventDigest { root, events: accumulator.events });
+            Self::deposit_event(Event::BridgeEventsDigested { era, root, events: accumulator.events });
+        }
+
+        /// Pending withdrawals with their tips, highest tip first and oldest first among equal tips
+        pub fn pending_withdrawals_by_tip() -> Vec<(T::Hash, BalanceOf<T>)> {
+            let mut pending: Vec<_> = WithdrawalRequests::<T>::iter()
+                .filter(|(_, request)| request.status == WithdrawalStatus::Pending)
+                .map(|(request_id, request)| (request_id, WithdrawalTips::<T>::get(&request_id), request.submitted_at))
+                .collect();
+            pending.sort_by(|a, b| b.1.cmp(&a.1).then(a.2.cmp(&b.2)));
+            pending.into_iter().map(|(request_id, tip, _)| (request_id, tip)).collect()
+        }
+
+        /// Account holding the priority tips of pending withdrawals
+        pub fn tip_pot_account() -> T::AccountId {
+            T::PalletId::get().into_sub_account_truncating(b"tips")
+        }
+
+        /// Account collecting rounding dust, covering reserve shortfalls
+        pub fn insurance_fund_account() -> T::AccountId {
+            T::PalletId::get().into_sub_account_truncating(b"insurance")
+        }
+
+        /// Account funding the fee grace of newly bridged accounts
+        pub fn fee_pot_account() -> T::AccountId {
+            T::PalletId::get().into_sub_account_truncating(b"fees")
+        }
+
         /// Get net supply (minted - burned)
         pub fn net_supply() -> BalanceOf<T> {
             let minted = Self::total_minted();
@@ -545,38 +2274,57 @@ pub mod pallet {
         }
     }
 
-    pub trait WeightInfo {
-        fn request_deposit() -> Weight;
-        fn approve_deposit() -> Weight;
-        fn reject_deposit() -> Weight;
-        fn request_withdrawal() -> Weight;
-        fn complete_withdrawal() -> Weight;
-        fn reject_withdrawal() -> Weight;
-        fn call_multicoin() -> Weight;
+    /// Fee coin selection for `pallet_multicoin`'s fee adapter, letting bridge
+    /// requests be paid in a multicoin coin, e.g. the wrapped asset being bridged.
+    /// Users arriving without native balance can then still submit requests.
+    pub struct BridgeFeeCoin<T>(PhantomData<T>);
+
+    impl<T: Config> pallet_multicoin::ExternalFeeCoin<<T as frame_system::Config>::RuntimeCall>
+        for BridgeFeeCoin<T>
+    {
+        fn fee_coin(call: &<T as frame_system::Config>::RuntimeCall) -> Option<CoinId> {
+            match Pallet::<T>::as_pallet_call(call)? {
+                Call::request_deposit { tx_fee_coin, .. } |
+                Call::request_split_deposit { tx_fee_coin, .. } |
+                Call::request_deposit_and_call { tx_fee_coin, .. } |
+                Call::request_withdrawal { tx_fee_coin, .. } => tx_fee_coin,
+                _ => None,
+            }
+        }
     }
 
-    impl WeightInfo for () {
-        fn request_deposit() -> Weight {
-            Weight::from_parts(50_000_000, 0)
-        }
-        fn approve_deposit() -> Weight {
-            Weight::from_parts(60_000_000, 0)
-        }
-        fn reject_deposit() -> Weight {
-            Weight::from_parts(30_000_000, 0)
+    /// Wrapper calls of this pallet, for `pallet_multicoin`'s wrappers to refuse nesting them
+    pub struct BridgeWrapperCall<T>(PhantomData<T>);
+
+    impl<T: Config> Contains<<T as frame_system::Config>::RuntimeCall> for BridgeWrapperCall<T> {
+        fn contains(call: &<T as frame_system::Config>::RuntimeCall) -> bool {
+            matches!(Pallet::<T>::as_pallet_call(call), Some(Call::call_multicoin { .. }))
         }
-        fn request_withdrawal() -> Weight {
-            Weight::from_parts(50_000_000, 0)
+    }
+
+    /// Pays the first fees of accounts endowed by a bridge deposit from the fee pot
+    impl<T: Config> pallet_multicoin::FeeSponsor<T::AccountId> for Pallet<T> {
+        fn sponsor_of(who: &T::AccountId) -> Option<T::AccountId> {
+            (FeeGrace::<T>::get(who) > 0).then(Self::fee_pot_account)
         }
-        fn complete_withdrawal() -> Weight {
-            Weight::from_parts(60_000_000, 0)
+
+        fn on_sponsored(who: &T::AccountId) {
+            FeeGrace::<T>::mutate_exists(who, |grace| {
+                *grace = grace.map(|left| left.saturating_sub(1)).filter(|left| *left > 0);
+            });
         }
-        fn reject_withdrawal() -> Weight {
-            Weight::from_parts(30_000_000, 0)
+    }
+
+    /// The bridge of a coin is paused while it's paused for that coin or for all coins
+    impl<T: Config> PausedQuery for Pallet<T> {
+        fn is_globally_paused() -> bool {
+            BridgePaused::<T>::get()
         }
 
-        fn call_multicoin() -> Weight {
-            Weight::from_parts(30_000_000, 0)
+        fn is_coin_paused(coin_name: &[u8]) -> bool {
+            Self::is_globally_paused()
+                || BoundedVec::<u8, T::MaxCoinNameLength>::try_from(coin_name.to_vec())
+                    .map_or(false, |coin_name| PausedCoins::<T>::contains_key(coin_name))
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,237 @@
+use crate as pallet_proof_of_reserve;
+use frame_support::{
+    derive_impl, ord_parameter_types, parameter_types,
+    traits::{ConstU128, ConstU32, ConstU64, Contains},
+    weights::Weight,
+    PalletId,
+};
+use sp_core::{sr25519, H256};
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup},
+    BuildStorage, FixedU128,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+    pub enum Test {
+        System: frame_system,
+        Balances: pallet_balances,
+        ProofOfReserve: pallet_proof_of_reserve,
+    }
+);
+
+/// Call filter blocking `remark_with_event`, to check `call_multicoin` can't get around it
+pub struct TestCallFilter;
+
+impl Contains<RuntimeCall> for TestCallFilter {
+    fn contains(call: &RuntimeCall) -> bool {
+        !matches!(call, RuntimeCall::System(frame_system::Call::remark_with_event { .. }))
+    }
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type BaseCallFilter = TestCallFilter;
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Nonce = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Block = Block;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU64<250>;
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<u128>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type Balance = u128;
+    type RuntimeEvent = RuntimeEvent;
+    type ExistentialDeposit = ConstU128<1>;
+    type AccountStore = System;
+}
+
+ord_parameter_types! {
+    /// Sovereign account of the reserve trusted to notify deposits
+    pub const TrustedReserve: u64 = 9;
+    /// Custodian attesting the external reserves
+    pub const Custodian: u64 = 7;
+}
+
+/// Light client accepting a proof that spells out the coin and balance, as `BTC:500`
+pub struct TestProofVerifier;
+
+impl pallet_proof_of_reserve::ReserveProofVerifier for TestProofVerifier {
+    fn verify(coin_name: &[u8], external_balance: u128, proof: &[u8]) -> bool {
+        proof == [coin_name, b":", external_balance.to_string().as_bytes()].concat()
+    }
+}
+
+/// Litecoin stand-in: addresses start with `ltc1`, and a transaction proof is the
+/// transaction ID followed by its number of confirmations
+pub struct TestLtcAdapter;
+
+impl pallet_proof_of_reserve::ChainAdapter for TestLtcAdapter {
+    fn validate_address(&self, address: &[u8]) -> bool {
+        address.starts_with(b"ltc1")
+    }
+
+    fn verify_tx_proof(&self, tx_id: &[u8], _wallet: &[u8], _amount: u128, proof: &[u8]) -> Option<u32> {
+        proof.strip_prefix(tx_id).and_then(|rest| rest.first()).map(|confirmations| *confirmations as u32)
+    }
+
+    fn confirmation_depth(&self) -> u32 {
+        6
+    }
+}
+
+/// Only `LTC` has a chain adapter
+pub struct TestChainAdapters;
+
+impl pallet_proof_of_reserve::ChainAdapters for TestChainAdapters {
+    fn adapter(coin_name: &[u8]) -> Option<&'static dyn pallet_proof_of_reserve::ChainAdapter> {
+        match coin_name {
+            b"LTC" => Some(&TestLtcAdapter),
+            _ => None,
+        }
+    }
+}
+
+/// Deposits may only be forwarded to native transfers and remarks
+pub struct TestForwardCalls;
+
+impl Contains<RuntimeCall> for TestForwardCalls {
+    fn contains(call: &RuntimeCall) -> bool {
+        matches!(
+            call,
+            RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death { .. }) |
+                RuntimeCall::System(frame_system::Call::remark { .. } | frame_system::Call::remark_with_event { .. })
+        )
+    }
+}
+
+parameter_types! {
+    pub const ProofOfReservePalletId: PalletId = PalletId(*b"py/pfrsr");
+    pub const MaxForwardWeight: Weight = Weight::from_parts(1_000_000_000, 64 * 1024);
+    /// Supply of the wrapped `BTC` coin reported by `TestCoinSupply`
+    pub static WrappedBtcSupply: u128 = 0;
+    /// Multicoin coin `TestCoinSupply` maps `BTC` to
+    pub static WrappedBtcMapping: Option<pallet_multicoin::MappedCoin> = Some(pallet_multicoin::MappedCoin {
+        coin_id: 1,
+        destroying: false,
+        can_mint: true,
+        can_burn: true,
+    });
+}
+
+/// Multicoin stand-in with a wrapped `BTC` coin only
+pub struct TestCoinSupply;
+
+impl pallet_multicoin::SupplyQuery for TestCoinSupply {
+    fn supply_of_symbol(symbol: &[u8]) -> Option<u128> {
+        (symbol == b"BTC").then(WrappedBtcSupply::get)
+    }
+}
+
+impl pallet_multicoin::CoinMapping<u64> for TestCoinSupply {
+    fn mapped_coin(symbol: &[u8], _account: &u64) -> Option<pallet_multicoin::MappedCoin> {
+        (symbol == b"BTC").then(WrappedBtcMapping::get).flatten()
+    }
+}
+
+/// Oracle pricing `ETH` at 10 native units with a 10% deviation bound
+pub struct TestOracle;
+
+impl pallet_multicoin::PriceOracle for TestOracle {
+    fn price_of_symbol(symbol: &[u8]) -> Option<FixedU128> {
+        (symbol == b"ETH").then(|| FixedU128::from_u32(10))
+    }
+
+    fn accepts_price(symbol: &[u8], price: FixedU128) -> bool {
+        Self::price_of_symbol(symbol)
+            .map_or(true, |median| median.into_inner().abs_diff(price.into_inner()) <= median.into_inner() / 10)
+    }
+}
+
+/// Benchmark inputs: `LTC` through the test adapter and light client, with remarks forwarded
+#[cfg(feature = "runtime-benchmarks")]
+pub struct TestBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_proof_of_reserve::BenchmarkHelper<sr25519::Public, sr25519::Signature, RuntimeCall>
+    for TestBenchmarkHelper
+{
+    fn coin() -> (Vec<u8>, Vec<u8>) {
+        (b"LTC".to_vec(), b"ltc1qbenchmark".to_vec())
+    }
+
+    fn sign_approval(index: u32, payload: &[u8]) -> (sr25519::Public, sr25519::Signature) {
+        use sp_core::Pair;
+        let pair = sr25519::Pair::from_seed(&[index as u8 + 1; 32]);
+        (pair.public(), pair.sign(payload))
+    }
+
+    fn reserve_proof(external_balance: u128, _len: u32) -> Option<Vec<u8>> {
+        Some([b"LTC:".as_slice(), external_balance.to_string().as_bytes()].concat())
+    }
+
+    fn tx_proof(tx_id: &[u8], _len: u32) -> Option<Vec<u8>> {
+        // Exactly the confirmation depth
+        Some([tx_id, &[6]].concat())
+    }
+
+    fn forward_call() -> Option<RuntimeCall> {
+        Some(RuntimeCall::System(frame_system::Call::remark { remark: vec![] }))
+    }
+}
+
+impl pallet_proof_of_reserve::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type PalletId = ProofOfReservePalletId;
+    type MaxTxIdLength = ConstU32<128>;
+    type MaxWalletLength = ConstU32<128>;
+    type MaxCoinNameLength = ConstU32<16>;
+    type FeeGraceTransactions = ConstU32<3>;
+    type PriceOracle = TestOracle;
+    type AdminOrigin = frame_system::EnsureRoot<u64>;
+    type XcmDepositOrigin = frame_system::EnsureSignedBy<TrustedReserve, u64>;
+    type ApprovalSignature = sr25519::Signature;
+    type ApprovalSigner = sr25519::Public;
+    type MaxApprovalSigners = ConstU32<3>;
+    type WithdrawalAddressDelay = ConstU64<10>;
+    type MaxWithdrawalAddresses = ConstU32<2>;
+    type ProcessorReward = ConstU128<5>;
+    type MaxDepositRecipients = ConstU32<3>;
+    type CustodianOrigin = frame_system::EnsureSignedBy<Custodian, u64>;
+    type ReserveProofVerifier = TestProofVerifier;
+    type ChainAdapters = TestChainAdapters;
+    type CoinSupply = TestCoinSupply;
+    type ReportEraLength = ConstU64<10>;
+    type MaxReportedCoins = ConstU32<4>;
+    type ForwardCallFilter = TestForwardCalls;
+    type MaxForwardWeight = MaxForwardWeight;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = TestBenchmarkHelper;
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(1, 1000), (2, 1000), (3, 1000), (ProofOfReserve::account_id(), 1_000_000)],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+
+    storage.into()
+}
+
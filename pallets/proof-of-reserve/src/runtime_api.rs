@@ -0,0 +1,35 @@
+//! Runtime API of the proof-of-reserve pallet, serving a public status page
+//! with one call per coin.
+
+use crate::{BridgeStats, CoinMappingIssue, ReserveReport};
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    /// Queries over the bridged coins of the proof-of-reserve pallet
+    pub trait ProofOfReserveApi<BlockNumber, Hash, Balance> where
+        BlockNumber: Codec,
+        Hash: Codec,
+        Balance: Codec,
+    {
+        /// Deposited and withdrawn totals, pending requests, average processing
+        /// time, reserve ratio and latest attestation block of `coin_name`;
+        /// `None` if the coin was never bridged nor attested
+        fn bridge_stats(coin_name: Vec<u8>) -> Option<BridgeStats<BlockNumber>>;
+
+        /// Pending withdrawal requests with their priority tips, in the order
+        /// processors should pay them out: highest tip first, then oldest first
+        fn pending_withdrawals_by_tip() -> Vec<(Hash, Balance)>;
+
+        /// On-chain supply, bridge net mint, attested reserves and consistency
+        /// status of `coin_name` as of now
+        fn reserve_report(coin_name: Vec<u8>) -> Option<ReserveReport<BlockNumber>>;
+
+        /// Reserve report of `coin_name` stored at the start of `era`
+        fn reserve_report_at(era: u32, coin_name: Vec<u8>) -> Option<ReserveReport<BlockNumber>>;
+
+        /// Bridged coins whose names don't map to a live multicoin coin the
+        /// custody account may mint and burn, with the first issue of each
+        fn coin_mapping_issues() -> Vec<(Vec<u8>, CoinMappingIssue)>;
+    }
+}
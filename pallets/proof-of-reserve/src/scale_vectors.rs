@@ -0,0 +1,556 @@
+//! Golden SCALE encodings of every call and event of the pallet.
+//!
+//! SDK authors can check their codecs against these vectors; run
+//! `cargo test -p pallet-proof-of-reserve scale_vectors -- --nocapture` to print
+//! them. Calls and events are encoded at pallet level, without the runtime's
+//! pallet index, except for the inner call of `call_multicoin`, which is a full
+//! mock `RuntimeCall` (`System` is pallet 0). Account IDs are `u64`, hashes
+//! `H256` and balances `u128` in the mock runtime.
+
+use crate::{mock::*, AttestationKind, Call, DustHandling, Event, Rounding, RoundingPolicy};
+use codec::Encode;
+use scale_info::{TypeDef, TypeInfo};
+use sp_core::{sr25519, H256};
+use sp_runtime::BoundedVec;
+
+/// A variant name, its encoding and the expected encoding in hex
+type Vector = (&'static str, Vec<u8>, &'static str);
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Names of the variants `E` declares in metadata
+fn variant_names<E: TypeInfo>() -> Vec<&'static str> {
+    match E::type_info().type_def {
+        TypeDef::Variant(def) => def.variants.iter().map(|v| v.name).collect(),
+        _ => panic!("expected an enum"),
+    }
+}
+
+/// Print every vector, then check that all declared variants are covered and
+/// that no encoding changed
+fn check_vectors(kind: &str, vectors: Vec<Vector>, declared: Vec<&'static str>) {
+    let mut mismatches = Vec::new();
+    for (name, encoded, expected) in &vectors {
+        let actual = hex(encoded);
+        println!("{kind} {name} 0x{actual}");
+        if actual != *expected {
+            mismatches.push(format!("{name}: expected 0x{expected}, got 0x{actual}"));
+        }
+    }
+
+    let covered: Vec<&str> = vectors.iter().map(|(name, ..)| *name).collect();
+    let missing: Vec<&str> = declared.into_iter().filter(|name| !covered.contains(name)).collect();
+    assert!(missing.is_empty(), "{kind} variants without a golden vector: {missing:?}");
+    assert!(mismatches.is_empty(), "{kind} encodings changed:\n{}", mismatches.join("\n"));
+}
+
+#[test]
+fn call_encodings_match_golden_vectors() {
+    let vectors: Vec<Vector> = vec![
+        (
+            "request_deposit",
+            Call::<Test>::request_deposit {
+                onchain_account: 2,
+                external_tx_id: b"btc-tx".to_vec(),
+                external_wallet: b"bc1qwallet".to_vec(),
+                coin_name: b"BTC".to_vec(),
+                external_amount: 100,
+                ratio: 1_000,
+                tx_fee_coin: Some(1),
+            }
+            .encode(),
+            "000200000000000000186274632d7478286263317177616c6c65740c42544364000000000000000000000000000000e80300000000000000000000000000000101000000",
+        ),
+        (
+            "approve_deposit",
+            Call::<Test>::approve_deposit { request_id: H256::repeat_byte(0xab) }.encode(),
+            "01abababababababababababababababababababababababababababababababab",
+        ),
+        (
+            "reject_deposit",
+            Call::<Test>::reject_deposit { request_id: H256::repeat_byte(0xab) }.encode(),
+            "02abababababababababababababababababababababababababababababababab",
+        ),
+        (
+            "request_withdrawal",
+            Call::<Test>::request_withdrawal {
+                external_wallet: b"bc1qwallet".to_vec(),
+                coin_name: b"BTC".to_vec(),
+                native_amount: 100_000,
+                ratio: 1_000,
+                tx_fee_coin: None,
+            }
+            .encode(),
+            "03286263317177616c6c65740c425443a0860100000000000000000000000000e803000000000000000000000000000000",
+        ),
+        (
+            "complete_withdrawal",
+            Call::<Test>::complete_withdrawal { request_id: H256::repeat_byte(0xab) }.encode(),
+            "04abababababababababababababababababababababababababababababababab",
+        ),
+        (
+            "reject_withdrawal",
+            Call::<Test>::reject_withdrawal { request_id: H256::repeat_byte(0xab) }.encode(),
+            "05abababababababababababababababababababababababababababababababab",
+        ),
+        (
+            "call_multicoin",
+            Call::<Test>::call_multicoin {
+                call: Box::new(RuntimeCall::System(frame_system::Call::remark { remark: vec![] })),
+            }
+            .encode(),
+            "06000000",
+        ),
+        (
+            "register_withdrawal_address",
+            Call::<Test>::register_withdrawal_address {
+                external_wallet: b"bc1qwallet".to_vec(),
+            }
+            .encode(),
+            "07286263317177616c6c6574",
+        ),
+        (
+            "remove_withdrawal_address",
+            Call::<Test>::remove_withdrawal_address {
+                external_wallet: b"bc1qwallet".to_vec(),
+            }
+            .encode(),
+            "08286263317177616c6c6574",
+        ),
+        (
+            "set_address_book_required",
+            Call::<Test>::set_address_book_required {
+                coin_name: b"BTC".to_vec(),
+                required: true,
+            }
+            .encode(),
+            "090c42544301",
+        ),
+        (
+            "xcm_deposit",
+            Call::<Test>::xcm_deposit {
+                recipient: 5,
+                external_tx_id: b"para-tx".to_vec(),
+                coin_name: b"DOT".to_vec(),
+                external_amount: 100,
+                ratio: 10,
+            }
+            .encode(),
+            "0a05000000000000001c706172612d74780c444f54640000000000000000000000000000000a000000000000000000000000000000",
+        ),
+        (
+            "set_approval_signers",
+            Call::<Test>::set_approval_signers {
+                signers: vec![sr25519::Public::from_raw([1; 32])],
+                threshold: 1,
+            }
+            .encode(),
+            "0b04010101010101010101010101010101010101010101010101010101010101010101000000",
+        ),
+        (
+            "approve_deposit_signed",
+            Call::<Test>::approve_deposit_signed {
+                request_id: H256::repeat_byte(0xab),
+                signatures: vec![(0, sr25519::Signature::from_raw([2; 64]))],
+            }
+            .encode(),
+            "0cabababababababababababababababababababababababababababababababab040000000002020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202020202",
+        ),
+        (
+            "set_processor",
+            Call::<Test>::set_processor { who: 3, registered: true }.encode(),
+            "0d030000000000000001",
+        ),
+        (
+            "claim_processor_reward",
+            Call::<Test>::claim_processor_reward {}.encode(),
+            "0e",
+        ),
+        (
+            "attest_reserves",
+            Call::<Test>::attest_reserves {
+                coin_name: b"BTC".to_vec(),
+                external_balance: 500,
+                proof: Some(b"BTC:500".to_vec()),
+            }
+            .encode(),
+            "0f0c425443f4010000000000000000000000000000011c4254433a353030",
+        ),
+        (
+            "set_rounding_policy",
+            Call::<Test>::set_rounding_policy {
+                coin_name: b"BTC".to_vec(),
+                policy: RoundingPolicy { rounding: Rounding::FavorUser, dust: DustHandling::InsuranceFund },
+            }
+            .encode(),
+            "100c4254430101",
+        ),
+        (
+            "tip_withdrawal",
+            Call::<Test>::tip_withdrawal { request_id: H256::repeat_byte(0xab), tip: 5 }.encode(),
+            "11abababababababababababababababababababababababababababababababab05000000000000000000000000000000",
+        ),
+        (
+            "request_split_deposit",
+            Call::<Test>::request_split_deposit {
+                recipients: BoundedVec::truncate_from(vec![(5, 1), (6, 2)]),
+                external_tx_id: b"sweep-tx".to_vec(),
+                external_wallet: b"bc1q".to_vec(),
+                coin_name: b"BTC".to_vec(),
+                external_amount: 100,
+                ratio: 10,
+                tx_fee_coin: None,
+            }
+            .encode(),
+            "12080500000000000000010000000600000000000000020000002073776565702d747810626331710c425443640000000000000000000000000000000a00000000000000000000000000000000",
+        ),
+        (
+            "set_bridge_pause",
+            Call::<Test>::set_bridge_pause {
+                coin_name: Some(b"BTC".to_vec()),
+                paused: true,
+            }
+            .encode(),
+            "13010c42544301",
+        ),
+        (
+            "set_ratio_bounds",
+            Call::<Test>::set_ratio_bounds {
+                coin_name: b"BTC".to_vec(),
+                bounds: Some((5, 20)),
+            }
+            .encode(),
+            "140c425443010500000000000000000000000000000014000000000000000000000000000000",
+        ),
+        (
+            "bind_wallet",
+            Call::<Test>::bind_wallet {
+                external_wallet: b"bc1qwallet".to_vec(),
+                proof: Some(b"sig".to_vec()),
+            }
+            .encode(),
+            "15286263317177616c6c6574010c736967",
+        ),
+        (
+            "unbind_wallet",
+            Call::<Test>::unbind_wallet { external_wallet: b"bc1qwallet".to_vec() }.encode(),
+            "16286263317177616c6c6574",
+        ),
+        (
+            "prove_deposit",
+            Call::<Test>::prove_deposit {
+                request_id: H256::repeat_byte(1),
+                proof: b"proof".to_vec(),
+            }
+            .encode(),
+            "1701010101010101010101010101010101010101010101010101010101010101011470726f6f66",
+        ),
+        (
+            "request_deposit_and_call",
+            Call::<Test>::request_deposit_and_call {
+                external_tx_id: b"btc-tx".to_vec(),
+                external_wallet: b"bc1qwallet".to_vec(),
+                coin_name: b"BTC".to_vec(),
+                external_amount: 100,
+                ratio: 10,
+                call: Box::new(RuntimeCall::System(frame_system::Call::remark { remark: vec![] })),
+                tx_fee_coin: None,
+            }
+            .encode(),
+            "18186274632d7478286263317177616c6c65740c425443640000000000000000000000000000000a00000000000000000000000000000000000000",
+        ),
+    ];
+
+    check_vectors("call", vectors, variant_names::<Call<Test>>());
+}
+
+#[test]
+fn event_encodings_match_golden_vectors() {
+    let vectors: Vec<Vector> = vec![
+        (
+            "DepositRequested",
+            Event::<Test>::DepositRequested {
+                request_id: H256::repeat_byte(0xab),
+                submitter: 1,
+                recipient: 2,
+                external_tx_id: BoundedVec::truncate_from(b"btc-tx".to_vec()),
+                external_wallet: BoundedVec::truncate_from(b"bc1qwallet".to_vec()),
+                coin_name: BoundedVec::truncate_from(b"BTC".to_vec()),
+                external_amount: 100,
+                native_amount: 100_000,
+            }
+            .encode(),
+            "00abababababababababababababababababababababababababababababababab01000000000000000200000000000000186274632d7478286263317177616c6c65740c42544364000000000000000000000000000000a0860100000000000000000000000000",
+        ),
+        (
+            "DepositApproved",
+            Event::<Test>::DepositApproved {
+                request_id: H256::repeat_byte(0xab),
+                user: 2,
+                validator: 3,
+                native_amount: 100_000,
+            }
+            .encode(),
+            "01abababababababababababababababababababababababababababababababab02000000000000000300000000000000a0860100000000000000000000000000",
+        ),
+        (
+            "DepositRejected",
+            Event::<Test>::DepositRejected {
+                request_id: H256::repeat_byte(0xab),
+                validator: 3,
+            }
+            .encode(),
+            "02abababababababababababababababababababababababababababababababab0300000000000000",
+        ),
+        (
+            "WithdrawalRequested",
+            Event::<Test>::WithdrawalRequested {
+                request_id: H256::repeat_byte(0xab),
+                user: 2,
+                external_wallet: BoundedVec::truncate_from(b"bc1qwallet".to_vec()),
+                native_amount: 100_000,
+                external_amount: 100,
+            }
+            .encode(),
+            "03abababababababababababababababababababababababababababababababab0200000000000000286263317177616c6c6574a086010000000000000000000000000064000000000000000000000000000000",
+        ),
+        (
+            "WithdrawalCompleted",
+            Event::<Test>::WithdrawalCompleted {
+                request_id: H256::repeat_byte(0xab),
+                user: 2,
+                processor: 3,
+                native_amount: 100_000,
+            }
+            .encode(),
+            "04abababababababababababababababababababababababababababababababab02000000000000000300000000000000a0860100000000000000000000000000",
+        ),
+        (
+            "WithdrawalRejected",
+            Event::<Test>::WithdrawalRejected {
+                request_id: H256::repeat_byte(0xab),
+                processor: 3,
+            }
+            .encode(),
+            "05abababababababababababababababababababababababababababababababab0300000000000000",
+        ),
+        (
+            "FeeGraceGranted",
+            Event::<Test>::FeeGraceGranted { who: 5, transactions: 3 }.encode(),
+            "06050000000000000003000000",
+        ),
+        (
+            "WithdrawalAddressRegistered",
+            Event::<Test>::WithdrawalAddressRegistered {
+                who: 1,
+                external_wallet: BoundedVec::truncate_from(b"bc1qwallet".to_vec()),
+                usable_from: 11,
+            }
+            .encode(),
+            "070100000000000000286263317177616c6c65740b00000000000000",
+        ),
+        (
+            "WithdrawalAddressRemoved",
+            Event::<Test>::WithdrawalAddressRemoved {
+                who: 1,
+                external_wallet: BoundedVec::truncate_from(b"bc1qwallet".to_vec()),
+            }
+            .encode(),
+            "080100000000000000286263317177616c6c6574",
+        ),
+        (
+            "AddressBookRequirementSet",
+            Event::<Test>::AddressBookRequirementSet {
+                coin_name: BoundedVec::truncate_from(b"BTC".to_vec()),
+                required: true,
+            }
+            .encode(),
+            "090c42544301",
+        ),
+        (
+            "XcmDepositReceived",
+            Event::<Test>::XcmDepositReceived {
+                request_id: H256::repeat_byte(0xab),
+                reserve: 9,
+                recipient: 5,
+                external_tx_id: BoundedVec::truncate_from(b"para-tx".to_vec()),
+                coin_name: BoundedVec::truncate_from(b"DOT".to_vec()),
+                external_amount: 100,
+                native_amount: 1_000,
+            }
+            .encode(),
+            "0aabababababababababababababababababababababababababababababababab090000000000000005000000000000001c706172612d74780c444f5464000000000000000000000000000000e8030000000000000000000000000000",
+        ),
+        (
+            "ApprovalSignersSet",
+            Event::<Test>::ApprovalSignersSet {
+                signers: BoundedVec::truncate_from(vec![sr25519::Public::from_raw([1; 32])]),
+                threshold: 1,
+            }
+            .encode(),
+            "0b04010101010101010101010101010101010101010101010101010101010101010101000000",
+        ),
+        (
+            "ProcessorSet",
+            Event::<Test>::ProcessorSet { who: 3, registered: true }.encode(),
+            "0c030000000000000001",
+        ),
+        (
+            "ProcessorRewardAccrued",
+            Event::<Test>::ProcessorRewardAccrued {
+                processor: 3,
+                request_id: H256::repeat_byte(0xab),
+                amount: 5,
+            }
+            .encode(),
+            "0d0300000000000000abababababababababababababababababababababababababababababababab05000000000000000000000000000000",
+        ),
+        (
+            "ProcessorRewardClaimed",
+            Event::<Test>::ProcessorRewardClaimed { processor: 3, amount: 10 }.encode(),
+            "0e03000000000000000a000000000000000000000000000000",
+        ),
+        (
+            "ReservesAttested",
+            Event::<Test>::ReservesAttested {
+                coin_name: BoundedVec::truncate_from(b"BTC".to_vec()),
+                custodian: 7,
+                external_balance: 500,
+                kind: AttestationKind::Verified,
+            }
+            .encode(),
+            "0f0c4254430700000000000000f401000000000000000000000000000000",
+        ),
+        (
+            "RoundingPolicySet",
+            Event::<Test>::RoundingPolicySet {
+                coin_name: BoundedVec::truncate_from(b"BTC".to_vec()),
+                policy: RoundingPolicy { rounding: Rounding::FavorUser, dust: DustHandling::InsuranceFund },
+            }
+            .encode(),
+            "100c4254430101",
+        ),
+        (
+            "WithdrawalDustRouted",
+            Event::<Test>::WithdrawalDustRouted {
+                request_id: H256::repeat_byte(0xab),
+                amount: 5,
+            }
+            .encode(),
+            "11abababababababababababababababababababababababababababababababab05000000000000000000000000000000",
+        ),
+        (
+            "WithdrawalTipped",
+            Event::<Test>::WithdrawalTipped {
+                request_id: H256::repeat_byte(0xab),
+                tip: 5,
+            }
+            .encode(),
+            "12abababababababababababababababababababababababababababababababab05000000000000000000000000000000",
+        ),
+        (
+            "WithdrawalTipPaid",
+            Event::<Test>::WithdrawalTipPaid {
+                request_id: H256::repeat_byte(0xab),
+                processor: 4,
+                tip: 5,
+            }
+            .encode(),
+            "13abababababababababababababababababababababababababababababababab040000000000000005000000000000000000000000000000",
+        ),
+        (
+            "DepositSplitRequested",
+            Event::<Test>::DepositSplitRequested {
+                request_id: H256::repeat_byte(0xab),
+                recipients: BoundedVec::truncate_from(vec![(5, 1), (6, 2)]),
+            }
+            .encode(),
+            "14abababababababababababababababababababababababababababababababab08050000000000000001000000060000000000000002000000",
+        ),
+        (
+            "BridgePauseSet",
+            Event::<Test>::BridgePauseSet {
+                coin_name: Some(BoundedVec::truncate_from(b"BTC".to_vec())),
+                paused: true,
+            }
+            .encode(),
+            "15010c42544301",
+        ),
+        (
+            "ReserveReportsRecorded",
+            Event::<Test>::ReserveReportsRecorded { era: 3, coins: 2 }.encode(),
+            "160300000002000000",
+        ),
+        (
+            "RatioBoundsSet",
+            Event::<Test>::RatioBoundsSet {
+                coin_name: BoundedVec::truncate_from(b"BTC".to_vec()),
+                bounds: Some((5, 20)),
+            }
+            .encode(),
+            "170c425443010500000000000000000000000000000014000000000000000000000000000000",
+        ),
+        (
+            "WalletBound",
+            Event::<Test>::WalletBound {
+                who: 1,
+                external_wallet: BoundedVec::truncate_from(b"bc1qwallet".to_vec()),
+                proof_hash: None,
+            }
+            .encode(),
+            "180100000000000000286263317177616c6c657400",
+        ),
+        (
+            "WalletUnbound",
+            Event::<Test>::WalletUnbound {
+                who: 1,
+                external_wallet: BoundedVec::truncate_from(b"bc1qwallet".to_vec()),
+            }
+            .encode(),
+            "190100000000000000286263317177616c6c6574",
+        ),
+        (
+            "DepositWalletMismatch",
+            Event::<Test>::DepositWalletMismatch {
+                request_id: H256::repeat_byte(1),
+                wallet_owner: 2,
+                recipient: 1,
+            }
+            .encode(),
+            "1a010101010101010101010101010101010101010101010101010101010101010102000000000000000100000000000000",
+        ),
+        (
+            "BridgeEventsDigested",
+            Event::<Test>::BridgeEventsDigested {
+                era: 3,
+                root: H256::repeat_byte(1),
+                events: 5,
+            }
+            .encode(),
+            "1b03000000010101010101010101010101010101010101010101010101010101010101010105000000",
+        ),
+        (
+            "DepositProven",
+            Event::<Test>::DepositProven {
+                request_id: H256::repeat_byte(1),
+                prover: 2,
+                confirmations: 6,
+            }
+            .encode(),
+            "1c0101010101010101010101010101010101010101010101010101010101010101020000000000000006000000",
+        ),
+        (
+            "DepositForwarded",
+            Event::<Test>::DepositForwarded {
+                request_id: H256::repeat_byte(1),
+                result: Ok(()),
+            }
+            .encode(),
+            "1d010101010101010101010101010101010101010101010101010101010101010100",
+        ),
+    ];
+
+    check_vectors("event", vectors, variant_names::<Event<Test>>());
+}
@@ -0,0 +1,933 @@
+use crate::{
+    mock::*, AttestationKind, BridgeFeeCoin, Call, CoinMappingIssue, DepositRequests, DepositStatus, DustHandling, Error,
+    Event, EventDigest, ReserveStatus, Rounding, RoundingPolicy, UserDeposits, WithdrawalRequests,
+    WithdrawalStatus,
+};
+use codec::{Decode, Encode};
+use frame_support::{assert_noop, assert_ok, traits::{Currency, Hooks}, BoundedVec};
+use pallet_multicoin::{ExternalFeeCoin, FeeSponsor, PausedQuery};
+use sp_core::{sr25519, Pair, H256};
+use sp_runtime::FixedU128;
+
+/// Submit a deposit of 100 external units at ratio 10 for `recipient` and approve it
+fn bridge_deposit(recipient: u64) {
+    assert_ok!(ProofOfReserve::request_deposit(
+        RuntimeOrigin::signed(1),
+        recipient,
+        b"btc-tx".to_vec(),
+        b"bc1qwallet".to_vec(),
+        b"BTC".to_vec(),
+        100,
+        10,
+        None,
+    ));
+    let (request_id, _) = DepositRequests::<Test>::iter().next().unwrap();
+    assert_ok!(ProofOfReserve::approve_deposit(RuntimeOrigin::signed(2), request_id));
+    DepositRequests::<Test>::remove(request_id);
+}
+
+#[test]
+fn bridge_requests_select_their_fee_coin() {
+    let deposit = |tx_fee_coin| {
+        RuntimeCall::ProofOfReserve(Call::request_deposit {
+            onchain_account: 2,
+            external_tx_id: b"btc-tx".to_vec(),
+            external_wallet: b"bc1qwallet".to_vec(),
+            coin_name: b"BTC".to_vec(),
+            external_amount: 100,
+            ratio: 1_000,
+            tx_fee_coin,
+        })
+    };
+    assert_eq!(BridgeFeeCoin::<Test>::fee_coin(&deposit(Some(3))), Some(3));
+    assert_eq!(BridgeFeeCoin::<Test>::fee_coin(&deposit(None)), None);
+
+    let withdrawal = RuntimeCall::ProofOfReserve(Call::request_withdrawal {
+        external_wallet: b"bc1qwallet".to_vec(),
+        coin_name: b"BTC".to_vec(),
+        native_amount: 100_000,
+        ratio: 1_000,
+        tx_fee_coin: Some(3),
+    });
+    assert_eq!(BridgeFeeCoin::<Test>::fee_coin(&withdrawal), Some(3));
+}
+
+#[test]
+fn first_bridge_deposit_grants_fee_grace() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        bridge_deposit(5);
+        System::assert_last_event(Event::FeeGraceGranted { who: 5, transactions: 3 }.into());
+        assert_eq!(ProofOfReserve::fee_grace(5), 3);
+
+        for _ in 0..3 {
+            assert_eq!(ProofOfReserve::sponsor_of(&5), Some(ProofOfReserve::fee_pot_account()));
+            ProofOfReserve::on_sponsored(&5);
+        }
+        assert_eq!(ProofOfReserve::sponsor_of(&5), None);
+        assert!(!crate::FeeGrace::<Test>::contains_key(5));
+
+        // A second deposit to an endowed account grants nothing
+        bridge_deposit(5);
+        assert_eq!(ProofOfReserve::fee_grace(5), 0);
+    });
+}
+
+#[test]
+fn deposits_to_endowed_accounts_get_no_fee_grace() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        bridge_deposit(3);
+        assert_eq!(ProofOfReserve::fee_grace(3), 0);
+        assert_eq!(ProofOfReserve::sponsor_of(&3), None);
+    });
+}
+
+#[test]
+fn ratios_must_agree_with_oracle_price() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let deposit = |ratio| {
+            ProofOfReserve::request_deposit(
+                RuntimeOrigin::signed(1),
+                1,
+                b"eth-tx".to_vec(),
+                b"0xwallet".to_vec(),
+                b"ETH".to_vec(),
+                100,
+                ratio,
+                None,
+            )
+        };
+        assert_noop!(deposit(20), Error::<Test>::RatioDeviatesFromOracle);
+        assert_ok!(deposit(11));
+
+        assert_noop!(
+            ProofOfReserve::request_withdrawal(RuntimeOrigin::signed(1), b"0xwallet".to_vec(), b"ETH".to_vec(), 100, 5, None),
+            Error::<Test>::RatioDeviatesFromOracle
+        );
+    });
+}
+
+#[test]
+fn other_calls_select_no_fee_coin() {
+    let approve = RuntimeCall::ProofOfReserve(Call::approve_deposit { request_id: H256::zero() });
+    assert_eq!(BridgeFeeCoin::<Test>::fee_coin(&approve), None);
+
+    let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+    assert_eq!(BridgeFeeCoin::<Test>::fee_coin(&remark), None);
+}
+
+#[test]
+fn coins_requiring_an_address_book_withdraw_to_confirmed_addresses_only() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let withdraw = |wallet: &[u8]| {
+            ProofOfReserve::request_withdrawal(RuntimeOrigin::signed(1), wallet.to_vec(), b"BTC".to_vec(), 100, 10, None)
+        };
+        assert_noop!(
+            ProofOfReserve::set_address_book_required(RuntimeOrigin::signed(1), b"BTC".to_vec(), true),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(ProofOfReserve::set_address_book_required(RuntimeOrigin::root(), b"BTC".to_vec(), true));
+
+        assert_noop!(withdraw(b"bc1qwallet"), Error::<Test>::AddressNotRegistered);
+        assert_ok!(ProofOfReserve::register_withdrawal_address(RuntimeOrigin::signed(1), b"bc1qwallet".to_vec()));
+        System::assert_last_event(
+            Event::WithdrawalAddressRegistered {
+                who: 1,
+                external_wallet: BoundedVec::truncate_from(b"bc1qwallet".to_vec()),
+                usable_from: 11,
+            }
+            .into(),
+        );
+        assert_noop!(
+            ProofOfReserve::register_withdrawal_address(RuntimeOrigin::signed(1), b"bc1qwallet".to_vec()),
+            Error::<Test>::AddressAlreadyRegistered
+        );
+        assert_noop!(withdraw(b"bc1qwallet"), Error::<Test>::AddressNotConfirmed);
+
+        System::set_block_number(11);
+        assert_ok!(withdraw(b"bc1qwallet"));
+
+        // Other coins still withdraw anywhere
+        assert_ok!(ProofOfReserve::request_withdrawal(
+            RuntimeOrigin::signed(1),
+            b"0xother".to_vec(),
+            b"DOT".to_vec(),
+            100,
+            10,
+            None,
+        ));
+
+        assert_ok!(ProofOfReserve::remove_withdrawal_address(RuntimeOrigin::signed(1), b"bc1qwallet".to_vec()));
+        assert!(!crate::WithdrawalAddresses::<Test>::contains_key(1));
+        assert_noop!(withdraw(b"bc1qwallet"), Error::<Test>::AddressNotRegistered);
+    });
+}
+
+#[test]
+fn withdrawal_addresses_are_bounded() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ProofOfReserve::register_withdrawal_address(RuntimeOrigin::signed(1), b"a".to_vec()));
+        assert_ok!(ProofOfReserve::register_withdrawal_address(RuntimeOrigin::signed(1), b"b".to_vec()));
+        assert_noop!(
+            ProofOfReserve::register_withdrawal_address(RuntimeOrigin::signed(1), b"c".to_vec()),
+            Error::<Test>::TooManyWithdrawalAddresses
+        );
+        assert_noop!(
+            ProofOfReserve::remove_withdrawal_address(RuntimeOrigin::signed(2), b"a".to_vec()),
+            Error::<Test>::AddressNotRegistered
+        );
+    });
+}
+
+#[test]
+fn trusted_reserve_deposits_are_approved_once() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let notify = |origin| {
+            ProofOfReserve::xcm_deposit(origin, 5, b"para-tx".to_vec(), b"DOT".to_vec(), 100, 10)
+        };
+        assert_noop!(notify(RuntimeOrigin::signed(1)), sp_runtime::DispatchError::BadOrigin);
+
+        assert_ok!(notify(RuntimeOrigin::signed(9)));
+        assert_eq!(Balances::free_balance(5), 1_000);
+        assert_eq!(ProofOfReserve::total_minted(), 1_000);
+        let (request_id, request) = DepositRequests::<Test>::iter().next().unwrap();
+        assert_eq!(request.status, DepositStatus::Approved);
+        assert_eq!(request.approved_by, Some(9));
+        System::assert_has_event(
+            Event::DepositApproved { request_id, user: 5, validator: 9, native_amount: 1_000 }.into(),
+        );
+
+        assert_noop!(notify(RuntimeOrigin::signed(9)), Error::<Test>::DepositAlreadyNotified);
+    });
+}
+
+#[test]
+fn signer_set_approves_deposits_by_threshold() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let keys: Vec<sr25519::Pair> = (1..=3).map(|i| sr25519::Pair::from_seed(&[i; 32])).collect();
+        let signers: Vec<sr25519::Public> = keys.iter().map(|key| key.public()).collect();
+        assert_noop!(
+            ProofOfReserve::set_approval_signers(RuntimeOrigin::root(), signers.clone(), 4),
+            Error::<Test>::InvalidApprovalThreshold
+        );
+        assert_ok!(ProofOfReserve::set_approval_signers(RuntimeOrigin::root(), signers, 2));
+
+        assert_ok!(ProofOfReserve::request_deposit(
+            RuntimeOrigin::signed(1),
+            5,
+            b"btc-tx".to_vec(),
+            b"bc1qwallet".to_vec(),
+            b"BTC".to_vec(),
+            100,
+            10,
+            None,
+        ));
+        let (request_id, _) = DepositRequests::<Test>::iter().next().unwrap();
+        let payload = ProofOfReserve::approval_payload(&request_id);
+        let sign = |i: u32| (i, keys[i as usize].sign(&payload));
+
+        assert_noop!(
+            ProofOfReserve::approve_deposit_signed(RuntimeOrigin::signed(1), request_id, vec![sign(2)]),
+            Error::<Test>::NotEnoughApprovalSignatures
+        );
+        assert_noop!(
+            ProofOfReserve::approve_deposit_signed(RuntimeOrigin::signed(1), request_id, vec![sign(2), sign(2)]),
+            Error::<Test>::InvalidApprovalSignature
+        );
+        assert_noop!(
+            ProofOfReserve::approve_deposit_signed(
+                RuntimeOrigin::signed(1),
+                request_id,
+                vec![sign(0), (1, keys[1].sign(b"other request"))],
+            ),
+            Error::<Test>::InvalidApprovalSignature
+        );
+
+        assert_ok!(ProofOfReserve::approve_deposit_signed(
+            RuntimeOrigin::signed(1),
+            request_id,
+            vec![sign(0), sign(2)],
+        ));
+        assert_eq!(Balances::free_balance(5), 1_000);
+        assert_eq!(DepositRequests::<Test>::get(request_id).unwrap().status, DepositStatus::Approved);
+    });
+}
+
+#[test]
+fn registered_processors_claim_withdrawal_rewards() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        // Sub-accounts of the pallet share one `u64` account in the mock, so
+        // balances are checked relative to where they started
+        let _ = Balances::deposit_creating(&ProofOfReserve::fee_pot_account(), 100);
+        let pot_funds = Balances::free_balance(ProofOfReserve::fee_pot_account());
+
+        let complete = |processor| {
+            assert_ok!(ProofOfReserve::request_withdrawal(
+                RuntimeOrigin::signed(1),
+                b"bc1qwallet".to_vec(),
+                b"BTC".to_vec(),
+                100,
+                10,
+                None,
+            ));
+            let (request_id, _) = WithdrawalRequests::<Test>::iter()
+                .find(|(_, request)| request.status == WithdrawalStatus::Pending)
+                .unwrap();
+            assert_ok!(ProofOfReserve::complete_withdrawal(RuntimeOrigin::signed(processor), request_id));
+        };
+
+        // Unregistered processors earn nothing
+        complete(3);
+        assert_eq!(ProofOfReserve::processor_rewards(3), 0);
+        assert_noop!(ProofOfReserve::claim_processor_reward(RuntimeOrigin::signed(3)), Error::<Test>::NoRewardToClaim);
+
+        assert_ok!(ProofOfReserve::set_processor(RuntimeOrigin::root(), 3, true));
+        complete(3);
+        complete(3);
+        assert_eq!(ProofOfReserve::processor_rewards(3), 10);
+
+        assert_ok!(ProofOfReserve::claim_processor_reward(RuntimeOrigin::signed(3)));
+        assert_eq!(Balances::free_balance(3), 1_010);
+        assert_eq!(Balances::free_balance(ProofOfReserve::fee_pot_account()), pot_funds - 10);
+        assert_eq!(ProofOfReserve::processor_rewards(3), 0);
+        System::assert_last_event(Event::ProcessorRewardClaimed { processor: 3, amount: 10 }.into());
+    });
+}
+
+#[test]
+fn reserve_attestations_record_whether_they_were_proven() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let attest = |proof: Option<&[u8]>| {
+            ProofOfReserve::attest_reserves(RuntimeOrigin::signed(7), b"BTC".to_vec(), 500, proof.map(|p| p.to_vec()))
+        };
+        assert_noop!(
+            ProofOfReserve::attest_reserves(RuntimeOrigin::signed(1), b"BTC".to_vec(), 500, None),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(attest(Some(b"BTC:600")), Error::<Test>::InvalidReserveProof);
+
+        assert_ok!(attest(None));
+        let attestation = ProofOfReserve::reserve_attestation(BoundedVec::truncate_from(b"BTC".to_vec())).unwrap();
+        assert_eq!(attestation.kind, AttestationKind::SelfReported);
+
+        assert_ok!(attest(Some(b"BTC:500")));
+        let attestation = ProofOfReserve::reserve_attestation(BoundedVec::truncate_from(b"BTC".to_vec())).unwrap();
+        assert_eq!((attestation.custodian, attestation.external_balance), (7, 500));
+        assert_eq!(attestation.kind, AttestationKind::Verified);
+    });
+}
+
+#[test]
+fn bridge_stats_summarize_a_coin() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_eq!(ProofOfReserve::bridge_stats(b"BTC"), None);
+
+        bridge_deposit(5);
+        assert_ok!(ProofOfReserve::request_withdrawal(
+            RuntimeOrigin::signed(1),
+            b"bc1qwallet".to_vec(),
+            b"BTC".to_vec(),
+            200,
+            10,
+            None,
+        ));
+        let (request_id, _) = WithdrawalRequests::<Test>::iter().next().unwrap();
+        System::set_block_number(5);
+        assert_ok!(ProofOfReserve::complete_withdrawal(RuntimeOrigin::signed(3), request_id));
+        assert_ok!(ProofOfReserve::request_withdrawal(
+            RuntimeOrigin::signed(1),
+            b"bc1qwallet".to_vec(),
+            b"BTC".to_vec(),
+            100,
+            10,
+            None,
+        ));
+        assert_ok!(ProofOfReserve::attest_reserves(RuntimeOrigin::signed(7), b"BTC".to_vec(), 160, None));
+
+        let stats = ProofOfReserve::bridge_stats(b"BTC").unwrap();
+        assert_eq!((stats.total_deposited, stats.total_withdrawn), (100, 20));
+        assert_eq!((stats.pending_deposits, stats.pending_withdrawals), (0, 1));
+        // The deposit was approved right away, the withdrawal after 4 blocks
+        assert_eq!(stats.average_processing_blocks, 2);
+        assert_eq!(stats.reserve_ratio, Some(FixedU128::from_u32(2)));
+        assert_eq!(stats.last_attestation, Some(5));
+    });
+}
+
+#[test]
+fn rounding_policy_decides_withdrawal_remainders() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let withdraw = |native_amount| {
+            assert_ok!(ProofOfReserve::request_withdrawal(
+                RuntimeOrigin::signed(1),
+                b"bc1qwallet".to_vec(),
+                b"BTC".to_vec(),
+                native_amount,
+                10,
+                None,
+            ));
+            WithdrawalRequests::<Test>::iter()
+                .find(|(_, request)| request.status == WithdrawalStatus::Pending)
+                .unwrap()
+        };
+
+        // By default the remainder is truncated and burned
+        let (request_id, request) = withdraw(105);
+        assert_eq!(request.external_amount, 10);
+        assert_ok!(ProofOfReserve::complete_withdrawal(RuntimeOrigin::signed(3), request_id));
+        assert_eq!(ProofOfReserve::total_burned(), 105);
+
+        let policy = RoundingPolicy { rounding: Rounding::FavorUser, dust: DustHandling::Burn };
+        assert_ok!(ProofOfReserve::set_rounding_policy(RuntimeOrigin::root(), b"BTC".to_vec(), policy));
+        let (request_id, request) = withdraw(105);
+        assert_eq!(request.external_amount, 11);
+        assert_ok!(ProofOfReserve::complete_withdrawal(RuntimeOrigin::signed(3), request_id));
+
+        let policy = RoundingPolicy { rounding: Rounding::FavorReserve, dust: DustHandling::InsuranceFund };
+        assert_ok!(ProofOfReserve::set_rounding_policy(RuntimeOrigin::root(), b"BTC".to_vec(), policy));
+        let fund = Balances::free_balance(ProofOfReserve::insurance_fund_account());
+        let (request_id, request) = withdraw(105);
+        assert_eq!(request.external_amount, 10);
+        assert_ok!(ProofOfReserve::complete_withdrawal(RuntimeOrigin::signed(3), request_id));
+        System::assert_has_event(Event::WithdrawalDustRouted { request_id, amount: 5 }.into());
+        assert_eq!(Balances::free_balance(ProofOfReserve::insurance_fund_account()), fund + 5);
+        assert_eq!(Balances::free_balance(1), 1_000 - 3 * 105);
+        assert_eq!(ProofOfReserve::total_burned(), 2 * 105 + 100);
+    });
+}
+
+#[test]
+fn withdrawal_tips_order_the_queue_and_pay_the_processor() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let mut requests = Vec::new();
+        for user in [1, 2, 3] {
+            assert_ok!(ProofOfReserve::request_withdrawal(
+                RuntimeOrigin::signed(user),
+                b"bc1qwallet".to_vec(),
+                b"BTC".to_vec(),
+                100,
+                10,
+                None,
+            ));
+            let (request_id, _) = WithdrawalRequests::<Test>::iter()
+                .find(|(_, request)| request.user == user)
+                .unwrap();
+            requests.push(request_id);
+            System::set_block_number(System::block_number() + 1);
+        }
+
+        assert_noop!(
+            ProofOfReserve::tip_withdrawal(RuntimeOrigin::signed(1), requests[1], 5),
+            Error::<Test>::NotAuthorized
+        );
+        assert_ok!(ProofOfReserve::tip_withdrawal(RuntimeOrigin::signed(3), requests[2], 5));
+        assert_ok!(ProofOfReserve::tip_withdrawal(RuntimeOrigin::signed(2), requests[1], 2));
+        assert_ok!(ProofOfReserve::tip_withdrawal(RuntimeOrigin::signed(2), requests[1], 2));
+        assert_eq!(
+            ProofOfReserve::pending_withdrawals_by_tip(),
+            vec![(requests[2], 5), (requests[1], 4), (requests[0], 0)]
+        );
+
+        assert_ok!(ProofOfReserve::complete_withdrawal(RuntimeOrigin::signed(4), requests[2]));
+        assert_eq!(Balances::free_balance(4), 5);
+        assert_ok!(ProofOfReserve::reject_withdrawal(RuntimeOrigin::signed(4), requests[1]));
+        assert_eq!(Balances::free_balance(2), 1_000);
+        assert_eq!(ProofOfReserve::pending_withdrawals_by_tip(), vec![(requests[0], 0)]);
+    });
+}
+
+#[test]
+fn split_deposits_credit_every_recipient_by_share() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let split = |recipients: Vec<(u64, u32)>| {
+            ProofOfReserve::request_split_deposit(
+                RuntimeOrigin::signed(1),
+                recipients.try_into().unwrap(),
+                b"sweep-tx".to_vec(),
+                b"bc1qexchange".to_vec(),
+                b"BTC".to_vec(),
+                100,
+                10,
+                None,
+            )
+        };
+        assert_noop!(split(vec![]), Error::<Test>::InvalidSplit);
+        assert_noop!(split(vec![(5, 1), (5, 2)]), Error::<Test>::InvalidSplit);
+        assert_noop!(split(vec![(5, 1), (6, 0)]), Error::<Test>::InvalidSplit);
+
+        // More than `MaxDepositRecipients` recipients don't even decode
+        let recipients = vec![(5u64, 1u32), (6, 1), (7, 1), (8, 1)];
+        let oversized =
+            (18u8, recipients, b"sweep-tx".to_vec(), b"bc1qexchange".to_vec(), b"BTC".to_vec(), 100u128, 10u128, None::<u32>)
+                .encode();
+        assert!(Call::<Test>::decode(&mut &oversized[..]).is_err());
+
+        assert_ok!(split(vec![(5, 1), (6, 1), (7, 1)]));
+        let (request_id, request) = DepositRequests::<Test>::iter().next().unwrap();
+        assert_eq!(request.recipient, 5);
+        assert_eq!(UserDeposits::<Test>::get(6).into_inner(), vec![request_id]);
+
+        assert_ok!(ProofOfReserve::approve_deposit(RuntimeOrigin::signed(2), request_id));
+        // 1_000 split three ways; the first recipient gets the remainder
+        assert_eq!(Balances::free_balance(5), 334);
+        assert_eq!(Balances::free_balance(6), 333);
+        assert_eq!(Balances::free_balance(7), 333);
+        assert_eq!(ProofOfReserve::deposit_split(request_id), None);
+        System::assert_has_event(Event::FeeGraceGranted { who: 7, transactions: 3 }.into());
+    });
+}
+
+#[test]
+fn paused_bridge_halts_requests_and_payouts() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let deposit = |tx_id: &[u8]| {
+            ProofOfReserve::request_deposit(
+                RuntimeOrigin::signed(1),
+                5,
+                tx_id.to_vec(),
+                b"bc1qwallet".to_vec(),
+                b"BTC".to_vec(),
+                100,
+                10,
+                None,
+            )
+        };
+        assert_ok!(deposit(b"btc-tx"));
+        let (request_id, _) = DepositRequests::<Test>::iter().next().unwrap();
+
+        assert_ok!(ProofOfReserve::set_bridge_pause(RuntimeOrigin::root(), Some(b"BTC".to_vec()), true));
+        assert!(<ProofOfReserve as PausedQuery>::is_coin_paused(b"BTC"));
+        assert!(!<ProofOfReserve as PausedQuery>::is_coin_paused(b"ETH"));
+        assert_noop!(deposit(b"btc-tx-2"), Error::<Test>::BridgePaused);
+        assert_noop!(
+            ProofOfReserve::approve_deposit(RuntimeOrigin::signed(2), request_id),
+            Error::<Test>::BridgePaused
+        );
+
+        assert_ok!(ProofOfReserve::set_bridge_pause(RuntimeOrigin::root(), Some(b"BTC".to_vec()), false));
+        assert_ok!(ProofOfReserve::set_bridge_pause(RuntimeOrigin::root(), None, true));
+        assert!(<ProofOfReserve as PausedQuery>::is_globally_paused());
+        assert!(<ProofOfReserve as PausedQuery>::is_coin_paused(b"ETH"));
+        assert_noop!(
+            ProofOfReserve::request_withdrawal(
+                RuntimeOrigin::signed(1),
+                b"bc1qwallet".to_vec(),
+                b"BTC".to_vec(),
+                100,
+                10,
+                None,
+            ),
+            Error::<Test>::BridgePaused
+        );
+
+        assert_ok!(ProofOfReserve::set_bridge_pause(RuntimeOrigin::root(), None, false));
+        assert_ok!(ProofOfReserve::approve_deposit(RuntimeOrigin::signed(2), request_id));
+    });
+}
+
+#[test]
+fn call_multicoin_neither_nests_nor_bypasses_the_call_filter() {
+    new_test_ext().execute_with(|| {
+        let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+        let wrapped = RuntimeCall::ProofOfReserve(Call::call_multicoin { call: Box::new(remark.clone()) });
+        assert_noop!(
+            ProofOfReserve::call_multicoin(RuntimeOrigin::signed(1), Box::new(wrapped)),
+            Error::<Test>::NestedWrapperCall
+        );
+
+        let filtered = RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![1] });
+        assert_noop!(
+            ProofOfReserve::call_multicoin(RuntimeOrigin::signed(1), Box::new(filtered.clone())),
+            frame_system::Error::<Test>::CallFiltered
+        );
+        assert_noop!(
+            ProofOfReserve::call_multicoin(RuntimeOrigin::root(), Box::new(filtered)),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(ProofOfReserve::call_multicoin(RuntimeOrigin::signed(1), Box::new(remark)));
+    });
+}
+
+#[test]
+fn reserve_reports_compare_supply_bridge_and_reserves() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_eq!(ProofOfReserve::reserve_report(b"BTC"), None);
+
+        bridge_deposit(5);
+        let status = || ProofOfReserve::reserve_report(b"BTC").unwrap().status;
+        assert_eq!(status(), ReserveStatus::Unattested);
+
+        assert_ok!(ProofOfReserve::attest_reserves(RuntimeOrigin::signed(7), b"BTC".to_vec(), 80, None));
+        assert_eq!(status(), ReserveStatus::Undercollateralized);
+
+        // Coins minted besides the bridge show up as a mismatch
+        assert_ok!(ProofOfReserve::attest_reserves(RuntimeOrigin::signed(7), b"BTC".to_vec(), 150, None));
+        WrappedBtcSupply::set(120);
+        assert_eq!(status(), ReserveStatus::SupplyMismatch);
+        WrappedBtcSupply::set(100);
+        assert_eq!(status(), ReserveStatus::Consistent);
+
+        // Reports are stored as each era starts
+        ProofOfReserve::on_initialize(9);
+        assert_eq!(ProofOfReserve::reserve_report_at(0, b"BTC"), None);
+        ProofOfReserve::on_initialize(20);
+        System::assert_last_event(Event::ReserveReportsRecorded { era: 2, coins: 1 }.into());
+        let report = ProofOfReserve::reserve_report_at(2, b"BTC").unwrap();
+        assert_eq!(report.onchain_supply, Some(100));
+        assert_eq!(report.bridge_net_minted, 100);
+        assert_eq!(report.attested_reserve, Some(150));
+        assert_eq!(report.status, ReserveStatus::Consistent);
+    });
+}
+
+#[test]
+fn coin_mapping_issues_flag_bridged_coins_without_a_usable_coin() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        bridge_deposit(5);
+        assert!(ProofOfReserve::coin_mapping_issues().is_empty());
+        #[cfg(feature = "try-runtime")]
+        assert_ok!(ProofOfReserve::try_state(1));
+
+        let btc = pallet_multicoin::MappedCoin { coin_id: 1, destroying: false, can_mint: true, can_burn: true };
+        let issue_with = |mapping| {
+            WrappedBtcMapping::set(mapping);
+            ProofOfReserve::coin_mapping_issues()
+        };
+        assert_eq!(issue_with(None), vec![(b"BTC".to_vec(), CoinMappingIssue::NotFound)]);
+        #[cfg(feature = "try-runtime")]
+        assert!(ProofOfReserve::try_state(1).is_err());
+        assert_eq!(
+            issue_with(Some(pallet_multicoin::MappedCoin { destroying: true, ..btc })),
+            vec![(b"BTC".to_vec(), CoinMappingIssue::Destroying)]
+        );
+        assert_eq!(
+            issue_with(Some(pallet_multicoin::MappedCoin { can_mint: false, ..btc })),
+            vec![(b"BTC".to_vec(), CoinMappingIssue::NoMintPermission)]
+        );
+        assert_eq!(
+            issue_with(Some(pallet_multicoin::MappedCoin { can_burn: false, ..btc })),
+            vec![(b"BTC".to_vec(), CoinMappingIssue::NoBurnPermission)]
+        );
+    });
+}
+
+#[test]
+fn ratio_bounds_reject_fat_finger_ratios() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let deposit = |ratio: u128| {
+            ProofOfReserve::request_deposit(
+                RuntimeOrigin::signed(1),
+                1,
+                b"btc-tx".to_vec(),
+                b"bc1qwallet".to_vec(),
+                b"BTC".to_vec(),
+                100,
+                ratio,
+                None,
+            )
+        };
+        let withdraw = |ratio: u128| {
+            ProofOfReserve::request_withdrawal(RuntimeOrigin::signed(1), b"bc1qwallet".to_vec(), b"BTC".to_vec(), 100, ratio, None)
+        };
+        assert_noop!(
+            ProofOfReserve::set_ratio_bounds(RuntimeOrigin::signed(1), b"BTC".to_vec(), Some((5, 20))),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            ProofOfReserve::set_ratio_bounds(RuntimeOrigin::root(), b"BTC".to_vec(), Some((20, 5))),
+            Error::<Test>::InvalidRatioBounds
+        );
+        assert_ok!(ProofOfReserve::set_ratio_bounds(RuntimeOrigin::root(), b"BTC".to_vec(), Some((5, 20))));
+        System::assert_last_event(
+            Event::RatioBoundsSet { coin_name: BoundedVec::truncate_from(b"BTC".to_vec()), bounds: Some((5, 20)) }
+                .into(),
+        );
+
+        assert_noop!(deposit(1000), Error::<Test>::RatioOutOfBounds);
+        assert_noop!(withdraw(4), Error::<Test>::RatioOutOfBounds);
+        assert_ok!(deposit(20));
+        assert_ok!(withdraw(5));
+
+        assert_ok!(ProofOfReserve::set_ratio_bounds(RuntimeOrigin::root(), b"BTC".to_vec(), None));
+        assert_ok!(deposit(1000));
+    });
+}
+
+#[test]
+fn deposits_from_bound_wallets_to_other_accounts_are_flagged() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let deposit = |recipient: u64| {
+            ProofOfReserve::request_deposit(
+                RuntimeOrigin::signed(1),
+                recipient,
+                b"btc-tx".to_vec(),
+                b"bc1qwallet".to_vec(),
+                b"BTC".to_vec(),
+                100,
+                10,
+                None,
+            )
+        };
+        let wallet = || BoundedVec::truncate_from(b"bc1qwallet".to_vec());
+        assert_ok!(ProofOfReserve::bind_wallet(RuntimeOrigin::signed(2), b"bc1qwallet".to_vec(), Some(b"sig".to_vec())));
+        let binding = ProofOfReserve::wallet_binding(wallet()).unwrap();
+        assert_eq!(binding.owner, 2);
+        assert!(binding.proof_hash.is_some());
+        assert_noop!(
+            ProofOfReserve::bind_wallet(RuntimeOrigin::signed(3), b"bc1qwallet".to_vec(), None),
+            Error::<Test>::WalletAlreadyBound
+        );
+
+        // Deposits to the wallet owner match it
+        assert_ok!(deposit(2));
+        assert_eq!(crate::FlaggedDeposits::<Test>::iter().count(), 0);
+
+        assert_ok!(deposit(1));
+        let (request_id, wallet_owner) = crate::FlaggedDeposits::<Test>::iter().next().unwrap();
+        assert_eq!(wallet_owner, 2);
+        System::assert_last_event(Event::DepositWalletMismatch { request_id, wallet_owner: 2, recipient: 1 }.into());
+
+        assert_noop!(
+            ProofOfReserve::unbind_wallet(RuntimeOrigin::signed(3), b"bc1qwallet".to_vec()),
+            Error::<Test>::WalletNotBound
+        );
+        assert_ok!(ProofOfReserve::unbind_wallet(RuntimeOrigin::signed(2), b"bc1qwallet".to_vec()));
+        System::assert_last_event(Event::WalletUnbound { who: 2, external_wallet: wallet() }.into());
+        assert_ok!(deposit(1));
+        assert_eq!(crate::FlaggedDeposits::<Test>::iter().count(), 1);
+    });
+}
+
+#[test]
+fn era_event_digest_commits_to_every_bridge_event() {
+    use sp_runtime::traits::{BlakeTwo256, Hash};
+
+    fn merkle_root(leaves: &[H256]) -> H256 {
+        if leaves.len() == 1 {
+            return leaves[0];
+        }
+        let (left, right) = leaves.split_at(leaves.len() / 2);
+        BlakeTwo256::hash_of(&(merkle_root(left), merkle_root(right)))
+    }
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        bridge_deposit(5);
+        assert_ok!(ProofOfReserve::set_ratio_bounds(RuntimeOrigin::root(), b"BTC".to_vec(), Some((5, 20))));
+        assert_ok!(ProofOfReserve::bind_wallet(RuntimeOrigin::signed(5), b"bc1qwallet".to_vec(), None));
+
+        let leaves: Vec<H256> = System::events()
+            .into_iter()
+            .filter_map(|record| match record.event {
+                RuntimeEvent::ProofOfReserve(event) => Some(BlakeTwo256::hash_of(&event)),
+                _ => None,
+            })
+            .collect();
+        // Perfect subtrees from the left, largest first, folded from the smallest
+        let mut peaks = Vec::new();
+        let mut rest = &leaves[..];
+        while !rest.is_empty() {
+            let size = 1 << rest.len().ilog2();
+            peaks.push(merkle_root(&rest[..size]));
+            rest = &rest[size..];
+        }
+        let root = ProofOfReserve::bag_peaks(&peaks).unwrap();
+
+        ProofOfReserve::on_initialize(10);
+        let digest = ProofOfReserve::event_digest(0).unwrap();
+        assert_eq!(digest, EventDigest { root, events: leaves.len() as u32 });
+
+        // The next era starts with the digest of the last one
+        ProofOfReserve::on_initialize(20);
+        let digest = ProofOfReserve::event_digest(1).unwrap();
+        assert_eq!(digest.events, 2);
+        System::assert_has_event(Event::BridgeEventsDigested { era: 1, root: digest.root, events: 2 }.into());
+    });
+}
+
+#[test]
+fn chain_adapters_check_addresses_and_settle_proven_deposits() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let request_deposit = |wallet: &[u8]| {
+            ProofOfReserve::request_deposit(
+                RuntimeOrigin::signed(1),
+                5,
+                b"ltc-tx".to_vec(),
+                wallet.to_vec(),
+                b"LTC".to_vec(),
+                100,
+                10,
+                None,
+            )
+        };
+
+        assert_noop!(request_deposit(b"bc1qwallet"), Error::<Test>::InvalidAddress);
+        assert_noop!(
+            ProofOfReserve::request_withdrawal(
+                RuntimeOrigin::signed(1),
+                b"bc1qwallet".to_vec(),
+                b"LTC".to_vec(),
+                100,
+                10,
+                None,
+            ),
+            Error::<Test>::InvalidAddress
+        );
+        assert_ok!(request_deposit(b"ltc1qwallet"));
+        let (request_id, _) = DepositRequests::<Test>::iter().next().unwrap();
+
+        assert_noop!(
+            ProofOfReserve::prove_deposit(RuntimeOrigin::signed(3), request_id, b"other-tx\x06".to_vec()),
+            Error::<Test>::InvalidTxProof
+        );
+        assert_noop!(
+            ProofOfReserve::prove_deposit(RuntimeOrigin::signed(3), request_id, b"ltc-tx\x05".to_vec()),
+            Error::<Test>::NotEnoughConfirmations
+        );
+        assert_ok!(ProofOfReserve::prove_deposit(RuntimeOrigin::signed(3), request_id, b"ltc-tx\x06".to_vec()));
+        System::assert_has_event(Event::DepositProven { request_id, prover: 3, confirmations: 6 }.into());
+        assert_eq!(Balances::free_balance(5), 1000);
+        assert_eq!(DepositRequests::<Test>::get(request_id).unwrap().approved_by, Some(3));
+
+        // Coins without an adapter take any address but can't be proven
+        assert_ok!(ProofOfReserve::request_deposit(
+            RuntimeOrigin::signed(1),
+            5,
+            b"btc-tx".to_vec(),
+            b"anything".to_vec(),
+            b"BTC".to_vec(),
+            100,
+            10,
+            None,
+        ));
+        let (request_id, _) = DepositRequests::<Test>::iter()
+            .find(|(_, request)| request.status == DepositStatus::Pending)
+            .unwrap();
+        assert_noop!(
+            ProofOfReserve::prove_deposit(RuntimeOrigin::signed(3), request_id, b"btc-tx\x06".to_vec()),
+            Error::<Test>::NoChainAdapter
+        );
+    });
+}
+#[test]
+fn approved_deposit_dispatches_its_forwarded_call() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        // Deposit of 100 * 10 native units, half of which moves on to account 3
+        let forward = RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death { dest: 3, value: 500 });
+        assert_noop!(
+            ProofOfReserve::request_deposit_and_call(
+                RuntimeOrigin::signed(5),
+                b"btc-tx".to_vec(),
+                b"bc1qwallet".to_vec(),
+                b"BTC".to_vec(),
+                100,
+                10,
+                Box::new(RuntimeCall::System(frame_system::Call::set_heap_pages { pages: 1 })),
+                None,
+            ),
+            Error::<Test>::ForwardCallNotAllowed
+        );
+        assert_ok!(ProofOfReserve::request_deposit_and_call(
+            RuntimeOrigin::signed(5),
+            b"btc-tx".to_vec(),
+            b"bc1qwallet".to_vec(),
+            b"BTC".to_vec(),
+            100,
+            10,
+            Box::new(forward.clone()),
+            None,
+        ));
+        let (request_id, request) = DepositRequests::<Test>::iter().next().unwrap();
+        assert_eq!(request.recipient, 5);
+        assert_eq!(ProofOfReserve::deposit_forward(request_id), Some(forward));
+
+        assert_ok!(ProofOfReserve::approve_deposit(RuntimeOrigin::signed(2), request_id));
+        System::assert_last_event(Event::DepositForwarded { request_id, result: Ok(()) }.into());
+        assert_eq!(Balances::free_balance(5), 500);
+        assert_eq!(Balances::free_balance(3), 1500);
+        assert_eq!(ProofOfReserve::deposit_forward(request_id), None);
+    });
+}
+
+#[test]
+fn failing_forwarded_call_leaves_deposit_credited() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        // More than the deposit pays out, so the transfer fails
+        let forward = RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death { dest: 3, value: 5_000 });
+        assert_ok!(ProofOfReserve::request_deposit_and_call(
+            RuntimeOrigin::signed(5),
+            b"btc-tx".to_vec(),
+            b"bc1qwallet".to_vec(),
+            b"BTC".to_vec(),
+            100,
+            10,
+            Box::new(forward),
+            None,
+        ));
+        let (request_id, _) = DepositRequests::<Test>::iter().next().unwrap();
+
+        assert_ok!(ProofOfReserve::approve_deposit(RuntimeOrigin::signed(2), request_id));
+        assert!(matches!(
+            System::events().last().unwrap().event,
+            RuntimeEvent::ProofOfReserve(Event::DepositForwarded { result: Err(_), .. })
+        ));
+        assert_eq!(DepositRequests::<Test>::get(request_id).unwrap().status, DepositStatus::Approved);
+        assert_eq!(Balances::free_balance(5), 1_000);
+        assert_eq!(Balances::free_balance(3), 1_000);
+
+        // The base call filter still applies to forwarded calls
+        assert_ok!(ProofOfReserve::request_deposit_and_call(
+            RuntimeOrigin::signed(5),
+            b"btc-tx-2".to_vec(),
+            b"bc1qwallet".to_vec(),
+            b"BTC".to_vec(),
+            100,
+            10,
+            Box::new(RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![] })),
+            None,
+        ));
+        let request_id = DepositRequests::<Test>::iter()
+            .find(|(_, request)| request.status == DepositStatus::Pending)
+            .unwrap()
+            .0;
+        assert_ok!(ProofOfReserve::approve_deposit(RuntimeOrigin::signed(2), request_id));
+        System::assert_last_event(
+            Event::DepositForwarded { request_id, result: Err(frame_system::Error::<Test>::CallFiltered.into()) }.into(),
+        );
+    });
+}
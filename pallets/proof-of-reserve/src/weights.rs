@@ -0,0 +1,723 @@
+//! Weights for pallet_proof_of_reserve
+//!
+//! NOT BENCHMARKED: these are hand estimates based on the storage accesses
+//! listed on each function, with proof sizes left at zero. Regenerate this file with
+//! `benchmark pallet --pallet pallet_proof_of_reserve --extrinsic '*'` once the
+//! benchmarks in `benchmarking.rs` have been run on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_proof_of_reserve.
+pub trait WeightInfo {
+	fn request_deposit() -> Weight;
+	fn approve_deposit() -> Weight;
+	fn reject_deposit() -> Weight;
+	fn request_withdrawal() -> Weight;
+	fn complete_withdrawal() -> Weight;
+	fn reject_withdrawal() -> Weight;
+	fn call_multicoin() -> Weight;
+	fn register_withdrawal_address() -> Weight;
+	fn remove_withdrawal_address() -> Weight;
+	fn set_address_book_required() -> Weight;
+	fn xcm_deposit() -> Weight;
+	fn set_approval_signers() -> Weight;
+	fn approve_deposit_signed(s: u32) -> Weight;
+	fn set_processor() -> Weight;
+	fn claim_processor_reward() -> Weight;
+	fn attest_reserves(p: u32) -> Weight;
+	fn set_rounding_policy() -> Weight;
+	fn tip_withdrawal() -> Weight;
+	fn request_split_deposit(r: u32) -> Weight;
+	fn set_bridge_pause() -> Weight;
+	fn record_reserve_reports(c: u32) -> Weight;
+	fn set_ratio_bounds() -> Weight;
+	fn bind_wallet(p: u32) -> Weight;
+	fn unbind_wallet() -> Weight;
+	fn seal_event_digest() -> Weight;
+	fn prove_deposit(p: u32) -> Weight;
+	fn request_deposit_and_call() -> Weight;
+}
+
+/// Estimated weights for pallet_proof_of_reserve, still to be benchmarked.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: ProofOfReserve RequestCounter (r:1 w:1)
+	/// Storage: ProofOfReserve DepositRequests (r:0 w:1)
+	/// Storage: ProofOfReserve UserDeposits (r:1 w:1)
+	/// Storage: MultiCoin SymbolToId (r:1 w:0)
+	/// Storage: MultiCoin OracleConfigs (r:1 w:0)
+	/// Storage: MultiCoin PriceFeeders (r:1 w:0)
+	/// Storage: ProofOfReserve RatioBounds (r:1 w:0)
+	/// Storage: ProofOfReserve CoinBridgeTotals (r:1 w:1)
+	/// Storage: ProofOfReserve BridgePaused (r:1 w:0)
+	/// Storage: ProofOfReserve PausedCoins (r:1 w:0)
+	/// Storage: ProofOfReserve WalletOwners (r:1 w:0)
+	/// Storage: ProofOfReserve FlaggedDeposits (r:0 w:1)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn request_deposit() -> Weight {
+		Weight::from_parts(50_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+
+	/// Storage: ProofOfReserve DepositRequests (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: ProofOfReserve TotalMinted (r:1 w:1)
+	/// Storage: ProofOfReserve FeeGrace (r:0 w:1)
+	/// Storage: ProofOfReserve CoinBridgeTotals (r:1 w:1)
+	/// Storage: ProofOfReserve BridgePaused (r:1 w:0)
+	/// Storage: ProofOfReserve PausedCoins (r:1 w:0)
+	/// Storage: ProofOfReserve DepositSplits (r:1 w:1)
+	/// Storage: ProofOfReserve DepositForwards (r:1 w:1)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn approve_deposit() -> Weight {
+		Weight::from_parts(60_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+
+	/// Storage: ProofOfReserve DepositRequests (r:1 w:1)
+	/// Storage: ProofOfReserve DepositSplits (r:0 w:1)
+	/// Storage: ProofOfReserve DepositForwards (r:0 w:1)
+	/// Storage: ProofOfReserve CoinBridgeTotals (r:1 w:1)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn reject_deposit() -> Weight {
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: System Account (r:1 w:0)
+	/// Storage: ProofOfReserve RequestCounter (r:1 w:1)
+	/// Storage: ProofOfReserve WithdrawalRequests (r:0 w:1)
+	/// Storage: ProofOfReserve UserWithdrawals (r:1 w:1)
+	/// Storage: ProofOfReserve AddressBookRequired (r:1 w:0)
+	/// Storage: ProofOfReserve WithdrawalAddresses (r:1 w:0)
+	/// Storage: MultiCoin SymbolToId (r:1 w:0)
+	/// Storage: MultiCoin OracleConfigs (r:1 w:0)
+	/// Storage: MultiCoin PriceFeeders (r:1 w:0)
+	/// Storage: ProofOfReserve RatioBounds (r:1 w:0)
+	/// Storage: ProofOfReserve CoinBridgeTotals (r:1 w:1)
+	/// Storage: ProofOfReserve RoundingPolicies (r:1 w:0)
+	/// Storage: ProofOfReserve BridgePaused (r:1 w:0)
+	/// Storage: ProofOfReserve PausedCoins (r:1 w:0)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn request_withdrawal() -> Weight {
+		Weight::from_parts(55_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: ProofOfReserve WithdrawalRequests (r:1 w:1)
+	/// Storage: System Account (r:3 w:3)
+	/// Storage: Balances TotalIssuance (r:1 w:1)
+	/// Storage: ProofOfReserve TotalBurned (r:1 w:1)
+	/// Storage: ProofOfReserve Processors (r:1 w:0)
+	/// Storage: ProofOfReserve ProcessorRewards (r:1 w:1)
+	/// Storage: ProofOfReserve CoinBridgeTotals (r:1 w:1)
+	/// Storage: ProofOfReserve RoundingPolicies (r:1 w:0)
+	/// Storage: ProofOfReserve WithdrawalTips (r:1 w:1)
+	/// Storage: ProofOfReserve BridgePaused (r:1 w:0)
+	/// Storage: ProofOfReserve PausedCoins (r:1 w:0)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn complete_withdrawal() -> Weight {
+		Weight::from_parts(95_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+
+	/// Storage: ProofOfReserve WithdrawalRequests (r:1 w:1)
+	/// Storage: ProofOfReserve CoinBridgeTotals (r:1 w:1)
+	/// Storage: ProofOfReserve WithdrawalTips (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn reject_withdrawal() -> Weight {
+		Weight::from_parts(45_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+
+	fn call_multicoin() -> Weight {
+		Weight::from_parts(30_000_000, 0)
+	}
+
+	/// Storage: ProofOfReserve WithdrawalAddresses (r:1 w:1)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn register_withdrawal_address() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: ProofOfReserve WithdrawalAddresses (r:1 w:1)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn remove_withdrawal_address() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: ProofOfReserve AddressBookRequired (r:0 w:1)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn set_address_book_required() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: ProofOfReserve XcmDeposits (r:1 w:1)
+	/// Storage: ProofOfReserve RequestCounter (r:1 w:1)
+	/// Storage: ProofOfReserve UserDeposits (r:1 w:1)
+	/// Storage: ProofOfReserve DepositRequests (r:0 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: ProofOfReserve TotalMinted (r:1 w:1)
+	/// Storage: ProofOfReserve FeeGrace (r:0 w:1)
+	/// Storage: MultiCoin SymbolToId (r:1 w:0)
+	/// Storage: MultiCoin OracleConfigs (r:1 w:0)
+	/// Storage: MultiCoin PriceFeeders (r:1 w:0)
+	/// Storage: ProofOfReserve RatioBounds (r:1 w:0)
+	/// Storage: ProofOfReserve CoinBridgeTotals (r:1 w:1)
+	/// Storage: ProofOfReserve BridgePaused (r:1 w:0)
+	/// Storage: ProofOfReserve PausedCoins (r:1 w:0)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn xcm_deposit() -> Weight {
+		Weight::from_parts(90_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+
+	/// Storage: ProofOfReserve ApprovalSigners (r:0 w:1)
+	/// Storage: ProofOfReserve ApprovalThreshold (r:0 w:1)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn set_approval_signers() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: ProofOfReserve DepositRequests (r:1 w:1)
+	/// Storage: ProofOfReserve ApprovalThreshold (r:1 w:0)
+	/// Storage: ProofOfReserve ApprovalSigners (r:1 w:0)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: ProofOfReserve TotalMinted (r:1 w:1)
+	/// Storage: ProofOfReserve FeeGrace (r:0 w:1)
+	/// Storage: ProofOfReserve CoinBridgeTotals (r:1 w:1)
+	/// Storage: ProofOfReserve BridgePaused (r:1 w:0)
+	/// Storage: ProofOfReserve PausedCoins (r:1 w:0)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	/// The range of component `s` is `[1, 16]`.
+	fn approve_deposit_signed(s: u32) -> Weight {
+		Weight::from_parts(50_000_000, 0)
+			.saturating_add(Weight::from_parts(45_000_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+
+	/// Storage: ProofOfReserve Processors (r:0 w:1)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn set_processor() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: ProofOfReserve ProcessorRewards (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn claim_processor_reward() -> Weight {
+		Weight::from_parts(45_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: ProofOfReserve ReserveAttestations (r:0 w:1)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	/// The range of component `p` is `[0, 4096]`.
+	fn attest_reserves(p: u32) -> Weight {
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(Weight::from_parts(20_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: ProofOfReserve RoundingPolicies (r:0 w:1)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn set_rounding_policy() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: ProofOfReserve WithdrawalRequests (r:1 w:0)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: ProofOfReserve WithdrawalTips (r:1 w:1)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn tip_withdrawal() -> Weight {
+		Weight::from_parts(45_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: ProofOfReserve RequestCounter (r:1 w:1)
+	/// Storage: ProofOfReserve DepositRequests (r:0 w:1)
+	/// Storage: ProofOfReserve DepositSplits (r:0 w:1)
+	/// Storage: MultiCoin SymbolToId (r:1 w:0)
+	/// Storage: MultiCoin OracleConfigs (r:1 w:0)
+	/// Storage: MultiCoin PriceFeeders (r:1 w:0)
+	/// Storage: ProofOfReserve RatioBounds (r:1 w:0)
+	/// Storage: ProofOfReserve CoinBridgeTotals (r:1 w:1)
+	/// Storage: ProofOfReserve BridgePaused (r:1 w:0)
+	/// Storage: ProofOfReserve PausedCoins (r:1 w:0)
+	/// Storage: ProofOfReserve WalletOwners (r:1 w:0)
+	/// Storage: ProofOfReserve FlaggedDeposits (r:0 w:1)
+	/// Storage: ProofOfReserve UserDeposits (r:32 w:32)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	/// The range of component `r` is `[1, 32]`.
+	fn request_split_deposit(r: u32) -> Weight {
+		Weight::from_parts(55_000_000, 0)
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+	}
+
+	/// Storage: ProofOfReserve PausedCoins (r:0 w:1)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn set_bridge_pause() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: ProofOfReserve CoinBridgeTotals (r:65 w:0)
+	/// Storage: ProofOfReserve ReserveAttestations (r:65 w:0)
+	/// Storage: MultiCoin SymbolToId (r:64 w:0)
+	/// Storage: MultiCoin TotalSupply (r:64 w:0)
+	/// Storage: MultiCoin AccrualIndex (r:64 w:0)
+	/// Storage: ProofOfReserve ReserveReports (r:0 w:64)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	/// The range of component `c` is `[0, 64]`.
+	fn record_reserve_reports(c: u32) -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(Weight::from_parts(30_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((7_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+	}
+
+	/// Storage: ProofOfReserve RatioBounds (r:0 w:1)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn set_ratio_bounds() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: ProofOfReserve WalletOwners (r:1 w:1)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	/// The range of component `p` is `[0, 4096]`.
+	fn bind_wallet(p: u32) -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(2_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: ProofOfReserve WalletOwners (r:1 w:1)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn unbind_wallet() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: ProofOfReserve EraEvents (r:1 w:2)
+	/// Storage: ProofOfReserve EventDigests (r:0 w:1)
+	fn seal_event_digest() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: ProofOfReserve DepositRequests (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: ProofOfReserve TotalMinted (r:1 w:1)
+	/// Storage: ProofOfReserve FeeGrace (r:0 w:1)
+	/// Storage: ProofOfReserve CoinBridgeTotals (r:1 w:1)
+	/// Storage: ProofOfReserve BridgePaused (r:1 w:0)
+	/// Storage: ProofOfReserve PausedCoins (r:1 w:0)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	/// The range of component `p` is `[0, 4096]`.
+	fn prove_deposit(p: u32) -> Weight {
+		Weight::from_parts(70_000_000, 0)
+			.saturating_add(Weight::from_parts(2_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+
+	/// Storage: ProofOfReserve RequestCounter (r:1 w:1)
+	/// Storage: ProofOfReserve DepositRequests (r:0 w:1)
+	/// Storage: ProofOfReserve DepositForwards (r:0 w:1)
+	/// Storage: ProofOfReserve UserDeposits (r:1 w:1)
+	/// Storage: MultiCoin SymbolToId (r:1 w:0)
+	/// Storage: MultiCoin OracleConfigs (r:1 w:0)
+	/// Storage: MultiCoin PriceFeeders (r:1 w:0)
+	/// Storage: ProofOfReserve RatioBounds (r:1 w:0)
+	/// Storage: ProofOfReserve CoinBridgeTotals (r:1 w:1)
+	/// Storage: ProofOfReserve BridgePaused (r:1 w:0)
+	/// Storage: ProofOfReserve PausedCoins (r:1 w:0)
+	/// Storage: ProofOfReserve WalletOwners (r:1 w:0)
+	/// Storage: ProofOfReserve FlaggedDeposits (r:0 w:1)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn request_deposit_and_call() -> Weight {
+		Weight::from_parts(55_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+}
+
+/// Weights for tests only; runtimes should use `SubstrateWeight`
+impl WeightInfo for () {
+	/// Storage: ProofOfReserve RequestCounter (r:1 w:1)
+	/// Storage: ProofOfReserve DepositRequests (r:0 w:1)
+	/// Storage: ProofOfReserve UserDeposits (r:1 w:1)
+	/// Storage: MultiCoin SymbolToId (r:1 w:0)
+	/// Storage: MultiCoin OracleConfigs (r:1 w:0)
+	/// Storage: MultiCoin PriceFeeders (r:1 w:0)
+	/// Storage: ProofOfReserve RatioBounds (r:1 w:0)
+	/// Storage: ProofOfReserve CoinBridgeTotals (r:1 w:1)
+	/// Storage: ProofOfReserve BridgePaused (r:1 w:0)
+	/// Storage: ProofOfReserve PausedCoins (r:1 w:0)
+	/// Storage: ProofOfReserve WalletOwners (r:1 w:0)
+	/// Storage: ProofOfReserve FlaggedDeposits (r:0 w:1)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn request_deposit() -> Weight {
+		Weight::from_parts(50_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+
+	/// Storage: ProofOfReserve DepositRequests (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: ProofOfReserve TotalMinted (r:1 w:1)
+	/// Storage: ProofOfReserve FeeGrace (r:0 w:1)
+	/// Storage: ProofOfReserve CoinBridgeTotals (r:1 w:1)
+	/// Storage: ProofOfReserve BridgePaused (r:1 w:0)
+	/// Storage: ProofOfReserve PausedCoins (r:1 w:0)
+	/// Storage: ProofOfReserve DepositSplits (r:1 w:1)
+	/// Storage: ProofOfReserve DepositForwards (r:1 w:1)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn approve_deposit() -> Weight {
+		Weight::from_parts(60_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+
+	/// Storage: ProofOfReserve DepositRequests (r:1 w:1)
+	/// Storage: ProofOfReserve DepositSplits (r:0 w:1)
+	/// Storage: ProofOfReserve DepositForwards (r:0 w:1)
+	/// Storage: ProofOfReserve CoinBridgeTotals (r:1 w:1)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn reject_deposit() -> Weight {
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: System Account (r:1 w:0)
+	/// Storage: ProofOfReserve RequestCounter (r:1 w:1)
+	/// Storage: ProofOfReserve WithdrawalRequests (r:0 w:1)
+	/// Storage: ProofOfReserve UserWithdrawals (r:1 w:1)
+	/// Storage: ProofOfReserve AddressBookRequired (r:1 w:0)
+	/// Storage: ProofOfReserve WithdrawalAddresses (r:1 w:0)
+	/// Storage: MultiCoin SymbolToId (r:1 w:0)
+	/// Storage: MultiCoin OracleConfigs (r:1 w:0)
+	/// Storage: MultiCoin PriceFeeders (r:1 w:0)
+	/// Storage: ProofOfReserve RatioBounds (r:1 w:0)
+	/// Storage: ProofOfReserve CoinBridgeTotals (r:1 w:1)
+	/// Storage: ProofOfReserve RoundingPolicies (r:1 w:0)
+	/// Storage: ProofOfReserve BridgePaused (r:1 w:0)
+	/// Storage: ProofOfReserve PausedCoins (r:1 w:0)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn request_withdrawal() -> Weight {
+		Weight::from_parts(55_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: ProofOfReserve WithdrawalRequests (r:1 w:1)
+	/// Storage: System Account (r:3 w:3)
+	/// Storage: Balances TotalIssuance (r:1 w:1)
+	/// Storage: ProofOfReserve TotalBurned (r:1 w:1)
+	/// Storage: ProofOfReserve Processors (r:1 w:0)
+	/// Storage: ProofOfReserve ProcessorRewards (r:1 w:1)
+	/// Storage: ProofOfReserve CoinBridgeTotals (r:1 w:1)
+	/// Storage: ProofOfReserve RoundingPolicies (r:1 w:0)
+	/// Storage: ProofOfReserve WithdrawalTips (r:1 w:1)
+	/// Storage: ProofOfReserve BridgePaused (r:1 w:0)
+	/// Storage: ProofOfReserve PausedCoins (r:1 w:0)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn complete_withdrawal() -> Weight {
+		Weight::from_parts(95_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+
+	/// Storage: ProofOfReserve WithdrawalRequests (r:1 w:1)
+	/// Storage: ProofOfReserve CoinBridgeTotals (r:1 w:1)
+	/// Storage: ProofOfReserve WithdrawalTips (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn reject_withdrawal() -> Weight {
+		Weight::from_parts(45_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+
+	fn call_multicoin() -> Weight {
+		Weight::from_parts(30_000_000, 0)
+	}
+
+	/// Storage: ProofOfReserve WithdrawalAddresses (r:1 w:1)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn register_withdrawal_address() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: ProofOfReserve WithdrawalAddresses (r:1 w:1)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn remove_withdrawal_address() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: ProofOfReserve AddressBookRequired (r:0 w:1)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn set_address_book_required() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: ProofOfReserve XcmDeposits (r:1 w:1)
+	/// Storage: ProofOfReserve RequestCounter (r:1 w:1)
+	/// Storage: ProofOfReserve UserDeposits (r:1 w:1)
+	/// Storage: ProofOfReserve DepositRequests (r:0 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: ProofOfReserve TotalMinted (r:1 w:1)
+	/// Storage: ProofOfReserve FeeGrace (r:0 w:1)
+	/// Storage: MultiCoin SymbolToId (r:1 w:0)
+	/// Storage: MultiCoin OracleConfigs (r:1 w:0)
+	/// Storage: MultiCoin PriceFeeders (r:1 w:0)
+	/// Storage: ProofOfReserve RatioBounds (r:1 w:0)
+	/// Storage: ProofOfReserve CoinBridgeTotals (r:1 w:1)
+	/// Storage: ProofOfReserve BridgePaused (r:1 w:0)
+	/// Storage: ProofOfReserve PausedCoins (r:1 w:0)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn xcm_deposit() -> Weight {
+		Weight::from_parts(90_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+
+	/// Storage: ProofOfReserve ApprovalSigners (r:0 w:1)
+	/// Storage: ProofOfReserve ApprovalThreshold (r:0 w:1)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn set_approval_signers() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: ProofOfReserve DepositRequests (r:1 w:1)
+	/// Storage: ProofOfReserve ApprovalThreshold (r:1 w:0)
+	/// Storage: ProofOfReserve ApprovalSigners (r:1 w:0)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: ProofOfReserve TotalMinted (r:1 w:1)
+	/// Storage: ProofOfReserve FeeGrace (r:0 w:1)
+	/// Storage: ProofOfReserve CoinBridgeTotals (r:1 w:1)
+	/// Storage: ProofOfReserve BridgePaused (r:1 w:0)
+	/// Storage: ProofOfReserve PausedCoins (r:1 w:0)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	/// The range of component `s` is `[1, 16]`.
+	fn approve_deposit_signed(s: u32) -> Weight {
+		Weight::from_parts(50_000_000, 0)
+			.saturating_add(Weight::from_parts(45_000_000, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+
+	/// Storage: ProofOfReserve Processors (r:0 w:1)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn set_processor() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: ProofOfReserve ProcessorRewards (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn claim_processor_reward() -> Weight {
+		Weight::from_parts(45_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: ProofOfReserve ReserveAttestations (r:0 w:1)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	/// The range of component `p` is `[0, 4096]`.
+	fn attest_reserves(p: u32) -> Weight {
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(Weight::from_parts(20_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: ProofOfReserve RoundingPolicies (r:0 w:1)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn set_rounding_policy() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: ProofOfReserve WithdrawalRequests (r:1 w:0)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: ProofOfReserve WithdrawalTips (r:1 w:1)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn tip_withdrawal() -> Weight {
+		Weight::from_parts(45_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: ProofOfReserve RequestCounter (r:1 w:1)
+	/// Storage: ProofOfReserve DepositRequests (r:0 w:1)
+	/// Storage: ProofOfReserve DepositSplits (r:0 w:1)
+	/// Storage: MultiCoin SymbolToId (r:1 w:0)
+	/// Storage: MultiCoin OracleConfigs (r:1 w:0)
+	/// Storage: MultiCoin PriceFeeders (r:1 w:0)
+	/// Storage: ProofOfReserve RatioBounds (r:1 w:0)
+	/// Storage: ProofOfReserve CoinBridgeTotals (r:1 w:1)
+	/// Storage: ProofOfReserve BridgePaused (r:1 w:0)
+	/// Storage: ProofOfReserve PausedCoins (r:1 w:0)
+	/// Storage: ProofOfReserve WalletOwners (r:1 w:0)
+	/// Storage: ProofOfReserve FlaggedDeposits (r:0 w:1)
+	/// Storage: ProofOfReserve UserDeposits (r:32 w:32)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	/// The range of component `r` is `[1, 32]`.
+	fn request_split_deposit(r: u32) -> Weight {
+		Weight::from_parts(55_000_000, 0)
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
+	}
+
+	/// Storage: ProofOfReserve PausedCoins (r:0 w:1)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn set_bridge_pause() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: ProofOfReserve CoinBridgeTotals (r:65 w:0)
+	/// Storage: ProofOfReserve ReserveAttestations (r:65 w:0)
+	/// Storage: MultiCoin SymbolToId (r:64 w:0)
+	/// Storage: MultiCoin TotalSupply (r:64 w:0)
+	/// Storage: MultiCoin AccrualIndex (r:64 w:0)
+	/// Storage: ProofOfReserve ReserveReports (r:0 w:64)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	/// The range of component `c` is `[0, 64]`.
+	fn record_reserve_reports(c: u32) -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(Weight::from_parts(30_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((7_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
+	}
+
+	/// Storage: ProofOfReserve RatioBounds (r:0 w:1)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn set_ratio_bounds() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: ProofOfReserve WalletOwners (r:1 w:1)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	/// The range of component `p` is `[0, 4096]`.
+	fn bind_wallet(p: u32) -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(2_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: ProofOfReserve WalletOwners (r:1 w:1)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn unbind_wallet() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: ProofOfReserve EraEvents (r:1 w:2)
+	/// Storage: ProofOfReserve EventDigests (r:0 w:1)
+	fn seal_event_digest() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: ProofOfReserve DepositRequests (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: ProofOfReserve TotalMinted (r:1 w:1)
+	/// Storage: ProofOfReserve FeeGrace (r:0 w:1)
+	/// Storage: ProofOfReserve CoinBridgeTotals (r:1 w:1)
+	/// Storage: ProofOfReserve BridgePaused (r:1 w:0)
+	/// Storage: ProofOfReserve PausedCoins (r:1 w:0)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	/// The range of component `p` is `[0, 4096]`.
+	fn prove_deposit(p: u32) -> Weight {
+		Weight::from_parts(70_000_000, 0)
+			.saturating_add(Weight::from_parts(2_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+
+	/// Storage: ProofOfReserve RequestCounter (r:1 w:1)
+	/// Storage: ProofOfReserve DepositRequests (r:0 w:1)
+	/// Storage: ProofOfReserve DepositForwards (r:0 w:1)
+	/// Storage: ProofOfReserve UserDeposits (r:1 w:1)
+	/// Storage: MultiCoin SymbolToId (r:1 w:0)
+	/// Storage: MultiCoin OracleConfigs (r:1 w:0)
+	/// Storage: MultiCoin PriceFeeders (r:1 w:0)
+	/// Storage: ProofOfReserve RatioBounds (r:1 w:0)
+	/// Storage: ProofOfReserve CoinBridgeTotals (r:1 w:1)
+	/// Storage: ProofOfReserve BridgePaused (r:1 w:0)
+	/// Storage: ProofOfReserve PausedCoins (r:1 w:0)
+	/// Storage: ProofOfReserve WalletOwners (r:1 w:0)
+	/// Storage: ProofOfReserve FlaggedDeposits (r:0 w:1)
+	/// Storage: ProofOfReserve EraEvents (r:1 w:1)
+	fn request_deposit_and_call() -> Weight {
+		Weight::from_parts(55_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+}
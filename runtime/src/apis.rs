@@ -42,8 +42,9 @@ use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-	AccountId, Aura, Balance, Block, Executive, Grandpa, InherentDataExt, Nonce, Runtime,
-	RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment, VERSION,
+	AccountId, Aura, Balance, Block, BlockNumber, Executive, Grandpa, Hash, InherentDataExt,
+	MultiCoin, Nonce, ProofOfReserve, Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys,
+	System, TransactionPayment, VERSION,
 };
 
 impl_runtime_apis! {
@@ -176,6 +177,54 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_multicoin::runtime_api::MultiCoinApi<Block, AccountId> for Runtime {
+		fn coin_dashboard(coin_id: pallet_multicoin::CoinId) -> Option<pallet_multicoin::CoinDashboard<AccountId>> {
+			MultiCoin::coin_dashboard(coin_id)
+		}
+
+		fn balances_many(account: AccountId, coin_ids: Vec<pallet_multicoin::CoinId>) -> Vec<u128> {
+			MultiCoin::balances_many(&account, &coin_ids)
+		}
+
+		fn can_transfer(
+			coin_id: pallet_multicoin::CoinId,
+			from: AccountId,
+			to: AccountId,
+			amount: u128,
+		) -> Result<pallet_multicoin::FeeBreakdown, sp_runtime::DispatchError> {
+			MultiCoin::can_transfer(coin_id, &from, &to, amount)
+		}
+
+		fn velocity(coin_id: pallet_multicoin::CoinId) -> Vec<pallet_multicoin::VelocityStats> {
+			MultiCoin::coin_velocity(coin_id).into_inner()
+		}
+	}
+
+	impl pallet_proof_of_reserve::runtime_api::ProofOfReserveApi<Block, BlockNumber, Hash, Balance> for Runtime {
+		fn bridge_stats(coin_name: Vec<u8>) -> Option<pallet_proof_of_reserve::BridgeStats<BlockNumber>> {
+			ProofOfReserve::bridge_stats(&coin_name)
+		}
+
+		fn pending_withdrawals_by_tip() -> Vec<(Hash, Balance)> {
+			ProofOfReserve::pending_withdrawals_by_tip()
+		}
+
+		fn reserve_report(coin_name: Vec<u8>) -> Option<pallet_proof_of_reserve::ReserveReport<BlockNumber>> {
+			ProofOfReserve::reserve_report(&coin_name)
+		}
+
+		fn reserve_report_at(
+			era: u32,
+			coin_name: Vec<u8>,
+		) -> Option<pallet_proof_of_reserve::ReserveReport<BlockNumber>> {
+			ProofOfReserve::reserve_report_at(era, &coin_name)
+		}
+
+		fn coin_mapping_issues() -> Vec<(Vec<u8>, pallet_proof_of_reserve::CoinMappingIssue)> {
+			ProofOfReserve::coin_mapping_issues()
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
 		fn query_info(
 			uxt: <Block as BlockT>::Extrinsic,
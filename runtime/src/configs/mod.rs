@@ -26,7 +26,7 @@
 // Substrate and Polkadot dependencies
 use frame_support::{
 	derive_impl, parameter_types,
-	traits::{ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, VariantCountOf},
+	traits::{ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, ExistenceRequirement, VariantCountOf},
 	weights::{
 		constants::{RocksDbWeight, WEIGHT_REF_TIME_PER_SECOND},
 		IdentityFee, Weight,
@@ -37,7 +37,7 @@ use frame_support::{
 use frame_system::limits::{BlockLength, BlockWeights};
 use pallet_transaction_payment::{ConstFeeMultiplier, Multiplier};
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use sp_runtime::{traits::One, Perbill};
+use sp_runtime::{traits::{AccountIdConversion, BlakeTwo256, Hash as _, One}, Perbill};
 use sp_version::RuntimeVersion;
 
 // Local module imports
@@ -177,6 +177,59 @@ parameter_types! {
 	pub const CoinDeposit: Balance = 10 * crate::UNIT;
 	/// Maximum supply for any coin
 	pub const MaxCoinSupply: u128 = u128::MAX;
+	/// Account holding native proceeds of coin launch sales
+	pub const MultiCoinPalletId: PalletId = PalletId(*b"py/mcoin");
+	/// Base lock period for 1x conviction coin votes
+	pub const VoteLockingPeriod: BlockNumber = 7 * crate::DAYS;
+	/// Window of the per-bridge coin mint caps
+	pub const BridgeMintWindow: BlockNumber = crate::DAYS;
+	/// Maximum recipients of a single split transfer
+	pub const MaxSplitRecipients: u32 = 64;
+	pub const MaxBatchRecipients: u32 = 500;
+	/// Maximum additional minters and burners granted at coin creation
+	pub const MaxInitialMinters: u32 = 32;
+	pub const MaxInitialBurners: u32 = 32;
+	/// Maximum price feeders of a coin
+	pub const MaxPriceFeeders: u32 = 16;
+	/// Share of all transaction fees a coin may pay before its fees get dearer
+	pub const FeeShareTarget: Perbill = Perbill::from_percent(25);
+	/// Per-block change of coin fee multipliers
+	pub const FeeMultiplierAdjustment: Perbill = Perbill::from_perthousand(1);
+	/// Blocks until a liquidation auction offers its full discount
+	pub const LiquidationAuctionDuration: BlockNumber = 6 * crate::HOURS;
+	/// Reward of keepers starting liquidation auctions
+	pub const KeeperReward: Perbill = Perbill::from_percent(1);
+	/// Supply checkpoints kept per coin
+	pub const MaxSupplyCheckpoints: u32 = 64;
+	/// Minimum spacing of a coin's supply checkpoints
+	pub const SupplyCheckpointInterval: BlockNumber = crate::HOURS;
+	/// Per-account entries visited by the idle cleanup per block
+	pub const MaxCleanupScan: u32 = 100;
+	pub const RemoveItemsLimit: u32 = 1000;
+	/// Accounts that may keep transferring a coin whose transfers are paused
+	pub const MaxPauseExceptions: u32 = 16;
+	/// Era length of the per-coin transfer velocity metrics
+	pub const VelocityEraLength: BlockNumber = crate::DAYS;
+	/// Eras of transfer velocity metrics kept per coin
+	pub const MaxVelocityEras: u32 = 30;
+	/// Native fee payments keep the payer alive, like `pallet_transaction_payment`
+	pub const NativeFeeExistence: ExistenceRequirement = ExistenceRequirement::KeepAlive;
+	/// Holders per segment of a coin's holder snapshot
+	pub const HolderCheckpointInterval: u32 = 64;
+	/// Segments of a coin's holder snapshot, covering up to 64 * 1024 holders
+	pub const MaxHolderCheckpoints: u32 = 1024;
+	/// Locales a coin can be localized for
+	pub const MaxLocales: u32 = 16;
+	/// Maximum length of a coin description
+	pub const MaxDescriptionLength: u32 = 512;
+	/// Maximum length of a URI or external reference in extended coin metadata
+	pub const MaxUriLength: u32 = 256;
+	/// Accounts a fee operator configures per call
+	pub const MaxFeePreferenceBatch: u32 = 100;
+	/// Deposit per byte of optional coin metadata
+	pub const MetadataDepositPerByte: Balance = crate::MICRO_UNIT;
+	/// Weight budget of the multicoin transfer hook
+	pub const MaxTransferHookWeight: Weight = Weight::from_parts(50_000_000, 10_000);
 }
 
 /// Configure the multi-coin pallet
@@ -189,8 +242,70 @@ impl pallet_multicoin::Config for Runtime {
 	type MaxCoins = MaxCoins;
 	type CoinDeposit = CoinDeposit;
 	type MaxSupply = MaxCoinSupply;
+	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+	type FeeCoinAdmissionOrigin = frame_system::EnsureRoot<AccountId>;
+	type VoteLockingPeriod = VoteLockingPeriod;
+	type BridgeMintWindow = BridgeMintWindow;
+	type PalletId = MultiCoinPalletId;
+	type MaxSplitRecipients = MaxSplitRecipients;
+	type MaxBatchRecipients = MaxBatchRecipients;
+	type MaxInitialMinters = MaxInitialMinters;
+	type MaxInitialBurners = MaxInitialBurners;
+	type MaxPriceFeeders = MaxPriceFeeders;
+	type FeeShareTarget = FeeShareTarget;
+	type FeeMultiplierAdjustment = FeeMultiplierAdjustment;
+	type LiquidationAuctionDuration = LiquidationAuctionDuration;
+	type KeeperReward = KeeperReward;
+	type TransferHook = ();
+	type MaxTransferHookWeight = MaxTransferHookWeight;
+	type ExternalFeeCoin = pallet_proof_of_reserve::BridgeFeeCoin<Runtime>;
+	type FeeSponsor = pallet_proof_of_reserve::Pallet<Runtime>;
+	type ExternalWrappers = pallet_proof_of_reserve::BridgeWrapperCall<Runtime>;
+	type NativeFeeExistence = NativeFeeExistence;
+	type OnCoinFee = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type MaxHolds = VariantCountOf<RuntimeHoldReason>;
+	type MaxSupplyCheckpoints = MaxSupplyCheckpoints;
+	type SupplyCheckpointInterval = SupplyCheckpointInterval;
+	type MaxCleanupScan = MaxCleanupScan;
+	type MaxPauseExceptions = MaxPauseExceptions;
+	type VelocityEraLength = VelocityEraLength;
+	type MaxVelocityEras = MaxVelocityEras;
+	type RemoveItemsLimit = RemoveItemsLimit;
+	type Randomness = ParentHashRandomness;
+	type HolderCheckpointInterval = HolderCheckpointInterval;
+	type MaxHolderCheckpoints = MaxHolderCheckpoints;
+	type MaxLocales = MaxLocales;
+	type MaxDescriptionLength = MaxDescriptionLength;
+	type MaxUriLength = MaxUriLength;
+	type MaxFeePreferenceBatch = MaxFeePreferenceBatch;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
 }
 
+/// Randomness from the parent block hash and the subject.
+///
+/// Block authors can predict and, by withholding blocks, influence it, so it only
+/// suits draws where that is acceptable, such as small holder airdrops.
+pub struct ParentHashRandomness;
+
+impl frame_support::traits::Randomness<Hash, BlockNumber> for ParentHashRandomness {
+	fn random(subject: &[u8]) -> (Hash, BlockNumber) {
+		let block = System::block_number();
+		(BlakeTwo256::hash_of(&(System::parent_hash(), subject)), block.saturating_sub(1))
+	}
+}
+
+parameter_types! {
+	/// Account treasury spends are paid from
+	pub TreasuryAccount: AccountId = PalletId(*b"py/trsry").into_account_truncating();
+}
+
+/// `Paymaster` for treasury spends and bounty payouts in any multicoin coin
+pub type TreasuryPaymaster = pallet_multicoin::PayFromAccount<Runtime, TreasuryAccount>;
+
+/// Pause state of coins across multicoin and the bridge, for components trading coins such as a DEX
+pub type CoinPauses = (pallet_multicoin::Pallet<Runtime>, pallet_proof_of_reserve::Pallet<Runtime>);
+
 
 // Parameter types for the proof of reserve pallet
 parameter_types! {
@@ -202,6 +317,26 @@ parameter_types! {
     pub const MaxWalletLength: u32 = 128;
     /// Maximum length for coin name
     pub const MaxCoinNameLength: u32 = 32;
+    /// Transactions of a newly bridged account paid from the bridge fee pot
+    pub const FeeGraceTransactions: u32 = 3;
+    /// Confirmation delay of newly registered withdrawal addresses
+    pub const WithdrawalAddressDelay: BlockNumber = crate::DAYS;
+    /// Withdrawal addresses an account can register
+    pub const MaxWithdrawalAddresses: u32 = 16;
+    /// Keys in the signer set approving deposits by signature
+    pub const MaxApprovalSigners: u32 = 16;
+    /// Fee pot reward per withdrawal a registered processor completes
+    pub const ProcessorReward: Balance = 10 * crate::MILLI_UNIT;
+    /// Accounts a single deposit can be split across
+    pub const MaxDepositRecipients: u32 = 32;
+    /// Blocks per era of stored reserve reports
+    pub const ReserveReportEra: BlockNumber = crate::DAYS;
+    /// Coins covered by each era's reserve reports
+    pub const MaxReportedCoins: u32 = 64;
+    /// Weight a deposit's forwarded call may take
+    pub const MaxForwardWeight: Weight = Weight::from_parts(WEIGHT_REF_TIME_PER_SECOND / 100, 64 * 1024);
+    /// Custodian recorded on reserve attestations
+    pub ReserveCustodian: AccountId = ProofOfReservePalletId::get().into_account_truncating();
 }
 
 /// Configure the pallet-proof-of-reserve.
@@ -212,7 +347,66 @@ impl pallet_proof_of_reserve::Config for Runtime {
     type MaxTxIdLength = MaxTxIdLength;
     type MaxWalletLength = MaxWalletLength;
     type MaxCoinNameLength = MaxCoinNameLength;
-    type WeightInfo = ();
+    type FeeGraceTransactions = FeeGraceTransactions;
+    type PriceOracle = pallet_multicoin::Pallet<Runtime>;
+    type AdminOrigin = frame_system::EnsureRoot<AccountId>;
+    // This solochain has no XCM; parachain runtimes plug in `EnsureXcm` over their trusted reserves
+    type XcmDepositOrigin = frame_system::EnsureNever<AccountId>;
+    type ApprovalSignature = sp_core::sr25519::Signature;
+    type ApprovalSigner = sp_core::sr25519::Public;
+    type MaxApprovalSigners = MaxApprovalSigners;
+    type WithdrawalAddressDelay = WithdrawalAddressDelay;
+    type MaxWithdrawalAddresses = MaxWithdrawalAddresses;
+    type ProcessorReward = ProcessorReward;
+    type MaxDepositRecipients = MaxDepositRecipients;
+    // Attested by root on behalf of the custody account; no light client yet, so self-reported only
+    type CustodianOrigin = frame_system::EnsureRootWithSuccess<AccountId, ReserveCustodian>;
+    type ReserveProofVerifier = ();
+    // No chain adapters yet: addresses go unchecked and validators settle every deposit
+    type ChainAdapters = ();
+    type CoinSupply = pallet_multicoin::Pallet<Runtime>;
+    type ReportEraLength = ReserveReportEra;
+    type MaxReportedCoins = MaxReportedCoins;
+    // No staking or liquidity pallets yet to forward deposits to
+    type ForwardCallFilter = frame_support::traits::Nothing;
+    type MaxForwardWeight = MaxForwardWeight;
+    type WeightInfo = pallet_proof_of_reserve::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ProofOfReserveBenchmarkHelper;
+}
+
+/// Benchmark inputs of the bridge: `BTC` without a chain adapter or light client,
+/// approval keys from the benchmark keystore and no calls to forward deposits to
+#[cfg(feature = "runtime-benchmarks")]
+pub struct ProofOfReserveBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_proof_of_reserve::BenchmarkHelper<sp_core::sr25519::Public, sp_core::sr25519::Signature, RuntimeCall>
+    for ProofOfReserveBenchmarkHelper
+{
+    fn coin() -> (alloc::vec::Vec<u8>, alloc::vec::Vec<u8>) {
+        (b"BTC".to_vec(), b"bc1qbenchmark".to_vec())
+    }
+
+    fn sign_approval(index: u32, payload: &[u8]) -> (sp_core::sr25519::Public, sp_core::sr25519::Signature) {
+        let key_type = sp_core::crypto::KeyTypeId(*b"pors");
+        let seed = alloc::format!("//approval{index}").into_bytes();
+        let signer = sp_io::crypto::sr25519_generate(key_type, Some(seed));
+        let signature = sp_io::crypto::sr25519_sign(key_type, &signer, payload).expect("key was just generated");
+        (signer, signature)
+    }
+
+    fn reserve_proof(_external_balance: u128, _len: u32) -> Option<alloc::vec::Vec<u8>> {
+        None
+    }
+
+    fn tx_proof(_tx_id: &[u8], _len: u32) -> Option<alloc::vec::Vec<u8>> {
+        None
+    }
+
+    fn forward_call() -> Option<RuntimeCall> {
+        None
+    }
 }
 
 /*impl sp_runtime::traits::SignedExtension for CoinSelection {
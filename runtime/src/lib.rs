@@ -71,7 +71,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 101,
 	impl_version: 1,
 	apis: apis::RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -175,7 +175,13 @@ pub type SignedPayload = generic::SignedPayload<RuntimeCall, TxExtension>;
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+	pallet_multicoin::migrations::v2::MigrateV1ToV2<Runtime>,
+	pallet_multicoin::migrations::v3::MigrateV2ToV3<Runtime>,
+	pallet_multicoin::migrations::v4::MigrateV3ToV4<Runtime>,
+	pallet_multicoin::migrations::v5::MigrateV4ToV5<Runtime>,
+	pallet_multicoin::migrations::v6::MigrateV5ToV6<Runtime>,
+);
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<